@@ -59,6 +59,26 @@ int32_t Glucose_Solve(Glucose::Solver* solver) {
     return solver->solve();
 }
 
+int32_t Glucose_SolveLimited(Glucose::Solver* solver, const int32_t* assumption_lits, int32_t n_assumptions, int64_t conflict_budget) {
+    Glucose::vec<Glucose::Lit> assumptions;
+    for (int i = 0; i < n_assumptions; ++i) {
+        assumptions.push(Glucose::Lit{assumption_lits[i]});
+    }
+    if (conflict_budget >= 0) {
+        solver->setConfBudget(conflict_budget);
+    }
+    Glucose::lbool res = solver->solveLimited(assumptions);
+    solver->budgetOff();
+
+    if (res == l_True) {
+        return 1;
+    } else if (res == l_False) {
+        return 0;
+    } else {
+        return -1;
+    }
+}
+
 int32_t Glucose_NumVar(const Glucose::Solver* solver) {
     return solver->nVars();
 }
@@ -28,6 +28,10 @@ impl Solver {
         self.num_vars
     }
 
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
     pub fn all_vars(&self) -> Vec<Var> {
         (0..self.num_var()).map(|i| Var(i)).collect()
     }
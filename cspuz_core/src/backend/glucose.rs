@@ -16,6 +16,12 @@ extern "C-unwind" {
     fn Glucose_NewNamedVar(solver: *mut Opaque, name: *const c_char) -> i32;
     fn Glucose_AddClause(solver: *mut Opaque, lits: *const Lit, n_lits: i32) -> i32;
     fn Glucose_Solve(solver: *mut Opaque) -> i32;
+    fn Glucose_SolveLimited(
+        solver: *mut Opaque,
+        assumptions: *const Lit,
+        n_assumptions: i32,
+        conflict_budget: i64,
+    ) -> i32;
     fn Glucose_NumVar(solver: *mut Opaque) -> i32;
     fn Glucose_GetModelValueVar(solver: *mut Opaque, var: i32) -> i32;
     fn Glucose_AddOrderEncodingLinear(
@@ -64,6 +70,7 @@ pub struct Solver {
     ptr: *mut Opaque,
     custom_constraints: Vec<Box<Box<dyn CustomPropagator>>>,
     order_encoding_linear_mode: OrderEncodingLinearMode,
+    num_clauses: usize,
 }
 
 const NUM_VAR_MAX: i32 = 0x3fffffff;
@@ -74,6 +81,7 @@ impl Solver {
             ptr: unsafe { Glucose_CreateSolver() },
             custom_constraints: vec![],
             order_encoding_linear_mode: OrderEncodingLinearMode::Cpp,
+            num_clauses: 0,
         }
     }
 
@@ -98,8 +106,14 @@ impl Solver {
         (0..self.num_var()).map(|i| Var(i)).collect()
     }
 
+    /// Returns the number of clauses added by `add_clause`.
+    pub fn num_clauses(&self) -> usize {
+        self.num_clauses
+    }
+
     pub fn add_clause(&mut self, clause: &[Lit]) -> bool {
         assert!(clause.len() <= i32::max_value() as usize);
+        self.num_clauses += 1;
         let res = unsafe { Glucose_AddClause(self.ptr, clause.as_ptr(), clause.len() as i32) };
         res != 0
     }
@@ -315,6 +329,31 @@ impl Solver {
         res != 0
     }
 
+    /// Solves the problem under `assumptions`, with at most `conflict_budget` more conflicts
+    /// (or without limit if `None`).
+    /// Returns `None` if the budget is exhausted before the satisfiability is determined.
+    /// Learnt clauses are kept, so calling this again resumes the search.
+    pub fn solve_without_model_limited(
+        &mut self,
+        assumptions: &[Lit],
+        conflict_budget: Option<u64>,
+    ) -> Option<bool> {
+        assert!(assumptions.len() <= i32::max_value() as usize);
+        let budget = conflict_budget.map_or(-1, |b| b.min(i64::MAX as u64) as i64);
+        match unsafe {
+            Glucose_SolveLimited(
+                self.ptr,
+                assumptions.as_ptr(),
+                assumptions.len() as i32,
+                budget,
+            )
+        } {
+            1 => Some(true),
+            0 => Some(false),
+            _ => None,
+        }
+    }
+
     pub(crate) unsafe fn model<'a>(&'a self) -> Model<'a> {
         Model { solver: self }
     }
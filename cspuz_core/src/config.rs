@@ -1,6 +1,6 @@
 use crate::sat::{Backend, OrderEncodingLinearMode};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Config {
     pub use_constant_folding: bool,
     pub use_constant_propagation: bool,
@@ -20,6 +20,7 @@ pub struct Config {
     pub dump_analysis_info: bool,
     pub backend: Backend,
     pub order_encoding_linear_mode: OrderEncodingLinearMode,
+    pub anytime_irrefutable_facts: bool,
     pub verbose: bool,
 }
 
@@ -56,6 +57,7 @@ impl Config {
             dump_analysis_info: false,
             backend: Backend::Glucose,
             order_encoding_linear_mode: OrderEncodingLinearMode::Cpp,
+            anytime_irrefutable_facts: false,
             verbose: false,
         }
     }
@@ -33,18 +33,15 @@ impl BoolVarData {
         }
     }
 
-    #[allow(dead_code)]
     fn is_feasible(&self, b: bool) -> bool {
         (self.possibility_mask & (if b { 2 } else { 1 })) != 0
     }
 
-    #[allow(dead_code)]
     fn is_unsatisfiable(&self) -> bool {
         self.possibility_mask == 0
     }
 
-    #[allow(dead_code)]
-    fn set_infeasible(&mut self, b: bool) -> UpdateStatus {
+    pub(super) fn set_infeasible(&mut self, b: bool) -> UpdateStatus {
         let res = self.is_feasible(b);
         self.possibility_mask &= if b { 1 } else { 2 };
         if res {
@@ -521,6 +518,10 @@ impl Assignment {
         self.int_val.get(&var).copied()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.bool_val.is_empty() && self.int_val.is_empty()
+    }
+
     pub fn remove_bool(&mut self, var: BoolVar) -> Option<bool> {
         self.bool_val.remove(&var)
     }
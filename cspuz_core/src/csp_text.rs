@@ -0,0 +1,914 @@
+//! Human-readable text format of `CSP`, for inspecting the models built by puzzle solvers.
+//!
+//! `CSP::dump` writes the variables and the constraints in an algebraic syntax, one per line:
+//!
+//! ```text
+//! bool b0
+//! int i0 : 0..5
+//! int i1 : {1, 3, 5}
+//! b0 || i0 + 2 * i1 <= 5
+//! alldifferent(i0, i1)
+//! ```
+//!
+//! and `CSP::parse_dump` reads it back. Variables are named after their kind and index (`b0`,
+//! `i0`, ...) and are declared in the order of their indices, so that the variables of the parsed
+//! CSP are numbered the same as the original ones. Custom constraints cannot be written in this
+//! format, and are dumped as comments (lines starting with `#`).
+//!
+//! Operators, from the lowest precedence: `<=>`, `=>` (right-associative), `||`, `&&`, `^`,
+//! comparisons (`==`, `!=`, `<=`, `<`, `>=`, `>`), `+` and `-`, `*`, and the unary `!` and `-`.
+//! `if(c, t, f)` and `abs(x)` are integer expressions. Other constraints are written as
+//! `alldifferent(x, ...)`, `circuit(i0, ...)`, `extension_supports([i0, ...], [(1, *), ...])`,
+//! `active_vertices_connected([b0, ...], [(0, 1), ...])` and
+//! `graph_division([i0, _, ...], [(0, 1), ...], [b0, ...])`, where `*` and `_` denote `None`.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::arithmetic::CmpOp;
+use crate::csp::{BoolExpr, BoolVar, BoolVarStatus, IntExpr, IntVar, Stmt, CSP};
+use crate::domain::Domain;
+use crate::util::ConvertMapIndex;
+
+const PREC_IFF: u8 = 1;
+const PREC_IMP: u8 = 2;
+const PREC_OR: u8 = 3;
+const PREC_AND: u8 = 4;
+const PREC_XOR: u8 = 5;
+const PREC_CMP: u8 = 6;
+const PREC_ADD: u8 = 7;
+const PREC_MUL: u8 = 8;
+/// Unary operators and atoms.
+const PREC_UNARY: u8 = 9;
+
+/// Text of an expression together with the precedence of its outermost operator.
+type Text = (u8, String);
+
+/// Returns `text` as an operand of an operator requiring precedence at least `min_prec`.
+fn operand((prec, text): Text, min_prec: u8) -> String {
+    if prec < min_prec {
+        format!("({})", text)
+    } else {
+        text
+    }
+}
+
+fn join<T>(items: &[T], f: impl Fn(&T) -> String) -> String {
+    items.iter().map(f).collect::<Vec<_>>().join(", ")
+}
+
+fn edges_text(edges: &[(usize, usize)]) -> String {
+    join(edges, |&(u, v)| format!("({}, {})", u, v))
+}
+
+fn bool_var_name(var: BoolVar) -> String {
+    format!("b{}", var.to_index())
+}
+
+fn int_var_name(var: IntVar) -> String {
+    format!("i{}", var.to_index())
+}
+
+fn bool_text(expr: &BoolExpr) -> Text {
+    let nary = |exprs: &[Box<BoolExpr>], op: &str, prec: u8, empty: bool| match exprs {
+        [] => (PREC_UNARY, empty.to_string()),
+        [e] => bool_text(e),
+        _ => {
+            let operands = exprs
+                .iter()
+                .map(|e| operand(bool_text(e), prec + 1))
+                .collect::<Vec<_>>();
+            (prec, operands.join(op))
+        }
+    };
+    let binary = |e1: &BoolExpr, op: &str, e2: &BoolExpr, prec: u8, left: u8, right: u8| {
+        let text = format!(
+            "{}{}{}",
+            operand(bool_text(e1), left),
+            op,
+            operand(bool_text(e2), right)
+        );
+        (prec, text)
+    };
+
+    match expr {
+        BoolExpr::Const(b) => (PREC_UNARY, b.to_string()),
+        &BoolExpr::Var(v) => (PREC_UNARY, bool_var_name(v)),
+        BoolExpr::NVar(v) => (PREC_UNARY, format!("nb{}", v.id())),
+        BoolExpr::And(exprs) => nary(exprs, " && ", PREC_AND, true),
+        BoolExpr::Or(exprs) => nary(exprs, " || ", PREC_OR, false),
+        BoolExpr::Not(e) => (
+            PREC_UNARY,
+            format!("!{}", operand(bool_text(e), PREC_UNARY)),
+        ),
+        BoolExpr::Xor(e1, e2) => binary(e1, " ^ ", e2, PREC_XOR, PREC_XOR, PREC_XOR + 1),
+        BoolExpr::Imp(e1, e2) => binary(e1, " => ", e2, PREC_IMP, PREC_IMP + 1, PREC_IMP),
+        BoolExpr::Iff(e1, e2) => binary(e1, " <=> ", e2, PREC_IFF, PREC_IMP, PREC_IMP),
+        BoolExpr::Cmp(op, e1, e2) => {
+            let text = format!(
+                "{} {} {}",
+                operand(int_text(e1), PREC_ADD),
+                op,
+                operand(int_text(e2), PREC_ADD)
+            );
+            (PREC_CMP, text)
+        }
+    }
+}
+
+/// Text of the term `coef * expr` of a linear expression.
+fn term_text(expr: &IntExpr, coef: i32) -> Text {
+    match coef {
+        1 => {
+            let (prec, text) = int_text(expr);
+            if prec < PREC_MUL {
+                (PREC_UNARY, format!("({})", text))
+            } else {
+                (prec, text)
+            }
+        }
+        -1 => (
+            PREC_UNARY,
+            format!("-{}", operand(int_text(expr), PREC_UNARY)),
+        ),
+        _ => (
+            PREC_MUL,
+            format!("{} * {}", coef, operand(int_text(expr), PREC_UNARY)),
+        ),
+    }
+}
+
+/// Returns `(e, c)` if `expr` is written as the term `c * e`.
+fn as_term(expr: &IntExpr) -> Option<(&IntExpr, i32)> {
+    match expr {
+        IntExpr::Linear(terms) if terms.len() == 1 => Some((&terms[0].0, terms[0].1)),
+        IntExpr::Mul(e1, e2) => match **e1 {
+            IntExpr::Const(c) => Some((e2, c)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Expands nested terms in the term `coef * expr`, since `2 * (3 * x)` is written as `6 * x`.
+fn flatten_term(mut expr: &IntExpr, mut coef: i32) -> (&IntExpr, i32) {
+    while let Some((inner, inner_coef)) = as_term(expr) {
+        match coef.checked_mul(inner_coef) {
+            Some(c) => {
+                expr = inner;
+                coef = c;
+            }
+            None => break,
+        }
+    }
+    (expr, coef)
+}
+
+fn linear_text(terms: &[(&IntExpr, i32)]) -> Text {
+    match terms {
+        [] => (PREC_UNARY, "0".to_string()),
+        &[(e, 1)] => int_text(e),
+        &[(e, c)] => term_text(e, c),
+        _ => {
+            let mut text = String::new();
+            for (i, &(e, c)) in terms.iter().enumerate() {
+                if i == 0 {
+                    text.push_str(&term_text(e, c).1);
+                } else if c < 0 && c != i32::MIN {
+                    text.push_str(" - ");
+                    text.push_str(&operand(term_text(e, -c), PREC_MUL));
+                } else {
+                    text.push_str(" + ");
+                    text.push_str(&operand(term_text(e, c), PREC_MUL));
+                }
+            }
+            (PREC_ADD, text)
+        }
+    }
+}
+
+fn int_text(expr: &IntExpr) -> Text {
+    match expr {
+        IntExpr::Const(c) => (PREC_UNARY, c.to_string()),
+        &IntExpr::Var(v) => (PREC_UNARY, int_var_name(v)),
+        IntExpr::NVar(v) => (PREC_UNARY, format!("ni{}", v.id())),
+        IntExpr::Linear(terms) => linear_text(
+            &terms
+                .iter()
+                .map(|(e, c)| flatten_term(e, *c))
+                .collect::<Vec<_>>(),
+        ),
+        IntExpr::If(c, t, f) => (
+            PREC_UNARY,
+            format!(
+                "if({}, {}, {})",
+                bool_text(c).1,
+                int_text(t).1,
+                int_text(f).1
+            ),
+        ),
+        IntExpr::Abs(e) => (PREC_UNARY, format!("abs({})", int_text(e).1)),
+        IntExpr::Mul(_, _) if as_term(expr).is_some() => linear_text(&[flatten_term(expr, 1)]),
+        IntExpr::Mul(e1, e2) => (
+            PREC_MUL,
+            format!(
+                "{} * {}",
+                operand(int_text(e1), PREC_MUL),
+                operand(int_text(e2), PREC_UNARY)
+            ),
+        ),
+    }
+}
+
+fn stmt_text(stmt: &Stmt) -> String {
+    match stmt {
+        Stmt::Expr(e) => bool_text(e).1,
+        Stmt::AllDifferent(exprs) => {
+            format!("alldifferent({})", join(exprs, |e| int_text(e).1))
+        }
+        Stmt::ActiveVerticesConnected(exprs, edges) => format!(
+            "active_vertices_connected([{}], [{}])",
+            join(exprs, |e| bool_text(e).1),
+            edges_text(edges)
+        ),
+        Stmt::Circuit(vars) => format!("circuit({})", join(vars, |&v| int_var_name(v))),
+        Stmt::ExtensionSupports(vars, supports) => format!(
+            "extension_supports([{}], [{}])",
+            join(vars, |&v| int_var_name(v)),
+            join(supports, |support| format!(
+                "({})",
+                join(support, |x| x.map_or("*".to_string(), |x| x.to_string()))
+            ))
+        ),
+        Stmt::GraphDivision(sizes, edges, edge_lits) => format!(
+            "graph_division([{}], [{}], [{}])",
+            join(sizes, |e| e
+                .as_ref()
+                .map_or("_".to_string(), |e| int_text(e).1)),
+            edges_text(edges),
+            join(edge_lits, |e| bool_text(e).1)
+        ),
+        Stmt::CustomConstraint(exprs, _) => {
+            format!("# custom_constraint({})", join(exprs, |e| bool_text(e).1))
+        }
+    }
+}
+
+fn domain_text(domain: &Domain) -> String {
+    match domain {
+        Domain::Range(low, high) if low <= high => format!("{}..{}", low.get(), high.get()),
+        Domain::Range(_, _) => "{}".to_string(),
+        Domain::Enumerative(cands) => format!("{{{}}}", join(cands, |c| c.get().to_string())),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Token {
+    Ident(String),
+    /// Non-negative integer literal. It is wider than `i32` so that `-2147483648` can be read.
+    Int(i64),
+    Sym(&'static str),
+}
+
+/// Symbols, longer ones first so that they are preferred over their prefixes.
+const SYMBOLS: &[&str] = &[
+    "<=>", "..", "&&", "||", "=>", "==", "!=", "<=", ">=", "<", ">", "!", "^", "+", "-", "*", "(",
+    ")", "[", "]", "{", "}", ",", ":", "_",
+];
+
+const KEYWORDS: &[&str] = &["bool", "int", "true", "false", "if", "abs"];
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    let mut tokens = vec![];
+    let mut rest = line.trim_start();
+    while !rest.is_empty() && !rest.starts_with('#') {
+        let c = rest.chars().next().unwrap();
+        let len = if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let value = rest[..len]
+                .parse()
+                .map_err(|_| format!("too large integer: {}", &rest[..len]))?;
+            tokens.push(Token::Int(value));
+            len
+        } else if c.is_ascii_alphabetic() || (c == '_' && rest[1..].starts_with(is_ident_char)) {
+            let len = rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..len].to_string()));
+            len
+        } else if let Some(sym) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            tokens.push(Token::Sym(sym));
+            sym.len()
+        } else {
+            return Err(format!("unexpected character: {}", c));
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Copy)]
+enum Var {
+    Bool(BoolVar),
+    Int(IntVar),
+}
+
+enum Value {
+    Bool(BoolExpr),
+    Int(IntExpr),
+}
+
+impl Value {
+    fn into_bool(self) -> Result<BoolExpr, String> {
+        match self {
+            Value::Bool(e) => Ok(e),
+            Value::Int(_) => Err("boolean expression expected".to_string()),
+        }
+    }
+
+    fn into_int(self) -> Result<IntExpr, String> {
+        match self {
+            Value::Int(e) => Ok(e),
+            Value::Bool(_) => Err("integer expression expected".to_string()),
+        }
+    }
+}
+
+/// Parser of a line of the text format.
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    vars: &'a HashMap<String, Var>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or("unexpected end of line")?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, sym: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Sym(s)) if *s == sym) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, sym: &str) -> Result<(), String> {
+        if self.eat(sym) {
+            Ok(())
+        } else {
+            Err(format!("`{}` expected", sym))
+        }
+    }
+
+    fn expect_end(&self) -> Result<(), String> {
+        match self.peek() {
+            None => Ok(()),
+            Some(token) => Err(format!("unexpected token: {:?}", token)),
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Ident(name) => Ok(name),
+            token => Err(format!("identifier expected, found {:?}", token)),
+        }
+    }
+
+    /// Parses an integer literal, possibly with a leading `-`.
+    fn int_literal(&mut self) -> Result<i32, String> {
+        let negative = self.eat("-");
+        match self.next()? {
+            Token::Int(n) => {
+                let n = if negative { -n } else { n };
+                i32::try_from(n).map_err(|_| format!("too large integer: {}", n))
+            }
+            token => Err(format!("integer expected, found {:?}", token)),
+        }
+    }
+
+    /// Parses a comma-separated list of `item`s until `close`.
+    fn list<T>(
+        &mut self,
+        close: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let mut items = vec![];
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn bracket_list<T>(
+        &mut self,
+        item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        self.expect("[")?;
+        self.list("]", item)
+    }
+
+    fn edges(&mut self) -> Result<Vec<(usize, usize)>, String> {
+        self.bracket_list(|p| {
+            p.expect("(")?;
+            let u = p.int_literal()?;
+            p.expect(",")?;
+            let v = p.int_literal()?;
+            p.expect(")")?;
+            match (usize::try_from(u), usize::try_from(v)) {
+                (Ok(u), Ok(v)) => Ok((u, v)),
+                _ => Err("vertices must be non-negative".to_string()),
+            }
+        })
+    }
+
+    fn var(&mut self) -> Result<Var, String> {
+        let name = self.ident()?;
+        self.vars
+            .get(&name)
+            .copied()
+            .ok_or_else(|| format!("unknown variable: {}", name))
+    }
+
+    fn int_var(&mut self) -> Result<IntVar, String> {
+        match self.var()? {
+            Var::Int(v) => Ok(v),
+            Var::Bool(_) => Err("integer variable expected".to_string()),
+        }
+    }
+
+    fn bool_expr(&mut self) -> Result<BoolExpr, String> {
+        self.expr(0)?.into_bool()
+    }
+
+    fn int_expr(&mut self) -> Result<IntExpr, String> {
+        self.expr(0)?.into_int()
+    }
+
+    /// Parses an expression consisting of operators of precedence at least `min_prec`.
+    fn expr(&mut self, min_prec: u8) -> Result<Value, String> {
+        let mut lhs = self.unary()?;
+        while let Some(&Token::Sym(op)) = self.peek() {
+            let prec = match op {
+                "<=>" => PREC_IFF,
+                "=>" => PREC_IMP,
+                "||" => PREC_OR,
+                "^" => PREC_XOR,
+                "&&" => PREC_AND,
+                "==" | "!=" | "<=" | "<" | ">=" | ">" => PREC_CMP,
+                "+" | "-" => PREC_ADD,
+                "*" => PREC_MUL,
+                _ => break,
+            };
+            if prec < min_prec {
+                break;
+            }
+            self.pos += 1;
+
+            lhs = match op {
+                "<=>" => {
+                    let rhs = self.expr(PREC_IMP)?.into_bool()?;
+                    Value::Bool(lhs.into_bool()?.iff(rhs))
+                }
+                "=>" => {
+                    let rhs = self.expr(PREC_IMP)?.into_bool()?;
+                    Value::Bool(lhs.into_bool()?.imp(rhs))
+                }
+                "||" | "&&" => {
+                    let mut exprs = vec![Box::new(lhs.into_bool()?)];
+                    loop {
+                        exprs.push(Box::new(self.expr(prec + 1)?.into_bool()?));
+                        if !self.eat(op) {
+                            break;
+                        }
+                    }
+                    Value::Bool(if op == "||" {
+                        BoolExpr::Or(exprs)
+                    } else {
+                        BoolExpr::And(exprs)
+                    })
+                }
+                "^" => {
+                    let rhs = self.expr(PREC_XOR + 1)?.into_bool()?;
+                    Value::Bool(lhs.into_bool()? ^ rhs)
+                }
+                "+" | "-" => {
+                    let mut terms = vec![linear_term(lhs.into_int()?, 1)];
+                    let mut sign = if op == "+" { 1 } else { -1 };
+                    loop {
+                        terms.push(linear_term(self.expr(PREC_MUL)?.into_int()?, sign));
+                        sign = if self.eat("+") {
+                            1
+                        } else if self.eat("-") {
+                            -1
+                        } else {
+                            break;
+                        };
+                    }
+                    Value::Int(IntExpr::Linear(terms))
+                }
+                "*" => {
+                    let rhs = self.expr(PREC_UNARY)?.into_int()?;
+                    Value::Int(match lhs.into_int()? {
+                        IntExpr::Const(coef) => IntExpr::Linear(vec![(Box::new(rhs), coef)]),
+                        lhs => lhs * rhs,
+                    })
+                }
+                _ => {
+                    let op = match op {
+                        "==" => CmpOp::Eq,
+                        "!=" => CmpOp::Ne,
+                        "<=" => CmpOp::Le,
+                        "<" => CmpOp::Lt,
+                        ">=" => CmpOp::Ge,
+                        ">" => CmpOp::Gt,
+                        _ => unreachable!(),
+                    };
+                    let rhs = self.expr(PREC_ADD)?.into_int()?;
+                    Value::Bool(BoolExpr::Cmp(op, Box::new(lhs.into_int()?), Box::new(rhs)))
+                }
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Value, String> {
+        match self.next()? {
+            Token::Sym("!") => Ok(Value::Bool(!self.unary()?.into_bool()?)),
+            Token::Sym("-") => {
+                if let Some(&Token::Int(_)) = self.peek() {
+                    self.pos -= 1;
+                    return Ok(Value::Int(IntExpr::Const(self.int_literal()?)));
+                }
+                let expr = self.unary()?.into_int()?;
+                Ok(Value::Int(IntExpr::Linear(vec![(Box::new(expr), -1)])))
+            }
+            Token::Sym("(") => {
+                let value = self.expr(0)?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Token::Int(n) => i32::try_from(n)
+                .map(|n| Value::Int(IntExpr::Const(n)))
+                .map_err(|_| format!("too large integer: {}", n)),
+            Token::Ident(name) => match name.as_str() {
+                "true" => Ok(Value::Bool(BoolExpr::Const(true))),
+                "false" => Ok(Value::Bool(BoolExpr::Const(false))),
+                "if" => {
+                    self.expect("(")?;
+                    let cond = self.bool_expr()?;
+                    self.expect(",")?;
+                    let t = self.int_expr()?;
+                    self.expect(",")?;
+                    let f = self.int_expr()?;
+                    self.expect(")")?;
+                    Ok(Value::Int(cond.ite(t, f)))
+                }
+                "abs" => {
+                    self.expect("(")?;
+                    let expr = self.int_expr()?;
+                    self.expect(")")?;
+                    Ok(Value::Int(expr.abs()))
+                }
+                _ => match self.vars.get(&name) {
+                    Some(&Var::Bool(v)) => Ok(Value::Bool(v.expr())),
+                    Some(&Var::Int(v)) => Ok(Value::Int(v.expr())),
+                    None => Err(format!("unknown variable: {}", name)),
+                },
+            },
+            token => Err(format!("unexpected token: {:?}", token)),
+        }
+    }
+
+    /// Parses a constraint, which occupies the whole line.
+    fn stmt(&mut self) -> Result<Stmt, String> {
+        let name = match (self.tokens.first(), self.tokens.get(1)) {
+            (Some(Token::Ident(name)), Some(Token::Sym("("))) => name.clone(),
+            _ => String::new(),
+        };
+        let stmt = match name.as_str() {
+            "alldifferent" => {
+                self.pos = 2;
+                Stmt::AllDifferent(self.list(")", Self::int_expr)?)
+            }
+            "circuit" => {
+                self.pos = 2;
+                Stmt::Circuit(self.list(")", Self::int_var)?)
+            }
+            "extension_supports" => {
+                self.pos = 2;
+                let vars = self.bracket_list(Self::int_var)?;
+                self.expect(",")?;
+                let supports = self.bracket_list(|p| {
+                    p.expect("(")?;
+                    p.list(")", |p| {
+                        if p.eat("*") {
+                            Ok(None)
+                        } else {
+                            p.int_literal().map(Some)
+                        }
+                    })
+                })?;
+                self.expect(")")?;
+                Stmt::ExtensionSupports(vars, supports)
+            }
+            "active_vertices_connected" => {
+                self.pos = 2;
+                let exprs = self.bracket_list(Self::bool_expr)?;
+                self.expect(",")?;
+                let edges = self.edges()?;
+                self.expect(")")?;
+                Stmt::ActiveVerticesConnected(exprs, edges)
+            }
+            "graph_division" => {
+                self.pos = 2;
+                let sizes = self.bracket_list(|p| {
+                    if p.eat("_") {
+                        Ok(None)
+                    } else {
+                        p.int_expr().map(Some)
+                    }
+                })?;
+                self.expect(",")?;
+                let edges = self.edges()?;
+                self.expect(",")?;
+                let edge_lits = self.bracket_list(Self::bool_expr)?;
+                self.expect(")")?;
+                Stmt::GraphDivision(sizes, edges, edge_lits)
+            }
+            _ => Stmt::Expr(self.bool_expr()?),
+        };
+        self.expect_end()?;
+        Ok(stmt)
+    }
+}
+
+/// Returns the term `sign * expr` of a linear expression, expanding `expr` if it is a linear
+/// expression of a single term (e.g. `2 * x`).
+fn linear_term(expr: IntExpr, sign: i32) -> (Box<IntExpr>, i32) {
+    if let IntExpr::Linear(mut terms) = expr {
+        if terms.len() == 1 {
+            if let Some(coef) = terms[0].1.checked_mul(sign) {
+                return (terms.remove(0).0, coef);
+            }
+        }
+        return (Box::new(IntExpr::Linear(terms)), sign);
+    }
+    (Box::new(expr), sign)
+}
+
+impl CSP {
+    /// Returns the variables and the constraints of this CSP in the text format described in
+    /// the `csp_text` module. Constraints which have already been passed to the normalizer (by
+    /// solving with an `IntegratedSolver`) are not included.
+    pub fn dump(&self) -> String {
+        let mut ret = String::new();
+        for var in self.vars.bool_vars_iter() {
+            let status = match self.vars[var].get_status() {
+                BoolVarStatus::Unfixed => String::new(),
+                BoolVarStatus::Fixed(b) => format!(" : {}", b),
+                BoolVarStatus::Infeasible => " : {}".to_string(),
+            };
+            ret.push_str(&format!("bool {}{}\n", bool_var_name(var), status));
+        }
+        for var in self.vars.int_vars_iter() {
+            ret.push_str(&format!(
+                "int {} : {}\n",
+                int_var_name(var),
+                domain_text(&self.vars.int_var(var).domain)
+            ));
+        }
+        if self.is_inconsistent() {
+            ret.push_str("false\n");
+        }
+        for stmt in &self.constraints {
+            ret.push_str(&stmt_text(stmt));
+            ret.push('\n');
+        }
+        ret
+    }
+
+    /// Reads a CSP written in the text format described in the `csp_text` module. Errors are
+    /// reported with the line number (starting from 1).
+    pub fn parse_dump(text: &str) -> Result<CSP, String> {
+        let mut csp = CSP::new();
+        let mut vars = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            parse_line(&mut csp, &mut vars, line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        }
+        Ok(csp)
+    }
+}
+
+fn parse_line(csp: &mut CSP, vars: &mut HashMap<String, Var>, line: &str) -> Result<(), String> {
+    let tokens = tokenize(line)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        vars,
+    };
+    let decl = match parser.peek() {
+        None => return Ok(()),
+        Some(Token::Ident(kind)) if kind == "bool" || kind == "int" => kind.clone(),
+        _ => {
+            let stmt = parser.stmt()?;
+            csp.add_constraint(stmt);
+            return Ok(());
+        }
+    };
+
+    parser.pos = 1;
+    let name = parser.ident()?;
+    if KEYWORDS.contains(&name.as_str()) {
+        return Err(format!("reserved name: {}", name));
+    }
+    if parser.vars.contains_key(&name) {
+        return Err(format!("duplicate variable: {}", name));
+    }
+    let var = if decl == "bool" {
+        let var = csp.new_bool_var();
+        if parser.eat(":") {
+            let infeasible_values: &[bool] = match parser.next()? {
+                Token::Ident(b) if b == "true" => &[false],
+                Token::Ident(b) if b == "false" => &[true],
+                Token::Sym("{") => {
+                    parser.expect("}")?;
+                    &[false, true]
+                }
+                token => return Err(format!("unexpected token: {:?}", token)),
+            };
+            for &b in infeasible_values {
+                csp.vars[var].set_infeasible(b);
+            }
+        }
+        Var::Bool(var)
+    } else {
+        parser.expect(":")?;
+        let domain = if parser.eat("{") {
+            let mut values = parser.list("}", Parser::int_literal)?;
+            values.sort();
+            values.dedup();
+            Domain::enumerative(values)
+        } else {
+            let low = parser.int_literal()?;
+            parser.expect("..")?;
+            let high = parser.int_literal()?;
+            Domain::range(low, high)
+        };
+        Var::Int(csp.new_int_var(domain))
+    };
+    parser.expect_end()?;
+    vars.insert(name, var);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csp::Assignment;
+    use crate::integration::IntegratedSolver;
+    use crate::random_instance::{InstanceConfig, RandomInstance, Rng};
+
+    #[test]
+    fn test_dump() {
+        let mut csp = CSP::new();
+        let b = csp.new_bool_var().expr();
+        let x = csp.new_int_var(Domain::range(0, 5)).expr();
+        let y = csp.new_int_var(Domain::enumerative(vec![1, 3, 5])).expr();
+        csp.add_constraint(Stmt::Expr(
+            b.clone() | (x.clone() + y.clone() * 2).le(IntExpr::Const(5)),
+        ));
+        csp.add_constraint(Stmt::Expr(
+            (x.clone() - y.clone() * -3).ne(b.clone().ite(x.clone(), IntExpr::Const(-1))),
+        ));
+        csp.add_constraint(Stmt::Expr((!b.clone()).imp(b.clone() ^ (b.clone() & b))));
+        csp.add_constraint(Stmt::AllDifferent(vec![x.clone() * y, x.abs()]));
+        csp.add_constraint(Stmt::ActiveVerticesConnected(vec![], vec![(0, 1)]));
+
+        let expected = "bool b0
+int i0 : 0..5
+int i1 : {1, 3, 5}
+b0 || i0 + 2 * i1 <= 5
+i0 + 3 * i1 != if(b0, i0, -1)
+!b0 => b0 ^ (b0 && b0)
+alldifferent(i0 * i1, abs(i0))
+active_vertices_connected([], [(0, 1)])
+";
+        assert_eq!(csp.dump(), expected);
+        assert_eq!(CSP::parse_dump(expected).unwrap().dump(), expected);
+    }
+
+    #[test]
+    fn test_parse_dump() {
+        let text = "# comment
+bool x
+int n : -2..2  # trailing comment
+int m : {3, -1}
+
+alldifferent(n, m, 2 * (n - m))
+x <=> -(n + 1) >= -3 - m
+graph_division([n, _], [(0, 1)], [x])
+extension_supports([n, m], [(1, *), (2, 3)])
+";
+        let csp = CSP::parse_dump(text).unwrap();
+        assert_eq!(
+            csp.dump(),
+            "bool b0
+int i0 : -2..2
+int i1 : {-1, 3}
+alldifferent(i0, i1, 2 * (i0 - i1))
+b0 <=> -(i0 + 1) >= -3 - i1
+graph_division([i0, _], [(0, 1)], [b0])
+extension_supports([i0, i1], [(1, *), (2, 3)])
+"
+        );
+    }
+
+    #[test]
+    fn test_parse_dump_errors() {
+        let cases = [
+            ("int n : 0..3\nn +", "line 2: unexpected end of line"),
+            (
+                "int n : 0..3\nn || n",
+                "line 2: boolean expression expected",
+            ),
+            ("bool x\nx && y", "line 2: unknown variable: y"),
+            ("bool x\nint x : 0..1", "line 2: duplicate variable: x"),
+            ("bool if", "line 1: reserved name: if"),
+            ("bool x\nx x", "line 2: unexpected token: Ident(\"x\")"),
+        ];
+        for (text, message) in cases {
+            assert_eq!(CSP::parse_dump(text).err().as_deref(), Some(message));
+        }
+    }
+
+    fn is_satisfied(csp: &CSP, assignment: &Assignment) -> bool {
+        csp.constraints.iter().all(|stmt| match stmt {
+            Stmt::Expr(e) => assignment.eval_bool_expr(e),
+            Stmt::AllDifferent(exprs) => {
+                let mut values = exprs
+                    .iter()
+                    .map(|e| assignment.eval_int_expr(e))
+                    .collect::<Vec<_>>();
+                values.sort();
+                values.windows(2).all(|w| w[0] != w[1])
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn test_dump_roundtrip_random() {
+        let config = InstanceConfig {
+            min_value: -3,
+            max_depth: 4,
+            ..InstanceConfig::default()
+        };
+        for seed in 0..200 {
+            let mut solver = IntegratedSolver::new();
+            let instance = RandomInstance::generate(&mut solver, &config, seed);
+            let text = solver.dump();
+            let parsed =
+                CSP::parse_dump(&text).unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, text));
+            assert_eq!(parsed.dump(), text, "seed {}", seed);
+
+            let mut rng = Rng::new(seed);
+            for _ in 0..20 {
+                let mut assignment = Assignment::new();
+                for &var in &instance.bool_vars {
+                    assignment.set_bool(var, rng.chance(0.5));
+                }
+                for (var, values) in &instance.int_vars {
+                    assignment.set_int(*var, *rng.choose(values));
+                }
+                assert_eq!(
+                    is_satisfied(&parsed, &assignment),
+                    instance.is_satisfied(&assignment),
+                    "seed {}\n{}",
+                    seed,
+                    text
+                );
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
 use std::ops::Index;
 
 use super::config::Config;
+use super::encoding_cache::{CacheReader, CacheWriter};
 use super::norm_csp::{
     BoolLit, BoolVar, Constraint, ExtraConstraint, IntVar, IntVarRepresentation, LinearLit,
     LinearSum, NormCSP, NormCSPVars,
@@ -478,6 +479,96 @@ impl EncodeMap {
     pub fn get_int_value(&self, model: &SATModel, var: IntVar) -> Option<i32> {
         self.get_int_value_checked(model, var).map(CheckedInt::get)
     }
+
+    pub(crate) fn write_cache(&self, out: &mut CacheWriter) {
+        out.push_len(self.bool_map.len());
+        for &lit in self.bool_map.values() {
+            out.push_optional_lit(lit);
+        }
+
+        out.push_len(self.int_map.len());
+        for encoding in self.int_map.values() {
+            let encoding = match encoding {
+                Some(encoding) => encoding,
+                None => {
+                    out.push(-1);
+                    continue;
+                }
+            };
+            out.push(
+                encoding.order_encoding.is_some() as i32
+                    | (encoding.direct_encoding.is_some() as i32) << 1
+                    | (encoding.log_encoding.is_some() as i32) << 2,
+            );
+            if let Some(order_encoding) = &encoding.order_encoding {
+                out.push_checked_ints(&order_encoding.domain);
+                out.push_lits(&order_encoding.lits);
+            }
+            if let Some(direct_encoding) = &encoding.direct_encoding {
+                out.push_checked_ints(&direct_encoding.domain);
+                out.push_lits(&direct_encoding.lits);
+            }
+            if let Some(log_encoding) = &encoding.log_encoding {
+                out.push_lits(&log_encoding.lits);
+                out.push(log_encoding.range.low.get());
+                out.push(log_encoding.range.high.get());
+            }
+        }
+    }
+
+    /// Reads a map written by `write_cache`. Returns `None` if `input` is malformed.
+    pub(crate) fn read_cache(input: &mut CacheReader) -> Option<EncodeMap> {
+        let num_bool_vars = input.len()?;
+        let mut bool_map = vec![];
+        for _ in 0..num_bool_vars {
+            bool_map.push(input.optional_lit()?);
+        }
+
+        let num_int_vars = input.len()?;
+        let mut int_map = vec![];
+        for _ in 0..num_int_vars {
+            let flags = input.next()?;
+            if flags == -1 {
+                int_map.push(None);
+                continue;
+            }
+            if !(0..8).contains(&flags) {
+                return None;
+            }
+            let mut encoding = Encoding {
+                order_encoding: None,
+                direct_encoding: None,
+                log_encoding: None,
+            };
+            if flags & 1 != 0 {
+                encoding.order_encoding = Some(OrderEncoding {
+                    domain: input.checked_ints()?,
+                    lits: input.lits()?,
+                });
+            }
+            if flags & 2 != 0 {
+                encoding.direct_encoding = Some(DirectEncoding {
+                    domain: input.checked_ints()?,
+                    lits: input.lits()?,
+                });
+            }
+            if flags & 4 != 0 {
+                encoding.log_encoding = Some(LogEncoding {
+                    lits: input.lits()?,
+                    range: Range::new(
+                        CheckedInt::new(input.next()?),
+                        CheckedInt::new(input.next()?),
+                    ),
+                });
+            }
+            int_map.push(Some(encoding));
+        }
+
+        Some(EncodeMap {
+            bool_map: ConvertMap::from_values(bool_map),
+            int_map: ConvertMap::from_values(int_map),
+        })
+    }
 }
 
 struct EncoderEnv<'a, 'b, 'c, 'd> {
@@ -493,6 +584,7 @@ impl<'a, 'b, 'c, 'd> EncoderEnv<'a, 'b, 'c, 'd> {
     }
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 pub fn encode(norm: &mut NormCSP, sat: &mut SAT, map: &mut EncodeMap, config: &Config) {
     let new_vars = norm.unencoded_int_vars().collect::<Vec<_>>();
     let constrs = std::mem::replace(&mut norm.constraints, vec![]);
@@ -0,0 +1,645 @@
+//! Persistent cache of SAT encodings.
+//!
+//! Once a cache directory is set by `set_cache_dir` (or `IntegratedSolver::set_encoding_cache_dir`
+//! for a single solver), the first encoding done by each solver is stored in the directory: the
+//! clauses and native constraints given to the SAT solver, and the map from CSP variables to SAT
+//! literals. The file is keyed by a hash of the normalized CSP and the config, so that solving an
+//! identical problem later, even in another process, loads the encoding instead of encoding the
+//! CSP again. Problems with custom constraints are never cached.
+//!
+//! Cache files depend on the encoder of the version of cspuz_core which wrote them; the directory
+//! should be cleared when cspuz_core is updated.
+
+use std::convert::TryFrom;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+use crate::arithmetic::CheckedInt;
+use crate::config::Config;
+use crate::domain::Domain;
+use crate::encoder::{self, EncodeMap};
+use crate::norm_csp::{BoolLit, ExtraConstraint, IntVarRepresentation, NormCSP};
+use crate::sat::{self, Lit, SATOperation, SAT};
+
+const MAGIC: &[u8; 8] = b"CSPUZENC";
+
+/// Version of the format of cache files. This should be incremented whenever the format or the
+/// encoder changes, so that stale files are no longer used.
+const FORMAT_VERSION: i32 = 1;
+
+static CACHE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Sets the directory of the encoding cache used by solvers created afterwards on any thread.
+/// `None` (the initial value) disables the cache.
+pub fn set_cache_dir(dir: Option<PathBuf>) {
+    *CACHE_DIR.write().unwrap() = dir;
+}
+
+pub fn cache_dir() -> Option<PathBuf> {
+    CACHE_DIR.read().unwrap().clone()
+}
+
+/// 128-bit FNV-1a hash of a sequence of words. Text fed through `std::fmt::Write` is hashed
+/// byte by byte.
+struct Fnv128(u128);
+
+impl Fnv128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    fn new() -> Fnv128 {
+        Fnv128(Fnv128::OFFSET_BASIS)
+    }
+
+    fn word(&mut self, word: u64) {
+        self.0 ^= word as u128;
+        self.0 = self.0.wrapping_mul(Fnv128::PRIME);
+    }
+
+    fn int(&mut self, value: CheckedInt) {
+        self.word(value.get() as u32 as u64);
+    }
+
+    fn bool_lit(&mut self, lit: &BoolLit) {
+        self.word((lit.var.id() as u64) << 1 | lit.negated as u64);
+    }
+}
+
+impl std::fmt::Write for Fnv128 {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        for &b in s.as_bytes() {
+            self.word(b as u64);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the key of the encoding of `norm`, or `None` if it cannot be cached.
+fn cache_key(norm: &NormCSP, config: &Config) -> Option<u128> {
+    let mut hasher = Fnv128::new();
+    write!(hasher, "{} {:?}", FORMAT_VERSION, config).unwrap();
+
+    // Variables and constraints make up most of the CSP, so they are hashed without formatting.
+    hasher.word(norm.vars.bool_vars_iter().count() as u64);
+    for var in norm.vars.int_vars_iter() {
+        match norm.vars.int_var(var) {
+            IntVarRepresentation::Domain(Domain::Range(low, high)) => {
+                hasher.word(0);
+                hasher.int(*low);
+                hasher.int(*high);
+            }
+            IntVarRepresentation::Domain(Domain::Enumerative(values)) => {
+                hasher.word(1);
+                hasher.word(values.len() as u64);
+                values.iter().for_each(|&x| hasher.int(x));
+            }
+            IntVarRepresentation::Binary(cond, f, t) => {
+                hasher.word(2);
+                hasher.bool_lit(cond);
+                hasher.int(*f);
+                hasher.int(*t);
+            }
+        }
+    }
+    for constr in &norm.constraints {
+        hasher.word(constr.bool_lit.len() as u64);
+        constr.bool_lit.iter().for_each(|lit| hasher.bool_lit(lit));
+        hasher.word(constr.linear_lit.len() as u64);
+        for lit in &constr.linear_lit {
+            hasher.word(lit.sum.len() as u64);
+            for (var, &coef) in lit.sum.iter() {
+                hasher.word(var.id() as u64);
+                hasher.int(coef);
+            }
+            hasher.int(lit.sum.constant);
+            hasher.word(lit.op as u64);
+        }
+    }
+    for constr in &norm.extra_constraints {
+        match constr {
+            ExtraConstraint::ActiveVerticesConnected(lits, edges) => {
+                write!(hasher, "ActiveVerticesConnected({:?}, {:?})", lits, edges)
+            }
+            ExtraConstraint::Mul(x, y, m) => write!(hasher, "Mul({:?}, {:?}, {:?})", x, y, m),
+            ExtraConstraint::ExtensionSupports(vars, supports) => {
+                write!(hasher, "ExtensionSupports({:?}, {:?})", vars, supports)
+            }
+            ExtraConstraint::GraphDivision(sizes, edges, lits) => {
+                write!(
+                    hasher,
+                    "GraphDivision({:?}, {:?}, {:?})",
+                    sizes, edges, lits
+                )
+            }
+            ExtraConstraint::CustomConstraint(_, _) => return None,
+        }
+        .unwrap();
+    }
+    write!(hasher, "{:?}", norm.prenormalize_vars).unwrap();
+    Some(hasher.0)
+}
+
+/// Writer of the contents of cache files, which consist of 32-bit integers.
+pub(crate) struct CacheWriter {
+    words: Vec<i32>,
+}
+
+impl CacheWriter {
+    fn new() -> CacheWriter {
+        CacheWriter { words: vec![] }
+    }
+
+    pub(crate) fn push(&mut self, value: i32) {
+        self.words.push(value);
+    }
+
+    pub(crate) fn push_len(&mut self, len: usize) {
+        self.push(len as i32);
+    }
+
+    pub(crate) fn push_ints(&mut self, values: &[i32]) {
+        self.push_len(values.len());
+        self.words.extend_from_slice(values);
+    }
+
+    pub(crate) fn push_checked_ints(&mut self, values: &[CheckedInt]) {
+        self.push_len(values.len());
+        self.words.extend(values.iter().map(|x| x.get()));
+    }
+
+    pub(crate) fn push_lits(&mut self, lits: &[Lit]) {
+        self.push_len(lits.len());
+        self.words.extend(lits.iter().map(|lit| lit.0));
+    }
+
+    pub(crate) fn push_optional_lit(&mut self, lit: Option<Lit>) {
+        self.push(lit.map_or(-1, |lit| lit.0));
+    }
+
+    fn push_edges(&mut self, edges: &[(usize, usize)]) {
+        self.push_len(edges.len());
+        for &(u, v) in edges {
+            self.push_len(u);
+            self.push_len(v);
+        }
+    }
+}
+
+/// Reader of the contents of cache files. All methods return `None` if the contents are
+/// malformed, in particular if a literal refers to a variable not in the cached encoding.
+#[derive(Clone, Copy)]
+pub(crate) struct CacheReader<'a> {
+    words: &'a [i32],
+    num_vars: usize,
+}
+
+impl<'a> CacheReader<'a> {
+    pub(crate) fn next(&mut self) -> Option<i32> {
+        let (&first, rest) = self.words.split_first()?;
+        self.words = rest;
+        Some(first)
+    }
+
+    fn index(&mut self) -> Option<usize> {
+        usize::try_from(self.next()?).ok()
+    }
+
+    /// Reads the length of a sequence, each of whose elements takes at least one word.
+    pub(crate) fn len(&mut self) -> Option<usize> {
+        self.index().filter(|&len| len <= self.words.len())
+    }
+
+    fn ints(&mut self) -> Option<Vec<i32>> {
+        let len = self.len()?;
+        let (values, rest) = self.words.split_at(len);
+        self.words = rest;
+        Some(values.to_vec())
+    }
+
+    pub(crate) fn checked_ints(&mut self) -> Option<Vec<CheckedInt>> {
+        Some(self.ints()?.into_iter().map(CheckedInt::new).collect())
+    }
+
+    fn lit(&mut self) -> Option<Lit> {
+        let lit = self.index()?;
+        (lit < self.num_vars * 2).then_some(Lit(lit as i32))
+    }
+
+    /// Reads a sequence of literals without converting them into `Lit`s.
+    fn lit_words(&mut self) -> Option<&'a [i32]> {
+        let len = self.len()?;
+        let (lits, rest) = self.words.split_at(len);
+        let max_lit = self.num_vars * 2;
+        if !lits
+            .iter()
+            .all(|&lit| usize::try_from(lit).is_ok_and(|lit| lit < max_lit))
+        {
+            return None;
+        }
+        self.words = rest;
+        Some(lits)
+    }
+
+    pub(crate) fn lits(&mut self) -> Option<Vec<Lit>> {
+        Some(self.lit_words()?.iter().map(|&lit| Lit(lit)).collect())
+    }
+
+    pub(crate) fn optional_lit(&mut self) -> Option<Option<Lit>> {
+        if self.words.first() == Some(&-1) {
+            self.next();
+            Some(None)
+        } else {
+            self.lit().map(Some)
+        }
+    }
+
+    fn edges(&mut self) -> Option<Vec<(usize, usize)>> {
+        let len = self.len()?;
+        (0..len)
+            .map(|_| Some((self.index()?, self.index()?)))
+            .collect()
+    }
+
+    fn sequence<T, F: FnMut(&mut Self) -> Option<T>>(&mut self, mut f: F) -> Option<Vec<T>> {
+        let len = self.len()?;
+        (0..len).map(|_| f(self)).collect()
+    }
+}
+
+const OP_CLAUSE: i32 = 0;
+const OP_ORDER_ENCODING_LINEAR: i32 = 1;
+const OP_ACTIVE_VERTICES_CONNECTED: i32 = 2;
+const OP_EXTENSION_SUPPORTS: i32 = 3;
+const OP_GRAPH_DIVISION: i32 = 4;
+
+fn write_operation(out: &mut CacheWriter, op: &SATOperation) {
+    match op {
+        SATOperation::Clause(clause) => {
+            out.push(OP_CLAUSE);
+            out.push_lits(clause);
+        }
+        SATOperation::OrderEncodingLinear(lits, domain, coefs, constant) => {
+            out.push(OP_ORDER_ENCODING_LINEAR);
+            out.push_len(lits.len());
+            lits.iter().for_each(|lits| out.push_lits(lits));
+            out.push_len(domain.len());
+            domain.iter().for_each(|domain| out.push_ints(domain));
+            out.push_ints(coefs);
+            out.push(*constant);
+        }
+        SATOperation::ActiveVerticesConnected(lits, edges) => {
+            out.push(OP_ACTIVE_VERTICES_CONNECTED);
+            out.push_lits(lits);
+            out.push_edges(edges);
+        }
+        SATOperation::ExtensionSupports(vars, supports) => {
+            out.push(OP_EXTENSION_SUPPORTS);
+            out.push_len(vars.len());
+            vars.iter().for_each(|lits| out.push_lits(lits));
+            out.push_len(supports.len());
+            for support in supports {
+                out.push_len(support.len());
+                for x in support {
+                    out.push(x.map_or(-1, |x| x as i32));
+                }
+            }
+        }
+        SATOperation::GraphDivision(domains, dom_lits, edges, edge_lits) => {
+            out.push(OP_GRAPH_DIVISION);
+            out.push_len(domains.len());
+            domains.iter().for_each(|domain| out.push_ints(domain));
+            out.push_len(dom_lits.len());
+            dom_lits.iter().for_each(|lits| out.push_lits(lits));
+            out.push_edges(edges);
+            out.push_lits(edge_lits);
+        }
+    }
+}
+
+/// Reads the operations written by `write_operation`, and performs them on `sat` if given.
+/// Clauses, which make up most of the operations, are added without allocating each of them.
+fn read_operations(input: &mut CacheReader, mut sat: Option<&mut SAT>) -> Option<()> {
+    let num_operations = input.len()?;
+    let mut clause = vec![];
+    for _ in 0..num_operations {
+        let op = match input.next()? {
+            OP_CLAUSE => {
+                let lits = input.lit_words()?;
+                if let Some(sat) = sat.as_deref_mut() {
+                    clause.clear();
+                    clause.extend(lits.iter().map(|&lit| Lit(lit)));
+                    sat.add_clause(&clause);
+                }
+                continue;
+            }
+            OP_ORDER_ENCODING_LINEAR => SATOperation::OrderEncodingLinear(
+                input.sequence(CacheReader::lits)?,
+                input.sequence(CacheReader::ints)?,
+                input.ints()?,
+                input.next()?,
+            ),
+            OP_ACTIVE_VERTICES_CONNECTED => {
+                SATOperation::ActiveVerticesConnected(input.lits()?, input.edges()?)
+            }
+            OP_EXTENSION_SUPPORTS => SATOperation::ExtensionSupports(
+                input.sequence(CacheReader::lits)?,
+                input.sequence(|input| {
+                    input.sequence(|input| match input.next()? {
+                        -1 => Some(None),
+                        x => usize::try_from(x).ok().map(Some),
+                    })
+                })?,
+            ),
+            OP_GRAPH_DIVISION => SATOperation::GraphDivision(
+                input.sequence(CacheReader::ints)?,
+                input.sequence(CacheReader::lits)?,
+                input.edges()?,
+                input.lits()?,
+            ),
+            _ => return None,
+        };
+        if let Some(sat) = sat.as_deref_mut() {
+            sat.replay(&op);
+        }
+    }
+    Some(())
+}
+
+struct CachedEncoding {
+    num_vars: usize,
+    operations: Vec<SATOperation>,
+    map: EncodeMap,
+}
+
+fn cache_file_path(dir: &Path, key: u128) -> PathBuf {
+    dir.join(format!("{:032x}.enc", key))
+}
+
+fn key_words(key: u128) -> [i32; 4] {
+    [0, 32, 64, 96].map(|shift| (key >> shift) as u32 as i32)
+}
+
+/// Reads the cache file of `key` in `dir`, and returns its contents after the header.
+fn read_cache_file(dir: &Path, key: u128) -> Option<Vec<i32>> {
+    let bytes = std::fs::read(cache_file_path(dir, key)).ok()?;
+    let body = bytes.strip_prefix(MAGIC)?;
+    if body.len() % 4 != 0 {
+        return None;
+    }
+    let mut words = body
+        .chunks_exact(4)
+        .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect::<Vec<_>>();
+
+    let mut header = vec![FORMAT_VERSION];
+    header.extend(key_words(key));
+    if !words.starts_with(&header) {
+        return None;
+    }
+    words.drain(..header.len());
+    Some(words)
+}
+
+/// Checks that `words` read by `read_cache_file` is well-formed, and returns the number of SAT
+/// variables, a reader of the operations and the map of the cached encoding.
+fn validate(words: &[i32]) -> Option<(usize, CacheReader<'_>, EncodeMap)> {
+    let mut input = CacheReader { words, num_vars: 0 };
+    let num_vars = input.index()?;
+    input.num_vars = num_vars;
+    let operations = input;
+    read_operations(&mut input, None)?;
+    let map = EncodeMap::read_cache(&mut input)?;
+    if !input.words.is_empty() {
+        return None;
+    }
+    Some((num_vars, operations, map))
+}
+
+fn store(dir: &Path, key: u128, encoding: &CachedEncoding) -> std::io::Result<()> {
+    let mut out = CacheWriter::new();
+    out.push(FORMAT_VERSION);
+    for word in key_words(key) {
+        out.push(word);
+    }
+    out.push_len(encoding.num_vars);
+    out.push_len(encoding.operations.len());
+    for op in &encoding.operations {
+        write_operation(&mut out, op);
+    }
+    encoding.map.write_cache(&mut out);
+
+    let mut bytes = MAGIC.to_vec();
+    for word in out.words {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+
+    // Write to a temporary file first so that other processes never see a partial file.
+    static NUM_WRITES: AtomicUsize = AtomicUsize::new(0);
+    std::fs::create_dir_all(dir)?;
+    let path = cache_file_path(dir, key);
+    let tmp_path = path.with_extension(format!(
+        "{}.{}.tmp",
+        std::process::id(),
+        NUM_WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, &path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp_path);
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum CacheStatus {
+    /// The encoding was loaded from the cache.
+    Hit,
+    /// The CSP was encoded, and the encoding was stored in the cache.
+    Stored,
+    /// The CSP was encoded without the cache, e.g. because it is not the first encoding of the
+    /// solver or has custom constraints.
+    Bypassed,
+}
+
+/// Same as `encoder::encode`, but loads the encoding from the cache in `dir` if available, and
+/// otherwise stores the encoding there. Errors on accessing the cache are ignored.
+pub(crate) fn encode(
+    dir: &Path,
+    norm: &mut NormCSP,
+    sat: &mut SAT,
+    map: &mut EncodeMap,
+    config: &Config,
+) -> CacheStatus {
+    // Only the encoding of a fresh solver is cached, as later encodings depend on earlier ones.
+    let key = if norm.num_encoded_vars == 0 && sat.num_var() == 0 {
+        cache_key(norm, config)
+    } else {
+        None
+    };
+    let key = match key {
+        Some(key) => key,
+        None => {
+            encoder::encode(norm, sat, map, config);
+            return CacheStatus::Bypassed;
+        }
+    };
+
+    let words = read_cache_file(dir, key);
+    if let Some((num_vars, mut operations, cached_map)) = words.as_deref().and_then(validate) {
+        #[cfg(feature = "sat-analyzer")]
+        sat.new_vars(num_vars, "cached");
+        #[cfg(not(feature = "sat-analyzer"))]
+        sat.new_vars(num_vars);
+        read_operations(&mut operations, Some(sat)).unwrap();
+        *map = cached_map;
+        norm.constraints.clear();
+        norm.extra_constraints.clear();
+        norm.num_encoded_vars = norm.vars.num_int_vars();
+        return CacheStatus::Hit;
+    }
+
+    let ((), operations) = sat::record_operations(|| encoder::encode(norm, sat, map, config));
+    let operations = match operations {
+        Some(operations) => operations,
+        None => return CacheStatus::Bypassed,
+    };
+    let cached = CachedEncoding {
+        num_vars: sat.num_var(),
+        operations,
+        map: std::mem::replace(map, EncodeMap::new()),
+    };
+    let stored = store(dir, key, &cached).is_ok();
+    *map = cached.map;
+    if stored {
+        CacheStatus::Stored
+    } else {
+        CacheStatus::Bypassed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::csp::{BoolExpr, IntExpr, Stmt, CSP};
+    use crate::integration::IntegratedSolver;
+    use crate::normalizer::{normalize, NormalizeMap};
+    use crate::random_instance::{InstanceConfig, RandomInstance};
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let path = std::env::temp_dir().join(format!(
+                "cspuz_core_encoding_cache_{}_{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            TempDir(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn encode_once(dir: &Path, csp: &mut CSP) -> (CacheStatus, SAT) {
+        let config = Config::default();
+        let mut norm = NormCSP::new();
+        let mut sat = SAT::new();
+        let mut map = EncodeMap::new();
+        normalize(csp, &mut norm, &mut NormalizeMap::new(), &config);
+        let status = encode(dir, &mut norm, &mut sat, &mut map, &config);
+        (status, sat)
+    }
+
+    fn small_csp(negate_b: bool) -> CSP {
+        let mut csp = CSP::new();
+        let x = csp.new_int_var(Domain::range(0, 5));
+        let y = csp.new_int_var(Domain::range(0, 5));
+        let b = csp.new_bool_var();
+        csp.add_constraint(Stmt::Expr(
+            (x.expr() + y.expr()).ge(IntExpr::Const(7)) | b.expr(),
+        ));
+        csp.add_constraint(Stmt::Expr(
+            IntExpr::If(Box::new(b.expr()), Box::new(x.expr()), Box::new(y.expr()))
+                .ne(IntExpr::Const(3)),
+        ));
+        if negate_b {
+            csp.add_constraint(Stmt::Expr(!BoolExpr::Var(b)));
+        }
+        csp
+    }
+
+    #[test]
+    fn test_encoding_cache_status() {
+        let dir = TempDir::new("status");
+
+        let (status, sat) = encode_once(&dir.0, &mut small_csp(false));
+        assert_eq!(status, CacheStatus::Stored);
+        let (num_vars, num_clauses) = (sat.num_var(), sat.num_clauses());
+
+        let (status, sat) = encode_once(&dir.0, &mut small_csp(false));
+        assert_eq!(status, CacheStatus::Hit);
+        assert_eq!(sat.num_var(), num_vars);
+        assert_eq!(sat.num_clauses(), num_clauses);
+
+        let (status, _) = encode_once(&dir.0, &mut small_csp(true));
+        assert_eq!(status, CacheStatus::Stored);
+    }
+
+    #[test]
+    fn test_encoding_cache_ignores_broken_files() {
+        let dir = TempDir::new("broken");
+
+        assert_eq!(
+            encode_once(&dir.0, &mut small_csp(false)).0,
+            CacheStatus::Stored
+        );
+        let path = std::fs::read_dir(&dir.0)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() - 4);
+        std::fs::write(&path, bytes).unwrap();
+        assert_eq!(
+            encode_once(&dir.0, &mut small_csp(false)).0,
+            CacheStatus::Stored
+        );
+        assert_eq!(
+            encode_once(&dir.0, &mut small_csp(false)).0,
+            CacheStatus::Hit
+        );
+    }
+
+    #[test]
+    fn test_encoding_cache_random() {
+        let dir = TempDir::new("random");
+        let config = InstanceConfig::default();
+
+        for seed in 0..50 {
+            for _ in 0..2 {
+                let mut solver = IntegratedSolver::new();
+                solver.set_encoding_cache_dir(Some(dir.0.clone()));
+                let instance = RandomInstance::generate(&mut solver, &config, seed);
+
+                let assignments = solver.enumerate_valid_assignments();
+                for assignment in &assignments {
+                    assert!(instance.is_satisfied(assignment), "seed {}", seed);
+                }
+                assert_eq!(
+                    assignments.len(),
+                    instance.enumerate_solutions_brute_force().len(),
+                    "seed {}",
+                    seed
+                );
+            }
+        }
+    }
+}
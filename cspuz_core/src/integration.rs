@@ -6,11 +6,13 @@ use super::csp::{
     Assignment, BoolExpr, BoolVar, BoolVarStatus, IntExpr, IntVar, IntVarStatus, Stmt, CSP,
 };
 use super::encoder::{encode, EncodeMap};
+use super::encoding_cache;
 use super::norm_csp::NormCSP;
 use super::normalizer::{normalize, NormalizeMap};
-use super::sat::{SATModel, SAT};
+use super::sat::{self, SATModel, SAT};
 use crate::domain::Domain;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
 pub struct PerfStats {
@@ -59,6 +61,88 @@ impl PerfStats {
     }
 }
 
+/// Deductions made by `decide_irrefutable_facts` calls, recorded by `trace_deductions`.
+/// For each call, the irrefutable facts are split into steps, each of which can be proven from the
+/// facts of the previous steps (earlier steps are proven with smaller conflict budgets).
+#[derive(Clone, Debug, Default)]
+pub struct DeductionTrace {
+    calls: Vec<Option<Vec<Assignment>>>,
+}
+
+impl DeductionTrace {
+    /// The maximum number of steps among the recorded calls.
+    pub fn num_steps(&self) -> usize {
+        self.calls
+            .iter()
+            .map(|steps| steps.as_ref().map_or(0, |steps| steps.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The steps of each recorded call (`None` if the problem had no solution).
+    pub fn calls(&self) -> &[Option<Vec<Assignment>>] {
+        &self.calls
+    }
+}
+
+enum TraceMode {
+    Record(DeductionTrace),
+    Replay {
+        trace: DeductionTrace,
+        num_steps: usize,
+        next_call: usize,
+    },
+}
+
+thread_local! {
+    static TRACE: RefCell<Option<TraceMode>> = const { RefCell::new(None) };
+}
+
+/// Conflict budgets for proving facts in `trace_deductions`.
+const TRACE_BUDGET_TIERS: [Option<u64>; 4] = [Some(10), Some(100), Some(1000), None];
+
+/// Runs `f` while recording the deductions made by `decide_irrefutable_facts` calls on the current
+/// thread. The results of the calls are not affected, but they are usually slower.
+pub fn trace_deductions<T, F: FnOnce() -> T>(f: F) -> (T, DeductionTrace) {
+    let outer =
+        TRACE.with(|trace| trace.replace(Some(TraceMode::Record(DeductionTrace::default()))));
+    let ret = f();
+    let trace = match TRACE.with(|trace| trace.replace(outer)) {
+        Some(TraceMode::Record(trace)) => trace,
+        _ => unreachable!(),
+    };
+    (ret, trace)
+}
+
+/// Runs `f` while replaying `trace` without SAT solving: the `i`-th `decide_irrefutable_facts`
+/// call returns the facts of the first `num_steps` steps of the `i`-th recorded call.
+/// `f` must build the same problems as the traced run; calls beyond the recorded ones return `None`.
+pub fn replay_deductions<T, F: FnOnce() -> T>(trace: &DeductionTrace, num_steps: usize, f: F) -> T {
+    let outer = TRACE.with(|t| {
+        t.replace(Some(TraceMode::Replay {
+            trace: trace.clone(),
+            num_steps,
+            next_call: 0,
+        }))
+    });
+    let ret = f();
+    TRACE.with(|t| t.replace(outer));
+    ret
+}
+
+fn merge_steps(steps: &[Assignment]) -> Assignment {
+    let mut ret = Assignment::new();
+    for step in steps {
+        for (&v, &b) in step.bool_iter() {
+            ret.set_bool(v, b);
+        }
+        for (&v, &i) in step.int_iter() {
+            ret.set_int(v, i);
+        }
+    }
+    ret
+}
+
 pub struct IntegratedSolver<'a> {
     csp: CSP,
     normalize_map: NormalizeMap,
@@ -67,6 +151,7 @@ pub struct IntegratedSolver<'a> {
     sat: SAT,
     already_used: bool,
     config: Config,
+    encoding_cache_dir: Option<PathBuf>,
     perf_stats: Option<&'a PerfStats>,
 }
 
@@ -84,6 +169,7 @@ impl<'a> IntegratedSolver<'a> {
             sat: SAT::new_with_backend(config.backend),
             already_used: false,
             config,
+            encoding_cache_dir: encoding_cache::cache_dir(),
             perf_stats: None,
         };
         ret.sat.set_rnd_init_act(ret.config.glucose_rnd_init_act);
@@ -123,6 +209,13 @@ impl<'a> IntegratedSolver<'a> {
         self.add_constraint(Stmt::Expr(expr))
     }
 
+    /// Returns the variables and the constraints added so far in a human-readable format (see
+    /// `CSP::dump`). Constraints are included only until they are encoded by `solve` or `encode`.
+    pub fn dump(&self) -> String {
+        self.csp.dump()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn encode(&mut self) -> bool {
         let is_first = !self.already_used;
         self.already_used = true;
@@ -157,19 +250,43 @@ impl<'a> IntegratedSolver<'a> {
         if self.norm.is_inconsistent() {
             return false;
         }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            constraints = self.norm.constraints.len(),
+            extra_constraints = self.norm.extra_constraints.len(),
+            "normalized"
+        );
 
         let start = std::time::Instant::now();
-        encode(
-            &mut self.norm,
-            &mut self.sat,
-            &mut self.encode_map,
-            &self.config,
-        );
+        if let Some(dir) = &self.encoding_cache_dir {
+            let _status = encoding_cache::encode(
+                dir,
+                &mut self.norm,
+                &mut self.sat,
+                &mut self.encode_map,
+                &self.config,
+            );
+            #[cfg(feature = "tracing")]
+            tracing::debug!(status = ?_status, "encoding cache");
+        } else {
+            encode(
+                &mut self.norm,
+                &mut self.sat,
+                &mut self.encode_map,
+                &self.config,
+            );
+        }
         if let Some(perf_stats) = self.perf_stats {
             perf_stats
                 .time_encode
                 .set(perf_stats.time_encode() + start.elapsed().as_secs_f64());
         }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            variables = self.sat.num_var(),
+            clauses = self.sat.num_clauses(),
+            "encoded"
+        );
         let solver_stats = self.sat.stats();
         if let Some(perf_stats) = self.perf_stats {
             if let Some(decisions) = solver_stats.decisions {
@@ -186,14 +303,44 @@ impl<'a> IntegratedSolver<'a> {
     }
 
     pub fn solve<'b>(&'b mut self) -> Option<Model<'b>> {
+        match self.solve_with_assumption(None, None) {
+            LimitedSolveResult::Sat(model) => Some(model),
+            _ => None,
+        }
+    }
+
+    /// Same as `solve`, but `assumption` (if any) is assumed to be true only during this call,
+    /// and the search is given up after `conflict_budget` conflicts.
+    /// `assumption` should have been registered by `add_prenormalize_var`.
+    fn solve_with_assumption<'b>(
+        &'b mut self,
+        assumption: Option<BoolVar>,
+        conflict_budget: Option<u64>,
+    ) -> LimitedSolveResult<'b> {
         if !self.encode() {
-            return None;
+            return LimitedSolveResult::Unsat;
+        }
+        let mut assumptions = vec![];
+        if let Some(var) = assumption {
+            match self.normalize_map.get_bool_var_raw(var) {
+                ConvertedBoolVar::Lit(norm_lit) => assumptions.push(
+                    self.encode_map
+                        .get_bool_lit(norm_lit)
+                        .expect("assumption variable is not encoded"),
+                ),
+                ConvertedBoolVar::Removed => match self.csp.get_bool_var_status(var) {
+                    BoolVarStatus::Fixed(true) => (),
+                    _ => return LimitedSolveResult::Unsat,
+                },
+                ConvertedBoolVar::NotConverted => (),
+            }
         }
         let start = std::time::Instant::now();
-        let solver_result = if self.sat.solve_without_model() {
-            Some(unsafe { self.sat.model() })
-        } else {
-            None
+        let solver_result = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("sat_solve", assumptions = assumptions.len()).entered();
+            self.sat
+                .solve_without_model_limited(&assumptions, conflict_budget)
         };
         if let Some(perf_stats) = self.perf_stats {
             perf_stats
@@ -212,16 +359,23 @@ impl<'a> IntegratedSolver<'a> {
                 perf_stats.conflicts.set(conflicts);
             }
         }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            result = ?solver_result,
+            conflicts = solver_stats.conflicts,
+            "sat_solved"
+        );
 
         match solver_result {
-            Some(model) => Some(Model {
+            Some(true) => LimitedSolveResult::Sat(Model {
                 csp: &self.csp,
                 normalize_map: &self.normalize_map,
                 norm_csp: &self.norm,
                 encode_map: &self.encode_map,
-                model,
+                model: unsafe { self.sat.model() },
             }),
-            None => None,
+            Some(false) => LimitedSolveResult::Unsat,
+            None => LimitedSolveResult::Unknown,
         }
     }
 
@@ -240,11 +394,43 @@ impl<'a> IntegratedSolver<'a> {
         self.answer_iter(&bool_vars, &int_vars).collect()
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn decide_irrefutable_facts(
         mut self,
         bool_vars: &[BoolVar],
         int_vars: &[IntVar],
     ) -> Option<Assignment> {
+        let replayed = TRACE.with(|trace| match trace.borrow_mut().as_mut() {
+            Some(TraceMode::Replay {
+                trace,
+                num_steps,
+                next_call,
+            }) => {
+                let steps = trace.calls.get(*next_call).cloned().flatten();
+                *next_call += 1;
+                Some(steps.map(|steps| merge_steps(&steps[..steps.len().min(*num_steps)])))
+            }
+            _ => None,
+        });
+        if let Some(replayed) = replayed {
+            return replayed;
+        }
+        let recording = TRACE.with(|trace| matches!(*trace.borrow(), Some(TraceMode::Record(_))));
+        if recording {
+            let steps =
+                self.decide_irrefutable_facts_stepwise(bool_vars, int_vars, &TRACE_BUDGET_TIERS);
+            let ret = steps.as_ref().map(|steps| merge_steps(steps));
+            TRACE.with(|trace| {
+                if let Some(TraceMode::Record(trace)) = trace.borrow_mut().as_mut() {
+                    trace.calls.push(steps);
+                }
+            });
+            return ret;
+        }
+        if self.config.anytime_irrefutable_facts {
+            return self.decide_irrefutable_facts_anytime(bool_vars, int_vars);
+        }
+
         let mut assignment = Assignment::new();
         match self.solve() {
             Some(model) => {
@@ -291,13 +477,161 @@ impl<'a> IntegratedSolver<'a> {
                         .iter()
                         .for_each(|&v| assert!(assignment.remove_int(v).is_some()));
                 }
-                None => break,
+                None => {
+                    if sat::is_interrupted() {
+                        // `assignment` may contain facts which are not proven yet
+                        return None;
+                    }
+                    break;
+                }
             }
         }
 
         Some(assignment)
     }
 
+    /// Variant of `decide_irrefutable_facts` which proves the facts one at a time, so that the facts
+    /// proven so far are returned when SAT solving is interrupted (see `sat::set_progress_callback`).
+    /// This is usually slower than refuting all the candidates at once.
+    fn decide_irrefutable_facts_anytime(
+        self,
+        bool_vars: &[BoolVar],
+        int_vars: &[IntVar],
+    ) -> Option<Assignment> {
+        let steps = self.decide_irrefutable_facts_stepwise(bool_vars, int_vars, &[None])?;
+        Some(merge_steps(&steps))
+    }
+
+    /// Proves the irrefutable facts in steps. Each step consists of the facts which can be proven
+    /// from the facts of the previous steps with the smallest conflict budget in `budget_tiers`, so
+    /// that the facts of earlier steps are "easier". The last tier should be `None` (unlimited),
+    /// otherwise some facts may be left unproven.
+    /// If SAT solving is interrupted, the facts proven so far are returned as the last step.
+    fn decide_irrefutable_facts_stepwise(
+        mut self,
+        bool_vars: &[BoolVar],
+        int_vars: &[IntVar],
+        budget_tiers: &[Option<u64>],
+    ) -> Option<Vec<Assignment>> {
+        let mut candidates = Assignment::new();
+        match self.solve() {
+            Some(model) => {
+                for &var in bool_vars {
+                    candidates.set_bool(var, model.get_bool(var));
+                }
+                for &var in int_vars {
+                    candidates.set_int(var, model.get_int(var));
+                }
+            }
+            None => {
+                return if sat::is_interrupted() {
+                    Some(vec![])
+                } else {
+                    None
+                };
+            }
+        }
+
+        let mut steps = vec![];
+        while !candidates.is_empty() {
+            let mut step = Assignment::new();
+            for &budget in budget_tiers {
+                let bool_candidates = candidates
+                    .bool_iter()
+                    .map(|(&v, &b)| (v, b))
+                    .collect::<Vec<_>>();
+                for (v, b) in bool_candidates {
+                    if candidates.get_bool(v).is_none() {
+                        continue;
+                    }
+                    let refutation = if b { !v.expr() } else { v.expr() };
+                    if self.refute_candidate(refutation, budget, &mut candidates) {
+                        step.set_bool(v, b);
+                        candidates.remove_bool(v);
+                    }
+                    if sat::is_interrupted() {
+                        steps.push(step);
+                        return Some(steps);
+                    }
+                }
+                let int_candidates = candidates
+                    .int_iter()
+                    .map(|(&v, &i)| (v, i))
+                    .collect::<Vec<_>>();
+                for (v, i) in int_candidates {
+                    if candidates.get_int(v).is_none() {
+                        continue;
+                    }
+                    let refutation = v.expr().ne(IntExpr::Const(i));
+                    if self.refute_candidate(refutation, budget, &mut candidates) {
+                        step.set_int(v, i);
+                        candidates.remove_int(v);
+                    }
+                    if sat::is_interrupted() {
+                        steps.push(step);
+                        return Some(steps);
+                    }
+                }
+                if !step.is_empty() {
+                    break;
+                }
+            }
+            if step.is_empty() {
+                // the budgets were too small to decide the remaining candidates
+                break;
+            }
+
+            for (&v, &b) in step.bool_iter() {
+                self.add_expr(if b { v.expr() } else { !v.expr() });
+            }
+            for (&v, &i) in step.int_iter() {
+                self.add_expr(v.expr().eq(IntExpr::Const(i)));
+            }
+            steps.push(step);
+        }
+
+        Some(steps)
+    }
+
+    /// Tries to show that `refutation` is unsatisfiable within `conflict_budget` conflicts and
+    /// returns whether it succeeded. If a solution is found instead, the candidates which differ
+    /// from it are removed from `candidates`.
+    fn refute_candidate(
+        &mut self,
+        refutation: BoolExpr,
+        conflict_budget: Option<u64>,
+        candidates: &mut Assignment,
+    ) -> bool {
+        // Assume `refutation` through a fresh guard variable so that it can be retracted.
+        let guard = self.new_bool_var();
+        self.add_prenormalize_var(guard);
+        self.add_expr(!guard.expr() | refutation);
+
+        match self.solve_with_assumption(Some(guard), conflict_budget) {
+            LimitedSolveResult::Sat(model) => {
+                let bool_erased = candidates
+                    .bool_iter()
+                    .filter_map(|(&v, &b)| (model.get_bool(v) != b).then_some(v))
+                    .collect::<Vec<_>>();
+                let int_erased = candidates
+                    .int_iter()
+                    .filter_map(|(&v, &i)| (model.get_int(v) != i).then_some(v))
+                    .collect::<Vec<_>>();
+                assert!(!bool_erased.is_empty() || !int_erased.is_empty());
+
+                bool_erased
+                    .iter()
+                    .for_each(|&v| assert!(candidates.remove_bool(v).is_some()));
+                int_erased
+                    .iter()
+                    .for_each(|&v| assert!(candidates.remove_int(v).is_some()));
+                false
+            }
+            LimitedSolveResult::Unsat => !sat::is_interrupted(),
+            LimitedSolveResult::Unknown => false,
+        }
+    }
+
     pub fn answer_iter(self, bool_vars: &[BoolVar], int_vars: &[IntVar]) -> AnswerIterator<'a> {
         AnswerIterator {
             solver: self,
@@ -306,6 +640,12 @@ impl<'a> IntegratedSolver<'a> {
         }
     }
 
+    /// Sets the directory of the encoding cache of this solver (see `encoding_cache`), overriding
+    /// the one given by `encoding_cache::set_cache_dir`. `None` disables the cache.
+    pub fn set_encoding_cache_dir(&mut self, dir: Option<PathBuf>) {
+        self.encoding_cache_dir = dir;
+    }
+
     pub fn set_perf_stats<'b: 'a>(&mut self, perf_stats: &'b PerfStats) {
         self.perf_stats = Some(perf_stats);
     }
@@ -348,6 +688,13 @@ impl<'a> Iterator for AnswerIterator<'a> {
     }
 }
 
+/// Result of a SAT solver call which may be given up.
+enum LimitedSolveResult<'a> {
+    Sat(Model<'a>),
+    Unsat,
+    Unknown,
+}
+
 pub struct Model<'a> {
     csp: &'a CSP,
     normalize_map: &'a NormalizeMap,
@@ -1078,6 +1425,149 @@ mod tests {
         assert_eq!(res.get_int(d), Some(4));
     }
 
+    #[test]
+    fn test_integration_irrefutable_anytime() {
+        let mut config = Config::default();
+        config.anytime_irrefutable_facts = true;
+        let mut solver = IntegratedSolver::with_config(config);
+
+        let x = solver.new_bool_var();
+        let y = solver.new_bool_var();
+        let a = solver.new_int_var(Domain::range(0, 2));
+        let b = solver.new_int_var(Domain::range(0, 2));
+        let c = solver.new_int_var(Domain::range(0, 2));
+        solver.add_expr(y.expr());
+        solver.add_expr(
+            x.expr()
+                .ite(a.expr(), b.expr())
+                .lt(c.expr() - IntExpr::Const(1)),
+        );
+        solver.add_expr(a.expr().ne(b.expr()));
+
+        let res = solver.decide_irrefutable_facts(&[x, y], &[a, b, c]);
+        assert!(res.is_some());
+        let res = res.unwrap();
+        assert_eq!(res.get_bool(x), None);
+        assert_eq!(res.get_bool(y), Some(true));
+        assert_eq!(res.get_int(a), None);
+        assert_eq!(res.get_int(b), None);
+        assert_eq!(res.get_int(c), Some(2));
+    }
+
+    #[test]
+    fn test_integration_irrefutable_anytime_interrupted() {
+        let mut config = Config::default();
+        config.anytime_irrefutable_facts = true;
+        let mut solver = IntegratedSolver::with_config(config);
+
+        let x = solver.new_bool_var();
+        let y = solver.new_bool_var();
+        let z = solver.new_bool_var();
+        solver.add_expr(x.expr() | y.expr());
+        solver.add_expr(!x.expr());
+
+        // `z` must be false, but proving it requires refuting the pigeonhole principle
+        let n = 9;
+        let pigeons = (0..=n)
+            .map(|_| (0..n).map(|_| solver.new_bool_var()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for row in &pigeons {
+            let mut clause = vec![Box::new(!z.expr())];
+            clause.extend(row.iter().map(|v| Box::new(v.expr())));
+            solver.add_expr(BoolExpr::Or(clause));
+        }
+        for p in 0..=n {
+            for q in (p + 1)..=n {
+                for (a, b) in pigeons[p].iter().zip(&pigeons[q]) {
+                    solver.add_expr(!(a.expr() & b.expr()));
+                }
+            }
+        }
+
+        sat::set_progress_callback(Some(Box::new(|_| false)));
+        let res = solver.decide_irrefutable_facts(&[x, y, z], &[]);
+        let interrupted = sat::is_interrupted();
+        sat::set_progress_callback(None);
+
+        assert!(interrupted);
+        assert!(res.is_some());
+        let res = res.unwrap();
+        assert_eq!(res.get_bool(x), Some(false));
+        assert_eq!(res.get_bool(y), Some(true));
+        assert_eq!(res.get_bool(z), None);
+    }
+
+    #[test]
+    fn test_integration_trace_deductions() {
+        let build = || {
+            let mut solver = IntegratedSolver::new();
+            let x = solver.new_bool_var();
+            let y = solver.new_bool_var();
+            let z = solver.new_bool_var();
+            let a = solver.new_int_var(Domain::range(0, 3));
+            solver.add_expr(x.expr());
+            solver.add_expr(x.expr().imp(a.expr().ge(IntExpr::Const(2))));
+            solver.add_expr(a.expr().ge(IntExpr::Const(3)).imp(y.expr()));
+            solver.add_expr(!y.expr());
+            (solver, [x, y, z], a)
+        };
+
+        let ((res, vars, a), trace) = trace_deductions(|| {
+            let (solver, vars, a) = build();
+            (solver.decide_irrefutable_facts(&vars, &[a]), vars, a)
+        });
+        let [x, y, z] = vars;
+        let res = res.unwrap();
+        assert_eq!(res.get_bool(x), Some(true));
+        assert_eq!(res.get_bool(y), Some(false));
+        assert_eq!(res.get_bool(z), None);
+        assert_eq!(res.get_int(a), Some(2));
+
+        assert_eq!(trace.calls().len(), 1);
+        let num_steps = trace.num_steps();
+        assert!(num_steps >= 1);
+        let steps = trace.calls()[0].as_ref().unwrap();
+        assert!(steps.iter().all(|step| !step.is_empty()));
+
+        for n in 0..=num_steps {
+            let replayed = replay_deductions(&trace, n, || {
+                let (solver, vars, a) = build();
+                solver.decide_irrefutable_facts(&vars, &[a])
+            })
+            .unwrap();
+            let expected = merge_steps(&steps[..n]);
+            assert_eq!(
+                replayed.bool_iter().collect::<Vec<_>>(),
+                expected.bool_iter().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                replayed.int_iter().collect::<Vec<_>>(),
+                expected.int_iter().collect::<Vec<_>>()
+            );
+        }
+        let replayed = replay_deductions(&trace, num_steps, || {
+            let (solver, vars, a) = build();
+            solver.decide_irrefutable_facts(&vars, &[a]);
+            let (solver, vars, a) = build();
+            solver.decide_irrefutable_facts(&vars, &[a])
+        });
+        assert!(replayed.is_none());
+    }
+
+    #[test]
+    fn test_integration_trace_deductions_unsat() {
+        let ((), trace) = trace_deductions(|| {
+            let mut solver = IntegratedSolver::new();
+            let x = solver.new_bool_var();
+            solver.add_expr(x.expr());
+            solver.add_expr(!x.expr());
+            assert!(solver.decide_irrefutable_facts(&[x], &[]).is_none());
+        });
+        assert_eq!(trace.calls().len(), 1);
+        assert!(trace.calls()[0].is_none());
+        assert_eq!(trace.num_steps(), 0);
+    }
+
     #[test]
     fn test_integration_solver_iterator() {
         let mut solver = IntegratedSolver::new();
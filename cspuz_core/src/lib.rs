@@ -2,14 +2,17 @@ pub mod arithmetic;
 pub mod config;
 pub mod csp;
 mod csp_repr;
+mod csp_text;
 pub mod custom_constraints;
 pub mod domain;
 
 pub mod backend;
 pub mod encoder;
+pub mod encoding_cache;
 pub mod integration;
 pub mod norm_csp;
 pub mod normalizer;
+pub mod random_instance;
 
 #[cfg(feature = "parser")]
 pub mod csugar_cli;
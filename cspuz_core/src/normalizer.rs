@@ -165,6 +165,7 @@ impl<'a, 'b, 'c, 'd> NormalizerEnv<'a, 'b, 'c, 'd> {
 }
 
 /// Normalize constraints in `csp`. Existing constraints in `csp` are cleared.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 pub fn normalize(csp: &mut CSP, norm: &mut NormCSP, map: &mut NormalizeMap, config: &Config) {
     let mut env = NormalizerEnv {
         csp_vars: &mut csp.vars,
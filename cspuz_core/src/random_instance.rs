@@ -0,0 +1,382 @@
+//! Random CSP instances for fuzzing and benchmarking.
+//!
+//! `RandomInstance::generate` adds randomly generated variables and constraints to an
+//! `IntegratedSolver`, and keeps a copy of the constraints so that the solutions can be
+//! enumerated by brute force (`RandomInstance::enumerate_solutions_brute_force`) and compared
+//! with those found by the solver. This makes it possible to test the normalizer and the encoder
+//! on many instances which are larger and more diverse than hand-written ones.
+//!
+//! Instances are determined by the `InstanceConfig` and the seed, so that a failing instance can
+//! be reproduced.
+
+use crate::csp::{Assignment, BoolExpr, BoolVar, IntExpr, IntVar, Stmt};
+use crate::domain::Domain;
+use crate::integration::IntegratedSolver;
+
+/// Small deterministic pseudorandom number generator (SplitMix64), so that this module does not
+/// depend on external crates.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly random integer in `[0, n)`. `n` must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0);
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns a uniformly random integer in `[low, high]`.
+    pub fn range(&mut self, low: i32, high: i32) -> i32 {
+        assert!(low <= high);
+        low + self.below((high - low) as usize + 1) as i32
+    }
+
+    /// Returns `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Parameters of random instances.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct InstanceConfig {
+    pub num_bool_vars: usize,
+    pub num_int_vars: usize,
+    /// Integer variables take values in `[min_value, max_value]`.
+    pub min_value: i32,
+    pub max_value: i32,
+    pub num_constraints: usize,
+    /// Maximum nesting depth of expressions. Larger values lead to denser constraints.
+    pub max_depth: usize,
+    /// Maximum number of terms of linear expressions.
+    pub max_linear_terms: usize,
+    /// Probability that a constraint is `AllDifferent` rather than a boolean expression.
+    pub all_different_ratio: f64,
+}
+
+impl Default for InstanceConfig {
+    fn default() -> Self {
+        InstanceConfig {
+            num_bool_vars: 2,
+            num_int_vars: 3,
+            min_value: -2,
+            max_value: 2,
+            num_constraints: 3,
+            max_depth: 2,
+            max_linear_terms: 3,
+            all_different_ratio: 0.1,
+        }
+    }
+}
+
+/// A constraint of a random instance (the subset of `Stmt` which can be cloned).
+#[derive(Clone, Debug)]
+pub enum Constraint {
+    Expr(BoolExpr),
+    AllDifferent(Vec<IntExpr>),
+}
+
+impl Constraint {
+    pub fn to_stmt(&self) -> Stmt {
+        match self {
+            Constraint::Expr(e) => Stmt::Expr(e.clone()),
+            Constraint::AllDifferent(exprs) => Stmt::AllDifferent(exprs.clone()),
+        }
+    }
+
+    pub fn is_satisfied(&self, assignment: &Assignment) -> bool {
+        match self {
+            Constraint::Expr(e) => assignment.eval_bool_expr(e),
+            Constraint::AllDifferent(exprs) => {
+                let values = exprs
+                    .iter()
+                    .map(|e| assignment.eval_int_expr(e))
+                    .collect::<Vec<_>>();
+                (0..values.len()).all(|i| !values[(i + 1)..].contains(&values[i]))
+            }
+        }
+    }
+}
+
+pub struct RandomInstance {
+    pub bool_vars: Vec<BoolVar>,
+    /// Integer variables with the values they can take.
+    pub int_vars: Vec<(IntVar, Vec<i32>)>,
+    pub constraints: Vec<Constraint>,
+}
+
+struct ExprGenerator<'a> {
+    config: &'a InstanceConfig,
+    rng: &'a mut Rng,
+    bool_vars: &'a [BoolVar],
+    int_vars: &'a [IntVar],
+}
+
+impl ExprGenerator<'_> {
+    fn bool_expr(&mut self, depth: usize) -> BoolExpr {
+        if depth == 0 || self.rng.chance(0.3) {
+            return self.bool_leaf();
+        }
+        match self.rng.below(6) {
+            0 | 1 => {
+                let n = self.rng.range(2, 3);
+                let children = (0..n)
+                    .map(|_| Box::new(self.bool_expr(depth - 1)))
+                    .collect();
+                if self.rng.chance(0.5) {
+                    BoolExpr::And(children)
+                } else {
+                    BoolExpr::Or(children)
+                }
+            }
+            2 => !self.bool_expr(depth - 1),
+            3 => self.bool_expr(depth - 1) ^ self.bool_expr(depth - 1),
+            4 => self.bool_expr(depth - 1).iff(self.bool_expr(depth - 1)),
+            _ => self.bool_expr(depth - 1).imp(self.bool_expr(depth - 1)),
+        }
+    }
+
+    fn bool_leaf(&mut self) -> BoolExpr {
+        if !self.bool_vars.is_empty() && (self.int_vars.is_empty() || self.rng.chance(0.4)) {
+            let var = self.rng.choose(self.bool_vars).expr();
+            if self.rng.chance(0.5) {
+                !var
+            } else {
+                var
+            }
+        } else {
+            self.comparison(0)
+        }
+    }
+
+    fn comparison(&mut self, depth: usize) -> BoolExpr {
+        let lhs = self.int_expr(depth);
+        let rhs = IntExpr::Const(self.rng.range(-2, 2));
+        match self.rng.below(6) {
+            0 => lhs.eq(rhs),
+            1 => lhs.ne(rhs),
+            2 => lhs.le(rhs),
+            3 => lhs.lt(rhs),
+            4 => lhs.ge(rhs),
+            _ => lhs.gt(rhs),
+        }
+    }
+
+    fn int_expr(&mut self, depth: usize) -> IntExpr {
+        if self.int_vars.is_empty() {
+            return IntExpr::Const(self.rng.range(-2, 2));
+        }
+        if depth == 0 || self.rng.chance(0.5) {
+            return self.linear_expr();
+        }
+        match self.rng.below(3) {
+            0 => {
+                let cond = self.bool_expr(depth - 1);
+                cond.ite(self.int_expr(depth - 1), self.int_expr(depth - 1))
+            }
+            1 => self.int_expr(depth - 1).abs(),
+            _ => {
+                let lhs = self.rng.choose(self.int_vars).expr();
+                let rhs = self.rng.choose(self.int_vars).expr();
+                lhs * rhs
+            }
+        }
+    }
+
+    fn linear_expr(&mut self) -> IntExpr {
+        let n = self
+            .rng
+            .range(1, self.config.max_linear_terms.max(1) as i32);
+        let mut terms = (0..n)
+            .map(|_| {
+                let var = self.rng.choose(self.int_vars).expr();
+                let coef = *self.rng.choose(&[-2, -1, 1, 2]);
+                (Box::new(var), coef)
+            })
+            .collect::<Vec<_>>();
+        let constant = self.rng.range(-1, 1);
+        if constant != 0 {
+            terms.push((Box::new(IntExpr::Const(constant)), 1));
+        }
+        IntExpr::Linear(terms)
+    }
+}
+
+impl RandomInstance {
+    /// Generates a random instance and adds it to `solver`.
+    pub fn generate(
+        solver: &mut IntegratedSolver,
+        config: &InstanceConfig,
+        seed: u64,
+    ) -> RandomInstance {
+        let mut rng = Rng::new(seed);
+
+        let bool_vars = (0..config.num_bool_vars)
+            .map(|_| solver.new_bool_var())
+            .collect::<Vec<_>>();
+        let mut int_vars = vec![];
+        for _ in 0..config.num_int_vars {
+            let (min, max) = (config.min_value, config.max_value);
+            if rng.chance(0.5) {
+                let low = rng.range(min, max);
+                let high = rng.range(low, max);
+                let var = solver.new_int_var(Domain::range(low, high));
+                int_vars.push((var, (low..=high).collect()));
+            } else {
+                let mut values = (min..=max).filter(|_| rng.chance(0.6)).collect::<Vec<_>>();
+                if values.is_empty() {
+                    values.push(rng.range(min, max));
+                }
+                let var = solver.new_int_var_from_list(values.clone());
+                int_vars.push((var, values));
+            }
+        }
+
+        let vars = int_vars.iter().map(|&(v, _)| v).collect::<Vec<_>>();
+        let mut generator = ExprGenerator {
+            config,
+            rng: &mut rng,
+            bool_vars: &bool_vars,
+            int_vars: &vars,
+        };
+        let mut constraints = vec![];
+        for _ in 0..config.num_constraints {
+            let constraint = if vars.len() >= 2 && generator.rng.chance(config.all_different_ratio)
+            {
+                let n = generator.rng.range(2, vars.len() as i32);
+                Constraint::AllDifferent((0..n).map(|_| generator.linear_expr()).collect())
+            } else {
+                Constraint::Expr(generator.bool_expr(config.max_depth))
+            };
+            solver.add_constraint(constraint.to_stmt());
+            constraints.push(constraint);
+        }
+
+        RandomInstance {
+            bool_vars,
+            int_vars,
+            constraints,
+        }
+    }
+
+    pub fn is_satisfied(&self, assignment: &Assignment) -> bool {
+        self.constraints.iter().all(|c| c.is_satisfied(assignment))
+    }
+
+    /// Enumerates all the assignments to the variables of this instance satisfying all the
+    /// constraints, by trying every assignment. Only feasible for small instances.
+    pub fn enumerate_solutions_brute_force(&self) -> Vec<Assignment> {
+        fn visit(
+            instance: &RandomInstance,
+            i: usize,
+            assignment: &mut Assignment,
+            ret: &mut Vec<Assignment>,
+        ) {
+            let num_bool_vars = instance.bool_vars.len();
+            if i < num_bool_vars {
+                for value in [false, true] {
+                    assignment.set_bool(instance.bool_vars[i], value);
+                    visit(instance, i + 1, assignment, ret);
+                }
+            } else if i < num_bool_vars + instance.int_vars.len() {
+                let (var, values) = &instance.int_vars[i - num_bool_vars];
+                for &value in values {
+                    assignment.set_int(*var, value);
+                    visit(instance, i + 1, assignment, ret);
+                }
+            } else if instance.is_satisfied(assignment) {
+                ret.push(assignment.clone());
+            }
+        }
+
+        let mut ret = vec![];
+        visit(self, 0, &mut Assignment::new(), &mut ret);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn check_instances(
+        config: Config,
+        instance_config: &InstanceConfig,
+        seeds: std::ops::Range<u64>,
+    ) {
+        for seed in seeds {
+            let mut solver = IntegratedSolver::with_config(config);
+            let instance = RandomInstance::generate(&mut solver, instance_config, seed);
+            let expected = instance.enumerate_solutions_brute_force().len();
+            let actual = solver.enumerate_valid_assignments();
+            assert!(
+                actual.iter().all(|a| instance.is_satisfied(a)),
+                "invalid solution for seed {}",
+                seed
+            );
+            assert_eq!(actual.len(), expected, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_rng_reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+        for _ in 0..100 {
+            let x = a.range(-3, 4);
+            assert!((-3..=4).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_random_instances_default_config() {
+        check_instances(Config::default(), &InstanceConfig::default(), 0..30);
+    }
+
+    #[test]
+    fn test_random_instances_log_encoding() {
+        let mut config = Config::default();
+        config.force_use_log_encoding = true;
+        // Log encoding does not support negative values
+        let instance_config = InstanceConfig {
+            min_value: 0,
+            max_value: 4,
+            ..InstanceConfig::default()
+        };
+        check_instances(config, &instance_config, 0..30);
+    }
+
+    #[test]
+    fn test_random_instances_without_optimizations() {
+        let mut config = Config::default();
+        config.use_constant_folding = false;
+        config.use_constant_propagation = false;
+        config.use_norm_domain_refinement = false;
+        config.use_direct_encoding = false;
+        config.merge_equivalent_variables = false;
+        check_instances(config, &InstanceConfig::default(), 0..30);
+    }
+}
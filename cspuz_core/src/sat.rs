@@ -1,4 +1,6 @@
+use std::cell::{Cell, RefCell};
 use std::ops::Not;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "backend-cadical")]
 use crate::backend::cadical;
@@ -49,6 +51,278 @@ pub struct SATSolverStats {
     pub conflicts: Option<u64>,
 }
 
+/// Progress of SAT solving, reported to the callback installed by `set_progress_callback`.
+pub struct SolveProgress {
+    /// Time elapsed since the callback was installed.
+    pub elapsed: Duration,
+    /// Number of conflicts in the current SAT solver call.
+    pub conflicts: u64,
+}
+
+/// Returns `true` to continue solving, or `false` to cancel it.
+pub type ProgressCallback = Box<dyn FnMut(&SolveProgress) -> bool>;
+
+/// Number of conflicts between two calls of the progress callback.
+const PROGRESS_CONFLICT_INTERVAL: u64 = 10000;
+
+struct ProgressState {
+    callback: ProgressCallback,
+    start: Instant,
+}
+
+thread_local! {
+    static PROGRESS: RefCell<Option<ProgressState>> = const { RefCell::new(None) };
+    static INTERRUPTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs (or removes, if `None`) the progress callback for SAT solving on the current thread.
+/// While a callback is installed, the Glucose backend solves in chunks of conflicts and calls it
+/// in between. Once it returns `false`, the ongoing and all subsequent SAT solver calls report
+/// unsatisfiability and `is_interrupted` returns `true` until the callback is replaced.
+/// Other backends never call the callback.
+pub fn set_progress_callback(callback: Option<ProgressCallback>) {
+    PROGRESS.with(|progress| {
+        *progress.borrow_mut() = callback.map(|callback| ProgressState {
+            callback,
+            start: Instant::now(),
+        });
+    });
+    INTERRUPTED.with(|interrupted| interrupted.set(false));
+}
+
+/// Returns whether solving was cancelled by the progress callback.
+/// Results obtained after cancellation are meaningless.
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.with(|interrupted| interrupted.get())
+}
+
+fn solve_glucose_with_progress(
+    solver: &mut glucose::Solver,
+    assumptions: &[Lit],
+    conflict_budget: Option<u64>,
+) -> Option<bool> {
+    if is_interrupted() {
+        return Some(false);
+    }
+    let has_callback = PROGRESS.with(|progress| progress.borrow().is_some());
+    if !has_callback {
+        if assumptions.is_empty() && conflict_budget.is_none() {
+            return Some(solver.solve_without_model());
+        }
+        return solver.solve_without_model_limited(assumptions, conflict_budget);
+    }
+
+    let conflicts_start = solver.stats_conflicts();
+    let mut remaining_budget = conflict_budget;
+    loop {
+        let chunk = remaining_budget.map_or(PROGRESS_CONFLICT_INTERVAL, |b| {
+            b.min(PROGRESS_CONFLICT_INTERVAL)
+        });
+        if let Some(res) = solver.solve_without_model_limited(assumptions, Some(chunk)) {
+            return Some(res);
+        }
+        if let Some(b) = remaining_budget.as_mut() {
+            *b -= chunk;
+            if *b == 0 {
+                return None;
+            }
+        }
+        let cont = PROGRESS.with(|progress| match progress.borrow_mut().as_mut() {
+            Some(state) => {
+                let info = SolveProgress {
+                    elapsed: state.start.elapsed(),
+                    conflicts: solver.stats_conflicts() - conflicts_start,
+                };
+                (state.callback)(&info)
+            }
+            None => true,
+        });
+        if !cont {
+            INTERRUPTED.with(|interrupted| interrupted.set(true));
+            return Some(false);
+        }
+    }
+}
+
+/// Statistics of SAT solver calls, gathered by `collect_stats`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SolveStats {
+    /// Number of SAT solver calls.
+    pub solver_calls: u64,
+    /// Number of variables of the largest SAT instance solved.
+    pub variables: usize,
+    /// Number of clauses of the largest SAT instance solved.
+    pub clauses: usize,
+    /// Total number of conflicts over all solver calls.
+    pub conflicts: u64,
+}
+
+impl SolveStats {
+    fn merge(&mut self, other: &SolveStats) {
+        self.solver_calls += other.solver_calls;
+        self.variables = self.variables.max(other.variables);
+        self.clauses = self.clauses.max(other.clauses);
+        self.conflicts += other.conflicts;
+    }
+}
+
+thread_local! {
+    static STATS: RefCell<Option<SolveStats>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns its result together with the statistics of the SAT solver calls made by
+/// it on the current thread. Only the Glucose backend reports statistics.
+pub fn collect_stats<T, F: FnOnce() -> T>(f: F) -> (T, SolveStats) {
+    let outer = STATS.with(|stats| stats.replace(Some(SolveStats::default())));
+    let ret = f();
+    let collected = STATS.with(|stats| stats.replace(outer)).unwrap_or_default();
+    STATS.with(|stats| {
+        if let Some(outer) = stats.borrow_mut().as_mut() {
+            outer.merge(&collected);
+        }
+    });
+    (ret, collected)
+}
+
+/// Limits on the size of SAT instances, enforced by `with_instance_limits`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct InstanceLimits {
+    pub max_variables: Option<usize>,
+    pub max_clauses: Option<usize>,
+}
+
+impl InstanceLimits {
+    fn is_exceeded_by(&self, variables: usize, clauses: usize) -> bool {
+        self.max_variables.is_some_and(|m| variables > m)
+            || self.max_clauses.is_some_and(|m| clauses > m)
+    }
+}
+
+thread_local! {
+    static LIMITS: Cell<InstanceLimits> = const {
+        Cell::new(InstanceLimits {
+            max_variables: None,
+            max_clauses: None,
+        })
+    };
+    static LIMIT_EXCEEDED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` with `limits` on the size of SAT instances solved on the current thread, and returns
+/// its result together with whether some instance exceeded the limits.
+///
+/// Instances are checked right before being solved. Once an instance exceeds the limits, it and
+/// all subsequent SAT solver calls in `f` report unsatisfiability without solving, so the result
+/// of `f` is meaningless in that case. Only the Glucose backend checks the limits.
+pub fn with_instance_limits<T, F: FnOnce() -> T>(limits: InstanceLimits, f: F) -> (T, bool) {
+    let outer_limits = LIMITS.with(|l| l.replace(limits));
+    let outer_exceeded = LIMIT_EXCEEDED.with(|e| e.replace(false));
+    let ret = f();
+    LIMITS.with(|l| l.set(outer_limits));
+    let exceeded = LIMIT_EXCEEDED.with(|e| e.replace(outer_exceeded));
+    (ret, exceeded)
+}
+
+fn check_instance_limits(solver: &glucose::Solver) -> bool {
+    if LIMIT_EXCEEDED.with(|e| e.get()) {
+        return false;
+    }
+    let limits = LIMITS.with(|l| l.get());
+    if limits.is_exceeded_by(solver.num_var() as usize, solver.num_clauses()) {
+        LIMIT_EXCEEDED.with(|e| e.set(true));
+        return false;
+    }
+    true
+}
+
+fn solve_glucose(
+    solver: &mut glucose::Solver,
+    assumptions: &[Lit],
+    conflict_budget: Option<u64>,
+) -> Option<bool> {
+    if !check_instance_limits(solver) {
+        return Some(false);
+    }
+    let conflicts_start = solver.stats_conflicts();
+    let res = solve_glucose_with_progress(solver, assumptions, conflict_budget);
+    STATS.with(|stats| {
+        if let Some(stats) = stats.borrow_mut().as_mut() {
+            stats.merge(&SolveStats {
+                solver_calls: 1,
+                variables: solver.num_var() as usize,
+                clauses: solver.num_clauses(),
+                conflicts: solver.stats_conflicts() - conflicts_start,
+            });
+        }
+    });
+    res
+}
+
+/// An operation on a SAT solver, recorded by `record_operations` to be replayed later.
+pub(crate) enum SATOperation {
+    Clause(Vec<Lit>),
+    OrderEncodingLinear(Vec<Vec<Lit>>, Vec<Vec<i32>>, Vec<i32>, i32),
+    ActiveVerticesConnected(Vec<Lit>, Vec<(usize, usize)>),
+    ExtensionSupports(Vec<Vec<Lit>>, Vec<Vec<Option<usize>>>),
+    GraphDivision(Vec<Vec<i32>>, Vec<Vec<Lit>>, Vec<(usize, usize)>, Vec<Lit>),
+}
+
+struct Recording {
+    operations: Vec<SATOperation>,
+    replayable: bool,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+    /// Whether `RECORDING` is `Some`, checked first so that adding clauses does not borrow
+    /// `RECORDING` unless operations are recorded.
+    static RECORDING_ACTIVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` and returns its result together with the operations made by it on SAT solvers on the
+/// current thread (except creation of variables), or `None` if some of them cannot be replayed,
+/// which is the case for custom constraints.
+pub(crate) fn record_operations<T, F: FnOnce() -> T>(f: F) -> (T, Option<Vec<SATOperation>>) {
+    let outer = RECORDING.with(|recording| {
+        recording.replace(Some(Recording {
+            operations: vec![],
+            replayable: true,
+        }))
+    });
+    RECORDING_ACTIVE.with(|active| active.set(true));
+    let ret = f();
+    RECORDING_ACTIVE.with(|active| active.set(outer.is_some()));
+    let recorded = RECORDING
+        .with(|recording| recording.replace(outer))
+        .unwrap();
+    (ret, recorded.replayable.then_some(recorded.operations))
+}
+
+fn record<F: FnOnce() -> SATOperation>(op: F) {
+    if !RECORDING_ACTIVE.with(|active| active.get()) {
+        return;
+    }
+    RECORDING.with(|recording| {
+        if let Some(recording) = recording.borrow_mut().as_mut() {
+            if recording.replayable {
+                recording.operations.push(op());
+            }
+        }
+    });
+}
+
+fn record_unreplayable() {
+    if !RECORDING_ACTIVE.with(|active| active.get()) {
+        return;
+    }
+    RECORDING.with(|recording| {
+        if let Some(recording) = recording.borrow_mut().as_mut() {
+            recording.replayable = false;
+            recording.operations.clear();
+        }
+    });
+}
+
 /// Adapter to SAT solver.
 /// To support other SAT solver without changing previous stages, we introduce an adapter instead of
 /// using `glucose::Solver` directly from the encoder.
@@ -60,14 +334,14 @@ pub enum SAT {
     CaDiCaL(cadical::Solver),
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Backend {
     Glucose,
     External,
     CaDiCaL,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum OrderEncodingLinearMode {
     Cpp,
     Rust,
@@ -127,6 +401,18 @@ impl SAT {
         }
     }
 
+    /// Number of clauses added so far, if the backend keeps track of it. Constraints handled
+    /// natively by the backend (e.g. `add_order_encoding_linear`) are not counted.
+    pub fn num_clauses(&self) -> Option<usize> {
+        match self {
+            SAT::Glucose(solver) => Some(solver.num_clauses()),
+            #[cfg(feature = "backend-external")]
+            SAT::External(solver) => Some(solver.num_clauses()),
+            #[cfg(feature = "backend-cadical")]
+            SAT::CaDiCaL(_) => None,
+        }
+    }
+
     pub fn all_vars(&self) -> Vec<Var> {
         match self {
             SAT::Glucose(solver) => {
@@ -197,6 +483,7 @@ impl SAT {
     }
 
     pub fn add_clause(&mut self, clause: &[Lit]) {
+        record(|| SATOperation::Clause(clause.to_vec()));
         match self {
             SAT::Glucose(solver) => {
                 solver.add_clause(clause);
@@ -219,6 +506,9 @@ impl SAT {
         coefs: Vec<i32>,
         constant: i32,
     ) -> bool {
+        record(|| {
+            SATOperation::OrderEncodingLinear(lits.clone(), domain.clone(), coefs.clone(), constant)
+        });
         match self {
             SAT::Glucose(solver) => {
                 solver.add_order_encoding_linear(&lits, &domain, &coefs, constant)
@@ -251,6 +541,7 @@ impl SAT {
         lits: Vec<Lit>,
         edges: Vec<(usize, usize)>,
     ) -> bool {
+        record(|| SATOperation::ActiveVerticesConnected(lits.clone(), edges.clone()));
         match self {
             SAT::Glucose(solver) => solver.add_active_vertices_connected(&lits, &edges),
             #[cfg(feature = "backend-external")]
@@ -280,6 +571,7 @@ impl SAT {
         vars: &[Vec<Lit>],
         supports: &[Vec<Option<usize>>],
     ) -> bool {
+        record(|| SATOperation::ExtensionSupports(vars.to_vec(), supports.to_vec()));
         match self {
             SAT::Glucose(solver) => solver.add_direct_encoding_extension_supports(&vars, supports),
             #[cfg(feature = "backend-external")]
@@ -298,6 +590,14 @@ impl SAT {
         edges: &[(usize, usize)],
         edge_lits: &[Lit],
     ) -> bool {
+        record(|| {
+            SATOperation::GraphDivision(
+                domains.to_vec(),
+                dom_lits.to_vec(),
+                edges.to_vec(),
+                edge_lits.to_vec(),
+            )
+        });
         match self {
             SAT::Glucose(solver) => solver.add_graph_division(domains, dom_lits, edges, edge_lits),
             #[cfg(feature = "backend-external")]
@@ -312,6 +612,7 @@ impl SAT {
         inputs: Vec<Lit>,
         constr: Box<dyn PropagatorGenerator>,
     ) -> bool {
+        record_unreplayable();
         #[allow(unreachable_patterns)]
         match self {
             SAT::Glucose(solver) => {
@@ -322,6 +623,30 @@ impl SAT {
         }
     }
 
+    /// Performs `op` recorded by `record_operations`.
+    pub(crate) fn replay(&mut self, op: &SATOperation) {
+        match op {
+            SATOperation::Clause(clause) => self.add_clause(clause),
+            SATOperation::OrderEncodingLinear(lits, domain, coefs, constant) => {
+                self.add_order_encoding_linear(
+                    lits.clone(),
+                    domain.clone(),
+                    coefs.clone(),
+                    *constant,
+                );
+            }
+            SATOperation::ActiveVerticesConnected(lits, edges) => {
+                self.add_active_vertices_connected(lits.clone(), edges.clone());
+            }
+            SATOperation::ExtensionSupports(vars, supports) => {
+                self.add_direct_encoding_extension_supports(vars, supports);
+            }
+            SATOperation::GraphDivision(domains, dom_lits, edges, edge_lits) => {
+                self.add_graph_division(domains, dom_lits, edges, edge_lits);
+            }
+        }
+    }
+
     pub fn set_seed(&mut self, seed: f64) {
         match self {
             SAT::Glucose(solver) => solver.set_seed(seed),
@@ -354,7 +679,13 @@ impl SAT {
 
     pub fn solve<'a>(&'a mut self) -> Option<SATModel<'a>> {
         match self {
-            SAT::Glucose(solver) => solver.solve().map(|model| SATModel::Glucose(model)),
+            SAT::Glucose(solver) => {
+                if solve_glucose(solver, &[], None) == Some(true) {
+                    Some(SATModel::Glucose(unsafe { solver.model() }))
+                } else {
+                    None
+                }
+            }
             #[cfg(feature = "backend-external")]
             SAT::External(solver) => solver.solve().map(|model| SATModel::External(model)),
             #[cfg(feature = "backend-cadical")]
@@ -364,7 +695,7 @@ impl SAT {
 
     pub fn solve_without_model(&mut self) -> bool {
         match self {
-            SAT::Glucose(solver) => solver.solve_without_model(),
+            SAT::Glucose(solver) => solve_glucose(solver, &[], None) == Some(true),
             #[cfg(feature = "backend-external")]
             SAT::External(solver) => solver.solve_without_model(),
             #[cfg(feature = "backend-cadical")]
@@ -372,6 +703,23 @@ impl SAT {
         }
     }
 
+    /// Same as `solve_without_model`, but every literal in `assumptions` is assumed to be true
+    /// (unlike clauses, the assumptions do not persist after this call), and the search is given up
+    /// after `conflict_budget` conflicts, in which case `None` is returned.
+    pub fn solve_without_model_limited(
+        &mut self,
+        assumptions: &[Lit],
+        conflict_budget: Option<u64>,
+    ) -> Option<bool> {
+        match self {
+            SAT::Glucose(solver) => solve_glucose(solver, assumptions, conflict_budget),
+            #[cfg(feature = "backend-external")]
+            SAT::External(_) => panic!("assumptions are not supported by the external backend"),
+            #[cfg(feature = "backend-cadical")]
+            SAT::CaDiCaL(_) => panic!("assumptions are not supported by the CaDiCaL backend"),
+        }
+    }
+
     pub(crate) unsafe fn model<'a>(&'a self) -> SATModel<'a> {
         match self {
             SAT::Glucose(solver) => SATModel::Glucose(solver.model()),
@@ -428,3 +776,127 @@ impl<'a> SATModel<'a> {
         self.assignment(lit.var()) ^ lit.is_negated()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pigeonhole(sat: &mut SAT, n: usize) {
+        // n + 1 pigeons in n holes: unsatisfiable, and hard enough to need many conflicts
+        let vars = (0..=n)
+            .map(|_| (0..n).map(|_| new_var_for_test(sat)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for row in &vars {
+            let clause = row.iter().map(|v| v.as_lit(false)).collect::<Vec<_>>();
+            sat.add_clause(&clause);
+        }
+        for p in 0..=n {
+            for q in (p + 1)..=n {
+                for (a, b) in vars[p].iter().zip(&vars[q]) {
+                    sat.add_clause(&[a.as_lit(true), b.as_lit(true)]);
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "sat-analyzer"))]
+    fn new_var_for_test(sat: &mut SAT) -> Var {
+        sat.new_var()
+    }
+
+    #[cfg(feature = "sat-analyzer")]
+    fn new_var_for_test(sat: &mut SAT) -> Var {
+        sat.new_var("x")
+    }
+
+    #[test]
+    fn test_collect_stats() {
+        let ((), stats) = collect_stats(|| {
+            let mut sat = SAT::new_glucose();
+            pigeonhole(&mut sat, 4);
+            assert!(!sat.solve_without_model());
+
+            let ((), inner) = collect_stats(|| {
+                let mut sat = SAT::new_glucose();
+                let x = new_var_for_test(&mut sat);
+                sat.add_clause(&[x.as_lit(false)]);
+                assert!(sat.solve().is_some());
+            });
+            assert_eq!(inner.solver_calls, 1);
+            assert_eq!(inner.variables, 1);
+            assert_eq!(inner.clauses, 1);
+        });
+
+        assert_eq!(stats.solver_calls, 2);
+        assert_eq!(stats.variables, 20);
+        assert_eq!(stats.clauses, 5 + 4 * 10);
+        assert!(stats.conflicts > 0);
+    }
+
+    #[test]
+    fn test_progress_callback_cancel() {
+        let calls = std::rc::Rc::new(Cell::new(0));
+        let calls2 = calls.clone();
+        set_progress_callback(Some(Box::new(move |progress| {
+            assert!(progress.conflicts >= PROGRESS_CONFLICT_INTERVAL);
+            calls2.set(calls2.get() + 1);
+            false
+        })));
+
+        let mut sat = SAT::new_glucose();
+        pigeonhole(&mut sat, 9);
+        assert!(!sat.solve_without_model());
+        assert!(is_interrupted());
+        assert_eq!(calls.get(), 1);
+
+        // Subsequent calls are cancelled immediately
+        let mut sat = SAT::new_glucose();
+        let x = new_var_for_test(&mut sat);
+        sat.add_clause(&[x.as_lit(false)]);
+        assert!(sat.solve().is_none());
+        assert_eq!(calls.get(), 1);
+
+        set_progress_callback(None);
+        assert!(!is_interrupted());
+        assert!(sat.solve().is_some());
+    }
+
+    #[test]
+    fn test_instance_limits() {
+        let limits = InstanceLimits {
+            max_variables: None,
+            max_clauses: Some(10),
+        };
+
+        let (res, exceeded) = with_instance_limits(limits, || {
+            let mut sat = SAT::new_glucose();
+            let x = new_var_for_test(&mut sat);
+            sat.add_clause(&[x.as_lit(false)]);
+            sat.solve().is_some()
+        });
+        assert!(res);
+        assert!(!exceeded);
+
+        let (res, exceeded) = with_instance_limits(limits, || {
+            let mut sat = SAT::new_glucose();
+            pigeonhole(&mut sat, 3);
+            let first = sat.solve_without_model();
+
+            // Subsequent calls are rejected even if the instance is small
+            let mut sat = SAT::new_glucose();
+            let x = new_var_for_test(&mut sat);
+            sat.add_clause(&[x.as_lit(false)]);
+            (first, sat.solve().is_some())
+        });
+        assert_eq!(res, (false, false));
+        assert!(exceeded);
+
+        // Limits do not apply outside `with_instance_limits`
+        let mut sat = SAT::new_glucose();
+        for _ in 0..20 {
+            let x = new_var_for_test(&mut sat);
+            sat.add_clause(&[x.as_lit(false)]);
+        }
+        assert!(sat.solve().is_some());
+    }
+}
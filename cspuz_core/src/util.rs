@@ -50,6 +50,19 @@ impl<K: ConvertMapIndex, V: Default> ConvertMap<K, V> {
     pub fn len(&self) -> usize {
         self.data.len()
     }
+
+    /// Returns the values stored so far, in the order of the indices of their keys.
+    pub fn values(&self) -> &[V] {
+        &self.data
+    }
+
+    pub fn from_values(data: Vec<V>) -> ConvertMap<K, V> {
+        ConvertMap {
+            data,
+            key_type: PhantomData,
+            default: V::default(),
+        }
+    }
 }
 
 impl<K: ConvertMapIndex, V: Default> Index<K> for ConvertMap<K, V> {
@@ -0,0 +1,140 @@
+#ifndef CSPUZ_CORE_H
+#define CSPUZ_CORE_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Comparison operator of a linear constraint.
+ */
+typedef enum CspuzComparison {
+  CspuzComparison_Eq,
+  CspuzComparison_Ne,
+  CspuzComparison_Le,
+  CspuzComparison_Lt,
+  CspuzComparison_Ge,
+  CspuzComparison_Gt,
+} CspuzComparison;
+
+/**
+ * Handle of a solver, opaque to C.
+ */
+typedef struct CspuzSolver CspuzSolver;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a solver, which must be released by `cspuz_solver_free`. Returns null if it fails.
+ */
+CspuzSolver *cspuz_solver_new(void);
+
+/**
+ * Releases `solver`. Does nothing if `solver` is null.
+ *
+ * # Safety
+ *
+ * `solver` must be null or a solver created by `cspuz_solver_new` which has not been released
+ * yet.
+ */
+void cspuz_solver_free(CspuzSolver *solver);
+
+/**
+ * Adds a boolean variable and returns its number, or 0 if it fails.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+int32_t cspuz_solver_add_bool_var(CspuzSolver *solver);
+
+/**
+ * Adds an integer variable taking a value in `[low, high]` and returns its number, or 0 if it
+ * fails (e.g. `low > high`).
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+int32_t cspuz_solver_add_int_var(CspuzSolver *solver, int32_t low, int32_t high);
+
+/**
+ * Adds the constraint that at least one of the `num_lits` literals `lits` holds.
+ * Returns `false` (and adds nothing) if it fails, e.g. if some literal refers to no variable.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`, and `lits` must point to
+ * `num_lits` integers (it may be null if `num_lits == 0`).
+ */
+bool cspuz_solver_add_clause(CspuzSolver *solver, const int32_t *lits, uintptr_t num_lits);
+
+/**
+ * Adds the linear constraint `sum(coefs[i] * vars[i]) <op> rhs` over the `num_terms` integer
+ * variables `vars`. Returns `false` (and adds nothing) if it fails, e.g. if some of `vars` is
+ * not a variable.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`, and `coefs` and `vars` must
+ * each point to `num_terms` integers (they may be null if `num_terms == 0`).
+ */
+bool cspuz_solver_add_linear(CspuzSolver *solver,
+                             const int32_t *coefs,
+                             const int32_t *vars,
+                             uintptr_t num_terms,
+                             CspuzComparison op,
+                             int32_t rhs);
+
+/**
+ * Solves the constraints added so far. Returns `true` if they are satisfiable, in which case
+ * the values of the variables can be obtained by `cspuz_solver_get_bool` and
+ * `cspuz_solver_get_int`. If it returns `false`, the constraints are unsatisfiable unless
+ * `cspuz_solver_last_error` returns an error.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+bool cspuz_solver_solve(CspuzSolver *solver);
+
+/**
+ * Returns the value of the boolean variable `var` in the last solution found by
+ * `cspuz_solver_solve`, or `false` if there is no such solution or variable.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+bool cspuz_solver_get_bool(CspuzSolver *solver, int32_t var);
+
+/**
+ * Returns the value of the integer variable `var` in the last solution found by
+ * `cspuz_solver_solve`, or 0 if there is no such solution or variable.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+int32_t cspuz_solver_get_int(CspuzSolver *solver, int32_t var);
+
+/**
+ * Returns the reason why the last call on `solver` failed, or null if it succeeded. The string
+ * is owned by `solver` and valid until the next call on it.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver created by `cspuz_solver_new`.
+ */
+const char *cspuz_solver_last_error(const CspuzSolver *solver);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CSPUZ_CORE_H */
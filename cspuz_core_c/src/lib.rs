@@ -0,0 +1,435 @@
+//! C interface of the CSP solver of `cspuz_core`, for embedding it in C / C++ applications and
+//! in other languages through their C FFI.
+//!
+//! The header `include/cspuz_core.h` is generated from this crate by
+//! [cbindgen](https://github.com/mozilla/cbindgen) (run `cbindgen --config cbindgen.toml --output
+//! include/cspuz_core.h` in the `cspuz_core_c` directory).
+//!
+//! A solver is created by `cspuz_solver_new` and released by `cspuz_solver_free`. Boolean and
+//! integer variables are numbered separately, starting from 1 in the order of creation; in
+//! clauses, the literal `v` stands for the boolean variable `v` and `-v` for its negation, as in
+//! the DIMACS CNF format. Constraints may be added after solving, to solve again.
+//!
+//! Functions taking a solver report their failures through their return values (`false` or 0), and
+//! `cspuz_solver_last_error` then returns the reason. Panics never unwind into the caller: they
+//! are reported in the same way, after which every call on the solver fails except
+//! `cspuz_solver_last_error` and `cspuz_solver_free`.
+//!
+//! When built for `wasm32-unknown-emscripten`, these functions are exported to JavaScript as well
+//! (see `util/emcc_wrapper` for the list of exports).
+
+use cspuz_core::csp::{BoolExpr, BoolVar, IntExpr, IntVar};
+use cspuz_core::domain::Domain;
+use cspuz_core::integration::IntegratedSolver;
+use std::any::Any;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Comparison operator of a linear constraint.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CspuzComparison {
+    Eq,
+    Ne,
+    Le,
+    Lt,
+    Ge,
+    Gt,
+}
+
+/// Handle of a solver, opaque to C.
+pub struct CspuzSolver {
+    solver: IntegratedSolver<'static>,
+    bool_vars: Vec<BoolVar>,
+    int_vars: Vec<IntVar>,
+    /// Values of `bool_vars` and `int_vars` in the last solution.
+    model: Option<(Vec<bool>, Vec<i32>)>,
+    /// Reason of the failure of the last call, returned by `cspuz_solver_last_error`.
+    error: Option<CString>,
+    /// Whether a call has panicked, leaving the solver in an unknown state.
+    broken: bool,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.as_str()
+    } else {
+        "unknown panic"
+    };
+    format!("internal error: {}", message)
+}
+
+/// Runs `f` on `solver`, returning `on_error` and recording the reason if it fails or panics.
+unsafe fn run<T>(
+    solver: *mut CspuzSolver,
+    on_error: T,
+    f: impl FnOnce(&mut CspuzSolver) -> Result<T, String>,
+) -> T {
+    let solver = &mut *solver;
+    if solver.broken {
+        return on_error;
+    }
+    solver.error = None;
+    let error = match catch_unwind(AssertUnwindSafe(|| f(&mut *solver))) {
+        Ok(Ok(ret)) => return ret,
+        Ok(Err(error)) => error,
+        Err(payload) => {
+            solver.broken = true;
+            panic_message(payload.as_ref())
+        }
+    };
+    // messages never contain NUL characters
+    solver.error = Some(CString::new(error).unwrap_or_default());
+    on_error
+}
+
+impl CspuzSolver {
+    fn bool_literal(&self, lit: i32) -> Option<BoolExpr> {
+        let var = *self
+            .bool_vars
+            .get((lit.unsigned_abs() as usize).checked_sub(1)?)?;
+        Some(if lit > 0 { var.expr() } else { !var.expr() })
+    }
+
+    fn int_var(&self, var: i32) -> Option<IntVar> {
+        self.int_vars
+            .get(usize::try_from(var).ok()?.checked_sub(1)?)
+            .copied()
+    }
+}
+
+/// Creates a solver, which must be released by `cspuz_solver_free`. Returns null if it fails.
+#[no_mangle]
+pub extern "C" fn cspuz_solver_new() -> *mut CspuzSolver {
+    catch_unwind(|| {
+        Box::into_raw(Box::new(CspuzSolver {
+            solver: IntegratedSolver::new(),
+            bool_vars: vec![],
+            int_vars: vec![],
+            model: None,
+            error: None,
+            broken: false,
+        }))
+    })
+    .unwrap_or(std::ptr::null_mut())
+}
+
+/// Releases `solver`. Does nothing if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a solver created by `cspuz_solver_new` which has not been released
+/// yet.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_free(solver: *mut CspuzSolver) {
+    if !solver.is_null() {
+        let _ = catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(solver))));
+    }
+}
+
+/// Adds a boolean variable and returns its number, or 0 if it fails.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_add_bool_var(solver: *mut CspuzSolver) -> i32 {
+    run(solver, 0, |solver| {
+        let var = solver.solver.new_bool_var();
+        solver.bool_vars.push(var);
+        Ok(solver.bool_vars.len() as i32)
+    })
+}
+
+/// Adds an integer variable taking a value in `[low, high]` and returns its number, or 0 if it
+/// fails (e.g. `low > high`).
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_add_int_var(
+    solver: *mut CspuzSolver,
+    low: i32,
+    high: i32,
+) -> i32 {
+    run(solver, 0, |solver| {
+        if low > high {
+            return Err(format!("empty domain [{}, {}]", low, high));
+        }
+        let var = solver.solver.new_int_var(Domain::range(low, high));
+        solver.int_vars.push(var);
+        Ok(solver.int_vars.len() as i32)
+    })
+}
+
+/// Adds the constraint that at least one of the `num_lits` literals `lits` holds.
+/// Returns `false` (and adds nothing) if it fails, e.g. if some literal refers to no variable.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`, and `lits` must point to
+/// `num_lits` integers (it may be null if `num_lits == 0`).
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_add_clause(
+    solver: *mut CspuzSolver,
+    lits: *const i32,
+    num_lits: usize,
+) -> bool {
+    let lits = if num_lits == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(lits, num_lits)
+    };
+    run(solver, false, |solver| {
+        let lits = lits
+            .iter()
+            .map(|&lit| {
+                solver
+                    .bool_literal(lit)
+                    .map(Box::new)
+                    .ok_or_else(|| format!("invalid literal {}", lit))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        solver.solver.add_expr(BoolExpr::Or(lits));
+        Ok(true)
+    })
+}
+
+/// Adds the linear constraint `sum(coefs[i] * vars[i]) <op> rhs` over the `num_terms` integer
+/// variables `vars`. Returns `false` (and adds nothing) if it fails, e.g. if some of `vars` is
+/// not a variable.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`, and `coefs` and `vars` must
+/// each point to `num_terms` integers (they may be null if `num_terms == 0`).
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_add_linear(
+    solver: *mut CspuzSolver,
+    coefs: *const i32,
+    vars: *const i32,
+    num_terms: usize,
+    op: CspuzComparison,
+    rhs: i32,
+) -> bool {
+    let (coefs, vars) = if num_terms == 0 {
+        (&[][..], &[][..])
+    } else {
+        (
+            std::slice::from_raw_parts(coefs, num_terms),
+            std::slice::from_raw_parts(vars, num_terms),
+        )
+    };
+    run(solver, false, |solver| {
+        let terms = coefs
+            .iter()
+            .zip(vars)
+            .map(|(&coef, &var)| {
+                let var = solver
+                    .int_var(var)
+                    .ok_or_else(|| format!("invalid integer variable {}", var))?;
+                Ok((Box::new(var.expr()), coef))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let lhs = IntExpr::Linear(terms);
+        let rhs = IntExpr::Const(rhs);
+        let expr = match op {
+            CspuzComparison::Eq => lhs.eq(rhs),
+            CspuzComparison::Ne => lhs.ne(rhs),
+            CspuzComparison::Le => lhs.le(rhs),
+            CspuzComparison::Lt => lhs.lt(rhs),
+            CspuzComparison::Ge => lhs.ge(rhs),
+            CspuzComparison::Gt => lhs.gt(rhs),
+        };
+        solver.solver.add_expr(expr);
+        Ok(true)
+    })
+}
+
+/// Solves the constraints added so far. Returns `true` if they are satisfiable, in which case
+/// the values of the variables can be obtained by `cspuz_solver_get_bool` and
+/// `cspuz_solver_get_int`. If it returns `false`, the constraints are unsatisfiable unless
+/// `cspuz_solver_last_error` returns an error.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_solve(solver: *mut CspuzSolver) -> bool {
+    run(solver, false, |solver| {
+        solver.model = None;
+        let model = solver.solver.solve().map(|model| {
+            let bool_values = solver.bool_vars.iter().map(|&v| model.get_bool(v));
+            let int_values = solver.int_vars.iter().map(|&v| model.get_int(v));
+            (bool_values.collect(), int_values.collect())
+        });
+        solver.model = model;
+        Ok(solver.model.is_some())
+    })
+}
+
+/// Returns the value of the boolean variable `var` in the last solution found by
+/// `cspuz_solver_solve`, or `false` if there is no such solution or variable.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_get_bool(solver: *mut CspuzSolver, var: i32) -> bool {
+    run(solver, false, |solver| {
+        let values = &solver
+            .model
+            .as_ref()
+            .ok_or_else(|| String::from("no solution"))?
+            .0;
+        usize::try_from(var)
+            .ok()
+            .and_then(|v| values.get(v.checked_sub(1)?))
+            .copied()
+            .ok_or_else(|| format!("invalid boolean variable {}", var))
+    })
+}
+
+/// Returns the value of the integer variable `var` in the last solution found by
+/// `cspuz_solver_solve`, or 0 if there is no such solution or variable.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_get_int(solver: *mut CspuzSolver, var: i32) -> i32 {
+    run(solver, 0, |solver| {
+        let values = &solver
+            .model
+            .as_ref()
+            .ok_or_else(|| String::from("no solution"))?
+            .1;
+        usize::try_from(var)
+            .ok()
+            .and_then(|v| values.get(v.checked_sub(1)?))
+            .copied()
+            .ok_or_else(|| format!("invalid integer variable {}", var))
+    })
+}
+
+/// Returns the reason why the last call on `solver` failed, or null if it succeeded. The string
+/// is owned by `solver` and valid until the next call on it.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver created by `cspuz_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solver_last_error(solver: *const CspuzSolver) -> *const c_char {
+    let solver = &*solver;
+    solver
+        .error
+        .as_ref()
+        .map_or(std::ptr::null(), |error| error.as_ptr())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_linear_and_clauses() {
+        unsafe {
+            let solver = cspuz_solver_new();
+            let x = cspuz_solver_add_int_var(solver, 0, 5);
+            let y = cspuz_solver_add_int_var(solver, 0, 5);
+            let a = cspuz_solver_add_bool_var(solver);
+            let b = cspuz_solver_add_bool_var(solver);
+            assert_eq!((x, y, a, b), (1, 2, 1, 2));
+
+            // x + y == 7, x - y >= 3
+            assert!(cspuz_solver_add_linear(
+                solver,
+                [1, 1].as_ptr(),
+                [x, y].as_ptr(),
+                2,
+                CspuzComparison::Eq,
+                7
+            ));
+            assert!(cspuz_solver_add_linear(
+                solver,
+                [1, -1].as_ptr(),
+                [x, y].as_ptr(),
+                2,
+                CspuzComparison::Ge,
+                3
+            ));
+            // !a, a || b
+            assert!(cspuz_solver_add_clause(solver, [-a].as_ptr(), 1));
+            assert!(cspuz_solver_add_clause(solver, [a, b].as_ptr(), 2));
+
+            assert!(cspuz_solver_solve(solver));
+            assert_eq!(cspuz_solver_get_int(solver, x), 5);
+            assert_eq!(cspuz_solver_get_int(solver, y), 2);
+            assert!(!cspuz_solver_get_bool(solver, a));
+            assert!(cspuz_solver_get_bool(solver, b));
+
+            assert!(cspuz_solver_add_linear(
+                solver,
+                [1].as_ptr(),
+                [y].as_ptr(),
+                1,
+                CspuzComparison::Ne,
+                2
+            ));
+            assert!(!cspuz_solver_solve(solver));
+            assert_eq!(cspuz_solver_get_int(solver, x), 0);
+
+            cspuz_solver_free(solver);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            let solver = cspuz_solver_new();
+            assert_eq!(cspuz_solver_add_int_var(solver, 3, 2), 0);
+            let a = cspuz_solver_add_bool_var(solver);
+            assert!(!cspuz_solver_add_clause(solver, [a, 2].as_ptr(), 2));
+            assert!(!cspuz_solver_add_clause(solver, [0].as_ptr(), 1));
+            assert!(!cspuz_solver_add_linear(
+                solver,
+                [1].as_ptr(),
+                [1].as_ptr(),
+                1,
+                CspuzComparison::Eq,
+                0
+            ));
+
+            assert!(cspuz_solver_solve(solver));
+            assert!(cspuz_solver_last_error(solver).is_null());
+            assert!(!cspuz_solver_get_bool(solver, 5));
+            assert_eq!(
+                CStr::from_ptr(cspuz_solver_last_error(solver)).to_str(),
+                Ok("invalid boolean variable 5")
+            );
+            cspuz_solver_free(solver);
+        }
+    }
+
+    #[test]
+    fn test_panic() {
+        unsafe {
+            let solver = cspuz_solver_new();
+            assert_eq!(run(solver, -1, |_| panic!("boom")), -1);
+            assert_eq!(
+                CStr::from_ptr(cspuz_solver_last_error(solver)).to_str(),
+                Ok("internal error: boom")
+            );
+
+            // the solver may be broken after a panic
+            assert_eq!(cspuz_solver_add_bool_var(solver), 0);
+            assert!(!cspuz_solver_solve(solver));
+            cspuz_solver_free(solver);
+        }
+    }
+}
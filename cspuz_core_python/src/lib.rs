@@ -1,5 +1,8 @@
 #![allow(static_mut_refs)] // TODO: remove this
 
+mod model;
+mod puzzles;
+
 use std::collections::HashMap;
 
 use pyo3::exceptions::PyValueError;
@@ -247,11 +250,16 @@ fn solver_with_perf(input: String) -> (String, HashMap<String, f64>) {
 }
 
 #[pymodule(name = "cspuz_core")]
-pub fn cspuz_core_python_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+pub fn cspuz_core_python_module(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(solver, m)?)?;
     m.add_function(wrap_pyfunction!(solver_with_perf, m)?)?;
     m.add_function(wrap_pyfunction!(set_config, m)?)?;
     m.add_class::<PyConfig>()?;
+    m.add_class::<model::PySolver>()?;
+    m.add_class::<model::PyBoolExpr>()?;
+    m.add_class::<model::PyIntExpr>()?;
+    m.add_class::<model::PyModel>()?;
+    m.add_submodule(&puzzles::puzzles_module(py)?)?;
 
     Ok(())
 }
@@ -0,0 +1,320 @@
+//! Python interface for building CSP models directly, instead of writing them in the Sugar-like
+//! text format accepted by `solver`:
+//!
+//! ```python
+//! import cspuz_core
+//!
+//! s = cspuz_core.Solver()
+//! x = s.int_var(0, 5)
+//! y = s.int_var(0, 5)
+//! b = s.bool_var()
+//! s.add(x + y == 7)
+//! s.add(b.imp(x > y))
+//! model = s.solve()
+//! if model is not None:
+//!     print(model[x], model[y], model[b])
+//! ```
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use cspuz_core::csp::{Assignment, BoolExpr, BoolVar, IntExpr, IntVar, Stmt};
+use cspuz_core::domain::Domain;
+use cspuz_core::integration::IntegratedSolver;
+
+use crate::{PyConfig, GLOBAL_CONFIG};
+
+/// Boolean expression, combined with `&`, `|`, `^` and `~`.
+#[pyclass(name = "BoolExpr")]
+#[derive(Clone)]
+pub struct PyBoolExpr {
+    expr: BoolExpr,
+}
+
+/// Integer expression, combined with `+`, `-` and `*` and compared with the comparison
+/// operators (which yield `BoolExpr`s).
+#[pyclass(name = "IntExpr")]
+#[derive(Clone)]
+pub struct PyIntExpr {
+    expr: IntExpr,
+}
+
+/// Operand of boolean operators: Python `bool`s are treated as constants.
+#[derive(FromPyObject)]
+enum BoolOperand {
+    Expr(PyBoolExpr),
+    Const(bool),
+}
+
+impl BoolOperand {
+    fn into_expr(self) -> BoolExpr {
+        match self {
+            BoolOperand::Expr(e) => e.expr,
+            BoolOperand::Const(b) => BoolExpr::Const(b),
+        }
+    }
+}
+
+/// Operand of integer operators: Python `int`s are treated as constants.
+#[derive(FromPyObject)]
+enum IntOperand {
+    Expr(PyIntExpr),
+    Const(i32),
+}
+
+impl IntOperand {
+    fn into_expr(self) -> IntExpr {
+        match self {
+            IntOperand::Expr(e) => e.expr,
+            IntOperand::Const(n) => IntExpr::Const(n),
+        }
+    }
+}
+
+fn bool_expr(expr: BoolExpr) -> PyBoolExpr {
+    PyBoolExpr { expr }
+}
+
+fn int_expr(expr: IntExpr) -> PyIntExpr {
+    PyIntExpr { expr }
+}
+
+fn pretty_print<F>(print: F) -> String
+where
+    F: FnOnce(&mut Vec<u8>) -> std::io::Result<()>,
+{
+    let mut out = vec![];
+    print(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+/// Rejects the use of an expression as a Python truth value, which is most likely a mistake
+/// such as `if x == y:` or a chained comparison `a < b < c`.
+fn truth_value_error() -> PyErr {
+    PyErr::new::<PyTypeError, _>("the truth value of an expression is undetermined")
+}
+
+#[pymethods]
+impl PyBoolExpr {
+    fn __and__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(self.expr.clone() & other.into_expr())
+    }
+
+    fn __rand__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(other.into_expr() & self.expr.clone())
+    }
+
+    fn __or__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(self.expr.clone() | other.into_expr())
+    }
+
+    fn __ror__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(other.into_expr() | self.expr.clone())
+    }
+
+    fn __xor__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(self.expr.clone() ^ other.into_expr())
+    }
+
+    fn __rxor__(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(other.into_expr() ^ self.expr.clone())
+    }
+
+    fn __invert__(&self) -> PyBoolExpr {
+        bool_expr(!self.expr.clone())
+    }
+
+    /// `self` implies `other`.
+    fn imp(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(self.expr.clone().imp(other.into_expr()))
+    }
+
+    /// `self` if and only if `other`.
+    fn iff(&self, other: BoolOperand) -> PyBoolExpr {
+        bool_expr(self.expr.clone().iff(other.into_expr()))
+    }
+
+    /// `then` if `self` holds, `otherwise` if not.
+    fn ite(&self, then: IntOperand, otherwise: IntOperand) -> PyIntExpr {
+        int_expr(
+            self.expr
+                .clone()
+                .ite(then.into_expr(), otherwise.into_expr()),
+        )
+    }
+
+    fn __bool__(&self) -> PyResult<bool> {
+        Err(truth_value_error())
+    }
+
+    fn __repr__(&self) -> String {
+        pretty_print(|out| self.expr.pretty_print(out))
+    }
+}
+
+#[pymethods]
+impl PyIntExpr {
+    fn __add__(&self, other: IntOperand) -> PyIntExpr {
+        int_expr(self.expr.clone() + other.into_expr())
+    }
+
+    fn __radd__(&self, other: IntOperand) -> PyIntExpr {
+        int_expr(other.into_expr() + self.expr.clone())
+    }
+
+    fn __sub__(&self, other: IntOperand) -> PyIntExpr {
+        int_expr(self.expr.clone() - other.into_expr())
+    }
+
+    fn __rsub__(&self, other: IntOperand) -> PyIntExpr {
+        int_expr(other.into_expr() - self.expr.clone())
+    }
+
+    fn __mul__(&self, other: IntOperand) -> PyIntExpr {
+        match other {
+            IntOperand::Expr(e) => int_expr(self.expr.clone() * e.expr),
+            IntOperand::Const(n) => int_expr(self.expr.clone() * n),
+        }
+    }
+
+    fn __rmul__(&self, other: IntOperand) -> PyIntExpr {
+        self.__mul__(other)
+    }
+
+    fn __neg__(&self) -> PyIntExpr {
+        int_expr(self.expr.clone() * -1)
+    }
+
+    fn __abs__(&self) -> PyIntExpr {
+        int_expr(self.expr.clone().abs())
+    }
+
+    fn __richcmp__(&self, other: IntOperand, op: CompareOp) -> PyBoolExpr {
+        let lhs = self.expr.clone();
+        let rhs = other.into_expr();
+        bool_expr(match op {
+            CompareOp::Eq => lhs.eq(rhs),
+            CompareOp::Ne => lhs.ne(rhs),
+            CompareOp::Le => lhs.le(rhs),
+            CompareOp::Lt => lhs.lt(rhs),
+            CompareOp::Ge => lhs.ge(rhs),
+            CompareOp::Gt => lhs.gt(rhs),
+        })
+    }
+
+    fn __bool__(&self) -> PyResult<bool> {
+        Err(truth_value_error())
+    }
+
+    fn __repr__(&self) -> String {
+        pretty_print(|out| self.expr.pretty_print(out))
+    }
+}
+
+/// Wrapper of `IntegratedSolver`.
+#[pyclass(name = "Solver", unsendable)]
+pub struct PySolver {
+    solver: IntegratedSolver<'static>,
+    bool_vars: Vec<BoolVar>,
+    int_vars: Vec<IntVar>,
+}
+
+#[pymethods]
+impl PySolver {
+    /// Creates a solver with `config`, or with the config set by `set_config` if omitted.
+    #[new]
+    #[pyo3(signature = (config=None))]
+    fn new(config: Option<PyConfig>) -> PySolver {
+        let config = config.map_or(unsafe { GLOBAL_CONFIG.config }, |c| c.config);
+        PySolver {
+            solver: IntegratedSolver::with_config(config),
+            bool_vars: vec![],
+            int_vars: vec![],
+        }
+    }
+
+    fn bool_var(&mut self) -> PyBoolExpr {
+        let var = self.solver.new_bool_var();
+        self.bool_vars.push(var);
+        bool_expr(var.expr())
+    }
+
+    /// Creates an integer variable taking a value in `[low, high]`.
+    fn int_var(&mut self, low: i32, high: i32) -> PyResult<PyIntExpr> {
+        if low > high {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "empty domain: [{}, {}]",
+                low, high
+            )));
+        }
+        let var = self.solver.new_int_var(Domain::range(low, high));
+        self.int_vars.push(var);
+        Ok(int_expr(var.expr()))
+    }
+
+    /// Creates an integer variable taking one of `values`.
+    fn int_var_from_list(&mut self, values: Vec<i32>) -> PyResult<PyIntExpr> {
+        if values.is_empty() {
+            return Err(PyErr::new::<PyValueError, _>("empty domain"));
+        }
+        let var = self.solver.new_int_var_from_list(values);
+        self.int_vars.push(var);
+        Ok(int_expr(var.expr()))
+    }
+
+    /// Adds the constraint that `expr` holds.
+    fn add(&mut self, expr: BoolOperand) {
+        self.solver.add_expr(expr.into_expr());
+    }
+
+    /// Adds the constraint that the values of `exprs` are pairwise distinct.
+    fn add_all_different(&mut self, exprs: Vec<IntOperand>) {
+        let exprs = exprs.into_iter().map(IntOperand::into_expr).collect();
+        self.solver.add_constraint(Stmt::AllDifferent(exprs));
+    }
+
+    /// Returns a model satisfying all the constraints added so far, or `None` if there is no
+    /// such model. More constraints can be added afterwards to solve again.
+    fn solve(&mut self) -> Option<PyModel> {
+        let model = self.solver.solve()?;
+        let mut assignment = Assignment::new();
+        for &var in &self.bool_vars {
+            assignment.set_bool(var, model.get_bool(var));
+        }
+        for &var in &self.int_vars {
+            assignment.set_int(var, model.get_int(var));
+        }
+        Some(PyModel { assignment })
+    }
+}
+
+#[derive(IntoPyObject)]
+enum Value {
+    Bool(bool),
+    Int(i32),
+}
+
+/// Values of the variables in a solution found by `Solver.solve`.
+#[pyclass(name = "Model")]
+pub struct PyModel {
+    assignment: Assignment,
+}
+
+#[pymethods]
+impl PyModel {
+    /// Returns the value of `expr` (a `BoolExpr` or an `IntExpr`) in this model.
+    fn __getitem__(&self, expr: &Bound<'_, PyAny>) -> PyResult<Value> {
+        if let Ok(e) = expr.downcast::<PyBoolExpr>() {
+            Ok(Value::Bool(
+                self.assignment.eval_bool_expr(&e.borrow().expr),
+            ))
+        } else if let Ok(e) = expr.downcast::<PyIntExpr>() {
+            Ok(Value::Int(self.assignment.eval_int_expr(&e.borrow().expr)))
+        } else {
+            Err(PyErr::new::<PyTypeError, _>(
+                "model values can only be retrieved for BoolExpr or IntExpr",
+            ))
+        }
+    }
+}
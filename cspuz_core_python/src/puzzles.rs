@@ -0,0 +1,188 @@
+//! Python interface of the puzzle solvers of `cspuz_rs_puzzles`, available as the submodule
+//! `cspuz_core.puzzles`.
+//!
+//! For each supported puzzle (e.g. Slitherlink), the following functions are provided:
+//!
+//! - `solve_slitherlink(url)`: returns the irrefutable facts of the problem given by `url`
+//!   (`None` for undecided cells), or `None` if the problem has no answer.
+//! - `enumerate_answers_slitherlink(url, num_max_answers=10)`: returns a list of answers.
+//!
+//! and, for puzzles whose problem is a grid of optional numbers,
+//!
+//! - `deserialize_slitherlink(url)` / `serialize_slitherlink(problem)`.
+//!
+//! Grids are lists of rows. Edges are returned as tuples `(horizontal, vertical)` in the layout
+//! of `cspuz_rs::graph::GridEdges`, and answers consisting of several grids as tuples.
+//! Invalid URLs raise `ValueError`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+
+use cspuz_rs::graph::GridEdges;
+use cspuz_rs_puzzles::puzzles::{
+    akari, creek, hashi, heyawake, kurotto, lits, masyu, mochikoro, norinori, nurikabe, nurimisaki,
+    simpleloop, slitherlink, sudoku, tapa, tents, yajilin, yinyang,
+};
+use cspuz_rs_puzzles::util::Puzzle;
+
+/// Conversion of puzzle answers into Python objects.
+trait IntoPyAnswer {
+    fn into_py_answer(self, py: Python<'_>) -> PyResult<PyObject>;
+}
+
+impl<T> IntoPyAnswer for Vec<Vec<Option<T>>>
+where
+    T: for<'py> IntoPyObject<'py>,
+{
+    fn into_py_answer(self, py: Python<'_>) -> PyResult<PyObject> {
+        self.into_py_any(py)
+    }
+}
+
+impl<T> IntoPyAnswer for GridEdges<Vec<Vec<Option<T>>>>
+where
+    T: for<'py> IntoPyObject<'py>,
+{
+    fn into_py_answer(self, py: Python<'_>) -> PyResult<PyObject> {
+        (self.horizontal, self.vertical).into_py_any(py)
+    }
+}
+
+impl<A: IntoPyAnswer, B: IntoPyAnswer> IntoPyAnswer for (A, B) {
+    fn into_py_answer(self, py: Python<'_>) -> PyResult<PyObject> {
+        (self.0.into_py_answer(py)?, self.1.into_py_answer(py)?).into_py_any(py)
+    }
+}
+
+fn parse_problem<T>(problem: Option<T>) -> PyResult<T> {
+    problem.ok_or_else(|| PyErr::new::<PyValueError, _>("invalid url"))
+}
+
+fn solve<P>(py: Python<'_>, problem: Option<P::Problem>) -> PyResult<Option<PyObject>>
+where
+    P: Puzzle,
+    P::Answer: IntoPyAnswer,
+{
+    let problem = parse_problem(problem)?;
+    P::solve(&problem)
+        .map(|answer| answer.into_py_answer(py))
+        .transpose()
+}
+
+fn enumerate_answers<P>(
+    py: Python<'_>,
+    problem: Option<P::Problem>,
+    num_max_answers: usize,
+) -> PyResult<Vec<PyObject>>
+where
+    P: Puzzle,
+    P::Answer: IntoPyAnswer,
+{
+    let problem = parse_problem(problem)?;
+    P::enumerate_answers(&problem, num_max_answers)
+        .into_iter()
+        .map(|answer| answer.into_py_answer(py))
+        .collect()
+}
+
+/// Defines `solve_<puzzle>` and `enumerate_answers_<puzzle>` for each `module::Puzzle`, and
+/// `add_solver_functions` registering them.
+macro_rules! solver_functions {
+    ($($module:ident :: $puzzle:ident => $solve:ident, $enumerate:ident;)*) => {
+        $(
+            #[pyfunction]
+            fn $solve(py: Python<'_>, url: &str) -> PyResult<Option<PyObject>> {
+                solve::<$module::$puzzle>(py, $module::deserialize_problem(url))
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (url, num_max_answers=10))]
+            fn $enumerate(
+                py: Python<'_>,
+                url: &str,
+                num_max_answers: usize,
+            ) -> PyResult<Vec<PyObject>> {
+                enumerate_answers::<$module::$puzzle>(
+                    py,
+                    $module::deserialize_problem(url),
+                    num_max_answers,
+                )
+            }
+        )*
+
+        fn add_solver_functions(m: &Bound<'_, PyModule>) -> PyResult<()> {
+            $(
+                m.add_function(wrap_pyfunction!($solve, m)?)?;
+                m.add_function(wrap_pyfunction!($enumerate, m)?)?;
+            )*
+            Ok(())
+        }
+    };
+}
+
+/// Defines `deserialize_<puzzle>` and `serialize_<puzzle>` for puzzles whose problem is a grid
+/// of optional numbers, and `add_serializer_functions` registering them.
+macro_rules! serializer_functions {
+    ($($module:ident => $deserialize:ident, $serialize:ident;)*) => {
+        $(
+            #[pyfunction]
+            fn $deserialize(url: &str) -> PyResult<Vec<Vec<Option<i32>>>> {
+                parse_problem($module::deserialize_problem(url))
+            }
+
+            #[pyfunction]
+            fn $serialize(problem: Vec<Vec<Option<i32>>>) -> PyResult<String> {
+                $module::serialize_problem(&problem)
+                    .ok_or_else(|| PyErr::new::<PyValueError, _>("unserializable problem"))
+            }
+        )*
+
+        fn add_serializer_functions(m: &Bound<'_, PyModule>) -> PyResult<()> {
+            $(
+                m.add_function(wrap_pyfunction!($deserialize, m)?)?;
+                m.add_function(wrap_pyfunction!($serialize, m)?)?;
+            )*
+            Ok(())
+        }
+    };
+}
+
+solver_functions! {
+    akari::Akari => solve_akari, enumerate_answers_akari;
+    creek::Creek => solve_creek, enumerate_answers_creek;
+    hashi::Hashi => solve_hashi, enumerate_answers_hashi;
+    heyawake::Heyawake => solve_heyawake, enumerate_answers_heyawake;
+    kurotto::Kurotto => solve_kurotto, enumerate_answers_kurotto;
+    lits::Lits => solve_lits, enumerate_answers_lits;
+    masyu::Masyu => solve_masyu, enumerate_answers_masyu;
+    mochikoro::Mochikoro => solve_mochikoro, enumerate_answers_mochikoro;
+    norinori::Norinori => solve_norinori, enumerate_answers_norinori;
+    nurikabe::Nurikabe => solve_nurikabe, enumerate_answers_nurikabe;
+    nurimisaki::Nurimisaki => solve_nurimisaki, enumerate_answers_nurimisaki;
+    simpleloop::Simpleloop => solve_simpleloop, enumerate_answers_simpleloop;
+    slitherlink::Slitherlink => solve_slitherlink, enumerate_answers_slitherlink;
+    sudoku::Sudoku => solve_sudoku, enumerate_answers_sudoku;
+    tapa::Tapa => solve_tapa, enumerate_answers_tapa;
+    tents::Tents => solve_tents, enumerate_answers_tents;
+    yajilin::Yajilin => solve_yajilin, enumerate_answers_yajilin;
+    yinyang::Yinyang => solve_yinyang, enumerate_answers_yinyang;
+}
+
+serializer_functions! {
+    akari => deserialize_akari, serialize_akari;
+    creek => deserialize_creek, serialize_creek;
+    kurotto => deserialize_kurotto, serialize_kurotto;
+    mochikoro => deserialize_mochikoro, serialize_mochikoro;
+    nurikabe => deserialize_nurikabe, serialize_nurikabe;
+    nurimisaki => deserialize_nurimisaki, serialize_nurimisaki;
+    slitherlink => deserialize_slitherlink, serialize_slitherlink;
+    sudoku => deserialize_sudoku, serialize_sudoku;
+}
+
+pub fn puzzles_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "puzzles")?;
+    add_solver_functions(&m)?;
+    add_serializer_functions(&m)?;
+    Ok(m)
+}
@@ -0,0 +1,315 @@
+//! Native Node.js bindings (built with [napi-rs](https://napi.rs)) of the puzzle solver backend
+//! and of the CSP solver of `cspuz_core`.
+//!
+//! They provide the same functionality as the WASM builds to server-side JavaScript, but run at
+//! native speed and can solve on multiple threads: `solveAsync` and `verifyUrls` run on the
+//! thread pool of libuv without blocking the event loop.
+//!
+//! Results are returned as plain JavaScript objects in the same format as the `description` of
+//! the responses of the WASM backend; errors are thrown as `Error`s whose message is that of the
+//! `SolveError`.
+
+use std::time::Duration;
+
+use cspuz_core::csp::{BoolExpr, BoolVar, IntExpr, IntVar};
+use cspuz_core::domain::Domain;
+use cspuz_core::integration::IntegratedSolver;
+use cspuz_solver_backend::batch::{self, BatchOptions, VerificationStatus};
+use cspuz_solver_backend::board::Board;
+use cspuz_solver_backend::SolveError;
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Error, JsUnknown, Result, Task};
+use napi_derive::napi;
+use serde::Serialize;
+use serde_json::Value;
+
+fn to_js_error(err: SolveError) -> Error {
+    Error::from_reason(err.to_string())
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value> {
+    serde_json::to_value(value).map_err(|e| Error::from_reason(e.to_string()))
+}
+
+/// Solves the problem given by `url` and returns the board of the irrefutable facts.
+#[napi]
+pub fn solve(url: String) -> Result<Value> {
+    let board = cspuz_solver_backend::decode_and_solve(url.as_bytes()).map_err(to_js_error)?;
+    to_value(&board)
+}
+
+pub struct SolveTask {
+    url: String,
+}
+
+impl Task for SolveTask {
+    type Output = Board;
+    type JsValue = JsUnknown;
+
+    fn compute(&mut self) -> Result<Board> {
+        cspuz_solver_backend::decode_and_solve(self.url.as_bytes()).map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, env: Env, board: Board) -> Result<JsUnknown> {
+        env.to_js_value(&board)
+    }
+}
+
+/// Same as `solve`, but solves on a worker thread and returns a `Promise`.
+#[napi(ts_return_type = "Promise<any>")]
+pub fn solve_async(url: String) -> AsyncTask<SolveTask> {
+    AsyncTask::new(SolveTask { url })
+}
+
+/// Checks whether the problem given by `url` has a unique answer, in the same format as the
+/// `check_uniqueness` response of the WASM backend.
+#[napi]
+pub fn check_uniqueness(url: String) -> Result<Value> {
+    let check =
+        cspuz_solver_backend::decode_and_check_uniqueness(url.as_bytes()).map_err(to_js_error)?;
+    to_value(&check)
+}
+
+/// Returns the puzzle kinds supported by the backend (see `kinds::list_puzzle_kinds`).
+#[napi]
+pub fn list_puzzle_kinds() -> Result<Value> {
+    to_value(&cspuz_solver_backend::kinds::list_puzzle_kinds())
+}
+
+#[napi(object)]
+pub struct VerifyOptions {
+    /// Number of worker threads. 0 or omitted means the available parallelism of the machine.
+    pub threads: Option<u32>,
+    /// Problems taking at least this long are reported as slow (1000 ms by default).
+    pub slow_ms: Option<u32>,
+    /// If set, solving each problem is stopped after this duration.
+    pub time_limit_ms: Option<u32>,
+}
+
+#[napi(object)]
+pub struct VerificationResult {
+    pub url: String,
+    /// One of `unique`, `nonUnique`, `solved`, `noAnswer`, `timeout` and `error`.
+    pub status: String,
+    /// Message of the error, if `status` is `error`.
+    pub error: Option<String>,
+    pub time_ms: f64,
+    pub slow: bool,
+}
+
+pub struct VerifyTask {
+    urls: Vec<String>,
+    options: BatchOptions,
+}
+
+impl Task for VerifyTask {
+    type Output = Vec<batch::VerificationResult>;
+    type JsValue = Vec<VerificationResult>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(batch::verify_urls(&self.urls, &self.options))
+    }
+
+    fn resolve(&mut self, _env: Env, results: Self::Output) -> Result<Self::JsValue> {
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                let (status, error) = match result.status {
+                    VerificationStatus::Unique => ("unique", None),
+                    VerificationStatus::NonUnique => ("nonUnique", None),
+                    VerificationStatus::Solved => ("solved", None),
+                    VerificationStatus::NoAnswer => ("noAnswer", None),
+                    VerificationStatus::Timeout => ("timeout", None),
+                    VerificationStatus::Error(e) => ("error", Some(e.to_string())),
+                };
+                VerificationResult {
+                    url: result.url,
+                    status: status.to_string(),
+                    error,
+                    time_ms: result.time.as_secs_f64() * 1000.0,
+                    slow: result.is_slow,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Verifies the problems given by `urls` in parallel (see `batch::verify_urls`), and returns a
+/// `Promise` of the results in the same order as `urls`.
+#[napi(ts_return_type = "Promise<VerificationResult[]>")]
+pub fn verify_urls(urls: Vec<String>, options: Option<VerifyOptions>) -> AsyncTask<VerifyTask> {
+    let mut batch_options = BatchOptions::default();
+    if let Some(options) = options {
+        if let Some(threads) = options.threads {
+            batch_options.num_threads = threads as usize;
+        }
+        if let Some(slow_ms) = options.slow_ms {
+            batch_options.slow_threshold = Duration::from_millis(slow_ms as u64);
+        }
+        batch_options.time_limit = options
+            .time_limit_ms
+            .map(|ms| Duration::from_millis(ms as u64));
+    }
+    AsyncTask::new(VerifyTask {
+        urls,
+        options: batch_options,
+    })
+}
+
+/// CSP solver with the same interface as the C API of `cspuz_core_c`: boolean and integer
+/// variables are numbered separately from 1, and in clauses `-v` stands for the negation of the
+/// boolean variable `v`. Invalid arguments throw instead of returning `false`.
+#[napi]
+pub struct Solver {
+    solver: IntegratedSolver<'static>,
+    bool_vars: Vec<BoolVar>,
+    int_vars: Vec<IntVar>,
+    /// Values of `bool_vars` and `int_vars` in the last solution.
+    model: Option<(Vec<bool>, Vec<i32>)>,
+}
+
+#[napi]
+impl Solver {
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Solver {
+        Solver {
+            solver: IntegratedSolver::new(),
+            bool_vars: vec![],
+            int_vars: vec![],
+            model: None,
+        }
+    }
+
+    /// Returns the index in `bool_vars` of the boolean variable numbered `var`.
+    fn bool_index(&self, var: u32) -> Result<usize> {
+        (var as usize)
+            .checked_sub(1)
+            .filter(|&i| i < self.bool_vars.len())
+            .ok_or_else(|| Error::from_reason(format!("unknown boolean variable: {}", var)))
+    }
+
+    /// Returns the index in `int_vars` of the integer variable numbered `var`.
+    fn int_index(&self, var: i32) -> Result<usize> {
+        usize::try_from(var)
+            .ok()
+            .and_then(|v| v.checked_sub(1))
+            .filter(|&i| i < self.int_vars.len())
+            .ok_or_else(|| Error::from_reason(format!("unknown integer variable: {}", var)))
+    }
+
+    fn bool_literal(&self, lit: i32) -> Result<BoolExpr> {
+        let var = self.bool_vars[self.bool_index(lit.unsigned_abs())?];
+        Ok(if lit > 0 { var.expr() } else { !var.expr() })
+    }
+
+    /// Adds a boolean variable and returns its number.
+    #[napi]
+    pub fn add_bool_var(&mut self) -> i32 {
+        let var = self.solver.new_bool_var();
+        self.bool_vars.push(var);
+        self.bool_vars.len() as i32
+    }
+
+    /// Adds an integer variable taking a value in `[low, high]` and returns its number.
+    #[napi]
+    pub fn add_int_var(&mut self, low: i32, high: i32) -> Result<i32> {
+        if low > high {
+            return Err(Error::from_reason(format!(
+                "empty domain: [{}, {}]",
+                low, high
+            )));
+        }
+        let var = self.solver.new_int_var(Domain::range(low, high));
+        self.int_vars.push(var);
+        Ok(self.int_vars.len() as i32)
+    }
+
+    /// Adds the constraint that at least one of `lits` holds.
+    #[napi]
+    pub fn add_clause(&mut self, lits: Vec<i32>) -> Result<()> {
+        let lits = lits
+            .iter()
+            .map(|&lit| self.bool_literal(lit).map(Box::new))
+            .collect::<Result<Vec<_>>>()?;
+        self.solver.add_expr(BoolExpr::Or(lits));
+        Ok(())
+    }
+
+    /// Adds the linear constraint `sum(coefs[i] * vars[i]) <op> rhs`, where `op` is one of `==`,
+    /// `!=`, `<=`, `<`, `>=` and `>`.
+    #[napi]
+    pub fn add_linear(
+        &mut self,
+        coefs: Vec<i32>,
+        vars: Vec<i32>,
+        op: String,
+        rhs: i32,
+    ) -> Result<()> {
+        if coefs.len() != vars.len() {
+            return Err(Error::from_reason(
+                "coefs and vars must have the same length",
+            ));
+        }
+        let terms = coefs
+            .iter()
+            .zip(&vars)
+            .map(|(&coef, &var)| Ok((Box::new(self.int_vars[self.int_index(var)?].expr()), coef)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let lhs = IntExpr::Linear(terms);
+        let rhs = IntExpr::Const(rhs);
+        let expr = match op.as_str() {
+            "==" => lhs.eq(rhs),
+            "!=" => lhs.ne(rhs),
+            "<=" => lhs.le(rhs),
+            "<" => lhs.lt(rhs),
+            ">=" => lhs.ge(rhs),
+            ">" => lhs.gt(rhs),
+            _ => return Err(Error::from_reason(format!("unknown operator: {}", op))),
+        };
+        self.solver.add_expr(expr);
+        Ok(())
+    }
+
+    /// Solves the constraints added so far. Returns `true` if they are satisfiable, in which case
+    /// the values of the variables can be obtained by `getBool` and `getInt`.
+    #[napi]
+    pub fn solve(&mut self) -> bool {
+        let model = self.solver.solve().map(|model| {
+            let bool_values = self.bool_vars.iter().map(|&v| model.get_bool(v));
+            let int_values = self.int_vars.iter().map(|&v| model.get_int(v));
+            (bool_values.collect(), int_values.collect())
+        });
+        self.model = model;
+        self.model.is_some()
+    }
+
+    /// Returns the value of the boolean variable `var` in the last solution found by `solve`.
+    #[napi]
+    pub fn get_bool(&self, var: i32) -> Result<bool> {
+        let index = self.bool_index(u32::try_from(var).unwrap_or(0))?;
+        self.model()?
+            .0
+            .get(index)
+            .copied()
+            .ok_or_else(|| Error::from_reason("the variable was added after solving"))
+    }
+
+    /// Returns the value of the integer variable `var` in the last solution found by `solve`.
+    #[napi]
+    pub fn get_int(&self, var: i32) -> Result<i32> {
+        let index = self.int_index(var)?;
+        self.model()?
+            .1
+            .get(index)
+            .copied()
+            .ok_or_else(|| Error::from_reason("the variable was added after solving"))
+    }
+
+    fn model(&self) -> Result<&(Vec<bool>, Vec<i32>)> {
+        self.model
+            .as_ref()
+            .ok_or_else(|| Error::from_reason("no solution has been found"))
+    }
+}
@@ -1331,6 +1331,24 @@ impl<'a> Solver<'a> {
         self.solver.set_perf_stats(perf_stats);
     }
 
+    /// Returns the variables and the constraints added so far in a human-readable format, for
+    /// inspecting the model built by a puzzle solver. Constraints are included only until the
+    /// next call to `solve` (or other methods solving the instance).
+    ///
+    /// # Example
+    /// ```
+    /// # use cspuz_rs::solver::Solver;
+    /// let mut solver = Solver::new();
+    /// let x = &solver.bool_var();
+    /// let n = &solver.int_var(0, 3);
+    /// solver.add_expr(x | n.ge(2));
+    ///
+    /// assert_eq!(solver.dump(), "bool b0\nint i0 : 0..3\nb0 || i0 >= 2\n");
+    /// ```
+    pub fn dump(&self) -> String {
+        self.solver.dump()
+    }
+
     /// Registers the specified boolean variable(s) as the answer key(s).
     ///
     /// Variables representing the "answer" of the problem instance (not proxy variables) can be
@@ -1421,6 +1439,10 @@ impl<'a> Solver<'a> {
     ///
     /// This method may introduce additional constraints when computing partial models and therefore consumes the `Solver` instance.
     ///
+    /// If SAT solving is cancelled through `cspuz_core::sat::set_progress_callback`, this method returns `None`,
+    /// unless `anytime_irrefutable_facts` is enabled in the config, in which case it returns the facts proven
+    /// before the cancellation.
+    ///
     /// # Example
     /// ```
     /// # use cspuz_rs::solver::Solver;
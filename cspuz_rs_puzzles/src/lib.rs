@@ -1,2 +1,3 @@
 pub mod puzzles;
+pub mod random_board;
 pub mod util;
@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{BoolVar, Solver};
 
 pub fn solve_akari(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_akari_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_akari_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -86,7 +93,7 @@ pub fn solve_akari(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>>
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(has_light))
+    util::query_answers(solver, query, |f| f.get(has_light))
 }
 
 struct AkariClueCombinator;
@@ -143,6 +150,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["akari"], url)
 }
 
+pub struct Akari;
+
+impl util::Puzzle for Akari {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_akari_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
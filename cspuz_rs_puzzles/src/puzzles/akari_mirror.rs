@@ -0,0 +1,194 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    get_kudamono_url_info, kudamono_url_info_to_problem, problem_to_kudamono_url_grid, Choice,
+    Combinator, DecInt, Dict, KudamonoGrid, Map, PrefixAndSuffix,
+};
+use cspuz_rs::solver::{any, Solver};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AkariMirrorClue {
+    Empty,
+    Block,
+    Num(i32),
+    /// Mirror connecting the bottom-left and top-right corners of the cell ('/').
+    Slash,
+    /// Mirror connecting the top-left and bottom-right corners of the cell ('\').
+    Backslash,
+}
+
+impl AkariMirrorClue {
+    fn is_block(&self) -> bool {
+        matches!(self, AkariMirrorClue::Block | AkariMirrorClue::Num(_))
+    }
+
+    fn is_mirror(&self) -> bool {
+        matches!(self, AkariMirrorClue::Slash | AkariMirrorClue::Backslash)
+    }
+}
+
+/// Returns the cells lit by a lamp placed at `(y, x)`, excluding `(y, x)` itself.
+/// Beams go straight through empty cells, are reflected by mirrors and stop at blocks.
+fn lit_cells(clues: &[Vec<AkariMirrorClue>], y: usize, x: usize) -> Vec<(usize, usize)> {
+    let (h, w) = util::infer_shape(clues);
+    let mut visited_state = vec![vec![[false; 4]; w]; h];
+    let mut is_lit = vec![vec![false; w]; h];
+    let mut ret = vec![];
+
+    for (dy0, dx0) in [(-1i32, 0i32), (0, 1), (1, 0), (0, -1)] {
+        let (mut cy, mut cx) = (y as i32, x as i32);
+        let (mut dy, mut dx) = (dy0, dx0);
+        loop {
+            cy += dy;
+            cx += dx;
+            if !(0 <= cy && cy < h as i32 && 0 <= cx && cx < w as i32) {
+                break;
+            }
+            let (py, px) = (cy as usize, cx as usize);
+            if clues[py][px].is_block() {
+                break;
+            }
+            let dir = match (dy, dx) {
+                (-1, 0) => 0,
+                (0, 1) => 1,
+                (1, 0) => 2,
+                _ => 3,
+            };
+            if visited_state[py][px][dir] {
+                break;
+            }
+            visited_state[py][px][dir] = true;
+
+            match clues[py][px] {
+                AkariMirrorClue::Slash => (dy, dx) = (-dx, -dy),
+                AkariMirrorClue::Backslash => (dy, dx) = (dx, dy),
+                _ => {
+                    if (py, px) != (y, x) && !is_lit[py][px] {
+                        is_lit[py][px] = true;
+                        ret.push((py, px));
+                    }
+                }
+            }
+        }
+    }
+
+    ret
+}
+
+pub fn solve_akari_mirror(clues: &[Vec<AkariMirrorClue>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_akari_mirror_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_akari_mirror_with_query(
+    clues: &[Vec<AkariMirrorClue>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let has_light = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(has_light);
+
+    for y in 0..h {
+        for x in 0..w {
+            if clues[y][x].is_block() || clues[y][x].is_mirror() {
+                solver.add_expr(!has_light.at((y, x)));
+                if let AkariMirrorClue::Num(n) = clues[y][x] {
+                    solver.add_expr(has_light.four_neighbors((y, x)).count_true().eq(n));
+                }
+                continue;
+            }
+
+            let lit = lit_cells(clues, y, x);
+            let lights = lit.iter().map(|&p| has_light.at(p)).collect::<Vec<_>>();
+            solver.add_expr(has_light.at((y, x)).imp(!any(lights.clone())));
+            solver.add_expr(has_light.at((y, x)) | any(lights));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(has_light))
+}
+
+pub type Problem = Vec<Vec<AkariMirrorClue>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    KudamonoGrid::new(
+        Choice::new(vec![
+            Box::new(Dict::new(AkariMirrorClue::Slash, "s")),
+            Box::new(Dict::new(AkariMirrorClue::Backslash, "b")),
+            Box::new(Dict::new(AkariMirrorClue::Block, "z")),
+            Box::new(Map::new(
+                PrefixAndSuffix::new("(", DecInt, ")"),
+                |c| match c {
+                    AkariMirrorClue::Num(n) => Some(n),
+                    _ => None,
+                },
+                |n| Some(AkariMirrorClue::Num(n)),
+            )),
+        ]),
+        AkariMirrorClue::Empty,
+    )
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_kudamono_url_grid(combinator(), "akari-mirror", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    let info = get_kudamono_url_info(url)?;
+    kudamono_url_info_to_problem(combinator(), info)
+}
+
+pub struct AkariMirror;
+
+impl util::Puzzle for AkariMirror {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_akari_mirror_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![AkariMirrorClue::Empty; 5]; 5];
+
+        ret[0][0] = AkariMirrorClue::Slash;
+        ret[0][1] = AkariMirrorClue::Slash;
+        ret[0][2] = AkariMirrorClue::Backslash;
+        ret[1][0] = AkariMirrorClue::Backslash;
+        ret[1][3] = AkariMirrorClue::Num(4);
+        ret[2][2] = AkariMirrorClue::Num(3);
+        ret[3][0] = AkariMirrorClue::Slash;
+        ret[4][1] = AkariMirrorClue::Backslash;
+
+        ret
+    }
+
+    #[test]
+    fn test_akari_mirror_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_akari_mirror(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 0, 1, 0],
+            [0, 0, 1, 0, 1],
+            [1, 0, 0, 1, 0],
+            [0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_akari_mirror_serializer() {
+        let problem = problem_for_tests();
+        let url =
+            "https://pedros.works/paper-puzzle-player?W=5x5&L=s1b2s1b1s4(3)3b2(4)4&G=akari-mirror";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     get_kudamono_url_info_detailed, parse_kudamono_dimension, Choice, Combinator, Context, DecInt,
@@ -10,6 +11,21 @@ pub fn solve_akari_region(
     clues: &[Vec<Option<i32>>], // clue on a cell (not region)
     has_block: &[Vec<bool>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_akari_region_with_query(
+        borders,
+        clues, // clue on a cell (not region)
+        has_block,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_akari_region_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>], // clue on a cell (not region)
+    has_block: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = borders.base_shape();
 
     let mut solver = Solver::new();
@@ -49,7 +65,7 @@ pub fn solve_akari_region(
             if let Some(c) = clues[y][x] {
                 if let Some(cc) = clue {
                     if cc != c {
-                        return None;
+                        return vec![];
                     }
                 } else {
                     clue = Some(c);
@@ -131,7 +147,7 @@ pub fn solve_akari_region(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(has_light))
+    util::query_answers(solver, query, |f| f.get(has_light))
 }
 
 pub type Problem = (
@@ -179,6 +195,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some((border, clues, has_block))
 }
 
+pub struct AkariRegions;
+
+impl util::Puzzle for AkariRegions {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_akari_region_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
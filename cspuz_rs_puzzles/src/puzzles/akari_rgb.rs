@@ -29,6 +29,13 @@ impl AkariRGBClue {
 }
 
 pub fn solve_akari_rgb(clues: &[Vec<AkariRGBClue>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_akari_rgb_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_akari_rgb_with_query(
+    clues: &[Vec<AkariRGBClue>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -147,7 +154,7 @@ pub fn solve_akari_rgb(clues: &[Vec<AkariRGBClue>]) -> Option<Vec<Vec<Option<i32
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(light))
+    util::query_answers(solver, query, |f| f.get(light))
 }
 
 pub type Problem = Vec<Vec<AkariRGBClue>>;
@@ -184,6 +191,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct AkariRgb;
+
+impl util::Puzzle for AkariRgb {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_akari_rgb_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
@@ -9,6 +10,14 @@ pub fn solve_akichiwake(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Option<i32>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_akichiwake_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_akichiwake_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = borders.base_shape();
 
     let mut solver = Solver::new();
@@ -70,7 +79,7 @@ pub fn solve_akichiwake(
                 } else if n == 1 {
                     solver.add_expr(!is_black.at(p));
                 } else {
-                    return None;
+                    return vec![];
                 }
                 continue;
             }
@@ -98,7 +107,7 @@ pub fn solve_akichiwake(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 pub(super) type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Option<i32>>);
@@ -125,6 +134,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["akichi"], url)
 }
 
+pub struct Akichiwake;
+
+impl util::Puzzle for Akichiwake {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_akichiwake_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
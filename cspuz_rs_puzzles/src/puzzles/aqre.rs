@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
@@ -9,6 +10,14 @@ pub fn solve_aqre(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Option<i32>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_aqre_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_aqre_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let h = borders.vertical.len();
     assert!(h > 0);
     let w = borders.vertical[0].len() + 1;
@@ -37,7 +46,7 @@ pub fn solve_aqre(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Option<i32>>);
@@ -64,6 +73,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["aqre"], url)
 }
 
+pub struct Aqre;
+
+impl util::Puzzle for Aqre {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_aqre_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{int_constant, Solver};
 
 pub fn solve_aquapelago(problem: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_aquapelago_with_query(problem, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_aquapelago_with_query(
+    problem: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(problem);
 
     let mut solver = Solver::new();
@@ -47,7 +54,7 @@ pub fn solve_aquapelago(problem: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<b
     }
     solver.add_graph_division(&aux_sizes, &aux_graph, &aux_edges);
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -68,6 +75,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["aquapelago"], url)
 }
 
+pub struct Aquapelago;
+
+impl util::Puzzle for Aquapelago {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_aquapelago_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,198 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
+    Rooms, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_aquarium(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_aquarium_with_query(
+        borders,
+        clues_up,
+        clues_left,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_aquarium_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let h = borders.vertical.len();
+    assert!(h > 0);
+    let w = borders.vertical[0].len() + 1;
+    if clues_up.len() != w || clues_left.len() != h {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let is_water = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_water);
+
+    // Each room is filled with water up to its water level, which is represented as the
+    // topmost row containing water in the room.
+    let rooms = graph::borders_to_rooms(borders);
+    for room in &rooms {
+        let top = room.iter().map(|&(y, _)| y).min().unwrap() as i32;
+        let bottom = room.iter().map(|&(y, _)| y).max().unwrap() as i32;
+        let level = solver.int_var(top, bottom + 1);
+        for &(y, x) in room {
+            solver.add_expr(is_water.at((y, x)).iff(level.le(y as i32)));
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_water.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_water.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_water))
+}
+
+pub type Problem = (
+    graph::InnerGridEdges<Vec<Vec<bool>>>,
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+pub struct AquariumCombinator;
+
+impl Combinator<Problem> for AquariumCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let mut ret = Rooms.serialize(ctx, std::slice::from_ref(&problem.0))?.1;
+        let surrounding = [&problem.1[..], &problem.2[..]].concat();
+        ret.extend(
+            Seq::new(clue_combinator(), width + height)
+                .serialize(ctx, &[surrounding])?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let borders = sequencer.deserialize_one_elem(ctx, Rooms)?;
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        Some((sequencer.n_read(), vec![(borders, clues_up, clues_left)]))
+    }
+}
+
+pub(crate) fn combinator() -> impl Combinator<Problem> {
+    Size::new(AquariumCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.2.len();
+    let width = problem.1.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "aquarium",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["aquarium"], url)
+}
+
+pub struct Aquarium;
+
+impl util::Puzzle for Aquarium {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_aquarium_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            vec![Some(3), None, Some(2), None],
+            vec![Some(0), None, Some(2), None],
+        )
+    }
+
+    #[test]
+    fn test_aquarium_problem() {
+        let (borders, clues_up, clues_left) = problem_for_tests();
+        let ans = solve_aquarium(&borders, &clues_up, &clues_left);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 0, 0],
+            [1, 1, 1, 1],
+            [1, 0, 0, 1],
+            [1, 1, 1, 1],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_aquarium_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?aquarium/4/4/9ag1p83g2g0g2g";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
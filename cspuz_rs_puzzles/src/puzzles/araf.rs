@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{any, count_true, Solver};
 
 pub fn solve_araf(clues: &[Vec<Option<i32>>]) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_araf_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_araf_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut clue_pos = vec![];
@@ -80,7 +87,7 @@ pub fn solve_araf(clues: &[Vec<Option<i32>>]) -> Option<graph::BoolInnerGridEdge
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(border))
+    util::query_answers(solver, query, |f| f.get(border))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -100,6 +107,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["araf"], url)
 }
 
+pub struct Araf;
+
+impl util::Puzzle for Araf {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_araf_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
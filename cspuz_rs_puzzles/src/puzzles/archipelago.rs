@@ -10,6 +10,13 @@ use std::collections::VecDeque;
 use cspuz_core::custom_constraints::SimpleCustomConstraint;
 
 pub fn solve_archipelago(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_archipelago_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_archipelago_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -103,7 +110,7 @@ pub fn solve_archipelago(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bo
         );
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -359,6 +366,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["archipelago"], url)
 }
 
+pub struct Archipelago;
+
+impl util::Puzzle for Archipelago {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_archipelago_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
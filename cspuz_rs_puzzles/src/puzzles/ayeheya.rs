@@ -1,4 +1,5 @@
 use crate::puzzles::heyawake;
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{problem_to_url_with_context, url_to_problem, Context};
 use cspuz_rs::solver::Solver;
@@ -7,6 +8,14 @@ pub fn solve_ayeheya(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Option<i32>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_ayeheya_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_ayeheya_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     assert!(all_room_symmetry(borders));
     let (h, w) = borders.base_shape();
 
@@ -29,7 +38,7 @@ pub fn solve_ayeheya(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 pub fn all_room_symmetry(borders: &graph::InnerGridEdges<Vec<Vec<bool>>>) -> bool {
@@ -69,3 +78,14 @@ pub fn serialize_problem(problem: &Problem) -> Option<String> {
 pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(heyawake::combinator(), &["ayeheya"], url)
 }
+
+pub struct Ayeheya;
+
+impl util::Puzzle for Ayeheya {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_ayeheya_with_query(&problem.0, &problem.1, query)
+    }
+}
@@ -10,6 +10,14 @@ pub fn solve_barns(
     icebarn: &[Vec<bool>],
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_barns_with_query(icebarn, borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_barns_with_query(
+    icebarn: &[Vec<bool>],
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(icebarn);
 
     let mut solver = Solver::new();
@@ -66,7 +74,7 @@ pub fn solve_barns(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = (Vec<Vec<bool>>, graph::InnerGridEdges<Vec<Vec<bool>>>);
@@ -96,6 +104,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["barns"], url)
 }
 
+pub struct Barns;
+
+impl util::Puzzle for Barns {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_barns_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
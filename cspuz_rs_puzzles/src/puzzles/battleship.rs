@@ -0,0 +1,299 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver, FALSE};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BattleshipClue {
+    None,
+    Water,
+    /// A ship of length 1
+    Single,
+    /// The top end of a vertical ship
+    Top,
+    /// The bottom end of a vertical ship
+    Bottom,
+    /// The left end of a horizontal ship
+    Left,
+    /// The right end of a horizontal ship
+    Right,
+    /// A ship cell which is neither end of a ship
+    Middle,
+    /// A ship cell of unknown shape
+    Segment,
+}
+
+/// Solves a Battleship puzzle.
+/// `fleet[i]` is the number of ships of length `i + 1`.
+pub fn solve_battleship(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<BattleshipClue>],
+    fleet: &[i32],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_battleship_with_query(
+        clues_up,
+        clues_left,
+        cells,
+        fleet,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_battleship_with_query(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<BattleshipClue>],
+    fleet: &[i32],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(cells);
+    if clues_up.len() != w || clues_left.len() != h {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let is_ship = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_ship);
+
+    // Ships are straight and do not touch each other, even diagonally
+    solver.add_expr(!(is_ship.slice((..(h - 1), ..(w - 1))) & is_ship.slice((1.., 1..))));
+    solver.add_expr(!(is_ship.slice((..(h - 1), 1..)) & is_ship.slice((1.., ..(w - 1)))));
+
+    let is_ship_or_false = |y: i32, x: i32| {
+        if 0 <= y && y < h as i32 && 0 <= x && x < w as i32 {
+            is_ship.at((y as usize, x as usize)).expr()
+        } else {
+            FALSE
+        }
+    };
+
+    let mut ship_of_length = vec![vec![]; fleet.len()];
+    for y in 0..h {
+        for x in 0..w {
+            // The top-left cell of each ship represents the ship
+            let is_head = is_ship.at((y, x))
+                & !is_ship_or_false(y as i32 - 1, x as i32)
+                & !is_ship_or_false(y as i32, x as i32 - 1);
+            let length = is_ship.slice_fixed_y((y, x..)).consecutive_prefix_true()
+                + is_ship.slice_fixed_x((y.., x)).consecutive_prefix_true()
+                - 1;
+            solver.add_expr(is_head.imp(length.le(fleet.len() as i32)));
+            for (i, ships) in ship_of_length.iter_mut().enumerate() {
+                ships.push(is_head.clone() & length.eq(i as i32 + 1));
+            }
+        }
+    }
+    for i in 0..fleet.len() {
+        solver.add_expr(count_true(&ship_of_length[i]).eq(fleet[i]));
+    }
+
+    for (y, row) in cells.iter().enumerate() {
+        for (x, clue) in row.iter().enumerate() {
+            let (yi, xi) = (y as i32, x as i32);
+            let up = is_ship_or_false(yi - 1, xi);
+            let down = is_ship_or_false(yi + 1, xi);
+            let left = is_ship_or_false(yi, xi - 1);
+            let right = is_ship_or_false(yi, xi + 1);
+
+            match clue {
+                BattleshipClue::None => (),
+                BattleshipClue::Water => solver.add_expr(!is_ship.at((y, x))),
+                BattleshipClue::Single => {
+                    solver.add_expr(is_ship.at((y, x)) & !up & !down & !left & !right)
+                }
+                BattleshipClue::Top => {
+                    solver.add_expr(is_ship.at((y, x)) & !up & down & !left & !right)
+                }
+                BattleshipClue::Bottom => {
+                    solver.add_expr(is_ship.at((y, x)) & up & !down & !left & !right)
+                }
+                BattleshipClue::Left => {
+                    solver.add_expr(is_ship.at((y, x)) & !up & !down & !left & right)
+                }
+                BattleshipClue::Right => {
+                    solver.add_expr(is_ship.at((y, x)) & !up & !down & left & !right)
+                }
+                BattleshipClue::Middle => {
+                    solver.add_expr(is_ship.at((y, x)) & ((up & down) | (left & right)))
+                }
+                BattleshipClue::Segment => solver.add_expr(is_ship.at((y, x))),
+            }
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_ship.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_ship.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_ship))
+}
+
+pub type Problem = (
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+    Vec<Vec<BattleshipClue>>,
+    Vec<i32>,
+);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+fn cell_combinator() -> impl Combinator<BattleshipClue> {
+    Choice::new(vec![
+        Box::new(Dict::new(BattleshipClue::Water, "1")),
+        Box::new(Dict::new(BattleshipClue::Single, "2")),
+        Box::new(Dict::new(BattleshipClue::Top, "3")),
+        Box::new(Dict::new(BattleshipClue::Bottom, "4")),
+        Box::new(Dict::new(BattleshipClue::Left, "5")),
+        Box::new(Dict::new(BattleshipClue::Right, "6")),
+        Box::new(Dict::new(BattleshipClue::Middle, "7")),
+        Box::new(Dict::new(BattleshipClue::Segment, "8")),
+        Box::new(Spaces::new(BattleshipClue::None, 'a')),
+    ])
+}
+
+pub struct BattleshipCombinator;
+
+impl Combinator<Problem> for BattleshipCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let surrounding = [&problem.0[..], &problem.1[..]].concat();
+        let mut ret = Seq::new(clue_combinator(), width + height)
+            .serialize(ctx, &[surrounding])?
+            .1;
+        ret.extend(
+            ContextBasedGrid::new(cell_combinator())
+                .serialize(ctx, std::slice::from_ref(&problem.2))?
+                .1,
+        );
+        ret.extend(HexInt.serialize(ctx, &[problem.3.len() as i32])?.1);
+        ret.extend(
+            Seq::new(HexInt, problem.3.len())
+                .serialize(ctx, std::slice::from_ref(&problem.3))?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        let cells =
+            sequencer.deserialize_one_elem(ctx, ContextBasedGrid::new(cell_combinator()))?;
+
+        let n_kinds = sequencer.deserialize_one_elem(ctx, HexInt)?;
+        if n_kinds < 0 {
+            return None;
+        }
+        let fleet = sequencer.deserialize_one_elem(ctx, Seq::new(HexInt, n_kinds as usize))?;
+
+        Some((
+            sequencer.n_read(),
+            vec![(clues_up, clues_left, cells, fleet)],
+        ))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(BattleshipCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.1.len();
+    let width = problem.0.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "battleship",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["battleship"], url)
+}
+
+pub struct Battleship;
+
+impl util::Puzzle for Battleship {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_battleship_with_query(&problem.0, &problem.1, &problem.2, &problem.3, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut cells = vec![vec![BattleshipClue::None; 5]; 5];
+        cells[0][0] = BattleshipClue::Left;
+        (
+            vec![Some(3), Some(1), Some(1), Some(1), Some(1)],
+            vec![Some(3), Some(1), Some(1), Some(2), Some(0)],
+            cells,
+            vec![2, 1, 1],
+        )
+    }
+
+    #[test]
+    fn test_battleship_problem() {
+        let (clues_up, clues_left, cells, fleet) = problem_for_tests();
+        let ans = solve_battleship(&clues_up, &clues_left, &cells, &fleet);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 1, 1, 0, 0],
+            [0, 0, 0, 0, 1],
+            [1, 0, 0, 0, 0],
+            [1, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_battleship_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?battleship/5/5/31111311205x3211";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_canal_view(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_canal_view_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_canal_view_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    graph::active_vertices_connected_2d(&mut solver, is_black);
+    solver.add_expr(!is_black.conv2d_and((2, 2)));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_black.at((y, x)));
+                if n < 0 {
+                    continue;
+                }
+                let up = is_black
+                    .slice_fixed_x((..y, x))
+                    .reverse()
+                    .consecutive_prefix_true();
+                let down = is_black
+                    .slice_fixed_x(((y + 1).., x))
+                    .consecutive_prefix_true();
+                let left = is_black
+                    .slice_fixed_y((y, ..x))
+                    .reverse()
+                    .consecutive_prefix_true();
+                let right = is_black
+                    .slice_fixed_y((y, (x + 1)..))
+                    .consecutive_prefix_true();
+                solver.add_expr((up + down + left + right).eq(n));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "canal", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["canal"], url)
+}
+
+pub struct CanalView;
+
+impl util::Puzzle for CanalView {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_canal_view_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[0][0] = Some(3);
+        ret[0][4] = Some(2);
+        ret[2][2] = Some(4);
+        ret[3][4] = Some(2);
+        ret[4][4] = Some(3);
+        ret
+    }
+
+    #[test]
+    fn test_canal_view_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_canal_view(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 1, 1, 0],
+            [1, 1, 1, 0, 0],
+            [1, 0, 0, 1, 1],
+            [1, 1, 0, 1, 0],
+            [0, 1, 1, 1, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_canal_view_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?canal/5/5/3i2m4l2j3";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -17,6 +17,13 @@ pub enum Side {
 pub fn solve_castle_wall(
     clues: &[Vec<Option<(Side, NumberedArrow)>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_castle_wall_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_castle_wall_with_query(
+    clues: &[Vec<Option<(Side, NumberedArrow)>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -67,7 +74,7 @@ pub fn solve_castle_wall(
                         if y > 0 && x > 0 {
                             solver.add_expr(cell_sides.at((y - 1, x - 1)));
                         } else {
-                            return None;
+                            return vec![];
                         }
                     }
                     Side::Outside => {
@@ -114,7 +121,7 @@ pub fn solve_castle_wall(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<(Side, NumberedArrow)>>>;
@@ -141,6 +148,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["castle"], url)
 }
 
+pub struct CastleWall;
+
+impl util::Puzzle for CastleWall {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_castle_wall_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::Solver;
 
 pub fn solve_cave(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_cave_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_cave_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -60,7 +67,7 @@ pub fn solve_cave(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>>
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -81,6 +88,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["cave"], url)
 }
 
+pub struct Cave;
+
+impl util::Puzzle for Cave {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_cave_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,10 +9,17 @@ use std::collections::VecDeque;
 use cspuz_core::custom_constraints::SimpleCustomConstraint;
 
 pub fn solve_chainedb(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_chainedb_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_chainedb_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
     if h < 2 || w < 2 {
         // a block cannot touch another block
-        return None;
+        return vec![];
     }
 
     let mut solver = Solver::new();
@@ -119,7 +126,7 @@ pub fn solve_chainedb(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>
         );
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -421,6 +428,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["chainedb"], url)
 }
 
+pub struct Chainedb;
+
+impl util::Puzzle for Chainedb {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_chainedb_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{any, int_constant, Solver, TRUE};
 
 pub fn solve_chocobanana(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_chocobanana_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_chocobanana_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -107,7 +114,7 @@ pub fn solve_chocobanana(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bo
     aux_graph_v.push(TRUE);
     graph::active_vertices_connected(&mut solver, &aux_graph_v, &aux_graph);
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -128,6 +135,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["cbanana"], url)
 }
 
+pub struct Chocobanana;
+
+impl util::Puzzle for Chocobanana {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_chocobanana_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
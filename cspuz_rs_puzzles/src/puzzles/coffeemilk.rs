@@ -8,6 +8,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{count_true, Solver};
 
 pub fn solve_coffeemilk(clues: &[Vec<i32>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_coffeemilk_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_coffeemilk_with_query(
+    clues: &[Vec<i32>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -117,7 +124,7 @@ pub fn solve_coffeemilk(clues: &[Vec<i32>]) -> Option<graph::BoolGridEdgesIrrefu
     }
 
     if grey_cells.len() == 0 {
-        return None;
+        return vec![];
     }
 
     let mut aux_graph = graph::Graph::new(n_vertices);
@@ -160,7 +167,7 @@ pub fn solve_coffeemilk(clues: &[Vec<i32>]) -> Option<graph::BoolGridEdgesIrrefu
         );
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<i32>>;
@@ -182,6 +189,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["coffeemilk"], url)
 }
 
+pub struct Coffeemilk;
+
+impl util::Puzzle for Coffeemilk {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_coffeemilk_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
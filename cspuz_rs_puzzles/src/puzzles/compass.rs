@@ -17,6 +17,13 @@ pub struct CompassClue {
 pub fn solve_compass(
     clues: &[Vec<Option<CompassClue>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_compass_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_compass_with_query(
+    clues: &[Vec<Option<CompassClue>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -76,7 +83,7 @@ pub fn solve_compass(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(edges))
+    util::query_answers(solver, query, |f| f.get(edges))
 }
 
 type Problem = Vec<Vec<Option<CompassClue>>>;
@@ -113,6 +120,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["compass"], url)
 }
 
+pub struct Compass;
+
+impl util::Puzzle for Compass {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_compass_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
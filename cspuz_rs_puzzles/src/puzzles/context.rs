@@ -0,0 +1,114 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+pub fn solve_context(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_context_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_context_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    graph::active_vertices_connected_2d(&mut solver, !is_black);
+    solver.add_expr(!(is_black.slice((..(h - 1), ..)) & is_black.slice((1.., ..))));
+    solver.add_expr(!(is_black.slice((.., ..(w - 1))) & is_black.slice((.., 1..))));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                if n < 0 {
+                    continue;
+                }
+                // A clue in a white cell counts orthogonally adjacent black cells,
+                // while a clue in a black cell counts diagonally adjacent black cells.
+                let p = (y, x);
+                let diagonal = count_true([
+                    is_black.at_offset(p, (-1, -1), false),
+                    is_black.at_offset(p, (-1, 1), false),
+                    is_black.at_offset(p, (1, -1), false),
+                    is_black.at_offset(p, (1, 1), false),
+                ]);
+                solver.add_expr(is_black.at(p).imp(diagonal.eq(n)));
+                solver
+                    .add_expr((!is_black.at(p)).imp(is_black.four_neighbors(p).count_true().eq(n)));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "context", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["context"], url)
+}
+
+pub struct Context;
+
+impl util::Puzzle for Context {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_context_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[0][0] = Some(1);
+        ret[2][2] = Some(4);
+        ret
+    }
+
+    #[test]
+    fn test_context_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_context(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [1, 0, 0, 0, 0],
+            [0, 1, 0, 1, 0],
+            [0, 0, 1, 0, 0],
+            [0, 1, 0, 1, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_context_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?context/5/5/1q4r";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
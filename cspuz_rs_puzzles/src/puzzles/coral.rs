@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context_and_site, url_to_problem, Choice, Combinator, Context, HexInt,
@@ -9,6 +10,19 @@ pub fn solve_coral(
     clue_vertical: &[Option<Vec<i32>>],
     clue_horizontal: &[Option<Vec<i32>>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_coral_with_query(
+        clue_vertical,
+        clue_horizontal,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_coral_with_query(
+    clue_vertical: &[Option<Vec<i32>>],
+    clue_horizontal: &[Option<Vec<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let h = clue_horizontal.len();
     let w = clue_vertical.len();
 
@@ -62,18 +76,18 @@ pub fn solve_coral(
     for y in 0..h {
         if let Some(clue) = &clue_horizontal[y] {
             if !add_coral_clue(&mut solver, &is_black.slice_fixed_y((y, ..)), clue) {
-                return None;
+                return vec![];
             }
         }
     }
     for x in 0..w {
         if let Some(clue) = &clue_vertical[x] {
             if !add_coral_clue(&mut solver, &is_black.slice_fixed_x((.., x)), clue) {
-                return None;
+                return vec![];
             }
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = (Vec<Option<Vec<i32>>>, Vec<Option<Vec<i32>>>);
@@ -240,6 +254,17 @@ fn add_coral_clue(solver: &mut Solver, cells: &BoolVarArray1D, clue: &Vec<i32>)
     true
 }
 
+pub struct Coral;
+
+impl util::Puzzle for Coral {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_coral_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::Solver;
 
 pub fn solve_creek(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_creek_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_creek_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h1, w1) = util::infer_shape(clues);
     let h = h1 - 1;
     let w = w1 - 1;
@@ -34,7 +41,7 @@ pub fn solve_creek(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>>
 
     graph::active_vertices_connected_2d(&mut solver, !is_black);
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -63,6 +70,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["creek"], url)
 }
 
+pub struct Creek;
+
+impl util::Puzzle for Creek {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_creek_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,6 +20,23 @@ pub fn solve_cross_border_parity_loop(
     clues_white: &[Vec<Option<i32>>],
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<i32>>>)> {
+    solve_cross_border_parity_loop_with_query(
+        board,
+        clues_black,
+        clues_white,
+        borders,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_cross_border_parity_loop_with_query(
+    board: &[Vec<CBPLCell>],
+    clues_black: &[Vec<Option<i32>>],
+    clues_white: &[Vec<Option<i32>>],
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<i32>>>)> {
     let (h, w) = util::infer_shape(board);
 
     let mut solver = Solver::new();
@@ -92,9 +109,7 @@ pub fn solve_cross_border_parity_loop(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(pass_type)))
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(pass_type)))
 }
 
 type Problem = (
@@ -176,6 +191,19 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some((board, clues_black, clues_white, border))
 }
 
+pub struct CrossBorderParityLoop;
+
+impl util::Puzzle for CrossBorderParityLoop {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<i32>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_cross_border_parity_loop_with_query(
+            &problem.0, &problem.1, &problem.2, &problem.3, query,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
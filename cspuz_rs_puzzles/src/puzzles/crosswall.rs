@@ -6,6 +6,13 @@ use cspuz_rs::solver::{any, int_constant, Solver, TRUE};
 pub fn solve_crosswall(
     clues: &[Vec<Option<(i32, i32)>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_crosswall_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_crosswall_with_query(
+    clues: &[Vec<Option<(i32, i32)>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -155,7 +162,7 @@ pub fn solve_crosswall(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<(i32, i32)>>>;
@@ -225,6 +232,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(ret)
 }
 
+pub struct Crosswall;
+
+impl util::Puzzle for Crosswall {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_crosswall_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
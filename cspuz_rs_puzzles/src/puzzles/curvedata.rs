@@ -37,6 +37,21 @@ pub fn solve_curvedata(
     borders: &Option<graph::InnerGridEdges<Vec<Vec<bool>>>>,
     pieces: &[graph::GridEdges<Vec<Vec<bool>>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_curvedata_with_query(
+        piece_id,
+        borders,
+        pieces,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_curvedata_with_query(
+    piece_id: &[Vec<PieceId>],
+    borders: &Option<graph::InnerGridEdges<Vec<Vec<bool>>>>,
+    pieces: &[graph::GridEdges<Vec<Vec<bool>>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(piece_id);
 
     let mut solver = Solver::new();
@@ -46,7 +61,7 @@ pub fn solve_curvedata(
 
     add_constraints(&mut solver, is_line, piece_id, borders, pieces);
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 pub fn enumerate_answers_curvedata(
@@ -348,6 +363,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some((piece_id, borders, pieces))
 }
 
+pub struct Curvedata;
+
+impl util::Puzzle for Curvedata {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_curvedata_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,6 +14,14 @@ pub fn solve_doublechoco(
     color: &[Vec<i32>],
     num: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_doublechoco_with_query(color, num, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_doublechoco_with_query(
+    color: &[Vec<i32>],
+    num: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(color);
     assert_eq!(util::infer_shape(num), (h, w));
 
@@ -75,7 +83,7 @@ pub fn solve_doublechoco(
         );
     }
 
-    solver.irrefutable_facts().map(|f| f.get(&is_border))
+    util::query_answers(solver, query, |f| f.get(&is_border))
 }
 
 type Problem = (Vec<Vec<i32>>, Vec<Vec<Option<i32>>>);
@@ -979,6 +987,17 @@ impl SimpleCustomConstraint for DoublechocoConstraint {
     }
 }
 
+pub struct Dbchoco;
+
+impl util::Puzzle for Dbchoco {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_doublechoco_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
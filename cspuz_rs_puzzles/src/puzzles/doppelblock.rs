@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
     HexInt, Optionalize, Seq, Sequencer, Size, Spaces,
@@ -9,11 +10,26 @@ pub fn solve_doppelblock(
     clues_left: &[Option<i32>],
     cells: &Option<Vec<Vec<Option<i32>>>>,
 ) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_doppelblock_with_query(
+        clues_up,
+        clues_left,
+        cells,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_doppelblock_with_query(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &Option<Vec<Vec<Option<i32>>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     // Some(-1) means that the cell is not a black cell
 
     let size = clues_left.len();
     if size != clues_up.len() {
-        return None;
+        return vec![];
     }
 
     let mut solver = Solver::new();
@@ -57,7 +73,7 @@ pub fn solve_doppelblock(
         add_constraints(numbers.slice_fixed_x((.., i)), clues_up[i]);
     }
 
-    solver.irrefutable_facts().map(|f| {
+    util::query_answers(solver, query, |f| {
         let numbers = f.get(numbers);
         let has_number = f.get(has_number);
 
@@ -183,6 +199,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["doppelblock"], url)
 }
 
+pub struct Doppelblock;
+
+impl util::Puzzle for Doppelblock {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_doppelblock_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
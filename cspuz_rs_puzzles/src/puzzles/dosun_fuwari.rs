@@ -0,0 +1,163 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Combinator, Context, ContextBasedGrid, Map,
+    MultiDigit, Rooms, Size, Tuple2,
+};
+use cspuz_rs::solver::Solver;
+
+pub const EMPTY: i32 = 0;
+pub const BALLOON: i32 = 1;
+pub const IRON_BALL: i32 = 2;
+
+/// Returns the content of each cell: `EMPTY`, `BALLOON` or `IRON_BALL`.
+/// Solid blocks are always `EMPTY`.
+pub fn solve_dosun_fuwari(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    blocks: &[Vec<bool>],
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_dosun_fuwari_with_query(borders, blocks, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_dosun_fuwari_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    blocks: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let (h, w) = borders.base_shape();
+
+    let mut solver = Solver::new();
+    let kind = &solver.int_var_2d((h, w), EMPTY, IRON_BALL);
+    solver.add_answer_key_int(kind);
+
+    for room in &graph::borders_to_rooms(borders) {
+        let cells = room
+            .iter()
+            .filter(|&&(y, x)| !blocks[y][x])
+            .cloned()
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            continue;
+        }
+        solver.add_expr(kind.select(&cells).eq(BALLOON).count_true().eq(1));
+        solver.add_expr(kind.select(&cells).eq(IRON_BALL).count_true().eq(1));
+    }
+
+    for y in 0..h {
+        for (x, &is_block) in blocks[y].iter().enumerate() {
+            if is_block {
+                solver.add_expr(kind.at((y, x)).eq(EMPTY));
+                continue;
+            }
+            // Balloons float up, and iron balls sink down
+            if y > 0 && !blocks[y - 1][x] {
+                solver.add_expr(
+                    kind.at((y, x))
+                        .eq(BALLOON)
+                        .imp(kind.at((y - 1, x)).eq(BALLOON)),
+                );
+            }
+            if y < h - 1 && !blocks[y + 1][x] {
+                solver.add_expr(
+                    kind.at((y, x))
+                        .eq(IRON_BALL)
+                        .imp(kind.at((y + 1, x)).eq(IRON_BALL)),
+                );
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(kind))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<bool>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Map::new(
+            MultiDigit::new(2, 5),
+            |x| Some(if x { 1 } else { 0 }),
+            |x| Some(x == 1),
+        )),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator(),
+        "dosufuwa",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["dosufuwa"], url)
+}
+
+pub struct DosunFuwari;
+
+impl util::Puzzle for DosunFuwari {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_dosun_fuwari_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            crate::util::tests::to_bool_2d([
+                [1, 0, 0, 0],
+                [0, 0, 0, 1],
+                [0, 0, 0, 0],
+                [0, 0, 0, 0],
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_dosun_fuwari_problem() {
+        let (borders, blocks) = problem_for_tests();
+        let ans = solve_dosun_fuwari(&borders, &blocks);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_2d([
+            [0, 0, 0, 2],
+            [1, 2, 0, 0],
+            [1, 2, 0, 1],
+            [0, 2, 0, 1],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_dosun_fuwari_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?dosufuwa/4/4/9ag1p8g400";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
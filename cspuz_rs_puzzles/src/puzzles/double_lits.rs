@@ -1,12 +1,21 @@
+use crate::puzzles::lits::add_tetromino_constraints;
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     get_kudamono_url_info_detailed, parse_kudamono_dimension, Combinator, Context, KudamonoBorder,
 };
-use cspuz_rs::solver::{any, count_true, Solver, FALSE};
+use cspuz_rs::solver::Solver;
 
 pub fn solve_double_lits(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_double_lits_with_query(borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_double_lits_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let h = borders.vertical.len();
     assert!(h > 0);
     let w = borders.vertical[0].len() + 1;
@@ -40,104 +49,23 @@ pub fn solve_double_lits(
             }
         }
     }
-    // 0: white cell
-    // 1: endpoint
-    // 2: L
-    // 3: I
-    // 4: T
-    let kinds = [
-        &solver.int_var_2d((h, w), 0, 4),
-        &solver.int_var_2d((h, w), 0, 4),
+    let rooms = graph::borders_to_rooms(borders);
+    let room_kinds = [
+        add_tetromino_constraints(&mut solver, borders, &rooms, sub_boards[0]),
+        add_tetromino_constraints(&mut solver, borders, &rooms, sub_boards[1]),
     ];
 
-    let cell_tetro_type = &solver.int_var_2d((h, w), 0, 4);
-
-    for t in 0..2 {
-        solver.add_expr(sub_boards[t].iff(kinds[t].ne(0)));
-
-        for y in 0..h {
-            for x in 0..w {
-                let mut neighbors = vec![];
-                if y > 0 && !borders.horizontal[y - 1][x] {
-                    neighbors.push(sub_boards[t].at((y - 1, x)).expr());
-                } else {
-                    neighbors.push(FALSE);
-                }
-                if x > 0 && !borders.vertical[y][x - 1] {
-                    neighbors.push(sub_boards[t].at((y, x - 1)).expr());
-                } else {
-                    neighbors.push(FALSE);
-                }
-                if y < h - 1 && !borders.horizontal[y][x] {
-                    neighbors.push(sub_boards[t].at((y + 1, x)).expr());
-                } else {
-                    neighbors.push(FALSE);
-                }
-                if x < w - 1 && !borders.vertical[y][x] {
-                    neighbors.push(sub_boards[t].at((y, x + 1)).expr());
-                } else {
-                    neighbors.push(FALSE);
-                }
-
-                solver.add_expr(kinds[t].at((y, x)).eq(1).imp(count_true(&neighbors).eq(1)));
-                solver.add_expr(kinds[t].at((y, x)).eq(2).imp(any([
-                    &neighbors[0] & &neighbors[1] & !&neighbors[2] & !&neighbors[3],
-                    &neighbors[1] & &neighbors[2] & !&neighbors[3] & !&neighbors[0],
-                    &neighbors[2] & &neighbors[3] & !&neighbors[0] & !&neighbors[1],
-                    &neighbors[3] & &neighbors[0] & !&neighbors[1] & !&neighbors[2],
-                ])));
-                solver.add_expr(kinds[t].at((y, x)).eq(3).imp(any([
-                    &neighbors[0] & &neighbors[2] & !&neighbors[1] & !&neighbors[3],
-                    &neighbors[1] & &neighbors[3] & !&neighbors[0] & !&neighbors[2],
-                ])));
-                solver.add_expr(kinds[t].at((y, x)).eq(4).imp(count_true(&neighbors).eq(3)));
-            }
-        }
-
-        let rooms = graph::borders_to_rooms(borders);
-        for room in &rooms {
-            let mut cell_kinds = vec![vec![]; 5];
-            let room_kind = solver.int_var(0, 3);
-            for &(y, x) in room {
-                for j in 1..=4 {
-                    cell_kinds[j].push(kinds[t].at((y, x)).eq(j as i32));
-                }
-            }
-            let cell_kind_counts = cell_kinds.iter().map(|x| count_true(x)).collect::<Vec<_>>();
-            // L
-            solver.add_expr(room_kind.eq(0).imp(
-                cell_kind_counts[1].eq(2)
-                    & cell_kind_counts[2].eq(1)
-                    & cell_kind_counts[3].eq(1)
-                    & cell_kind_counts[4].eq(0),
-            ));
-            // I
-            solver.add_expr(room_kind.eq(1).imp(
-                cell_kind_counts[1].eq(2)
-                    & cell_kind_counts[2].eq(0)
-                    & cell_kind_counts[3].eq(2)
-                    & cell_kind_counts[4].eq(0),
-            ));
-            // T
-            solver.add_expr(room_kind.eq(2).imp(
-                cell_kind_counts[1].eq(3)
-                    & cell_kind_counts[2].eq(0)
-                    & cell_kind_counts[3].eq(0)
-                    & cell_kind_counts[4].eq(1),
-            ));
-            // S
-            solver.add_expr(room_kind.eq(3).imp(
-                cell_kind_counts[1].eq(2)
-                    & cell_kind_counts[2].eq(2)
-                    & cell_kind_counts[3].eq(0)
-                    & cell_kind_counts[4].eq(0),
-            ));
+    let cell_tetro_type = &solver.int_var_2d((h, w), 0, 3);
+    for (i, room) in rooms.iter().enumerate() {
+        // The two tetrominoes in a region must have different shapes
+        solver.add_expr(room_kinds[0].at(i).ne(room_kinds[1].at(i)));
 
+        for t in 0..2 {
             for &(y, x) in room {
                 solver.add_expr(
                     sub_boards[t]
                         .at((y, x))
-                        .imp(cell_tetro_type.at((y, x)).eq(&room_kind)),
+                        .imp(cell_tetro_type.at((y, x)).eq(room_kinds[t].at(i))),
                 );
             }
         }
@@ -166,7 +94,7 @@ pub fn solve_double_lits(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = graph::InnerGridEdges<Vec<Vec<bool>>>;
@@ -190,6 +118,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(border)
 }
 
+pub struct DoubleLits;
+
+impl util::Puzzle for DoubleLits {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_double_lits_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,22 +155,45 @@ mod tests {
 
     #[test]
     fn test_double_lits() {
-        let problem = problem_for_tests();
+        let problem = graph::InnerGridEdges {
+            horizontal: crate::util::tests::to_bool_2d([
+                [0, 0, 0, 0, 0, 0],
+                [0, 0, 1, 1, 0, 0],
+                [0, 0, 0, 1, 0, 0],
+                [1, 1, 1, 1, 1, 1],
+                [0, 0, 0, 0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_bool_2d([
+                [0, 1, 0, 0, 0],
+                [0, 1, 0, 0, 0],
+                [0, 0, 0, 1, 0],
+                [0, 0, 1, 0, 0],
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]),
+        };
         let ans = solve_double_lits(&problem);
         assert!(ans.is_some());
         let ans = ans.unwrap();
 
         let expected = crate::util::tests::to_option_bool_2d([
-            [1, 1, 0, 2, 1, 1],
-            [1, 0, 1, 1, 0, 1],
-            [1, 1, 0, 1, 1, 2],
-            [0, 1, 1, 0, 1, 0],
-            [1, 0, 1, 1, 1, 2],
-            [1, 1, 1, 0, 2, 0],
+            [1, 0, 1, 1, 1, 0],
+            [1, 1, 1, 0, 0, 1],
+            [1, 0, 1, 1, 1, 1],
+            [0, 1, 1, 0, 0, 1],
+            [0, 1, 0, 1, 1, 1],
+            [1, 1, 1, 0, 0, 1],
         ]);
         assert_eq!(ans, expected);
     }
 
+    #[test]
+    fn test_double_lits_same_shapes_rejected() {
+        // This problem requires two tetrominoes of the same shape in a region
+        let problem = problem_for_tests();
+        assert!(solve_double_lits(&problem).is_none());
+    }
+
     #[test]
     fn test_double_lits_serializer() {
         let problem = problem_for_tests();
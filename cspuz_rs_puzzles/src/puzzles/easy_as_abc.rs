@@ -0,0 +1,229 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, DecInt, HexInt,
+    Optionalize, PrefixAndSuffix, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{IntExpr, Solver};
+
+/// Solves an Easy as ABC problem.
+///
+/// Letters are represented by numbers (A = 1, B = 2, ...) and blank cells by 0.
+/// `clues_up[x]` is the first letter seen from the top of the column `x`, and so on.
+pub fn solve_easy_as_abc(
+    n_letters: i32,
+    clues_up: &[Option<i32>],
+    clues_down: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    clues_right: &[Option<i32>],
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_easy_as_abc_with_query(
+        n_letters,
+        clues_up,
+        clues_down,
+        clues_left,
+        clues_right,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_easy_as_abc_with_query(
+    n_letters: i32,
+    clues_up: &[Option<i32>],
+    clues_down: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    clues_right: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let h = clues_left.len();
+    let w = clues_up.len();
+    if clues_down.len() != w || clues_right.len() != h {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let num = &solver.int_var_2d((h, w), 0, n_letters);
+    solver.add_answer_key_int(num);
+
+    for y in 0..h {
+        let row = num.slice_fixed_y((y, ..));
+        for i in 1..=n_letters {
+            solver.add_expr(row.eq(i).count_true().eq(1));
+        }
+    }
+    for x in 0..w {
+        let col = num.slice_fixed_x((.., x));
+        for i in 1..=n_letters {
+            solver.add_expr(col.eq(i).count_true().eq(1));
+        }
+    }
+
+    let mut add_first_letter = |cells: Vec<IntExpr>, clue: Option<i32>| {
+        let c = match clue {
+            Some(c) if c > 0 => c,
+            _ => return,
+        };
+        for i in 0..cells.len() {
+            let mut cond = cells[i].ne(0);
+            for prev in &cells[..i] {
+                cond = cond & prev.eq(0);
+            }
+            solver.add_expr(cond.imp(cells[i].eq(c)));
+        }
+    };
+
+    for x in 0..w {
+        let cells = (0..h).map(|y| num.at((y, x)).expr()).collect::<Vec<_>>();
+        add_first_letter(cells.clone(), clues_up[x]);
+        add_first_letter(cells.into_iter().rev().collect(), clues_down[x]);
+    }
+    for y in 0..h {
+        let cells = (0..w).map(|x| num.at((y, x)).expr()).collect::<Vec<_>>();
+        add_first_letter(cells.clone(), clues_left[y]);
+        add_first_letter(cells.into_iter().rev().collect(), clues_right[y]);
+    }
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+pub type Problem = (
+    i32,
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+pub struct EasyAsAbcCombinator;
+
+impl Combinator<Problem> for EasyAsAbcCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let (n_letters, clues_up, clues_down, clues_left, clues_right) = &input[0];
+
+        let mut ret = PrefixAndSuffix::new("", DecInt, "/")
+            .serialize(ctx, &[*n_letters])?
+            .1;
+        let surrounding = [
+            &clues_up[..],
+            &clues_down[..],
+            &clues_left[..],
+            &clues_right[..],
+        ]
+        .concat();
+        ret.extend(
+            Seq::new(clue_combinator(), (width + height) * 2)
+                .serialize(ctx, &[surrounding])?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let n_letters =
+            sequencer.deserialize_one_elem(ctx, PrefixAndSuffix::new("", DecInt, "/"))?;
+        let surrounding = sequencer
+            .deserialize_one_elem(ctx, Seq::new(clue_combinator(), (width + height) * 2))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_down = surrounding[width..(width * 2)].to_vec();
+        let clues_left = surrounding[(width * 2)..(width * 2 + height)].to_vec();
+        let clues_right = surrounding[(width * 2 + height)..].to_vec();
+
+        Some((
+            sequencer.n_read(),
+            vec![(n_letters, clues_up, clues_down, clues_left, clues_right)],
+        ))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(EasyAsAbcCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.3.len();
+    let width = problem.1.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "easyasabc",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["easyasabc"], url)
+}
+
+pub struct EasyAsAbc;
+
+impl util::Puzzle for EasyAsAbc {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_easy_as_abc_with_query(
+            problem.0, &problem.1, &problem.2, &problem.3, &problem.4, query,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        (
+            3,
+            vec![Some(1), Some(1), None, Some(1), None],
+            vec![None, None, Some(3), None, None],
+            vec![Some(2), None, Some(1), None, None],
+            vec![None, None, None, None, None],
+        )
+    }
+
+    #[test]
+    fn test_easy_as_abc_problem() {
+        let (n_letters, clues_up, clues_down, clues_left, clues_right) = problem_for_tests();
+        let ans = solve_easy_as_abc(n_letters, &clues_up, &clues_down, &clues_left, &clues_right);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_2d([
+            [0, 0, 2, 1, 3],
+            [0, 1, 0, 3, 2],
+            [1, 3, 0, 2, 0],
+            [3, 2, 1, 0, 0],
+            [2, 0, 3, 0, 1],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_easy_as_abc_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?easyasabc/5/5/3/11g1i3h2g1m";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -24,6 +24,13 @@ pub struct Problem {
 }
 
 pub fn solve_evolmino(problem: &Problem) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_evolmino_with_query(problem, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_evolmino_with_query(
+    problem: &Problem,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(&problem.cells);
     let mut solver = Solver::new();
     let is_square = &solver.bool_var_2d((h, w));
@@ -116,7 +123,10 @@ pub fn solve_evolmino(problem: &Problem) -> Option<Vec<Vec<Option<bool>>>> {
         }
     }
 
-    let problem = ProblemWithArrowId::new(problem)?;
+    let problem = match ProblemWithArrowId::new(problem) {
+        Some(problem) => problem,
+        None => return vec![],
+    };
 
     #[cfg(not(test))]
     {
@@ -147,7 +157,7 @@ pub fn solve_evolmino(problem: &Problem) -> Option<Vec<Vec<Option<bool>>>> {
         );
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_square))
+    util::query_answers(solver, query, |f| f.get(is_square))
 }
 
 type ProblemProxy = (
@@ -1218,6 +1228,17 @@ fn traverse_grid<F>(
     }
 }
 
+pub struct Evolmino;
+
+impl util::Puzzle for Evolmino {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_evolmino_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,51 @@
+use std::collections::HashMap;
+
 use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
-    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Map, Optionalize,
+    PrefixAndSuffix, Spaces,
 };
 use cspuz_rs::solver::Solver;
 
+use cspuz_core::custom_constraints::SimpleCustomConstraint;
+
+/// Additional rules of Fillomino variants.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FillominoVariant {
+    /// No region may be a rectangle (including squares and 1xN strips).
+    pub no_rectangles: bool,
+
+    /// No two regions may have the same shape, where rotations and reflections are considered the same.
+    pub distinct_shapes: bool,
+}
+
 pub fn solve_fillomino(
     clues: &[Vec<Option<i32>>],
 ) -> Option<(
     Vec<Vec<Option<i32>>>,
     graph::BoolInnerGridEdgesIrrefutableFacts,
+)> {
+    solve_fillomino_with_variant(clues, FillominoVariant::default())
+}
+
+pub fn solve_fillomino_with_variant(
+    clues: &[Vec<Option<i32>>],
+    variant: FillominoVariant,
+) -> Option<(
+    Vec<Vec<Option<i32>>>,
+    graph::BoolInnerGridEdgesIrrefutableFacts,
+)> {
+    solve_fillomino_with_query(clues, variant, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_fillomino_with_query(
+    clues: &[Vec<Option<i32>>],
+    variant: FillominoVariant,
+    query: util::AnswerQuery,
+) -> Vec<(
+    Vec<Vec<Option<i32>>>,
+    graph::BoolInnerGridEdgesIrrefutableFacts,
 )> {
     let (h, w) = util::infer_shape(clues);
 
@@ -41,9 +77,324 @@ pub fn solve_fillomino(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(num), f.get(&is_border)))
+    if variant.no_rectangles || variant.distinct_shapes {
+        let borders_flat = is_border
+            .horizontal
+            .clone()
+            .into_iter()
+            .chain(is_border.vertical.clone())
+            .collect::<Vec<_>>();
+
+        #[cfg(not(test))]
+        {
+            solver.add_custom_constraint(
+                Box::new(RegionShapeConstraint::new(h, w, variant)),
+                borders_flat,
+            );
+        }
+
+        #[cfg(test)]
+        {
+            solver.add_custom_constraint(
+                Box::new(util::tests::ReasonVerifier::new(
+                    RegionShapeConstraint::new(h, w, variant),
+                    RegionShapeConstraint::new(h, w, variant),
+                )),
+                borders_flat,
+            );
+        }
+    }
+
+    util::query_answers(solver, query, |f| (f.get(num), f.get(&is_border)))
+}
+
+fn normalize_shape(cells: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut ret: Option<Vec<(i32, i32)>> = None;
+
+    for t in 0..8 {
+        let mut transformed = cells
+            .iter()
+            .map(|&(y, x)| {
+                let (y, x) = if t & 4 != 0 { (x, y) } else { (y, x) };
+                let y = if t & 2 != 0 { -y } else { y };
+                let x = if t & 1 != 0 { -x } else { x };
+                (y, x)
+            })
+            .collect::<Vec<_>>();
+        let ymin = transformed.iter().map(|&(y, _)| y).min().unwrap();
+        let xmin = transformed.iter().map(|&(_, x)| x).min().unwrap();
+        for p in &mut transformed {
+            p.0 -= ymin;
+            p.1 -= xmin;
+        }
+        transformed.sort();
+
+        if ret.as_ref().is_none_or(|r| transformed < *r) {
+            ret = Some(transformed);
+        }
+    }
+
+    ret.unwrap()
+}
+
+/// A region whose boundary was fully decided when it was checked.
+struct ClosedRegion {
+    /// Smallest index (`y * width + x`) among the cells of the region.
+    first_cell: usize,
+    shape: Vec<(i32, i32)>,
+    reason: Vec<(usize, bool)>,
+    /// One more than the largest position in `decision_stack` of the borders in `reason`.
+    /// The region is no longer valid once `decision_stack` gets shorter than this.
+    depth: usize,
+}
+
+/// Checks the shapes of regions whose boundaries are fully decided.
+/// Inputs are the horizontal borders followed by the vertical borders of `BoolInnerGridEdges`.
+///
+/// Only regions touching the borders decided since the last check are examined. For
+/// `distinct_shapes`, the regions found so far are kept in `closed_regions` until one of the
+/// borders in their reasons is undone.
+struct RegionShapeConstraint {
+    height: usize,
+    width: usize,
+    variant: FillominoVariant,
+    borders: Vec<Option<bool>>,
+    decision_stack: Vec<usize>,
+    // Position of each decided border in `decision_stack`.
+    position: Vec<usize>,
+    // Borders in `decision_stack[..num_checked]` have already been checked.
+    num_checked: usize,
+    // Sorted in ascending order of `depth`, so that invalidated regions are always at the end.
+    closed_regions: Vec<ClosedRegion>,
+    shapes: HashMap<Vec<(i32, i32)>, usize>,
+    is_recorded: Vec<bool>,
+    // `region_stamp[y * width + x]` is the value of `stamp` when the cell was last visited.
+    region_stamp: Vec<u64>,
+    stamp: u64,
+}
+
+impl RegionShapeConstraint {
+    fn new(height: usize, width: usize, variant: FillominoVariant) -> RegionShapeConstraint {
+        let num_borders = (height - 1) * width + height * (width - 1);
+        RegionShapeConstraint {
+            height,
+            width,
+            variant,
+            borders: vec![None; num_borders],
+            decision_stack: vec![],
+            position: vec![0; num_borders],
+            num_checked: 0,
+            closed_regions: vec![],
+            shapes: HashMap::new(),
+            is_recorded: vec![false; height * width],
+            region_stamp: vec![0; height * width],
+            stamp: 0,
+        }
+    }
+
+    // Returns the cells adjacent to (y, x) together with the index of the border between them.
+    fn neighbors(&self, y: usize, x: usize) -> Vec<((usize, usize), usize)> {
+        let height = self.height;
+        let width = self.width;
+        let mut ret = vec![];
+
+        if y > 0 {
+            ret.push(((y - 1, x), (y - 1) * width + x));
+        }
+        if y < height - 1 {
+            ret.push(((y + 1, x), y * width + x));
+        }
+        if x > 0 {
+            ret.push(((y, x - 1), (height - 1) * width + y * (width - 1) + x - 1));
+        }
+        if x < width - 1 {
+            ret.push(((y, x + 1), (height - 1) * width + y * (width - 1) + x));
+        }
+
+        ret
+    }
+
+    // Returns the two cells separated by the border `idx`.
+    fn cells_of_border(&self, idx: usize) -> [(usize, usize); 2] {
+        let width = self.width;
+        let num_horizontal = (self.height - 1) * width;
+
+        if idx < num_horizontal {
+            let (y, x) = (idx / width, idx % width);
+            [(y, x), (y + 1, x)]
+        } else {
+            let idx = idx - num_horizontal;
+            let (y, x) = (idx / (width - 1), idx % (width - 1));
+            [(y, x), (y, x + 1)]
+        }
+    }
+
+    // Returns the cells of the region containing (y, x) and the reason of its shape, or `None` if
+    // the boundary of the region is not fully decided.
+    fn closed_region(
+        &mut self,
+        y: usize,
+        x: usize,
+    ) -> Option<(Vec<(usize, usize)>, Vec<(usize, bool)>)> {
+        let width = self.width;
+
+        self.stamp += 1;
+        let stamp = self.stamp;
+        let mut cells = vec![(y, x)];
+        self.region_stamp[y * width + x] = stamp;
+        let mut reason = vec![];
+        let mut undecided_neighbors = vec![];
+        let mut i = 0;
+        while i < cells.len() {
+            let (cy, cx) = cells[i];
+            i += 1;
+            for ((ny, nx), idx) in self.neighbors(cy, cx) {
+                match self.borders[idx] {
+                    Some(false) => {
+                        if self.region_stamp[ny * width + nx] != stamp {
+                            self.region_stamp[ny * width + nx] = stamp;
+                            cells.push((ny, nx));
+                            reason.push((idx, false));
+                        }
+                    }
+                    Some(true) => reason.push((idx, true)),
+                    None => undecided_neighbors.push((ny, nx)),
+                }
+            }
+        }
+
+        // Undecided borders inside the region do not affect its shape
+        if undecided_neighbors
+            .iter()
+            .any(|&(ny, nx)| self.region_stamp[ny * width + nx] != stamp)
+        {
+            return None;
+        }
+        reason.sort();
+        reason.dedup();
+
+        Some((cells, reason))
+    }
+
+    fn pop_closed_region(&mut self) {
+        let region = self.closed_regions.pop().unwrap();
+        self.shapes.remove(&region.shape);
+        self.is_recorded[region.first_cell] = false;
+    }
+
+    // Checks the regions touching the borders decided since the last check. The regions recorded
+    // for `distinct_shapes` are appended to `closed_regions` in arbitrary order of `depth`.
+    fn check_touched_regions(&mut self) -> Option<Vec<(usize, bool)>> {
+        let width = self.width;
+        let first_stamp = self.stamp + 1;
+
+        for i in self.num_checked..self.decision_stack.len() {
+            for (y, x) in self.cells_of_border(self.decision_stack[i]) {
+                if self.region_stamp[y * width + x] >= first_stamp {
+                    continue;
+                }
+                let Some((cells, reason)) = self.closed_region(y, x) else {
+                    continue;
+                };
+                let first_cell = cells.iter().map(|&(y, x)| y * width + x).min().unwrap();
+                if self.is_recorded[first_cell] {
+                    continue;
+                }
+
+                if self.variant.no_rectangles {
+                    let ymin = cells.iter().map(|&(y, _)| y).min().unwrap();
+                    let ymax = cells.iter().map(|&(y, _)| y).max().unwrap();
+                    let xmin = cells.iter().map(|&(_, x)| x).min().unwrap();
+                    let xmax = cells.iter().map(|&(_, x)| x).max().unwrap();
+                    if (ymax - ymin + 1) * (xmax - xmin + 1) == cells.len() {
+                        return Some(reason);
+                    }
+                }
+
+                if self.variant.distinct_shapes {
+                    let shape = normalize_shape(
+                        &cells
+                            .iter()
+                            .map(|&(y, x)| (y as i32, x as i32))
+                            .collect::<Vec<_>>(),
+                    );
+                    if let Some(&other) = self.shapes.get(&shape) {
+                        let mut ret = self.closed_regions[other].reason.clone();
+                        ret.extend(reason);
+                        return Some(ret);
+                    }
+                    let depth = reason
+                        .iter()
+                        .map(|&(idx, _)| self.position[idx] + 1)
+                        .max()
+                        .unwrap_or(0);
+                    self.shapes.insert(shape.clone(), self.closed_regions.len());
+                    self.is_recorded[first_cell] = true;
+                    self.closed_regions.push(ClosedRegion {
+                        first_cell,
+                        shape,
+                        reason,
+                        depth,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl SimpleCustomConstraint for RegionShapeConstraint {
+    fn initialize_sat(&mut self, num_inputs: usize) {
+        assert_eq!(num_inputs, self.borders.len());
+    }
+
+    fn notify(&mut self, index: usize, value: bool) {
+        self.borders[index] = Some(value);
+        self.position[index] = self.decision_stack.len();
+        self.decision_stack.push(index);
+    }
+
+    fn find_inconsistency(&mut self) -> Option<Vec<(usize, bool)>> {
+        let num_recorded = self.closed_regions.len();
+
+        if let Some(reason) = self.check_touched_regions() {
+            // The touched borders will be checked again, so the regions found in this call are
+            // discarded to avoid recording them twice.
+            while self.closed_regions.len() > num_recorded {
+                self.pop_closed_region();
+            }
+            return Some(reason);
+        }
+
+        // The regions found in this call include a border decided after all the older ones, so
+        // sorting them keeps `closed_regions` sorted by `depth`.
+        let mut new_regions = self.closed_regions.split_off(num_recorded);
+        new_regions.sort_by_key(|region| region.depth);
+        for region in new_regions {
+            self.shapes
+                .insert(region.shape.clone(), self.closed_regions.len());
+            self.closed_regions.push(region);
+        }
+        self.num_checked = self.decision_stack.len();
+
+        None
+    }
+
+    fn undo(&mut self) {
+        let index = self.decision_stack.pop().unwrap();
+        self.borders[index] = None;
+
+        let len = self.decision_stack.len();
+        self.num_checked = self.num_checked.min(len);
+        while self
+            .closed_regions
+            .last()
+            .is_some_and(|region| region.depth > len)
+        {
+            self.pop_closed_region();
+        }
+    }
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -64,6 +415,65 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["fillomino"], url)
 }
 
+pub type VariantProblem = (FillominoVariant, Problem);
+
+fn variant_combinator() -> impl Combinator<VariantProblem> {
+    let mut choices: Vec<Box<dyn Combinator<VariantProblem>>> = vec![];
+    for (prefix, no_rectangles, distinct_shapes) in [
+        ("rd/", true, true),
+        ("r/", true, false),
+        ("d/", false, true),
+        ("", false, false),
+    ] {
+        let variant = FillominoVariant {
+            no_rectangles,
+            distinct_shapes,
+        };
+        choices.push(Box::new(Map::new(
+            PrefixAndSuffix::new(prefix, combinator(), ""),
+            move |(v, problem): VariantProblem| if v == variant { Some(problem) } else { None },
+            move |problem| Some((variant, problem)),
+        )));
+    }
+    Choice::new(choices)
+}
+
+pub fn serialize_variant_problem(problem: &VariantProblem) -> Option<String> {
+    problem_to_url(variant_combinator(), "fillomino", problem.clone())
+}
+
+pub fn deserialize_variant_problem(url: &str) -> Option<VariantProblem> {
+    url_to_problem(variant_combinator(), &["fillomino"], url)
+}
+
+pub struct Fillomino;
+
+impl util::Puzzle for Fillomino {
+    type Problem = Problem;
+    type Answer = (
+        Vec<Vec<Option<i32>>>,
+        graph::BoolInnerGridEdgesIrrefutableFacts,
+    );
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_fillomino_with_query(problem, FillominoVariant::default(), query)
+    }
+}
+
+pub struct FillominoWithVariant;
+
+impl util::Puzzle for FillominoWithVariant {
+    type Problem = VariantProblem;
+    type Answer = (
+        Vec<Vec<Option<i32>>>,
+        graph::BoolInnerGridEdgesIrrefutableFacts,
+    );
+
+    fn query_answers(problem: &VariantProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_fillomino_with_query(&problem.1, problem.0, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +510,76 @@ mod tests {
         let url = "https://puzz.link/p?fillomino/5/5/g1k34g2h5h4n";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    #[test]
+    fn test_fillomino_no_rectangles() {
+        let problem = vec![
+            vec![Some(5), None, None, None, Some(5)],
+            vec![None, None, None, None, None],
+            vec![None, None, None, None, None],
+            vec![None, None, Some(4), None, Some(3)],
+            vec![None, None, None, Some(5), Some(5)],
+        ];
+        let variant = FillominoVariant {
+            no_rectangles: true,
+            distinct_shapes: false,
+        };
+        let ans = solve_fillomino_with_variant(&problem, variant);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = crate::util::tests::to_option_2d([
+            [5, 3, 5, 5, 5],
+            [5, 3, 3, 5, 5],
+            [5, 5, 5, 3, 3],
+            [4, 4, 4, 5, 3],
+            [4, 5, 5, 5, 5],
+        ]);
+        assert_eq!(ans.0, expected);
+
+        let url = "https://puzz.link/p?fillomino/r/5/5/5i5r4g3i55";
+        util::tests::serializer_test(
+            (variant, problem),
+            url,
+            serialize_variant_problem,
+            deserialize_variant_problem,
+        );
+    }
+
+    #[test]
+    fn test_fillomino_distinct_shapes() {
+        let problem = vec![
+            vec![None, None, Some(1), Some(4), None],
+            vec![None, None, None, None, Some(2)],
+            vec![None, None, None, None, Some(5)],
+            vec![Some(3), None, None, None, None],
+            vec![Some(3), None, None, None, Some(3)],
+        ];
+        let variant = FillominoVariant {
+            no_rectangles: false,
+            distinct_shapes: true,
+        };
+        let ans = solve_fillomino_with_variant(&problem, variant);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = crate::util::tests::to_option_2d([
+            [7, 7, 1, 4, 2],
+            [7, 7, 4, 4, 2],
+            [3, 7, 4, 5, 5],
+            [3, 7, 5, 5, 3],
+            [3, 7, 5, 3, 3],
+        ]);
+        assert_eq!(ans.0, expected);
+
+        let url = "https://puzz.link/p?fillomino/d/5/5/h14k2j53j3i3";
+        util::tests::serializer_test(
+            (variant, problem.clone()),
+            url,
+            serialize_variant_problem,
+            deserialize_variant_problem,
+        );
+        assert_eq!(
+            deserialize_variant_problem("https://puzz.link/p?fillomino/5/5/h14k2j53j3i3"),
+            Some((FillominoVariant::default(), problem))
+        );
+    }
 }
@@ -10,6 +10,13 @@ use cspuz_rs::solver::{count_true, BoolVar, IntVar, Solver};
 pub fn solve_firefly(
     clues: &[Vec<Option<NumberedArrow>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_firefly_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_firefly_with_query(
+    clues: &[Vec<Option<NumberedArrow>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -101,7 +108,7 @@ pub fn solve_firefly(
                     Arrow::Right => 3,
                 };
                 if adj[out_idx].is_none() {
-                    return None;
+                    return vec![];
                 }
                 let (_, out_edge, n_turn) = adj[out_idx].as_ref().unwrap();
                 solver.add_expr(out_edge);
@@ -155,7 +162,7 @@ pub fn solve_firefly(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<NumberedArrow>>>;
@@ -178,6 +185,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["firefly"], url)
 }
 
+pub struct Firefly;
+
+impl util::Puzzle for Firefly {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_firefly_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -10,6 +10,14 @@ pub fn solve_firewalk(
     fire_cell: &[Vec<bool>],
     num: &[Vec<Option<i32>>],
 ) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_firewalk_with_query(fire_cell, num, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_firewalk_with_query(
+    fire_cell: &[Vec<bool>],
+    num: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
     let (h, w) = util::infer_shape(fire_cell);
 
     let mut solver = Solver::new();
@@ -234,9 +242,7 @@ pub fn solve_firewalk(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(fire_cell_mode)))
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(fire_cell_mode)))
 }
 
 type Problem = (Vec<Vec<bool>>, Vec<Vec<Option<i32>>>);
@@ -271,6 +277,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["firewalk"], url)
 }
 
+pub struct Firewalk;
+
+impl util::Puzzle for Firewalk {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_firewalk_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
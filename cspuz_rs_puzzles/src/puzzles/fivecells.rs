@@ -8,6 +8,13 @@ use cspuz_rs::solver::{count_true, int_constant, Solver};
 pub fn solve_fivecells(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_fivecells_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_fivecells_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -82,7 +89,7 @@ pub fn solve_fivecells(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(&is_border))
+    util::query_answers(solver, query, |f| f.get(&is_border))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -108,6 +115,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["fivecells"], url)
 }
 
+pub struct Fivecells;
+
+impl util::Puzzle for Fivecells {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_fivecells_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
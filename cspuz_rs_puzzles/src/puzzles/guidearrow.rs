@@ -20,6 +20,15 @@ pub fn solve_guidearrow(
     tx: usize,
     clues: &[Vec<Option<GuidearrowClue>>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_guidearrow_with_query(ty, tx, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_guidearrow_with_query(
+    ty: usize,
+    tx: usize,
+    clues: &[Vec<Option<GuidearrowClue>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -51,28 +60,28 @@ pub fn solve_guidearrow(
                 match clue {
                     GuidearrowClue::Up => {
                         if y == 0 {
-                            return None;
+                            return vec![];
                         }
                         solver.add_expr(!is_black.at((y - 1, x)));
                         solver.add_expr(rank.at((y - 1, x)).lt(rank.at((y, x))));
                     }
                     GuidearrowClue::Down => {
                         if y == h - 1 {
-                            return None;
+                            return vec![];
                         }
                         solver.add_expr(!is_black.at((y + 1, x)));
                         solver.add_expr(rank.at((y + 1, x)).lt(rank.at((y, x))));
                     }
                     GuidearrowClue::Left => {
                         if x == 0 {
-                            return None;
+                            return vec![];
                         }
                         solver.add_expr(!is_black.at((y, x - 1)));
                         solver.add_expr(rank.at((y, x - 1)).lt(rank.at((y, x))));
                     }
                     GuidearrowClue::Right => {
                         if x == w - 1 {
-                            return None;
+                            return vec![];
                         }
                         solver.add_expr(!is_black.at((y, x + 1)));
                         solver.add_expr(rank.at((y, x + 1)).lt(rank.at((y, x))));
@@ -82,7 +91,7 @@ pub fn solve_guidearrow(
             }
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 pub struct GuidearrowClueCombinator;
@@ -165,6 +174,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(((res.1 - 1) as usize, (res.0 - 1) as usize, res.2))
 }
 
+pub struct Guidearrow;
+
+impl util::Puzzle for Guidearrow {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_guidearrow_with_query(problem.0, problem.1, &problem.2, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
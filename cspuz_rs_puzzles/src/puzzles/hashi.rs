@@ -1,41 +1,101 @@
 use crate::util;
 use cspuz_rs::graph::{self, GridEdges};
+use cspuz_rs::items::{Arrow, NumberedArrow};
 use cspuz_rs::serializer::{
-    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+    problem_to_url, url_to_problem, Choice, Combinator, DecInt, Dict, Grid, HexInt, Map,
+    NumberedArrowCombinator, Optionalize, PrefixAndSuffix, Spaces, Tuple2,
 };
 use cspuz_rs::solver::{sum, Solver};
 
+pub const DEFAULT_MAX_BRIDGES: i32 = 2;
+
 pub fn solve_hashi(clues: &[Vec<Option<i32>>]) -> Option<GridEdges<Vec<Vec<Option<i32>>>>> {
+    solve_hashi_with_variant(&to_arrow_clues(clues), DEFAULT_MAX_BRIDGES)
+}
+
+/// Solves Hashi with at most `max_bridges` bridges between each pair of islands.
+/// A clue `(Arrow::Unspecified, n)` gives the total number of bridges of the island, while a clue
+/// with a direction gives the number of bridges leaving the island in that direction.
+/// `n = -1` means that the number is unknown.
+pub fn solve_hashi_with_variant(
+    clues: &[Vec<Option<NumberedArrow>>],
+    max_bridges: i32,
+) -> Option<GridEdges<Vec<Vec<Option<i32>>>>> {
+    solve_hashi_with_query(clues, max_bridges, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn to_arrow_clues(clues: &[Vec<Option<i32>>]) -> Vec<Vec<Option<NumberedArrow>>> {
+    clues
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|c| c.map(|n| (Arrow::Unspecified, n)))
+                .collect()
+        })
+        .collect()
+}
+
+fn solve_hashi_with_query(
+    clues: &[Vec<Option<NumberedArrow>>],
+    max_bridges: i32,
+    query: util::AnswerQuery,
+) -> Vec<GridEdges<Vec<Vec<Option<i32>>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
-    let answer_horizontal = &solver.int_var_2d((h, w - 1), 0, 2);
-    let answer_vertical = &solver.int_var_2d((h - 1, w), 0, 2);
+    let answer_horizontal = &solver.int_var_2d((h, w - 1), 0, max_bridges);
+    let answer_vertical = &solver.int_var_2d((h - 1, w), 0, max_bridges);
     solver.add_answer_key_int(answer_horizontal);
     solver.add_answer_key_int(answer_vertical);
 
     for y in 0..h {
         for x in 0..w {
-            if let Some(n) = clues[y][x] {
+            if let Some((dir, n)) = clues[y][x] {
                 let mut deg = vec![];
+                let mut bridges_up = None;
+                let mut bridges_down = None;
+                let mut bridges_left = None;
+                let mut bridges_right = None;
                 if y > 0 {
                     deg.push(answer_vertical.at((y - 1, x)));
+                    bridges_up = Some(answer_vertical.at((y - 1, x)));
                 }
                 if y < h - 1 {
                     deg.push(answer_vertical.at((y, x)));
+                    bridges_down = Some(answer_vertical.at((y, x)));
                 }
                 if x > 0 {
                     deg.push(answer_horizontal.at((y, x - 1)));
+                    bridges_left = Some(answer_horizontal.at((y, x - 1)));
                 }
                 if x < w - 1 {
                     deg.push(answer_horizontal.at((y, x)));
+                    bridges_right = Some(answer_horizontal.at((y, x)));
                 }
 
-                if n >= 0 {
+                if dir == Arrow::Unspecified && n >= 0 {
                     solver.add_expr(sum(deg).eq(n));
                 } else {
                     solver.add_expr(sum(deg).gt(0));
                 }
+
+                if dir != Arrow::Unspecified && n >= 0 {
+                    let bridges = match dir {
+                        Arrow::Up => bridges_up,
+                        Arrow::Down => bridges_down,
+                        Arrow::Left => bridges_left,
+                        Arrow::Right => bridges_right,
+                        Arrow::Unspecified => unreachable!(),
+                    };
+                    match bridges {
+                        Some(b) => solver.add_expr(b.eq(n)),
+                        None => {
+                            if n != 0 {
+                                return vec![];
+                            }
+                        }
+                    }
+                }
             } else {
                 if y == 0 {
                     solver.add_expr(answer_vertical.at((y, x)).eq(0));
@@ -77,7 +137,7 @@ pub fn solve_hashi(clues: &[Vec<Option<i32>>]) -> Option<GridEdges<Vec<Vec<Optio
     let (edges, g) = is_connected.representation();
     graph::active_vertices_connected(&mut solver, edges, &g.line_graph());
 
-    solver.irrefutable_facts().map(|f| GridEdges {
+    util::query_answers(solver, query, |f| GridEdges {
         horizontal: f.get(answer_horizontal),
         vertical: f.get(answer_vertical),
     })
@@ -101,6 +161,73 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["hashi"], url)
 }
 
+/// A Hashi problem with the maximum number of bridges between each pair of islands.
+pub type VariantProblem = (i32, Vec<Vec<Option<NumberedArrow>>>);
+
+fn variant_combinator() -> impl Combinator<VariantProblem> {
+    Choice::new(vec![
+        Box::new(Map::new(
+            combinator(),
+            |(max_bridges, clues): VariantProblem| {
+                if max_bridges != DEFAULT_MAX_BRIDGES {
+                    return None;
+                }
+                clues
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|c| match c {
+                                None => Some(None),
+                                Some((Arrow::Unspecified, n)) => Some(Some(*n)),
+                                Some(_) => None,
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .collect::<Option<Vec<_>>>()
+            },
+            |clues: Problem| Some((DEFAULT_MAX_BRIDGES, to_arrow_clues(&clues))),
+        )),
+        Box::new(Tuple2::new(
+            PrefixAndSuffix::new("v", DecInt, "/"),
+            Grid::new(Choice::new(vec![
+                Box::new(Optionalize::new(NumberedArrowCombinator)),
+                Box::new(Spaces::new(None, 'a')),
+            ])),
+        )),
+    ])
+}
+
+/// Serializes a variant problem. Problems of the classic genre are serialized in the classic format.
+pub fn serialize_variant_problem(problem: &VariantProblem) -> Option<String> {
+    problem_to_url(variant_combinator(), "hashi", problem.clone())
+}
+
+pub fn deserialize_variant_problem(url: &str) -> Option<VariantProblem> {
+    url_to_problem(variant_combinator(), &["hashi"], url)
+}
+
+pub struct Hashi;
+
+impl util::Puzzle for Hashi {
+    type Problem = Problem;
+    type Answer = GridEdges<Vec<Vec<Option<i32>>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_hashi_with_query(&to_arrow_clues(problem), DEFAULT_MAX_BRIDGES, query)
+    }
+}
+
+pub struct HashiVariant;
+
+impl util::Puzzle for HashiVariant {
+    type Problem = VariantProblem;
+    type Answer = GridEdges<Vec<Vec<Option<i32>>>>;
+
+    fn query_answers(problem: &VariantProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_hashi_with_query(&problem.1, problem.0, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +276,70 @@ mod tests {
         let url = "https://puzz.link/p?hashi/6/6/3g1g2.g.g2h.g2g.h.g4g3h4g.g2g.h2";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    fn variant_problem_for_tests() -> VariantProblem {
+        let mut clues = vec![vec![None; 6]; 6];
+        clues[0][0] = Some((Arrow::Unspecified, 6));
+        clues[0][1] = Some((Arrow::Unspecified, 9));
+        clues[0][3] = Some((Arrow::Unspecified, 6));
+        clues[1][0] = Some((Arrow::Unspecified, 6));
+        clues[1][3] = Some((Arrow::Unspecified, 9));
+        clues[1][5] = Some((Arrow::Unspecified, 6));
+        clues[2][0] = Some((Arrow::Unspecified, 3));
+        clues[3][1] = Some((Arrow::Unspecified, 6));
+        clues[3][3] = Some((Arrow::Left, 3));
+        clues[4][4] = Some((Arrow::Unspecified, 3));
+        clues[4][5] = Some((Arrow::Unspecified, -1));
+        clues[5][2] = Some((Arrow::Up, 0));
+        clues[5][3] = Some((Arrow::Unspecified, 6));
+        (3, clues)
+    }
+
+    #[test]
+    fn test_hashi_variant_problem() {
+        let (max_bridges, clues) = variant_problem_for_tests();
+        let ans = solve_hashi_with_variant(&clues, max_bridges);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::GridEdges {
+            horizontal: crate::util::tests::to_option_2d([
+                [3, 3, 3, 0, 0],
+                [0, 0, 0, 3, 3],
+                [0, 0, 0, 0, 0],
+                [0, 3, 3, 0, 0],
+                [0, 0, 0, 0, 3],
+                [0, 0, 3, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_2d([
+                [3, 3, 0, 3, 0, 0],
+                [3, 3, 0, 3, 0, 3],
+                [0, 3, 0, 3, 0, 3],
+                [0, 0, 0, 3, 0, 3],
+                [0, 0, 0, 3, 0, 0],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_hashi_variant_serializer() {
+        let problem = variant_problem_for_tests();
+        let url = "https://puzz.link/p?hashi/v3/6/6/0609a06b06b09a0603f06a33f030.b1006b";
+        util::tests::serializer_test(
+            problem,
+            url,
+            serialize_variant_problem,
+            deserialize_variant_problem,
+        );
+
+        let classic_problem = problem_for_tests();
+        let classic_url = "https://puzz.link/p?hashi/6/6/3g1g2.g.g2h.g2g.h.g4g3h4g.g2g.h2";
+        util::tests::serializer_test(
+            (DEFAULT_MAX_BRIDGES, to_arrow_clues(&classic_problem)),
+            classic_url,
+            serialize_variant_problem,
+            deserialize_variant_problem,
+        );
+    }
 }
@@ -0,0 +1,242 @@
+use crate::util;
+use cspuz_rs::items::{Arrow, NumberedArrow};
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Grid, NumberedArrowCombinator, Optionalize,
+    Spaces,
+};
+use cspuz_rs::solver::{any, IntVarArray2D, Solver};
+
+const SNAKE_LENGTH: i32 = 5;
+
+pub fn solve_hebi_ichigo(clues: &[Vec<Option<NumberedArrow>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_hebi_ichigo_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_hebi_ichigo_with_query(
+    clues: &[Vec<Option<NumberedArrow>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let mut solver = Solver::new();
+    let num = &add_constraints(&mut solver, clues);
+    solver.add_answer_key_int(num);
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+fn add_constraints(solver: &mut Solver, clues: &[Vec<Option<NumberedArrow>>]) -> IntVarArray2D {
+    let (h, w) = util::infer_shape(clues);
+
+    // 0 means that the cell is empty
+    let num = solver.int_var_2d((h, w), 0, SNAKE_LENGTH);
+
+    // Cells visible from `(y, x)` in the direction `(dy, dx)`, until a clue cell or the border
+    let ray = |y: usize, x: usize, dy: i32, dx: i32| -> Vec<(usize, usize)> {
+        let mut ret = vec![];
+        let mut y = y as i32 + dy;
+        let mut x = x as i32 + dx;
+        while 0 <= y && y < h as i32 && 0 <= x && x < w as i32 {
+            if clues[y as usize][x as usize].is_some() {
+                break;
+            }
+            ret.push((y as usize, x as usize));
+            y += dy;
+            x += dx;
+        }
+        ret
+    };
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some((dir, n)) = clue {
+                solver.add_expr(num.at((y, x)).eq(0));
+
+                let (dy, dx) = match dir {
+                    Arrow::Unspecified => continue,
+                    Arrow::Up => (-1, 0),
+                    Arrow::Down => (1, 0),
+                    Arrow::Left => (0, -1),
+                    Arrow::Right => (0, 1),
+                };
+                if n < 0 {
+                    continue;
+                }
+                let cells = ray(y, x, dy, dx);
+                if n == 0 {
+                    for &p in &cells {
+                        solver.add_expr(num.at(p).eq(0));
+                    }
+                } else {
+                    solver.add_expr(any(cells.iter().map(|&p| num.at(p).ne(0))));
+                    for i in 0..cells.len() {
+                        solver.add_expr(
+                            (num.select(&cells[..i]).eq(0).all() & num.at(cells[i]).ne(0))
+                                .imp(num.at(cells[i]).eq(n)),
+                        );
+                    }
+                }
+                continue;
+            }
+
+            let neighbors = num.four_neighbors((y, x));
+            for k in 1..=SNAKE_LENGTH {
+                if k > 1 {
+                    solver.add_expr(
+                        num.at((y, x))
+                            .eq(k)
+                            .imp(neighbors.eq(k - 1).count_true().eq(1)),
+                    );
+                }
+                if k < SNAKE_LENGTH {
+                    solver.add_expr(
+                        num.at((y, x))
+                            .eq(k)
+                            .imp(neighbors.eq(k + 1).count_true().eq(1)),
+                    );
+                }
+            }
+
+            // The head of a snake must not see any snake cell in front of it
+            for &(dy, dx) in &[(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let by = y as i32 - dy;
+                let bx = x as i32 - dx;
+                if !(0 <= by && by < h as i32 && 0 <= bx && bx < w as i32) {
+                    continue;
+                }
+                let cells = ray(y, x, dy, dx);
+                if cells.is_empty() {
+                    continue;
+                }
+                solver.add_expr(
+                    (num.at((y, x)).eq(1) & num.at((by as usize, bx as usize)).eq(2))
+                        .imp(num.select(&cells).eq(0).all()),
+                );
+            }
+        }
+    }
+
+    // Different snakes must not be orthogonally adjacent.
+    // Two adjacent cells in the same snake have numbers differing by 1, or are the ends of
+    // a 2x2 block which is formed by 4 consecutive cells of the snake.
+    for y in 0..h {
+        for x in 0..w {
+            for &(dy, dx) in &[(1, 0), (0, 1)] {
+                let y2 = y + dy;
+                let x2 = x + dx;
+                if y2 >= h || x2 >= w {
+                    continue;
+                }
+                let a = num.at((y, x));
+                let b = num.at((y2, x2));
+
+                // 2x2 blocks containing both of (y, x) and (y2, x2)
+                let mut blocks = vec![];
+                if dy == 1 {
+                    if x > 0 {
+                        blocks.push(((y, x - 1), (y2, x - 1)));
+                    }
+                    if x + 1 < w {
+                        blocks.push(((y, x + 1), (y2, x + 1)));
+                    }
+                } else {
+                    if y > 0 {
+                        blocks.push(((y - 1, x), (y - 1, x2)));
+                    }
+                    if y + 1 < h {
+                        blocks.push(((y + 1, x), (y + 1, x2)));
+                    }
+                }
+
+                for &(p, q) in &[((y, x), (y2, x2)), ((y2, x2), (y, x))] {
+                    let a = num.at(p);
+                    let b = num.at(q);
+                    for k in 1..=(SNAKE_LENGTH - 3) {
+                        let same_snake = blocks
+                            .iter()
+                            .map(|&(bp, bq)| {
+                                let (bp, bq) = if p == (y, x) { (bp, bq) } else { (bq, bp) };
+                                num.at(bp).eq(k + 1) & num.at(bq).eq(k + 2)
+                            })
+                            .collect::<Vec<_>>();
+                        solver.add_expr((a.eq(k) & b.eq(k + 3)).imp(any(same_snake)));
+                    }
+                }
+
+                solver.add_expr(
+                    (a.ne(0) & b.ne(0))
+                        .imp(a.eq(&b + 1) | b.eq(&a + 1) | a.eq(&b + 3) | b.eq(&a + 3)),
+                );
+            }
+        }
+    }
+
+    num
+}
+
+type Problem = Vec<Vec<Option<NumberedArrow>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(NumberedArrowCombinator)),
+        Box::new(Spaces::new(None, 'a')),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "hebi", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["hebi"], url)
+}
+
+pub struct HebiIchigo;
+
+impl util::Puzzle for HebiIchigo {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_hebi_ichigo_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[1][0] = Some((Arrow::Right, 0));
+        ret[1][1] = Some((Arrow::Down, 0));
+        ret[1][2] = Some((Arrow::Up, 3));
+        ret[1][3] = Some((Arrow::Up, 4));
+        ret[2][0] = Some((Arrow::Right, 3));
+        ret[3][3] = Some((Arrow::Up, 2));
+        ret
+    }
+
+    #[test]
+    fn test_hebi_ichigo_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_hebi_ichigo(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_2d([
+            [1, 2, 3, 4, 5],
+            [0, 0, 0, 0, 0],
+            [0, 0, 3, 2, 1],
+            [0, 0, 4, 0, 0],
+            [0, 0, 5, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_hebi_ichigo_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?hebi/5/5/e40201314a43g12f";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -10,6 +10,14 @@ pub fn solve_herugolf(
     pond: &[Vec<bool>],
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_herugolf_with_query(pond, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_herugolf_with_query(
+    pond: &[Vec<bool>],
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(&clues);
 
     let mut solver = Solver::new();
@@ -26,7 +34,7 @@ pub fn solve_herugolf(
         }
     }
     if clue_max >= h as i32 || clue_max >= w as i32 {
-        return None;
+        return vec![];
     }
     let level = &solver.int_var_2d((h, w), 0, clue_max);
     let rank = &solver.int_var_2d((h, w), 0, clue_max);
@@ -157,7 +165,7 @@ pub fn solve_herugolf(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = (Vec<Vec<bool>>, Vec<Vec<Option<i32>>>);
@@ -191,6 +199,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["herugolf"], url)
 }
 
+pub struct Herugolf;
+
+impl util::Puzzle for Herugolf {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_herugolf_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
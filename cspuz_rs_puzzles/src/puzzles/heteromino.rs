@@ -0,0 +1,190 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Combinator, Context, ContextBasedGrid, Map,
+    MultiDigit, Size,
+};
+use cspuz_rs::solver::{any, count_true, BoolExpr, Solver};
+
+// Trominoes distinguished by their orientations
+const TROMINOES: [[(usize, usize); 3]; 6] = [
+    [(0, 0), (0, 1), (0, 2)],
+    [(0, 0), (1, 0), (2, 0)],
+    [(0, 0), (0, 1), (1, 0)],
+    [(0, 0), (0, 1), (1, 1)],
+    [(0, 0), (1, 0), (1, 1)],
+    [(0, 1), (1, 0), (1, 1)],
+];
+
+pub fn solve_heteromino(
+    is_black: &[Vec<bool>],
+) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_heteromino_with_query(is_black, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_heteromino_with_query(
+    is_black: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(is_black);
+
+    let mut solver = Solver::new();
+    let is_border = &graph::BoolInnerGridEdges::new(&mut solver, (h, w));
+    solver.add_answer_key_bool(&is_border.horizontal);
+    solver.add_answer_key_bool(&is_border.vertical);
+
+    // All possible placements of trominoes: (shape id, cells, variable)
+    let mut placements = vec![];
+    for (i, shape) in TROMINOES.iter().enumerate() {
+        for y in 0..h {
+            for x in 0..w {
+                let cells = shape
+                    .iter()
+                    .map(|&(dy, dx)| (y + dy, x + dx))
+                    .collect::<Vec<_>>();
+                if cells
+                    .iter()
+                    .all(|&(cy, cx)| cy < h && cx < w && !is_black[cy][cx])
+                {
+                    placements.push((i, cells, solver.bool_var()));
+                }
+            }
+        }
+    }
+
+    let mut covering: Vec<Vec<Vec<BoolExpr>>> = vec![vec![vec![]; w]; h];
+    for (_, cells, v) in &placements {
+        for &(y, x) in cells {
+            covering[y][x].push(v.expr());
+        }
+    }
+    for y in 0..h {
+        for x in 0..w {
+            if !is_black[y][x] {
+                solver.add_expr(count_true(&covering[y][x]).eq(1));
+            }
+        }
+    }
+
+    let adjacent =
+        |a: (usize, usize), b: (usize, usize)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1;
+
+    for i in 0..placements.len() {
+        for j in (i + 1)..placements.len() {
+            let (si, ci, vi) = &placements[i];
+            let (sj, cj, vj) = &placements[j];
+            if si != sj {
+                continue;
+            }
+            if ci.iter().any(|&p| cj.iter().any(|&q| adjacent(p, q))) {
+                solver.add_expr(!(vi & vj));
+            }
+        }
+    }
+
+    for y in 0..h {
+        for x in 0..w {
+            for (y2, x2) in [(y + 1, x), (y, x + 1)] {
+                if y2 >= h || x2 >= w {
+                    continue;
+                }
+                let same = placements
+                    .iter()
+                    .filter(|(_, cells, _)| cells.contains(&(y, x)) && cells.contains(&(y2, x2)))
+                    .map(|(_, _, v)| v.expr())
+                    .collect::<Vec<_>>();
+                let border = if y2 > y {
+                    is_border.horizontal.at((y, x))
+                } else {
+                    is_border.vertical.at((y, x))
+                };
+                solver.add_expr(border.iff(!any(same)));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_border))
+}
+
+type Problem = Vec<Vec<bool>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(ContextBasedGrid::new(Map::new(
+        MultiDigit::new(2, 5),
+        |x| Some(if x { 1 } else { 0 }),
+        |x| Some(x == 1),
+    )))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = util::infer_shape(problem);
+    problem_to_url_with_context(
+        combinator(),
+        "heteromino",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["heteromino"], url)
+}
+
+pub struct Heteromino;
+
+impl util::Puzzle for Heteromino {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_heteromino_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        util::tests::to_bool_2d([
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0],
+            [1, 0, 0, 1, 0],
+            [0, 0, 0, 1, 0],
+        ])
+    }
+
+    #[test]
+    fn test_heteromino_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_heteromino(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::BoolInnerGridEdgesIrrefutableFacts {
+            horizontal: util::tests::to_option_bool_2d([
+                [0, 1, 0, 1, 0],
+                [1, 1, 1, 1, 1],
+                [1, 0, 0, 1, 0],
+                [1, 1, 1, 1, 0],
+            ]),
+            vertical: util::tests::to_option_bool_2d([
+                [0, 1, 1, 0],
+                [1, 0, 1, 1],
+                [0, 1, 0, 1],
+                [1, 1, 1, 1],
+                [0, 0, 1, 1],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_heteromino_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?heteromino/5/5/020i2";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -1,7 +1,8 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
-    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
-    RoomsWithValues, Size, Spaces,
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, Dict, HexInt,
+    Optionalize, RoomsWithValues, Size, Spaces,
 };
 use cspuz_rs::solver::{count_true, BoolVarArray2D, Solver};
 
@@ -9,6 +10,14 @@ pub fn solve_heyawake(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Option<i32>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_heyawake_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_heyawake_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = borders.base_shape();
 
     let mut solver = Solver::new();
@@ -17,7 +26,7 @@ pub fn solve_heyawake(
 
     add_constraints(&mut solver, is_black, borders, clues);
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 pub fn enumerate_answers_heyawake(
@@ -84,6 +93,10 @@ pub(super) fn add_constraints(
 
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
+            // "?" clues (represented by -1) do not restrict the number of black cells
+            if n < 0 {
+                continue;
+            }
             let mut cells = vec![];
             for &pt in &rooms[i] {
                 cells.push(is_black.at(pt));
@@ -99,6 +112,7 @@ pub(super) fn combinator() -> impl Combinator<Problem> {
     Size::new(RoomsWithValues::new(Choice::new(vec![
         Box::new(Optionalize::new(HexInt)),
         Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
     ])))
 }
 
@@ -117,6 +131,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["heyawake"], url)
 }
 
+pub struct Heyawake;
+
+impl util::Puzzle for Heyawake {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_heyawake_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +170,18 @@ mod tests {
         ]);
         assert_eq!(ans, expected);
     }
+
+    #[test]
+    fn test_heyawake_unknown_clue() {
+        let url = "https://puzz.link/p?heyawake/6/6/aa66aapv0fu0g.i3k";
+        let problem = deserialize_problem(url);
+        assert!(problem.is_some());
+        let problem = problem.unwrap();
+        assert_eq!(problem.1[1], Some(-1));
+        assert_eq!(serialize_problem(&problem), Some(String::from(url)));
+        let (borders, clues) = problem;
+
+        let ans = solve_heyawake(&borders, &clues);
+        assert!(ans.is_some());
+    }
 }
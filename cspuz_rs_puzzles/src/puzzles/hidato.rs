@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::Solver;
 
 pub fn solve_hidato(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_hidato_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_hidato_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut num_cells = 0;
@@ -74,7 +81,7 @@ pub fn solve_hidato(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>>
             );
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(num))
+    util::query_answers(solver, query, |f| f.get(num))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -98,6 +105,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct Hidato;
+
+impl util::Puzzle for Hidato {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_hidato_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
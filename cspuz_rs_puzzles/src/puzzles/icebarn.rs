@@ -0,0 +1,496 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::items::Arrow;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    DecInt, Dict, Map, MultiDigit, PrefixAndSuffix, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+/// A gate on the outer border of the grid: the cell next to the border and the side of the cell
+/// on which the border lies.
+pub type IcebarnGate = ((usize, usize), Arrow);
+
+pub fn solve_icebarn(
+    icebarn: &[Vec<bool>],
+    arrows: &graph::GridEdges<Vec<Vec<Arrow>>>,
+    entrance: IcebarnGate,
+    exit: IcebarnGate,
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_icebarn_with_query(
+        icebarn,
+        arrows,
+        entrance,
+        exit,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_icebarn_with_query(
+    icebarn: &[Vec<bool>],
+    arrows: &graph::GridEdges<Vec<Vec<Arrow>>>,
+    entrance: IcebarnGate,
+    exit: IcebarnGate,
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(icebarn);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    // The grid is surrounded by an extra ring of cells, through which the path is closed
+    // from the exit to the entrance. This turns the path into a single cycle.
+    let frame = &graph::BoolGridEdges::new(&mut solver, (h + 1, w + 1));
+    solver.add_expr(
+        frame
+            .horizontal
+            .slice((1..(h + 1), 1..w))
+            .iff(&is_line.horizontal),
+    );
+    solver.add_expr(
+        frame
+            .vertical
+            .slice((1..h, 1..(w + 1)))
+            .iff(&is_line.vertical),
+    );
+
+    let mut ring = vec![];
+    for x in 0..(w + 1) {
+        ring.push((0, x));
+    }
+    for y in 0..(h + 1) {
+        ring.push((y, w + 1));
+    }
+    for x in (1..(w + 2)).rev() {
+        ring.push((h + 1, x));
+    }
+    for y in (1..(h + 2)).rev() {
+        ring.push((y, 0));
+    }
+
+    type GateInfo = ((usize, usize), (bool, usize, usize), bool);
+
+    // Returns the ring cell next to the gate, and the edge connecting it to the grid
+    // with the direction value of the path entering the grid through the edge
+    let gate_info = |gate: IcebarnGate| -> Option<GateInfo> {
+        let ((y, x), dir) = gate;
+        if y >= h || x >= w {
+            return None;
+        }
+        match dir {
+            Arrow::Up if y == 0 => Some(((0, x + 1), (false, 0, x + 1), false)),
+            Arrow::Down if y == h - 1 => Some(((h + 1, x + 1), (false, h, x + 1), true)),
+            Arrow::Left if x == 0 => Some(((y + 1, 0), (true, y + 1, 0), false)),
+            Arrow::Right if x == w - 1 => Some(((y + 1, w + 1), (true, y + 1, w), true)),
+            _ => None,
+        }
+    };
+    let (entrance_cell, entrance_edge, entrance_dir) = match gate_info(entrance) {
+        Some(info) => info,
+        None => return vec![],
+    };
+    let (exit_cell, exit_edge, _) = match gate_info(exit) {
+        Some(info) => info,
+        None => return vec![],
+    };
+    if entrance_cell == exit_cell {
+        return vec![];
+    }
+
+    let direction = &graph::BoolGridEdges::new(&mut solver, (h + 1, w + 1));
+    let edge_var = |frame: &graph::BoolGridEdges, (is_horizontal, y, x): (bool, usize, usize)| {
+        if is_horizontal {
+            frame.horizontal.at((y, x))
+        } else {
+            frame.vertical.at((y, x))
+        }
+    };
+
+    // Edges between the ring and the grid are used only at the gates
+    for y in 0..h {
+        for e in [(true, y + 1, 0), (true, y + 1, w)] {
+            if e != entrance_edge && e != exit_edge {
+                solver.add_expr(!edge_var(frame, e));
+            }
+        }
+    }
+    for x in 0..w {
+        for e in [(false, 0, x + 1), (false, h, x + 1)] {
+            if e != entrance_edge && e != exit_edge {
+                solver.add_expr(!edge_var(frame, e));
+            }
+        }
+    }
+    solver.add_expr(edge_var(frame, entrance_edge));
+    solver.add_expr(edge_var(frame, exit_edge));
+    solver.add_expr(edge_var(direction, entrance_edge).iff(entrance_dir));
+
+    // The path in the ring goes clockwise from the exit to the entrance
+    let start = ring.iter().position(|&p| p == exit_cell).unwrap();
+    let mut on_route = vec![false; ring.len()];
+    let mut i = start;
+    while ring[i] != entrance_cell {
+        on_route[i] = true;
+        i = (i + 1) % ring.len();
+    }
+    for i in 0..ring.len() {
+        let (y1, x1) = ring[i];
+        let (y2, x2) = ring[(i + 1) % ring.len()];
+        let e = if y1 == y2 {
+            (true, y1, x1.min(x2))
+        } else {
+            (false, y1.min(y2), x1)
+        };
+        solver.add_expr(edge_var(frame, e).iff(on_route[i]));
+    }
+
+    let (is_passed, is_cross) = graph::crossable_single_cycle_grid_edges(&mut solver, frame);
+    for (y, row) in icebarn.iter().enumerate() {
+        for (x, &ice) in row.iter().enumerate() {
+            if ice {
+                // The path goes straight on ice
+                let (py, px) = (y + 1, x + 1);
+                solver.add_expr(
+                    frame
+                        .horizontal
+                        .at((py, px - 1))
+                        .iff(frame.horizontal.at((py, px))),
+                );
+                solver.add_expr(
+                    (frame.horizontal.at((py, px - 1)) & frame.horizontal.at((py, px))).imp(
+                        direction
+                            .horizontal
+                            .at((py, px - 1))
+                            .iff(direction.horizontal.at((py, px))),
+                    ),
+                );
+                solver.add_expr(
+                    frame
+                        .vertical
+                        .at((py - 1, px))
+                        .iff(frame.vertical.at((py, px))),
+                );
+                solver.add_expr(
+                    (frame.vertical.at((py - 1, px)) & frame.vertical.at((py, px))).imp(
+                        direction
+                            .vertical
+                            .at((py - 1, px))
+                            .iff(direction.vertical.at((py, px))),
+                    ),
+                );
+            } else {
+                solver.add_expr(!is_cross.at((y + 1, x + 1)));
+            }
+        }
+    }
+
+    // `direction` is true for leftward and upward edges
+    for y in 0..(h + 2) {
+        for x in 0..(w + 2) {
+            let mut inbound = vec![];
+            let mut outbound = vec![];
+            if y > 0 {
+                inbound.push(frame.vertical.at((y - 1, x)) & !direction.vertical.at((y - 1, x)));
+                outbound.push(frame.vertical.at((y - 1, x)) & direction.vertical.at((y - 1, x)));
+            }
+            if y < h + 1 {
+                inbound.push(frame.vertical.at((y, x)) & direction.vertical.at((y, x)));
+                outbound.push(frame.vertical.at((y, x)) & !direction.vertical.at((y, x)));
+            }
+            if x > 0 {
+                inbound
+                    .push(frame.horizontal.at((y, x - 1)) & !direction.horizontal.at((y, x - 1)));
+                outbound
+                    .push(frame.horizontal.at((y, x - 1)) & direction.horizontal.at((y, x - 1)));
+            }
+            if x < w + 1 {
+                inbound.push(frame.horizontal.at((y, x)) & direction.horizontal.at((y, x)));
+                outbound.push(frame.horizontal.at((y, x)) & !direction.horizontal.at((y, x)));
+            }
+            solver.add_expr(count_true(&inbound).eq(count_true(&outbound)));
+        }
+    }
+
+    for y in 0..h {
+        for x in 0..(w - 1) {
+            let (line, dir) = (
+                frame.horizontal.at((y + 1, x + 1)),
+                direction.horizontal.at((y + 1, x + 1)),
+            );
+            match arrows.horizontal[y][x] {
+                Arrow::Unspecified => (),
+                Arrow::Left => solver.add_expr(line & dir),
+                Arrow::Right => solver.add_expr(line & !dir),
+                _ => return vec![],
+            }
+        }
+    }
+    for y in 0..(h - 1) {
+        for x in 0..w {
+            let (line, dir) = (
+                frame.vertical.at((y + 1, x + 1)),
+                direction.vertical.at((y + 1, x + 1)),
+            );
+            match arrows.vertical[y][x] {
+                Arrow::Unspecified => (),
+                Arrow::Up => solver.add_expr(line & dir),
+                Arrow::Down => solver.add_expr(line & !dir),
+                _ => return vec![],
+            }
+        }
+    }
+
+    // Every ice region is passed
+    let mut ice_borders = graph::InnerGridEdges {
+        horizontal: vec![vec![false; w]; h - 1],
+        vertical: vec![vec![false; w - 1]; h],
+    };
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 {
+                ice_borders.horizontal[y][x] = icebarn[y][x] != icebarn[y + 1][x];
+            }
+            if x < w - 1 {
+                ice_borders.vertical[y][x] = icebarn[y][x] != icebarn[y][x + 1];
+            }
+        }
+    }
+    for room in graph::borders_to_rooms(&ice_borders) {
+        let (y, x) = room[0];
+        if icebarn[y][x] {
+            let cells = room
+                .iter()
+                .map(|&(y, x)| (y + 1, x + 1))
+                .collect::<Vec<_>>();
+            solver.add_expr(is_passed.select(&cells).any());
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+pub type Problem = (
+    Vec<Vec<bool>>,
+    graph::GridEdges<Vec<Vec<Arrow>>>,
+    IcebarnGate,
+    IcebarnGate,
+);
+
+fn gate_to_index(gate: IcebarnGate, height: usize, width: usize) -> Option<i32> {
+    let ((y, x), dir) = gate;
+    let idx = match dir {
+        Arrow::Up if y == 0 => x,
+        Arrow::Down if y == height - 1 => width + x,
+        Arrow::Left if x == 0 => width * 2 + y,
+        Arrow::Right if x == width - 1 => width * 2 + height + y,
+        _ => return None,
+    };
+    Some(idx as i32)
+}
+
+fn index_to_gate(idx: i32, height: usize, width: usize) -> Option<IcebarnGate> {
+    if idx < 0 {
+        return None;
+    }
+    let idx = idx as usize;
+    if idx < width {
+        Some(((0, idx), Arrow::Up))
+    } else if idx < width * 2 {
+        Some(((height - 1, idx - width), Arrow::Down))
+    } else if idx < width * 2 + height {
+        Some(((idx - width * 2, 0), Arrow::Left))
+    } else if idx < (width + height) * 2 {
+        Some(((idx - width * 2 - height, width - 1), Arrow::Right))
+    } else {
+        None
+    }
+}
+
+fn ice_combinator() -> impl Combinator<Vec<Vec<bool>>> {
+    ContextBasedGrid::new(Map::new(
+        MultiDigit::new(2, 5),
+        |x| Some(if x { 1 } else { 0 }),
+        |x| Some(x == 1),
+    ))
+}
+
+fn arrow_combinator() -> impl Combinator<Arrow> {
+    Choice::new(vec![
+        Box::new(Dict::new(Arrow::Up, "1")),
+        Box::new(Dict::new(Arrow::Down, "2")),
+        Box::new(Dict::new(Arrow::Left, "3")),
+        Box::new(Dict::new(Arrow::Right, "4")),
+        Box::new(Spaces::new(Arrow::Unspecified, 'a')),
+    ])
+}
+
+pub struct IcebarnCombinator;
+
+impl Combinator<Problem> for IcebarnCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let (icebarn, arrows, entrance, exit) = &input[0];
+
+        let mut ret = ice_combinator()
+            .serialize(ctx, std::slice::from_ref(icebarn))?
+            .1;
+
+        // Arrows on horizontal edges are followed by those on vertical edges, in row-major order
+        let arrows = [arrows.horizontal.concat(), arrows.vertical.concat()].concat();
+        ret.push(b'/');
+        ret.extend(
+            Seq::new(arrow_combinator(), arrows.len())
+                .serialize(ctx, &[arrows])?
+                .1,
+        );
+
+        for gate in [entrance, exit] {
+            let idx = gate_to_index(*gate, height, width)?;
+            ret.extend(
+                PrefixAndSuffix::new("/", DecInt, "")
+                    .serialize(ctx, &[idx])?
+                    .1,
+            );
+        }
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let icebarn = sequencer.deserialize_one_elem(ctx, ice_combinator())?;
+
+        let n_horizontal = height * (width - 1);
+        let n_vertical = (height - 1) * width;
+        let arrows = sequencer.deserialize_one_elem(
+            ctx,
+            PrefixAndSuffix::new(
+                "/",
+                Seq::new(arrow_combinator(), n_horizontal + n_vertical),
+                "",
+            ),
+        )?;
+        let arrows = graph::GridEdges {
+            horizontal: arrows[..n_horizontal]
+                .chunks(width - 1)
+                .map(|row| row.to_vec())
+                .collect(),
+            vertical: arrows[n_horizontal..]
+                .chunks(width)
+                .map(|row| row.to_vec())
+                .collect(),
+        };
+
+        let entrance =
+            sequencer.deserialize_one_elem(ctx, PrefixAndSuffix::new("/", DecInt, ""))?;
+        let exit = sequencer.deserialize_one_elem(ctx, PrefixAndSuffix::new("/", DecInt, ""))?;
+
+        Some((
+            sequencer.n_read(),
+            vec![(
+                icebarn,
+                arrows,
+                index_to_gate(entrance, height, width)?,
+                index_to_gate(exit, height, width)?,
+            )],
+        ))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(IcebarnCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = util::infer_shape(&problem.0);
+    problem_to_url_with_context(
+        combinator(),
+        "icebarn",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["icebarn"], url)
+}
+
+pub struct Icebarn;
+
+impl util::Puzzle for Icebarn {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_icebarn_with_query(&problem.0, &problem.1, problem.2, problem.3, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut arrows = graph::GridEdges {
+            horizontal: vec![vec![Arrow::Unspecified; 4]; 4],
+            vertical: vec![vec![Arrow::Unspecified; 5]; 3],
+        };
+        arrows.horizontal[0][1] = Arrow::Right;
+        arrows.vertical[1][3] = Arrow::Down;
+
+        (
+            crate::util::tests::to_bool_2d([
+                [0, 0, 0, 0, 0],
+                [0, 1, 1, 0, 0],
+                [0, 1, 1, 0, 1],
+                [0, 0, 0, 0, 0],
+            ]),
+            arrows,
+            ((0, 0), Arrow::Up),
+            ((3, 3), Arrow::Down),
+        )
+    }
+
+    #[test]
+    fn test_icebarn_problem() {
+        let (icebarn, arrows, entrance, exit) = problem_for_tests();
+        let ans = solve_icebarn(&icebarn, &arrows, entrance, exit);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::GridEdges {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [0, 1, 1, 1],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 0, 0, 1],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 1, 0, 0, 1],
+                [0, 1, 0, 1, 1],
+                [1, 1, 0, 0, 1],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_icebarn_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?icebarn/5/4/0cd0/a4v2f/0/8";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
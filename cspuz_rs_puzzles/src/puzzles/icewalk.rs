@@ -10,6 +10,14 @@ pub fn solve_icewalk(
     icebarn: &[Vec<bool>],
     num: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_icewalk_with_query(icebarn, num, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_icewalk_with_query(
+    icebarn: &[Vec<bool>],
+    num: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(icebarn);
 
     let mut solver = Solver::new();
@@ -128,7 +136,7 @@ pub fn solve_icewalk(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = (Vec<Vec<bool>>, Vec<Vec<Option<i32>>>);
@@ -161,6 +169,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["icewalk"], url)
 }
 
+pub struct Icewalk;
+
+impl util::Puzzle for Icewalk {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_icewalk_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Combinator, Context, Rooms, Size,
@@ -7,6 +8,13 @@ use cspuz_rs::solver::{any, count_true, Solver, FALSE};
 pub fn solve_inverse_litso(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_inverse_litso_with_query(borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_inverse_litso_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let h = borders.vertical.len();
     assert!(h > 0);
     let w = borders.vertical[0].len() + 1;
@@ -141,7 +149,7 @@ pub fn solve_inverse_litso(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = graph::InnerGridEdges<Vec<Vec<bool>>>;
@@ -165,6 +173,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["invlitso"], url)
 }
 
+pub struct InverseLitso;
+
+impl util::Puzzle for InverseLitso {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_inverse_litso_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,153 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
+    Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{sum, Solver};
+
+/// Solves a Kakurasu puzzle.
+/// The weight of the `i`-th (0-indexed) row or column is `i + 1`.
+pub fn solve_kakurasu(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_kakurasu_with_query(clues_up, clues_left, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kakurasu_with_query(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let h = clues_left.len();
+    let w = clues_up.len();
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(sum((0..w).map(|x| is_black.at((y, x)).ite(x as i32 + 1, 0))).eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(sum((0..h).map(|y| is_black.at((y, x)).ite(y as i32 + 1, 0))).eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+pub type Problem = (Vec<Option<i32>>, Vec<Option<i32>>);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+pub struct KakurasuCombinator;
+
+impl Combinator<Problem> for KakurasuCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let surrounding = [&problem.0[..], &problem.1[..]].concat();
+        let ret = Seq::new(clue_combinator(), width + height)
+            .serialize(ctx, &[surrounding])?
+            .1;
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        Some((sequencer.n_read(), vec![(clues_up, clues_left)]))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(KakurasuCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.1.len();
+    let width = problem.0.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "kakurasu",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["kakurasu"], url)
+}
+
+pub struct Kakurasu;
+
+impl util::Puzzle for Kakurasu {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kakurasu_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        (
+            vec![Some(4), Some(5), Some(6), Some(1)],
+            vec![Some(5), Some(5), Some(3), Some(3)],
+        )
+    }
+
+    #[test]
+    fn test_kakurasu_problem() {
+        let (clues_up, clues_left) = problem_for_tests();
+        let ans = solve_kakurasu(&clues_up, &clues_left);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 0, 0, 1],
+            [0, 1, 1, 0],
+            [1, 1, 0, 0],
+            [0, 0, 1, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kakurasu_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?kakurasu/4/4/45615533";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::util;
 use cspuz_rs::serializer::{
-    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
-    Dict, Optionalize, Size, Spaces, Tuple2, UnlimitedSeq,
+    from_base16, problem_to_url_with_context, to_base16, url_to_problem, Choice, Combinator,
+    Context, ContextBasedGrid, DecInt, Dict, Map, Optionalize, PrefixAndSuffix, Size, Spaces,
+    Tuple2, Tuple3, UnlimitedSeq,
 };
 use cspuz_rs::solver::{all, any, IntVarArray1D, Solver};
 
@@ -11,11 +12,65 @@ pub struct KakuroClue {
     pub right: Option<i32>,
 }
 
+/// Digit range and per-run restrictions of a Kakuro variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KakuroOptions {
+    /// Digits from 1 to `max_digit` can be written (9 in the standard rules).
+    pub max_digit: i32,
+    /// Digits which must not appear in a run, specified by the clue cell starting the run and
+    /// the direction of the run (`true` for the down run, `false` for the right run).
+    pub excluded_digits: Vec<((usize, usize), bool, Vec<i32>)>,
+}
+
+impl Default for KakuroOptions {
+    fn default() -> KakuroOptions {
+        KakuroOptions {
+            max_digit: 9,
+            excluded_digits: vec![],
+        }
+    }
+}
+
+/// Largest `max_digit` supported by `solve_kakuro_with_options`.
+pub const MAX_DIGIT_LIMIT: i32 = 16;
+
 pub fn solve_kakuro(clues: &[Vec<Option<KakuroClue>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kakuro_with_options(clues, &KakuroOptions::default())
+}
+
+pub fn solve_kakuro_with_options(
+    clues: &[Vec<Option<KakuroClue>>],
+    options: &KakuroOptions,
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kakuro_with_query(clues, options, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kakuro_with_query(
+    clues: &[Vec<Option<KakuroClue>>],
+    options: &KakuroOptions,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
+    let max_digit = options.max_digit;
+    if !(1..=MAX_DIGIT_LIMIT).contains(&max_digit) {
+        return vec![];
+    }
+    let n_digits = max_digit as usize;
+
+    let mut excluded = vec![vec![[0u32; 2]; w]; h];
+    for &((y, x), is_down, ref digits) in &options.excluded_digits {
+        if !(y < h && x < w && clues[y][x].is_some()) {
+            return vec![];
+        }
+        for &d in digits {
+            if (1..=max_digit).contains(&d) {
+                excluded[y][x][if is_down { 0 } else { 1 }] |= 1 << (d - 1);
+            }
+        }
+    }
 
     let mut solver = Solver::new();
-    let numbers = &solver.int_var_2d((h, w), 0, 9);
+    let numbers = &solver.int_var_2d((h, w), 0, max_digit);
     solver.add_answer_key_int(numbers);
 
     for y in 0..h {
@@ -28,38 +83,51 @@ pub fn solve_kakuro(clues: &[Vec<Option<KakuroClue>>]) -> Option<Vec<Vec<Option<
         }
     }
 
-    let mut dict = vec![vec![vec![]; 46]; 10];
-    for b in 1i32..512 {
+    let max_sum = max_digit * (max_digit + 1) / 2;
+    let mut dict = vec![vec![vec![]; max_sum as usize + 1]; n_digits + 1];
+    for b in 1u32..(1 << n_digits) {
         let num = b.count_ones();
         let mut sum = 0;
-        for i in 0..9 {
+        for i in 0..n_digits {
             if (b & (1 << i)) != 0 {
                 sum += i + 1;
             }
         }
-        dict[num as usize][sum as usize].push(b);
+        dict[num as usize][sum].push(b);
     }
 
-    let mut add_constraints = |cells: IntVarArray1D, clue: Option<i32>| -> bool {
+    let mut add_constraints = |cells: IntVarArray1D, clue: Option<i32>, excluded: u32| -> bool {
+        if cells.len() > n_digits {
+            return false;
+        }
         solver.all_different(&cells);
 
+        for i in 0..n_digits {
+            if (excluded & (1 << i)) != 0 {
+                solver.add_expr(cells.ne(i as i32 + 1));
+            }
+        }
+
         if let Some(n) = clue {
             let n_cells = cells.len() as i32;
             let n_min = n_cells * (n_cells + 1) / 2;
-            let n_max = n_cells * 10 - n_min;
+            let n_max = n_cells * (max_digit + 1) - n_min;
             if !(n_min <= n && n <= n_max) {
                 return false;
             }
 
-            let appear = solver.bool_var_1d(9);
-            for i in 0..9 {
+            let appear = solver.bool_var_1d(n_digits);
+            for i in 0..n_digits {
                 solver.add_expr(appear.at(i).iff(cells.eq(i as i32 + 1).any()));
             }
 
             let mut cands = vec![];
             for b in &dict[cells.len()][n as usize] {
+                if (b & excluded) != 0 {
+                    continue;
+                }
                 let mut lits = vec![];
-                for i in 0..9 {
+                for i in 0..n_digits {
                     if (b & (1 << i)) != 0 {
                         lits.push(appear.at(i).expr());
                     } else {
@@ -83,8 +151,12 @@ pub fn solve_kakuro(clues: &[Vec<Option<KakuroClue>>]) -> Option<Vec<Vec<Option<
                     y2 += 1;
                 }
                 if y2 - y >= 2 {
-                    if !add_constraints(numbers.slice_fixed_x(((y + 1)..y2, x)), clue.down) {
-                        return None;
+                    if !add_constraints(
+                        numbers.slice_fixed_x(((y + 1)..y2, x)),
+                        clue.down,
+                        excluded[y][x][0],
+                    ) {
+                        return vec![];
                     }
                 }
 
@@ -94,94 +166,112 @@ pub fn solve_kakuro(clues: &[Vec<Option<KakuroClue>>]) -> Option<Vec<Vec<Option<
                     x2 += 1;
                 }
                 if x2 - x >= 2 {
-                    if !add_constraints(numbers.slice_fixed_y((y, (x + 1)..x2)), clue.right) {
-                        return None;
+                    if !add_constraints(
+                        numbers.slice_fixed_y((y, (x + 1)..x2)),
+                        clue.right,
+                        excluded[y][x][1],
+                    ) {
+                        return vec![];
                     }
                 }
             }
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(numbers))
+    util::query_answers(solver, query, |f| f.get(numbers))
 }
 
-struct KakuroNumCombinator;
+/// Clue numbers of Kakuro. puzz.link encodes numbers up to 45 by a single character. If `wide`,
+/// larger numbers are encoded by `+` followed by two hexadecimal digits.
+struct KakuroNumCombinator {
+    wide: bool,
+}
 
 impl Combinator<Option<i32>> for KakuroNumCombinator {
     fn serialize(&self, _: &Context, input: &[Option<i32>]) -> Option<(usize, Vec<u8>)> {
-        if input.len() == 0 {
+        if input.is_empty() {
             return None;
         }
-        let n = input[0];
-
-        if n.is_none() {
-            return Some((1, vec!['-' as u8]));
-        }
+        let n = match input[0] {
+            Some(n) => n,
+            None => return Some((1, vec![b'-'])),
+        };
 
-        let n = n.unwrap();
-        let c = if 1 <= n && n <= 9 {
-            n as u8 + '0' as u8
-        } else if 10 <= n && n <= 19 {
-            n as u8 - 10 + 'a' as u8
-        } else if 20 <= n && n <= 45 {
-            n as u8 - 20 + 'A' as u8
-        } else {
-            return None;
+        let ret = match n {
+            1..=9 => vec![n as u8 + b'0'],
+            10..=19 => vec![(n - 10) as u8 + b'a'],
+            20..=45 => vec![(n - 20) as u8 + b'A'],
+            46..=255 if self.wide => vec![b'+', to_base16(n >> 4), to_base16(n & 15)],
+            _ => return None,
         };
 
-        Some((1, vec![c]))
+        Some((1, ret))
     }
 
     fn deserialize(&self, _: &Context, input: &[u8]) -> Option<(usize, Vec<Option<i32>>)> {
-        if input.len() == 0 {
+        if input.is_empty() {
             return None;
         }
         let c = input[0];
 
-        if c == '-' as u8 {
-            return Some((1, vec![None]));
-        }
-
-        let v = if '0' as u8 <= c && c <= '9' as u8 {
-            c - '0' as u8
-        } else if 'a' as u8 <= c && c <= 'j' as u8 {
-            c - 'a' as u8 + 10
-        } else if 'A' as u8 <= c && c <= 'Z' as u8 {
-            c - 'A' as u8 + 20
-        } else {
-            return None;
+        let (n_read, v) = match c {
+            b'-' => return Some((1, vec![None])),
+            b'0'..=b'9' => (1, (c - b'0') as i32),
+            b'a'..=b'j' => (1, (c - b'a') as i32 + 10),
+            b'A'..=b'Z' => (1, (c - b'A') as i32 + 20),
+            b'+' if self.wide && input.len() >= 3 => {
+                let v = from_base16(input[1])? * 16 + from_base16(input[2])?;
+                if v <= 45 {
+                    return None;
+                }
+                (3, v)
+            }
+            _ => return None,
         };
 
-        Some((1, vec![Some(v as i32)]))
+        Some((n_read, vec![Some(v)]))
     }
 }
 
 pub type Problem = Vec<Vec<Option<KakuroClue>>>;
 
-type IntermediateProblem = (
-    Vec<Vec<Option<(Option<i32>, Option<i32>)>>>,
-    Vec<Option<i32>>,
-);
+type IntermediateGrid = Vec<Vec<Option<(Option<i32>, Option<i32>)>>>;
+
+type IntermediateProblem = (IntermediateGrid, Vec<Option<i32>>);
+
+fn grid_combinator(wide: bool) -> impl Combinator<IntermediateGrid> {
+    ContextBasedGrid::new(Choice::new(vec![
+        Box::new(Optionalize::new(Tuple2::new(
+            KakuroNumCombinator { wide },
+            KakuroNumCombinator { wide },
+        ))),
+        Box::new(Dict::new(Some((None, None)), ".")),
+        Box::new(Spaces::new(None, 'k')),
+    ]))
+}
 
 fn combinator() -> impl Combinator<IntermediateProblem> {
     Size::new(Tuple2::new(
-        ContextBasedGrid::new(Choice::new(vec![
-            Box::new(Optionalize::new(Tuple2::new(
-                KakuroNumCombinator,
-                KakuroNumCombinator,
-            ))),
-            Box::new(Dict::new(Some((None, None)), ".")),
-            Box::new(Spaces::new(None, 'k')),
-        ])),
-        UnlimitedSeq::new(KakuroNumCombinator),
+        grid_combinator(false),
+        UnlimitedSeq::new(KakuroNumCombinator { wide: false }),
     ))
 }
 
-pub fn serialize_problem(problem: &Problem) -> Option<String> {
+/// Converts `problem` into the form used for URLs, failing if a clue number exceeds `max_clue`.
+fn problem_to_intermediate(problem: &Problem, max_clue: i32) -> Option<IntermediateProblem> {
     let (h, w) = util::infer_shape(problem);
     if !(h >= 2 && w >= 2) {
         return None;
     }
+    let in_range = |n: Option<i32>| n.is_none_or(|n| (1..=max_clue).contains(&n));
+    if !problem
+        .iter()
+        .flatten()
+        .flatten()
+        .all(|clue| in_range(clue.down) && in_range(clue.right))
+    {
+        return None;
+    }
 
     let mut intermediate_grid = vec![vec![None; w - 1]; h - 1];
     for y in 0..(h - 1) {
@@ -208,16 +298,11 @@ pub fn serialize_problem(problem: &Problem) -> Option<String> {
         }
     }
 
-    problem_to_url_with_context(
-        combinator(),
-        "kakuro",
-        (intermediate_grid, rem_seq),
-        &Context::sized(h - 1, w - 1),
-    )
+    Some((intermediate_grid, rem_seq))
 }
 
-pub fn deserialize_problem(url: &str) -> Option<Problem> {
-    let (intermediate_grid, rem_seq) = url_to_problem(combinator(), &["kakuro"], url)?;
+fn intermediate_to_problem(intermediate: IntermediateProblem) -> Option<Problem> {
+    let (intermediate_grid, rem_seq) = intermediate;
 
     let (h, w) = util::infer_shape(&intermediate_grid);
     let h = h + 1;
@@ -273,6 +358,131 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(ret)
 }
 
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = util::infer_shape(problem);
+    let intermediate = problem_to_intermediate(problem, 45)?;
+    problem_to_url_with_context(
+        combinator(),
+        "kakuro",
+        intermediate,
+        &Context::sized(h - 1, w - 1),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    intermediate_to_problem(url_to_problem(combinator(), &["kakuro"], url)?)
+}
+
+pub type ExtendedProblem = (Problem, KakuroOptions);
+
+type Exclusion = ((usize, usize), bool, Vec<i32>);
+
+type ExtendedIntermediateProblem = (
+    IntermediateGrid,
+    Vec<Option<i32>>,
+    Option<(i32, Vec<Exclusion>)>,
+);
+
+fn exclusion_combinator() -> impl Combinator<Exclusion> {
+    Map::new(
+        Tuple3::new(
+            PrefixAndSuffix::new("/", DecInt, "_"),
+            PrefixAndSuffix::new("", DecInt, "_"),
+            Tuple2::new(
+                Choice::new(vec![
+                    Box::new(Dict::new(true, "d")),
+                    Box::new(Dict::new(false, "r")),
+                ]),
+                UnlimitedSeq::new(PrefixAndSuffix::new("_", DecInt, "")),
+            ),
+        ),
+        |((y, x), is_down, digits): Exclusion| Some((y as i32, x as i32, (is_down, digits))),
+        |(y, x, (is_down, digits)): (i32, i32, (bool, Vec<i32>))| {
+            Some(((y as usize, x as usize), is_down, digits))
+        },
+    )
+}
+
+/// The standard format followed by `/<max_digit>` and `/<y>_<x>_<d|r>_<digit>_<digit>...` for
+/// each run with excluded digits, which are omitted for the default options.
+fn extended_combinator() -> impl Combinator<ExtendedIntermediateProblem> {
+    Size::new(Tuple3::new(
+        grid_combinator(true),
+        UnlimitedSeq::new(KakuroNumCombinator { wide: true }),
+        Choice::new(vec![
+            Box::new(Optionalize::new(Tuple2::new(
+                PrefixAndSuffix::new("/", DecInt, ""),
+                UnlimitedSeq::new(exclusion_combinator()),
+            ))),
+            Box::new(Dict::new(None, "")),
+        ]),
+    ))
+}
+
+/// Serializes a Kakuro problem with options. Clue numbers above 45, which the standard format
+/// cannot represent, are allowed.
+pub fn serialize_extended_problem(problem: &ExtendedProblem) -> Option<String> {
+    let (clues, options) = problem;
+    let (h, w) = util::infer_shape(clues);
+    let (intermediate_grid, rem_seq) = problem_to_intermediate(clues, 255)?;
+    let options = if *options == KakuroOptions::default() {
+        None
+    } else if options.max_digit < 0
+        || options
+            .excluded_digits
+            .iter()
+            .flat_map(|e| &e.2)
+            .any(|&d| d < 0)
+    {
+        return None;
+    } else {
+        Some((options.max_digit, options.excluded_digits.clone()))
+    };
+    problem_to_url_with_context(
+        extended_combinator(),
+        "kakuro",
+        (intermediate_grid, rem_seq, options),
+        &Context::sized(h - 1, w - 1),
+    )
+}
+
+pub fn deserialize_extended_problem(url: &str) -> Option<ExtendedProblem> {
+    let (intermediate_grid, rem_seq, options) =
+        url_to_problem(extended_combinator(), &["kakuro"], url)?;
+    let clues = intermediate_to_problem((intermediate_grid, rem_seq))?;
+    let options = match options {
+        Some((max_digit, excluded_digits)) => KakuroOptions {
+            max_digit,
+            excluded_digits,
+        },
+        None => KakuroOptions::default(),
+    };
+
+    Some((clues, options))
+}
+
+pub struct Kakuro;
+
+impl util::Puzzle for Kakuro {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kakuro_with_query(problem, &KakuroOptions::default(), query)
+    }
+}
+
+pub struct KakuroWithOptions;
+
+impl util::Puzzle for KakuroWithOptions {
+    type Problem = ExtendedProblem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &ExtendedProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kakuro_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,4 +589,102 @@ mod tests {
         let url = "https://puzz.link/p?kakuro/6/5/Dclh4t9fl3-p-gl-alJeC3BgG";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    fn small_problem_for_tests(rows: [i32; 2], cols: [i32; 2]) -> Problem {
+        let clue = |down, right| Some(KakuroClue { down, right });
+        vec![
+            vec![
+                clue(None, None),
+                clue(Some(cols[0]), None),
+                clue(Some(cols[1]), None),
+            ],
+            vec![clue(None, Some(rows[0])), None, None],
+            vec![clue(None, Some(rows[1])), None, None],
+        ]
+    }
+
+    #[test]
+    fn test_kakuro_max_digit() {
+        let problem = small_problem_for_tests([20, 3], [9, 14]);
+        assert_eq!(solve_kakuro(&problem), None);
+
+        let options = KakuroOptions {
+            max_digit: 12,
+            excluded_digits: vec![],
+        };
+        let ans = solve_kakuro_with_options(&problem, &options);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = crate::util::tests::to_option_2d([[0, 0, 0], [0, 8, 12], [0, 1, 2]]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kakuro_excluded_digits() {
+        let problem = small_problem_for_tests([5, 3], [3, 5]);
+        let ans = solve_kakuro(&problem);
+        assert!(ans.is_some());
+        assert_eq!(ans.unwrap()[1][1], None);
+
+        let options = KakuroOptions {
+            max_digit: 9,
+            excluded_digits: vec![((1, 0), false, vec![1])],
+        };
+        let ans = solve_kakuro_with_options(&problem, &options);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = crate::util::tests::to_option_2d([[0, 0, 0], [0, 2, 3], [0, 1, 2]]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kakuro_extended_serializer() {
+        let problem = (
+            small_problem_for_tests([20, 3], [9, 14]),
+            KakuroOptions {
+                max_digit: 12,
+                excluded_digits: vec![((1, 0), false, vec![1, 10]), ((0, 2), true, vec![4])],
+            },
+        );
+        let url = "https://puzz.link/p?kakuro/2/2/n9eA3/12/1_0_r_1_10/0_2_d_4";
+        util::tests::serializer_test(
+            problem,
+            url,
+            serialize_extended_problem,
+            deserialize_extended_problem,
+        );
+    }
+
+    #[test]
+    fn test_kakuro_extended_serializer_large_sum() {
+        let clue = |right| Some(KakuroClue { down: None, right });
+        let clues = vec![
+            vec![clue(None); 5],
+            vec![clue(Some(58)), None, None, None, None],
+        ];
+        let options = KakuroOptions {
+            max_digit: 16,
+            excluded_digits: vec![],
+        };
+        assert!(solve_kakuro_with_options(&clues, &options).is_some());
+        let options_15 = KakuroOptions {
+            max_digit: 15,
+            excluded_digits: vec![],
+        };
+        assert!(solve_kakuro_with_options(&clues, &options_15).is_none());
+
+        let problem = (clues, options);
+        let url = "https://puzz.link/p?kakuro/4/1/n----+3a/16";
+        util::tests::serializer_test(
+            problem.clone(),
+            url,
+            serialize_extended_problem,
+            deserialize_extended_problem,
+        );
+        assert_eq!(serialize_problem(&problem.0), None);
+        assert_eq!(
+            deserialize_extended_problem(&format!("{}/", url)),
+            Some(problem)
+        );
+    }
 }
@@ -0,0 +1,233 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
+    Rooms, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+/// Solves a Kazunori Room problem.
+///
+/// `sums` holds clues placed between two adjacent cells, each of which is the sum of the numbers
+/// in these cells.
+pub fn solve_kazunori_room(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    sums: &graph::InnerGridEdges<Vec<Vec<Option<i32>>>>,
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kazunori_room_with_query(borders, sums, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kazunori_room_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    sums: &graph::InnerGridEdges<Vec<Vec<Option<i32>>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let (h, w) = borders.base_shape();
+
+    let rooms = graph::borders_to_rooms(borders);
+    let max_size = rooms.iter().map(|room| room.len()).max().unwrap_or(0) as i32;
+
+    let mut solver = Solver::new();
+    let num = &solver.int_var_2d((h, w), 1, max_size);
+    solver.add_answer_key_int(num);
+
+    for room in &rooms {
+        solver.add_expr(num.select(room).le(room.len() as i32));
+        solver.all_different(num.select(room));
+    }
+
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 {
+                if let Some(n) = sums.horizontal[y][x] {
+                    solver.add_expr((num.at((y, x)) + num.at((y + 1, x))).eq(n));
+                }
+            }
+            if x < w - 1 {
+                if let Some(n) = sums.vertical[y][x] {
+                    solver.add_expr((num.at((y, x)) + num.at((y, x + 1))).eq(n));
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+pub type Problem = (
+    graph::InnerGridEdges<Vec<Vec<bool>>>,
+    graph::InnerGridEdges<Vec<Vec<Option<i32>>>>,
+);
+
+fn sum_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+pub struct KazunoriRoomCombinator;
+
+impl Combinator<Problem> for KazunoriRoomCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let (borders, sums) = &input[0];
+
+        let mut ret = Rooms.serialize(ctx, std::slice::from_ref(borders))?.1;
+
+        // Sums between horizontally adjacent cells come first, as in the border encoding
+        let mut seq = vec![];
+        for y in 0..height {
+            for x in 0..(width - 1) {
+                seq.push(sums.vertical[y][x]);
+            }
+        }
+        for y in 0..(height - 1) {
+            for x in 0..width {
+                seq.push(sums.horizontal[y][x]);
+            }
+        }
+        let n_items = seq.len();
+        ret.extend(
+            Seq::new(sum_combinator(), n_items)
+                .serialize(ctx, &[seq])?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let borders = sequencer.deserialize_one_elem(ctx, Rooms)?;
+
+        let n_items = height * (width - 1) + (height - 1) * width;
+        let seq = sequencer.deserialize_one_elem(ctx, Seq::new(sum_combinator(), n_items))?;
+
+        let mut vertical = vec![];
+        for y in 0..height {
+            vertical.push(seq[(y * (width - 1))..((y + 1) * (width - 1))].to_vec());
+        }
+        let offset = height * (width - 1);
+        let mut horizontal = vec![];
+        for y in 0..(height - 1) {
+            horizontal.push(seq[(offset + y * width)..(offset + (y + 1) * width)].to_vec());
+        }
+
+        Some((
+            sequencer.n_read(),
+            vec![(
+                borders,
+                graph::InnerGridEdges {
+                    horizontal,
+                    vertical,
+                },
+            )],
+        ))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(KazunoriRoomCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator(),
+        "kazunori",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["kazunori"], url)
+}
+
+pub struct KazunoriRoom;
+
+impl util::Puzzle for KazunoriRoom {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kazunori_room_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 1, 1],
+                    [1, 1, 0, 0, 0],
+                    [0, 1, 1, 1, 0],
+                    [0, 0, 0, 1, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0, 0],
+                    [0, 1, 1, 0],
+                    [0, 1, 1, 0],
+                    [1, 0, 0, 1],
+                    [1, 0, 1, 0],
+                ]),
+            },
+            graph::InnerGridEdges {
+                horizontal: vec![
+                    vec![None, None, None, Some(2), Some(4)],
+                    vec![Some(2), Some(4), None, Some(4), Some(6)],
+                    vec![None, None, Some(6), None, None],
+                    vec![None, Some(7), None, Some(2), None],
+                ],
+                vertical: vec![
+                    vec![None, Some(6), None, None],
+                    vec![None, None, None, None],
+                    vec![None, None, None, None],
+                    vec![Some(6), None, None, None],
+                    vec![None, None, None, None],
+                ],
+            },
+        )
+    }
+
+    #[test]
+    fn test_kazunori_room_problem() {
+        let (borders, sums) = problem_for_tests();
+        let ans = solve_kazunori_room(&borders, &sums);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_2d([
+            [4, 3, 3, 1, 2],
+            [1, 2, 5, 1, 2],
+            [1, 2, 4, 3, 4],
+            [3, 3, 2, 1, 5],
+            [4, 4, 5, 1, 2],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kazunori_room_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?kazunori/5/5/8pkq3oe3g6p6p2424g46h6i7g2g";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
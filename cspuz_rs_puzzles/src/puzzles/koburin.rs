@@ -0,0 +1,130 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_koburin(
+    clues: &[Vec<Option<i32>>],
+) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_koburin_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_koburin_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    let is_passed = &graph::single_cycle_grid_edges(&mut solver, is_line);
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+    solver.add_expr(!is_black.conv2d_and((1, 2)));
+    solver.add_expr(!is_black.conv2d_and((2, 1)));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_passed.at((y, x)));
+                solver.add_expr(!is_black.at((y, x)));
+
+                if n >= 0 {
+                    solver.add_expr(is_black.four_neighbors((y, x)).count_true().eq(n));
+                }
+            } else {
+                solver.add_expr(is_passed.at((y, x)) ^ is_black.at((y, x)));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(is_black)))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "koburin", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["koburin"], url)
+}
+
+pub struct Koburin;
+
+impl util::Puzzle for Koburin {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_koburin_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[1][1] = Some(2);
+        ret[1][4] = Some(2);
+        ret
+    }
+
+    #[test]
+    fn test_koburin_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_koburin(&problem);
+        assert!(ans.is_some());
+        let (is_line, is_black) = ans.unwrap();
+
+        let expected_line = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: util::tests::to_option_bool_2d([
+                [1, 1, 1, 0],
+                [0, 0, 0, 0],
+                [0, 0, 1, 0],
+                [1, 0, 1, 1],
+                [0, 1, 1, 1],
+            ]),
+            vertical: util::tests::to_option_bool_2d([
+                [1, 0, 0, 1, 0],
+                [1, 0, 0, 1, 0],
+                [1, 0, 1, 0, 0],
+                [0, 1, 0, 0, 1],
+            ]),
+        };
+        assert_eq!(is_line, expected_line);
+
+        let expected_black = util::tests::to_option_bool_2d([
+            [0, 0, 0, 0, 1],
+            [0, 0, 1, 0, 0],
+            [0, 1, 0, 0, 1],
+            [0, 0, 0, 0, 0],
+            [1, 0, 0, 0, 0],
+        ]);
+        assert_eq!(is_black, expected_black);
+    }
+
+    #[test]
+    fn test_koburin_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?koburin/5/5/l2h2u";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -9,6 +9,13 @@ use cspuz_rs::solver::{count_true, Solver};
 pub type Pt = (usize, usize);
 
 pub fn solve_kouchoku(clues: &[Vec<Option<i32>>]) -> Option<(Vec<(Pt, Pt)>, Vec<(Pt, Pt)>)> {
+    solve_kouchoku_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kouchoku_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(Vec<(Pt, Pt)>, Vec<(Pt, Pt)>)> {
     let (h, w) = util::infer_shape(clues);
 
     let mut points = vec![];
@@ -88,7 +95,7 @@ pub fn solve_kouchoku(clues: &[Vec<Option<i32>>]) -> Option<(Vec<(Pt, Pt)>, Vec<
         }
     }
 
-    solver.irrefutable_facts().map(|f| {
+    util::query_answers(solver, query, |f| {
         let mut fixed_edges = vec![];
         let mut undet_edges = vec![];
 
@@ -173,6 +180,17 @@ fn is_perpendicular(a: Pt, b: Pt, c: Pt, d: Pt) -> bool {
     (bx - ax) * (dx - cx) + (by - ay) * (dy - cy) == 0
 }
 
+pub struct Kouchoku;
+
+impl util::Puzzle for Kouchoku {
+    type Problem = Problem;
+    type Answer = (Vec<(Pt, Pt)>, Vec<(Pt, Pt)>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kouchoku_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
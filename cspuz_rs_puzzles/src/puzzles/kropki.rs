@@ -1,7 +1,8 @@
-use cspuz_rs::graph::InnerGridEdges;
+use crate::util;
+use cspuz_rs::graph::{self, InnerGridEdges};
 use cspuz_rs::serializer::{
-    map_2d, problem_to_url_with_context, url_to_problem, Combinator, Context, MultiDigit,
-    Sequencer, Size,
+    map_2d, problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, Map,
+    MultiDigit, PrefixAndSuffix, Rooms, Sequencer, Size, Tuple2,
 };
 use cspuz_rs::solver::{IntVar, Solver};
 
@@ -26,6 +27,34 @@ fn add_kropki_constraint(solver: &mut Solver, a: &IntVar, b: &IntVar, clue: Krop
 }
 
 pub fn solve_kropki(clues: &InnerGridEdges<Vec<Vec<KropkiClue>>>) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kropki_with_query(clues, None, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+/// Solves Kropki in which each region (e.g. sudoku boxes) delimited by `regions` must also contain
+/// different numbers.
+pub fn solve_kropki_with_regions(
+    clues: &InnerGridEdges<Vec<Vec<KropkiClue>>>,
+    regions: &InnerGridEdges<Vec<Vec<bool>>>,
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kropki_with_query(clues, Some(regions), util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+/// Returns the borders of boxes of size `block_size` (height, width) in a board of size `n`.
+pub fn box_borders(n: usize, block_size: (usize, usize)) -> InnerGridEdges<Vec<Vec<bool>>> {
+    let (bh, bw) = block_size;
+    InnerGridEdges {
+        horizontal: (0..(n - 1)).map(|y| vec![(y + 1) % bh == 0; n]).collect(),
+        vertical: (0..n)
+            .map(|_| (0..(n - 1)).map(|x| (x + 1) % bw == 0).collect())
+            .collect(),
+    }
+}
+
+fn solve_kropki_with_query(
+    clues: &InnerGridEdges<Vec<Vec<KropkiClue>>>,
+    regions: Option<&InnerGridEdges<Vec<Vec<bool>>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = clues.base_shape();
     assert_eq!(h, w);
     let n = h;
@@ -39,6 +68,15 @@ pub fn solve_kropki(clues: &InnerGridEdges<Vec<Vec<KropkiClue>>>) -> Option<Vec<
         solver.all_different(num.slice_fixed_x((.., i)));
     }
 
+    if let Some(regions) = regions {
+        for room in graph::borders_to_rooms(regions) {
+            if room.len() > n {
+                return vec![];
+            }
+            solver.all_different(room.iter().map(|&p| num.at(p)));
+        }
+    }
+
     for y in 0..n {
         for x in 0..n {
             if y < n - 1 {
@@ -60,7 +98,7 @@ pub fn solve_kropki(clues: &InnerGridEdges<Vec<Vec<KropkiClue>>>) -> Option<Vec<
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(num))
+    util::query_answers(solver, query, |f| f.get(num))
 }
 
 fn kropi_clue_to_i32(clue: &KropkiClue) -> i32 {
@@ -189,6 +227,66 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["kropki"], url)
 }
 
+/// A Kropki problem optionally with regions in which numbers must be different.
+pub type ProblemWithRegions = (Problem, Option<InnerGridEdges<Vec<Vec<bool>>>>);
+
+fn combinator_with_regions() -> impl Combinator<ProblemWithRegions> {
+    Size::new(Choice::new(vec![
+        Box::new(Map::new(
+            Tuple2::new(KropkiCombinator, PrefixAndSuffix::new("/", Rooms, "")),
+            |(clues, regions): ProblemWithRegions| Some((clues, regions?)),
+            |(clues, regions)| Some((clues, Some(regions))),
+        )),
+        Box::new(Map::new(
+            KropkiCombinator,
+            |(clues, regions): ProblemWithRegions| {
+                if regions.is_none() {
+                    Some(clues)
+                } else {
+                    None
+                }
+            },
+            |clues| Some((clues, None)),
+        )),
+    ]))
+}
+
+pub fn serialize_problem_with_regions(problem: &ProblemWithRegions) -> Option<String> {
+    let (height, width) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator_with_regions(),
+        "kropki",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem_with_regions(url: &str) -> Option<ProblemWithRegions> {
+    url_to_problem(combinator_with_regions(), &["kropki"], url)
+}
+
+pub struct Kropki;
+
+impl util::Puzzle for Kropki {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kropki_with_query(problem, None, query)
+    }
+}
+
+pub struct KropkiWithRegions;
+
+impl util::Puzzle for KropkiWithRegions {
+    type Problem = ProblemWithRegions;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &ProblemWithRegions, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kropki_with_query(&problem.0, problem.1.as_ref(), query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,4 +374,68 @@ mod tests {
             util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
         }
     }
+
+    fn problem_with_regions_for_tests() -> ProblemWithRegions {
+        let parse = |rows: &[&str]| {
+            rows.iter()
+                .map(|row| {
+                    row.chars()
+                        .map(|c| match c {
+                            'w' => KropkiClue::White,
+                            'b' => KropkiClue::Black,
+                            _ => KropkiClue::None,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let clues = InnerGridEdges {
+            horizontal: parse(&[".bb..w", "w.wwb.", "w..bb.", "w.b.ww", "......"]),
+            vertical: parse(&["bw.w.", "w..b.", ".w.wb", ".b..w", "w..ww", "ww..w"]),
+        };
+        (clues, Some(box_borders(6, (2, 3))))
+    }
+
+    #[test]
+    fn test_kropki_with_regions_problem() {
+        let (clues, regions) = problem_with_regions_for_tests();
+        let ans = solve_kropki_with_regions(&clues, regions.as_ref().unwrap());
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_2d([
+            [1, 2, 3, 5, 6, 4],
+            [5, 4, 6, 2, 1, 3],
+            [4, 6, 5, 3, 2, 1],
+            [3, 1, 2, 6, 4, 5],
+            [2, 3, 1, 4, 5, 6],
+            [6, 5, 4, 1, 3, 2],
+        ]);
+        assert_eq!(ans, expected);
+
+        // without boxes, the problem has multiple solutions
+        let ans = solve_kropki(&clues).unwrap();
+        assert!(ans.iter().flatten().any(|n| n.is_none()));
+    }
+
+    #[test]
+    fn test_kropki_with_regions_serializer() {
+        let problem = problem_with_regions_for_tests();
+        let url = "https://puzz.link/p?kropki/6/6/la215641d181af9ob400/4444440fo3u0";
+        util::tests::serializer_test(
+            problem,
+            url,
+            serialize_problem_with_regions,
+            deserialize_problem_with_regions,
+        );
+
+        let problem = (problem_for_tests(), None);
+        let url = "https://puzz.link/p?kropki/4/4/o52l49p4";
+        util::tests::serializer_test(
+            problem,
+            url,
+            serialize_problem_with_regions,
+            deserialize_problem_with_regions,
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph::InnerGridEdges;
 use cspuz_rs::serializer::{
     get_kudamono_url_info_detailed, parse_kudamono_dimension, Choice, Combinator, Context, DecInt,
@@ -32,6 +33,14 @@ pub fn solve_kropki_pairs(
     walls: &InnerGridEdges<Vec<Vec<KropkiClue>>>,
     cells: &[Vec<Option<i32>>],
 ) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_kropki_pairs_with_query(walls, cells, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kropki_pairs_with_query(
+    walls: &InnerGridEdges<Vec<Vec<KropkiClue>>>,
+    cells: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = walls.base_shape();
     let n = h.max(w);
 
@@ -91,7 +100,7 @@ pub fn solve_kropki_pairs(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(num))
+    util::query_answers(solver, query, |f| f.get(num))
 }
 
 type Problem = (InnerGridEdges<Vec<Vec<KropkiClue>>>, Vec<Vec<Option<i32>>>);
@@ -153,6 +162,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some((walls, cells))
 }
 
+pub struct KropkiPairs;
+
+impl util::Puzzle for KropkiPairs {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kropki_pairs_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
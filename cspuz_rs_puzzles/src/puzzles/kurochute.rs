@@ -0,0 +1,117 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+pub fn solve_kurochute(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_kurochute_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kurochute_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    graph::active_vertices_connected_2d(&mut solver, !is_black);
+    solver.add_expr(!(is_black.slice((..(h - 1), ..)) & is_black.slice((1.., ..))));
+    solver.add_expr(!(is_black.slice((.., ..(w - 1))) & is_black.slice((.., 1..))));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_black.at((y, x)));
+                if n <= 0 {
+                    continue;
+                }
+                // Exactly one of the cells `n` cells away is black
+                let mut cells = vec![];
+                for (dy, dx) in [(-n, 0), (n, 0), (0, -n), (0, n)] {
+                    let y2 = y as i32 + dy;
+                    let x2 = x as i32 + dx;
+                    if 0 <= y2 && y2 < h as i32 && 0 <= x2 && x2 < w as i32 {
+                        cells.push(is_black.at((y2 as usize, x2 as usize)));
+                    }
+                }
+                solver.add_expr(count_true(cells).eq(1));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "kurochute", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["kurochute"], url)
+}
+
+pub struct Kurochute;
+
+impl util::Puzzle for Kurochute {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kurochute_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[0][0] = Some(3);
+        ret[0][1] = Some(4);
+        ret[0][4] = Some(3);
+        ret[1][0] = Some(1);
+        ret[2][0] = Some(2);
+        ret
+    }
+
+    #[test]
+    fn test_kurochute_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_kurochute(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [0, 0, 0, 1, 0],
+            [0, 1, 0, 0, 0],
+            [0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 1],
+            [0, 1, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kurochute_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?kurochute/5/5/34h31j2t";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
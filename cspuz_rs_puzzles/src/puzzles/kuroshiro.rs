@@ -0,0 +1,147 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url, url_to_problem, Combinator, Grid, Map, MultiDigit};
+use cspuz_rs::solver::Solver;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KuroshiroClue {
+    None,
+    White,
+    Black,
+}
+
+pub fn solve_kuroshiro(
+    clues: &[Vec<KuroshiroClue>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_kuroshiro_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_kuroshiro_with_query(
+    clues: &[Vec<KuroshiroClue>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    let is_passed = &graph::single_cycle_grid_edges(&mut solver, is_line);
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, clue) in row.iter().enumerate() {
+            let p = (y, x);
+            let is_vertical = is_line.vertical.at_offset(p, (-1, 0), false)
+                | is_line.vertical.at_offset(p, (0, 0), false);
+            let is_horizontal = is_line.horizontal.at_offset(p, (0, -1), false)
+                | is_line.horizontal.at_offset(p, (0, 0), false);
+
+            match clue {
+                KuroshiroClue::None => {
+                    // The loop turns only at black circles
+                    solver.add_expr(!(is_vertical & is_horizontal));
+                }
+                KuroshiroClue::White => {
+                    solver.add_expr(is_passed.at(p));
+                    solver.add_expr(!(is_vertical & is_horizontal));
+                }
+                KuroshiroClue::Black => {
+                    solver.add_expr(is_passed.at(p));
+                    solver.add_expr(is_vertical & is_horizontal);
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+type Problem = Vec<Vec<KuroshiroClue>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Map::new(
+        MultiDigit::new(3, 3),
+        |x: KuroshiroClue| {
+            Some(match x {
+                KuroshiroClue::None => 0,
+                KuroshiroClue::White => 1,
+                KuroshiroClue::Black => 2,
+            })
+        },
+        |n: i32| match n {
+            0 => Some(KuroshiroClue::None),
+            1 => Some(KuroshiroClue::White),
+            2 => Some(KuroshiroClue::Black),
+            _ => None,
+        },
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "kuroshiro", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["kuroshiro"], url)
+}
+
+pub struct Kuroshiro;
+
+impl util::Puzzle for Kuroshiro {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_kuroshiro_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![KuroshiroClue::None; 5]; 5];
+        ret[0][0] = KuroshiroClue::Black;
+        ret[0][2] = KuroshiroClue::Black;
+        ret[2][2] = KuroshiroClue::Black;
+        ret[2][4] = KuroshiroClue::Black;
+        ret[4][4] = KuroshiroClue::Black;
+        ret[4][0] = KuroshiroClue::Black;
+        ret[4][2] = KuroshiroClue::White;
+        ret
+    }
+
+    #[test]
+    fn test_kuroshiro_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_kuroshiro(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::GridEdges {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [1, 1, 0, 0],
+                [0, 0, 0, 0],
+                [0, 0, 1, 1],
+                [0, 0, 0, 0],
+                [1, 1, 1, 1],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 0, 1, 0, 0],
+                [1, 0, 1, 0, 0],
+                [1, 0, 0, 0, 1],
+                [1, 0, 0, 0, 1],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_kuroshiro_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?kuroshiro/5/5/k000k023i";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
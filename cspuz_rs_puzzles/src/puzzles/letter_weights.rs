@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::serializer::{
     get_kudamono_url_info_detailed, parse_kudamono_dimension, AlphaToNum, Choice, Combinator,
     Context, DecInt, Dict, KudamonoGrid, KudamonoSequence, Map, PrefixAndSuffix,
@@ -9,6 +10,17 @@ pub fn solve_letter_weights(
     chars: &[char],
     nums: &[i32],
 ) -> (Vec<char>, Vec<i32>, Option<Vec<Vec<Option<bool>>>>) {
+    let (chars, unique_nums, mut ans) =
+        solve_letter_weights_with_query(sums, chars, nums, util::AnswerQuery::IrrefutableFacts);
+    (chars, unique_nums, ans.pop())
+}
+
+fn solve_letter_weights_with_query(
+    sums: &[(Vec<char>, i32)],
+    chars: &[char],
+    nums: &[i32],
+    query: util::AnswerQuery,
+) -> (Vec<char>, Vec<i32>, Vec<Vec<Vec<Option<bool>>>>) {
     assert_eq!(chars.len(), nums.len());
 
     let mut nums = nums.to_vec();
@@ -71,7 +83,7 @@ pub fn solve_letter_weights(
         solver.add_expr(e.eq(sums[i].1));
     }
 
-    let ans = solver.irrefutable_facts().map(|f| f.get(mapping));
+    let ans = util::query_answers(solver, query, |f| f.get(mapping));
 
     (chars.to_vec(), unique_nums, ans)
 }
@@ -255,6 +267,21 @@ pub fn deserialize_problem(url: &str) -> Result<Problem, &'static str> {
     Ok((sums, chars, nums))
 }
 
+pub struct LetterWeights;
+
+impl util::Puzzle for LetterWeights {
+    type Problem = Problem;
+    type Answer = (Vec<char>, Vec<i32>, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        let (chars, unique_nums, ans) =
+            solve_letter_weights_with_query(&problem.0, &problem.1, &problem.2, query);
+        ans.into_iter()
+            .map(|a| (chars.clone(), unique_nums.clone(), a))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,13 @@ use cspuz_rs::solver::{Solver, TRUE};
 pub fn solve_litherslink(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_litherslink_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_litherslink_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -105,7 +112,7 @@ pub fn solve_litherslink(
     }
 
     graph::active_vertices_connected(&mut solver, &indicator, &aux_graph);
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -118,6 +125,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["lither"], url)
 }
 
+pub struct Litherslink;
+
+impl util::Puzzle for Litherslink {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_litherslink_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,12 +1,20 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Combinator, Context, Rooms, Size,
 };
-use cspuz_rs::solver::{any, count_true, Solver, FALSE};
+use cspuz_rs::solver::{any, count_true, BoolVarArray2D, IntVarArray1D, Solver, FALSE};
 
 pub fn solve_lits(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_lits_with_query(borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_lits_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let h = borders.vertical.len();
     assert!(h > 0);
     let w = borders.vertical[0].len() + 1;
@@ -23,6 +31,51 @@ pub fn solve_lits(
             & is_black.slice((1.., 1..))),
     );
 
+    let rooms = graph::borders_to_rooms(borders);
+    let mut room_id = vec![vec![0; w]; h];
+    for (i, room) in rooms.iter().enumerate() {
+        for &(y, x) in room {
+            room_id[y][x] = i;
+        }
+    }
+    let room_kind = &add_tetromino_constraints(&mut solver, borders, &rooms, is_black);
+
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 && room_id[y][x] != room_id[y + 1][x] {
+                solver.add_expr(
+                    (is_black.at((y, x)) & is_black.at((y + 1, x))).imp(
+                        room_kind
+                            .at(room_id[y][x])
+                            .ne(room_kind.at(room_id[y + 1][x])),
+                    ),
+                );
+            }
+            if x < w - 1 && room_id[y][x] != room_id[y][x + 1] {
+                solver.add_expr(
+                    (is_black.at((y, x)) & is_black.at((y, x + 1))).imp(
+                        room_kind
+                            .at(room_id[y][x])
+                            .ne(room_kind.at(room_id[y][x + 1])),
+                    ),
+                );
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+/// Adds constraints so that black cells in each room form a single tetromino.
+/// Returns the shape of the tetromino in each room (0: L, 1: I, 2: T, 3: S).
+pub(crate) fn add_tetromino_constraints(
+    solver: &mut Solver,
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    rooms: &[Vec<(usize, usize)>],
+    is_black: &BoolVarArray2D,
+) -> IntVarArray1D {
+    let (h, w) = is_black.shape();
+
     // 0: white cell
     // 1: endpoint
     // 2: L
@@ -70,13 +123,10 @@ pub fn solve_lits(
         }
     }
 
-    let rooms = graph::borders_to_rooms(borders);
-    let mut room_id = vec![vec![0; w]; h];
-    let room_kind = &solver.int_var_1d(rooms.len(), 0, 3);
+    let room_kind = solver.int_var_1d(rooms.len(), 0, 3);
     for (i, room) in rooms.iter().enumerate() {
         let mut cell_kinds = vec![vec![]; 5];
         for &(y, x) in room {
-            room_id[y][x] = i;
             for j in 1..=4 {
                 cell_kinds[j].push(kind.at((y, x)).eq(j as i32));
             }
@@ -111,30 +161,8 @@ pub fn solve_lits(
                 & cell_kind_counts[4].eq(0),
         ));
     }
-    for y in 0..h {
-        for x in 0..w {
-            if y < h - 1 && room_id[y][x] != room_id[y + 1][x] {
-                solver.add_expr(
-                    (is_black.at((y, x)) & is_black.at((y + 1, x))).imp(
-                        room_kind
-                            .at(room_id[y][x])
-                            .ne(room_kind.at(room_id[y + 1][x])),
-                    ),
-                );
-            }
-            if x < w - 1 && room_id[y][x] != room_id[y][x + 1] {
-                solver.add_expr(
-                    (is_black.at((y, x)) & is_black.at((y, x + 1))).imp(
-                        room_kind
-                            .at(room_id[y][x])
-                            .ne(room_kind.at(room_id[y][x + 1])),
-                    ),
-                );
-            }
-        }
-    }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    room_kind
 }
 
 type Problem = graph::InnerGridEdges<Vec<Vec<bool>>>;
@@ -158,6 +186,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["lits"], url)
 }
 
+pub struct Lits;
+
+impl util::Puzzle for Lits {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_lits_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,13 @@ use cspuz_rs::solver::Solver;
 pub fn solve_lohkous(
     clues: &[Vec<Option<Vec<i32>>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_lohkous_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_lohkous_with_query(
+    clues: &[Vec<Option<Vec<i32>>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -93,13 +100,13 @@ pub fn solve_lohkous(
                 if 1 <= c && c <= max_span as i32 {
                     solver.add_expr(spans.at(c as usize));
                 } else {
-                    return None;
+                    return vec![];
                 }
             }
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(edges))
+    util::query_answers(solver, query, |f| f.get(edges))
 }
 
 type Problem = Vec<Vec<Option<Vec<i32>>>>;
@@ -151,6 +158,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(ret)
 }
 
+pub struct Lohkous;
+
+impl util::Puzzle for Lohkous {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_lohkous_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,163 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_look_air(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_look_air_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_look_air_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    // Each black region is a square
+    for y in 0..(h - 1) {
+        for x in 0..(w - 1) {
+            solver.add_expr(is_black.slice((y..(y + 2), x..(x + 2))).count_true().ne(3));
+        }
+    }
+    let size = &solver.int_var_2d((h, w), 0, h.min(w) as i32);
+    for y in 0..h {
+        for x in 0..w {
+            let p = (y, x);
+            let up = is_black
+                .slice_fixed_x((..y, x))
+                .reverse()
+                .consecutive_prefix_true();
+            let down = is_black
+                .slice_fixed_x(((y + 1).., x))
+                .consecutive_prefix_true();
+            let left = is_black
+                .slice_fixed_y((y, ..x))
+                .reverse()
+                .consecutive_prefix_true();
+            let right = is_black
+                .slice_fixed_y((y, (x + 1)..))
+                .consecutive_prefix_true();
+            solver.add_expr(
+                is_black
+                    .at(p)
+                    .imp(size.at(p).eq(up + down + 1) & size.at(p).eq(left + right + 1)),
+            );
+            solver.add_expr((!is_black.at(p)).imp(size.at(p).eq(0)));
+        }
+    }
+
+    // Squares of the same size do not see each other
+    for y in 0..h {
+        for x1 in 0..w {
+            for x2 in (x1 + 2)..w {
+                solver.add_expr(
+                    (is_black.at((y, x1))
+                        & is_black.at((y, x2))
+                        & !is_black.slice_fixed_y((y, (x1 + 1)..x2)).any())
+                    .imp(size.at((y, x1)).ne(size.at((y, x2)))),
+                );
+            }
+        }
+    }
+    for x in 0..w {
+        for y1 in 0..h {
+            for y2 in (y1 + 2)..h {
+                solver.add_expr(
+                    (is_black.at((y1, x))
+                        & is_black.at((y2, x))
+                        & !is_black.slice_fixed_x(((y1 + 1)..y2, x)).any())
+                    .imp(size.at((y1, x)).ne(size.at((y2, x)))),
+                );
+            }
+        }
+    }
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                if n >= 0 {
+                    solver.add_expr(
+                        (is_black.four_neighbors((y, x)).count_true()
+                            + is_black.at((y, x)).ite(1, 0))
+                        .eq(n),
+                    );
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "lookair", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["lookair"], url)
+}
+
+pub struct LookAir;
+
+impl util::Puzzle for LookAir {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_look_air_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        vec![
+            vec![None, None, None, Some(1), None],
+            vec![Some(3), None, None, None, Some(0)],
+            vec![None, Some(1), None, None, None],
+            vec![Some(1), None, None, None, None],
+            vec![None, Some(0), None, Some(3), None],
+        ]
+    }
+
+    #[test]
+    fn test_look_air_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_look_air(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [1, 1, 0, 1, 0],
+            [1, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [1, 0, 0, 1, 1],
+            [0, 0, 0, 1, 1],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_look_air_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?lookair/5/5/i1g3i0g1i1k0g3g";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -21,6 +21,13 @@ pub enum LoopSpecialClue {
 pub fn solve_loop_special(
     clues: &[Vec<LoopSpecialClue>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_loop_special_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_loop_special_with_query(
+    clues: &[Vec<LoopSpecialClue>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -90,7 +97,7 @@ pub fn solve_loop_special(
             };
             if up {
                 if y == 0 {
-                    return None;
+                    return vec![];
                 }
                 solver.add_expr(is_line.vertical.at((y - 1, x)));
             } else {
@@ -100,7 +107,7 @@ pub fn solve_loop_special(
             }
             if down {
                 if y == h - 1 {
-                    return None;
+                    return vec![];
                 }
                 solver.add_expr(is_line.vertical.at((y, x)));
             } else {
@@ -110,7 +117,7 @@ pub fn solve_loop_special(
             }
             if left {
                 if x == 0 {
-                    return None;
+                    return vec![];
                 }
                 solver.add_expr(is_line.horizontal.at((y, x - 1)));
             } else {
@@ -120,7 +127,7 @@ pub fn solve_loop_special(
             }
             if right {
                 if x == w - 1 {
-                    return None;
+                    return vec![];
                 }
                 solver.add_expr(is_line.horizontal.at((y, x)));
             } else {
@@ -131,7 +138,7 @@ pub fn solve_loop_special(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<LoopSpecialClue>>;
@@ -171,6 +178,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["loopsp"], url)
 }
 
+pub struct LoopSpecial;
+
+impl util::Puzzle for LoopSpecial {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_loop_special_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
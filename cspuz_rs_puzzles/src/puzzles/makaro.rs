@@ -0,0 +1,217 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::items::Arrow;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Map, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::Solver;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MakaroCell {
+    Empty,
+    Num(i32),
+    Arrow(Arrow),
+    Black,
+}
+
+pub fn solve_makaro(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    cells: &[Vec<MakaroCell>],
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_makaro_with_query(borders, cells, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_makaro_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    cells: &[Vec<MakaroCell>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let (h, w) = util::infer_shape(cells);
+
+    let is_number_cell =
+        |y: usize, x: usize| matches!(cells[y][x], MakaroCell::Empty | MakaroCell::Num(_));
+
+    let rooms = graph::borders_to_rooms(borders)
+        .into_iter()
+        .map(|room| {
+            room.into_iter()
+                .filter(|&(y, x)| is_number_cell(y, x))
+                .collect::<Vec<_>>()
+        })
+        .filter(|room| !room.is_empty())
+        .collect::<Vec<_>>();
+    let max_size = rooms.iter().map(|room| room.len()).max().unwrap_or(0) as i32;
+
+    let mut solver = Solver::new();
+    // 0 means that the cell is an arrow or a black cell
+    let num = &solver.int_var_2d((h, w), 0, max_size);
+    solver.add_answer_key_int(num);
+
+    for room in &rooms {
+        solver.add_expr(num.select(room).ge(1));
+        solver.add_expr(num.select(room).le(room.len() as i32));
+        solver.all_different(num.select(room));
+    }
+
+    for (y, row) in cells.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if y < h - 1 && is_number_cell(y, x) && is_number_cell(y + 1, x) {
+                solver.add_expr(num.at((y, x)).ne(num.at((y + 1, x))));
+            }
+            if x < w - 1 && is_number_cell(y, x) && is_number_cell(y, x + 1) {
+                solver.add_expr(num.at((y, x)).ne(num.at((y, x + 1))));
+            }
+
+            match cell {
+                MakaroCell::Empty => (),
+                MakaroCell::Num(n) => {
+                    if n > 0 {
+                        solver.add_expr(num.at((y, x)).eq(n));
+                    }
+                }
+                MakaroCell::Black => solver.add_expr(num.at((y, x)).eq(0)),
+                MakaroCell::Arrow(dir) => {
+                    solver.add_expr(num.at((y, x)).eq(0));
+
+                    let (dy, dx) = match dir {
+                        Arrow::Unspecified => continue,
+                        Arrow::Up => (-1, 0),
+                        Arrow::Down => (1, 0),
+                        Arrow::Left => (0, -1),
+                        Arrow::Right => (0, 1),
+                    };
+                    let ty = y as i32 + dy;
+                    let tx = x as i32 + dx;
+                    if !(0..h as i32).contains(&ty)
+                        || !(0..w as i32).contains(&tx)
+                        || !is_number_cell(ty as usize, tx as usize)
+                    {
+                        return vec![];
+                    }
+                    let target = (ty as usize, tx as usize);
+
+                    // The arrow points to the unique largest number among its neighbors
+                    for p in num.four_neighbor_indices((y, x)) {
+                        if p != target && is_number_cell(p.0, p.1) {
+                            solver.add_expr(num.at(target).gt(num.at(p)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<MakaroCell>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Map::new(
+                HexInt,
+                |c: MakaroCell| match c {
+                    MakaroCell::Num(n) => Some(n),
+                    _ => None,
+                },
+                |n| Some(MakaroCell::Num(n)),
+            )),
+            Box::new(Dict::new(MakaroCell::Num(-1), ".")),
+            Box::new(Dict::new(MakaroCell::Arrow(Arrow::Up), "g")),
+            Box::new(Dict::new(MakaroCell::Arrow(Arrow::Down), "h")),
+            Box::new(Dict::new(MakaroCell::Arrow(Arrow::Left), "i")),
+            Box::new(Dict::new(MakaroCell::Arrow(Arrow::Right), "j")),
+            Box::new(Dict::new(MakaroCell::Black, "-")),
+            Box::new(Spaces::new(MakaroCell::Empty, 'k')),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator(),
+        "makaro",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["makaro"], url)
+}
+
+pub struct Makaro;
+
+impl util::Puzzle for Makaro {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_makaro_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut cells = vec![vec![MakaroCell::Empty; 5]; 5];
+        cells[0][0] = MakaroCell::Num(1);
+        cells[0][2] = MakaroCell::Num(4);
+        cells[1][1] = MakaroCell::Arrow(Arrow::Right);
+        cells[2][0] = MakaroCell::Num(2);
+        cells[2][1] = MakaroCell::Num(1);
+        cells[2][4] = MakaroCell::Arrow(Arrow::Left);
+        cells[3][1] = MakaroCell::Num(4);
+        cells[3][2] = MakaroCell::Num(1);
+        cells[3][3] = MakaroCell::Num(3);
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 1, 0, 1, 1],
+                    [1, 1, 0, 0, 1],
+                    [0, 1, 1, 1, 1],
+                    [0, 0, 0, 1, 0],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 1, 1],
+                    [1, 0, 0, 1],
+                    [1, 0, 1, 0],
+                ]),
+            },
+            cells,
+        )
+    }
+
+    #[test]
+    fn test_makaro_problem() {
+        let (borders, cells) = problem_for_tests();
+        let ans = solve_makaro(&borders, &cells);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_2d([
+            [1, 2, 4, 1, 2],
+            [3, 0, 5, 3, 1],
+            [2, 1, 3, 2, 0],
+            [3, 4, 1, 3, 1],
+            [4, 2, 5, 2, 3],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_makaro_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?makaro/5/5/9psqbpf21k4mjm21lik413p";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -11,6 +11,19 @@ pub enum MasyuClue {
 }
 
 pub fn solve_masyu(clues: &[Vec<MasyuClue>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_masyu_with_query(clues, false, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+/// Solves Ura-Masyu, in which the rules of white and black circles are swapped.
+pub fn solve_ura_masyu(clues: &[Vec<MasyuClue>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_masyu_with_query(clues, true, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_masyu_with_query(
+    clues: &[Vec<MasyuClue>],
+    is_ura: bool,
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -23,7 +36,12 @@ pub fn solve_masyu(clues: &[Vec<MasyuClue>]) -> Option<graph::BoolGridEdgesIrref
     for y in 0..h {
         for x in 0..w {
             let p = (y, x);
-            match clues[y][x] {
+            let clue = match (clues[y][x], is_ura) {
+                (MasyuClue::White, true) => MasyuClue::Black,
+                (MasyuClue::Black, true) => MasyuClue::White,
+                (clue, _) => clue,
+            };
+            match clue {
                 MasyuClue::None => (),
                 MasyuClue::White => {
                     solver.add_expr(
@@ -55,7 +73,7 @@ pub fn solve_masyu(clues: &[Vec<MasyuClue>]) -> Option<graph::BoolGridEdgesIrref
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<MasyuClue>>;
@@ -87,6 +105,36 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["masyu", "mashu"], url)
 }
 
+pub fn serialize_ura_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "ura-mashu", problem.clone())
+}
+
+pub fn deserialize_ura_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["ura-mashu", "ura-masyu"], url)
+}
+
+pub struct Masyu;
+
+impl util::Puzzle for Masyu {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_masyu_with_query(problem, false, query)
+    }
+}
+
+pub struct UraMasyu;
+
+impl util::Puzzle for UraMasyu {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_masyu_with_query(problem, true, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +189,51 @@ mod tests {
         let url = "https://puzz.link/p?masyu/10/10/0600003i06b1300600000a30600i090330";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    fn ura_problem_for_tests() -> Vec<Vec<MasyuClue>> {
+        let mut ret = vec![vec![MasyuClue::None; 6]; 6];
+        ret[1][0] = MasyuClue::Black;
+        ret[1][2] = MasyuClue::White;
+        ret[1][5] = MasyuClue::Black;
+        ret[2][3] = MasyuClue::White;
+        ret[3][3] = MasyuClue::Black;
+        ret[4][0] = MasyuClue::White;
+        ret[5][5] = MasyuClue::White;
+        ret
+    }
+
+    #[test]
+    fn test_ura_masyu_problem() {
+        let problem = ura_problem_for_tests();
+        let ans = solve_ura_masyu(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [1, 0, 0, 0, 1],
+                [0, 0, 1, 1, 0],
+                [0, 0, 0, 1, 1],
+                [0, 1, 0, 0, 1],
+                [1, 1, 0, 1, 0],
+                [0, 0, 1, 1, 1],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 1, 0, 0, 1, 1],
+                [1, 1, 1, 0, 0, 1],
+                [1, 1, 1, 1, 0, 0],
+                [1, 0, 0, 1, 1, 1],
+                [0, 0, 1, 0, 0, 1],
+            ]),
+        };
+        assert_eq!(ans, expected);
+
+        assert_ne!(solve_masyu(&problem), Some(expected));
+    }
+
+    #[test]
+    fn test_ura_masyu_serializer() {
+        let problem = ura_problem_for_tests();
+        let url = "https://puzz.link/p?ura-mashu/6/6/00j2090i9001";
+        util::tests::serializer_test(problem, url, serialize_ura_problem, deserialize_ura_problem);
+    }
 }
@@ -7,6 +7,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{any, Solver};
 
 pub fn solve_milktea(clues: &[Vec<i32>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_milktea_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_milktea_with_query(
+    clues: &[Vec<i32>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -157,7 +164,7 @@ pub fn solve_milktea(clues: &[Vec<i32>]) -> Option<graph::BoolGridEdgesIrrefutab
             solver.add_expr(is_center.at((y, x)).imp(any(pat)));
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 pub type Problem = Vec<Vec<i32>>;
@@ -181,6 +188,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct Milktea;
+
+impl util::Puzzle for Milktea {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_milktea_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
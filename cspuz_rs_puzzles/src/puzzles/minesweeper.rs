@@ -0,0 +1,121 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+/// Determines which cells must (or must not) contain a mine.
+///
+/// `clues[y][x]` is `Some(n)` if the cell `(y, x)` is revealed and `n` of its 8 neighbors
+/// contain a mine. Unlike most puzzles, the answer need not be unique: irrefutable facts
+/// show the cells whose status is forced by the clues.
+pub fn solve_minesweeper(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_minesweeper_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_minesweeper_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_mine = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_mine);
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_mine.at((y, x)));
+                if n >= 0 {
+                    solver.add_expr(
+                        is_mine
+                            .slice((
+                                y.saturating_sub(1)..(y + 2).min(h),
+                                x.saturating_sub(1)..(x + 2).min(w),
+                            ))
+                            .count_true()
+                            .eq(n),
+                    );
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_mine))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "minesweeper", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["minesweeper", "mines"], url)
+}
+
+pub struct Minesweeper;
+
+impl util::Puzzle for Minesweeper {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_minesweeper_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        vec![
+            vec![Some(1), None, Some(2), Some(1), None],
+            vec![None, Some(2), Some(2), None, Some(1)],
+            vec![None, Some(2), None, None, None],
+            vec![None, None, None, None, None],
+            vec![Some(0), None, Some(1), None, Some(1)],
+        ]
+    }
+
+    #[test]
+    fn test_minesweeper_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_minesweeper(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [0, 1, 0, 0, 0],
+            [0, 0, 0, 1, 0],
+            [1, 0, 0, 0, 0],
+            [0, 0, 1, 0, 1],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_minesweeper_partial() {
+        let problem = vec![vec![Some(1), None], vec![None, None]];
+        let ans = solve_minesweeper(&problem);
+        assert_eq!(ans, Some(vec![vec![Some(false), None], vec![None, None]]));
+    }
+
+    #[test]
+    fn test_minesweeper_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?minesweeper/5/5/1g21h22g1g2n0g1g1";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
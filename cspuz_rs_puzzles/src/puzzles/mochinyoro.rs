@@ -0,0 +1,141 @@
+use crate::puzzles::mochikoro::combinator;
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url, url_to_problem};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_mochinyoro(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_mochinyoro_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_mochinyoro_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    solver.add_expr(!is_black.conv2d_and((2, 2)));
+
+    // White cells are connected diagonally
+    let mut g = graph::Graph::new(h * w);
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 {
+                g.add_edge(y * w + x, (y + 1) * w + x);
+            }
+            if x < w - 1 {
+                g.add_edge(y * w + x, y * w + x + 1);
+            }
+            if y < h - 1 && x < w - 1 {
+                g.add_edge(y * w + x, (y + 1) * w + x + 1);
+            }
+            if y < h - 1 && x > 0 {
+                g.add_edge(y * w + x, (y + 1) * w + x - 1);
+            }
+        }
+    }
+    graph::active_vertices_connected(&mut solver, (!is_black).flatten(), &g);
+
+    let mut clue_pos = vec![];
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                clue_pos.push((y, x, n));
+            }
+        }
+    }
+
+    // 0: black cell, 1..=clue_pos.len(): white cell in the region of the corresponding clue,
+    // clue_pos.len() + 1: white cell in a region without any clue
+    let n_clues = clue_pos.len() as i32;
+    let group_id = solver.int_var_2d((h, w), 0, n_clues + 1);
+    solver.add_expr(is_black.iff(group_id.eq(0)));
+
+    solver.add_expr(
+        (!is_black.conv2d_or((2, 1))).imp(
+            group_id
+                .slice((..(h - 1), ..))
+                .eq(group_id.slice((1.., ..))),
+        ),
+    );
+    solver.add_expr(
+        (!is_black.conv2d_or((1, 2))).imp(
+            group_id
+                .slice((.., ..(w - 1)))
+                .eq(group_id.slice((.., 1..))),
+        ),
+    );
+
+    for (i, &(y, x, n)) in clue_pos.iter().enumerate() {
+        graph::active_vertices_connected_2d(&mut solver, group_id.eq((i + 1) as i32));
+        solver.add_expr(group_id.at((y, x)).eq((i + 1) as i32));
+        if n > 0 {
+            solver.add_expr(group_id.eq((i + 1) as i32).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "mochinyoro", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["mochinyoro"], url)
+}
+
+pub struct Mochinyoro;
+
+impl util::Puzzle for Mochinyoro {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_mochinyoro_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[1][4] = Some(2);
+        ret[4][0] = Some(10);
+        ret[4][2] = Some(7);
+        ret
+    }
+
+    #[test]
+    fn test_mochinyoro_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_mochinyoro(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 1, 0, 1],
+            [0, 1, 0, 0, 0],
+            [0, 1, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_mochinyoro_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?mochinyoro/5/5/o2pag7h";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -1,13 +1,17 @@
 pub mod akari;
+pub mod akari_mirror;
 pub mod akari_regions;
 pub mod akari_rgb;
 pub mod akichiwake;
 pub mod aqre;
 pub mod aquapelago;
+pub mod aquarium;
 pub mod araf;
 pub mod archipelago;
 pub mod ayeheya;
 pub mod barns;
+pub mod battleship;
+pub mod canal_view;
 pub mod castle_wall;
 pub mod cave;
 pub mod chainedb;
@@ -15,6 +19,7 @@ pub mod chocobanana;
 pub mod cocktail;
 pub mod coffeemilk;
 pub mod compass;
+pub mod context;
 pub mod coral;
 pub mod creek;
 pub mod cross_border_parity_loop;
@@ -22,7 +27,9 @@ pub mod crosswall;
 pub mod curvedata;
 pub mod dbchoco;
 pub mod doppelblock;
+pub mod dosun_fuwari;
 pub mod double_lits;
+pub mod easy_as_abc;
 pub mod evolmino;
 pub mod fillomino;
 pub mod firefly;
@@ -30,25 +37,40 @@ pub mod firewalk;
 pub mod fivecells;
 pub mod guidearrow;
 pub mod hashi;
+pub mod hebi_ichigo;
 pub mod herugolf;
+pub mod heteromino;
 pub mod heyawake;
 pub mod hidato;
+pub mod icebarn;
 pub mod icewalk;
 pub mod inverse_litso;
+pub mod kakurasu;
 pub mod kakuro;
+pub mod kazunori_room;
+pub mod koburin;
 pub mod kouchoku;
 pub mod kropki;
 pub mod kropki_pairs;
+pub mod kurochute;
+pub mod kuroshiro;
 pub mod kurotto;
 pub mod letter_weights;
 pub mod litherslink;
 pub mod lits;
 pub mod lohkous;
+pub mod look_air;
 pub mod loop_special;
+pub mod makaro;
 pub mod masyu;
+pub mod meandering_numbers;
 pub mod milktea;
+pub mod minesweeper;
+pub mod mochikoro;
+pub mod mochinyoro;
 pub mod moonsun;
 pub mod multiplication_link;
+pub mod nagareru_loop;
 pub mod nagenawa;
 pub mod nikoji;
 pub mod norinori;
@@ -56,22 +78,27 @@ pub mod nothree;
 pub mod nurikabe;
 pub mod nurimaze;
 pub mod nurimisaki;
+pub mod onsen;
 pub mod parrot_loop;
 pub mod pencils;
 pub mod polyominous;
 pub mod reflect;
 pub mod ringring;
 pub mod sasahigane;
+pub mod satogaeri;
 pub mod scrabble;
+pub mod scrin;
 pub mod seiza;
 pub mod shakashaka;
 pub mod shikaku;
 pub mod shimaguni;
 pub mod simpleloop;
 pub mod slalom;
+pub mod slant;
 pub mod slashpack;
 pub mod slicy;
 pub mod slitherlink;
+pub mod snake;
 pub mod sniping_arrow;
 pub mod soulmates;
 pub mod spokes;
@@ -80,10 +107,21 @@ pub mod star_battle;
 pub mod statue_park;
 pub mod stostone;
 pub mod sudoku;
+pub mod suguru;
+pub mod sukoro;
 pub mod tapa;
+pub mod tapa_loop;
+pub mod tasquare;
+pub mod tentaisho;
+pub mod tents;
 pub mod the_longest;
+pub mod thermometers;
+pub mod tilepaint;
 pub mod timebomb;
 pub mod tontonbeya;
 pub mod tricklayer;
+pub mod usoone;
+pub mod wataridori;
 pub mod yajilin;
 pub mod yajilin_regions;
+pub mod yinyang;
@@ -10,6 +10,14 @@ pub fn solve_moonsun(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Vec<i32>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_moonsun_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_moonsun_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<i32>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -38,7 +46,7 @@ pub fn solve_moonsun(
             }
         }
         if !(has_one || has_two) {
-            return None;
+            return vec![];
         }
         if !has_one {
             solver.add_expr(room_mode.at(i));
@@ -83,7 +91,7 @@ pub fn solve_moonsun(
         solver.add_expr(count_true(&room_entrance[i]).eq(2));
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<i32>>);
@@ -110,6 +118,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["moonsun"], url)
 }
 
+pub struct Moonsun;
+
+impl util::Puzzle for Moonsun {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_moonsun_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,6 +9,13 @@ use cspuz_rs::solver::{any, Solver, FALSE};
 pub fn solve_multiplication_link(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_multiplication_link_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_multiplication_link_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -103,7 +110,7 @@ pub fn solve_multiplication_link(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -128,6 +135,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct MultiplicationLink;
+
+impl util::Puzzle for MultiplicationLink {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_multiplication_link_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
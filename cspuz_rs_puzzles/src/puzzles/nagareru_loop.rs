@@ -0,0 +1,230 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::items::Arrow;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NagareruCell {
+    Empty,
+    /// A white arrow: the loop passes this cell straight in the direction of the arrow.
+    White(Arrow),
+    /// A black cell, optionally with an arrow blowing wind in its direction.
+    Black(Arrow),
+}
+
+fn arrow_offset(dir: Arrow) -> Option<(i32, i32)> {
+    match dir {
+        Arrow::Unspecified => None,
+        Arrow::Up => Some((-1, 0)),
+        Arrow::Down => Some((1, 0)),
+        Arrow::Left => Some((0, -1)),
+        Arrow::Right => Some((0, 1)),
+    }
+}
+
+pub fn solve_nagareru_loop(
+    cells: &[Vec<NagareruCell>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_nagareru_loop_with_query(cells, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_nagareru_loop_with_query(
+    cells: &[Vec<NagareruCell>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(cells);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    let is_passed = &graph::single_cycle_grid_edges(&mut solver, is_line);
+
+    // `direction` is true for leftward and upward edges
+    let direction = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    for y in 0..h {
+        for x in 0..w {
+            let mut inbound = vec![];
+            let mut outbound = vec![];
+            if y > 0 {
+                inbound.push(is_line.vertical.at((y - 1, x)) & !direction.vertical.at((y - 1, x)));
+                outbound.push(is_line.vertical.at((y - 1, x)) & direction.vertical.at((y - 1, x)));
+            }
+            if y < h - 1 {
+                inbound.push(is_line.vertical.at((y, x)) & direction.vertical.at((y, x)));
+                outbound.push(is_line.vertical.at((y, x)) & !direction.vertical.at((y, x)));
+            }
+            if x > 0 {
+                inbound
+                    .push(is_line.horizontal.at((y, x - 1)) & !direction.horizontal.at((y, x - 1)));
+                outbound
+                    .push(is_line.horizontal.at((y, x - 1)) & direction.horizontal.at((y, x - 1)));
+            }
+            if x < w - 1 {
+                inbound.push(is_line.horizontal.at((y, x)) & direction.horizontal.at((y, x)));
+                outbound.push(is_line.horizontal.at((y, x)) & !direction.horizontal.at((y, x)));
+            }
+            solver.add_expr(count_true(&inbound).eq(count_true(&outbound)));
+        }
+    }
+
+    // Requires that the line between `(y, x)` and its neighbor in the direction `(dy, dx)` (if exists)
+    // is directed toward the neighbor
+    let forward = |y: usize, x: usize, dy: i32, dx: i32| {
+        if dy == 0 {
+            let x2 = if dx < 0 { x - 1 } else { x };
+            (
+                is_line.horizontal.at((y, x2)),
+                direction.horizontal.at((y, x2)),
+                dx < 0,
+            )
+        } else {
+            let y2 = if dy < 0 { y - 1 } else { y };
+            (
+                is_line.vertical.at((y2, x)),
+                direction.vertical.at((y2, x)),
+                dy < 0,
+            )
+        }
+    };
+
+    for y in 0..h {
+        for x in 0..w {
+            match cells[y][x] {
+                NagareruCell::Empty => (),
+                NagareruCell::White(dir) => {
+                    solver.add_expr(is_passed.at((y, x)));
+                    let (dy, dx) = match arrow_offset(dir) {
+                        Some(d) => d,
+                        None => continue,
+                    };
+                    let (by, bx) = (y as i32 - dy, x as i32 - dx);
+                    let (fy, fx) = (y as i32 + dy, x as i32 + dx);
+                    if !(0 <= by && by < h as i32 && 0 <= bx && bx < w as i32)
+                        || !(0 <= fy && fy < h as i32 && 0 <= fx && fx < w as i32)
+                    {
+                        return vec![];
+                    }
+                    let (line, dir, neg) = forward(y, x, dy, dx);
+                    solver.add_expr(line & dir.iff(neg));
+                    let (line, dir, neg) = forward(by as usize, bx as usize, dy, dx);
+                    solver.add_expr(line & dir.iff(neg));
+                }
+                NagareruCell::Black(dir) => {
+                    solver.add_expr(!is_passed.at((y, x)));
+                    let (dy, dx) = match arrow_offset(dir) {
+                        Some(d) => d,
+                        None => continue,
+                    };
+
+                    // Cells blown by the wind, until a black cell or the border
+                    let mut ray = vec![];
+                    let (mut cy, mut cx) = (y as i32 + dy, x as i32 + dx);
+                    while 0 <= cy && cy < h as i32 && 0 <= cx && cx < w as i32 {
+                        if let NagareruCell::Black(_) = cells[cy as usize][cx as usize] {
+                            break;
+                        }
+                        ray.push((cy as usize, cx as usize));
+                        cy += dy;
+                        cx += dx;
+                    }
+                    // The loop must not go against the wind
+                    for i in 1..ray.len() {
+                        let (py, px) = ray[i - 1];
+                        let (line, dir, neg) = forward(py, px, dy, dx);
+                        solver.add_expr(line.imp(dir.iff(neg)));
+                    }
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+type Problem = Vec<Vec<NagareruCell>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Dict::new(NagareruCell::White(Arrow::Up), "1")),
+        Box::new(Dict::new(NagareruCell::White(Arrow::Down), "2")),
+        Box::new(Dict::new(NagareruCell::White(Arrow::Left), "3")),
+        Box::new(Dict::new(NagareruCell::White(Arrow::Right), "4")),
+        Box::new(Dict::new(NagareruCell::Black(Arrow::Unspecified), "5")),
+        Box::new(Dict::new(NagareruCell::Black(Arrow::Up), "6")),
+        Box::new(Dict::new(NagareruCell::Black(Arrow::Down), "7")),
+        Box::new(Dict::new(NagareruCell::Black(Arrow::Left), "8")),
+        Box::new(Dict::new(NagareruCell::Black(Arrow::Right), "9")),
+        Box::new(Spaces::new(NagareruCell::Empty, 'a')),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "nagare", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["nagare"], url)
+}
+
+pub struct NagareruLoop;
+
+impl util::Puzzle for NagareruLoop {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nagareru_loop_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![NagareruCell::Empty; 5]; 5];
+        ret[1][1] = NagareruCell::White(Arrow::Up);
+        ret[2][0] = NagareruCell::Black(Arrow::Right);
+        ret[3][0] = NagareruCell::Black(Arrow::Right);
+        ret[3][3] = NagareruCell::White(Arrow::Up);
+        ret
+    }
+
+    #[test]
+    fn test_nagareru_loop_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_nagareru_loop(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: util::tests::to_option_bool_2d([
+                [0, 1, 1, 1],
+                [0, 0, 1, 0],
+                [0, 0, 0, 0],
+                [0, 0, 0, 0],
+                [0, 1, 0, 1],
+            ]),
+            vertical: util::tests::to_option_bool_2d([
+                [0, 1, 0, 0, 1],
+                [0, 1, 1, 1, 1],
+                [0, 1, 1, 1, 1],
+                [0, 1, 1, 1, 1],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_nagareru_loop_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?nagare/5/5/f1c9d9b1f";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
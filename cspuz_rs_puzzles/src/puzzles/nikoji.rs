@@ -10,6 +10,13 @@ use cspuz_core::custom_constraints::SimpleCustomConstraint;
 pub fn solve_nikoji(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_nikoji_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_nikoji_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -133,7 +140,7 @@ pub fn solve_nikoji(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_border))
+    util::query_answers(solver, query, |f| f.get(is_border))
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -362,6 +369,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["nikoji"], url)
 }
 
+pub struct Nikoji;
+
+impl util::Puzzle for Nikoji {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nikoji_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url_with_context, url_to_problem, Combinator, Context, Rooms, Size,
@@ -7,6 +8,13 @@ use cspuz_rs::solver::{count_true, Solver};
 pub fn solve_norinori(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_norinori_with_query(borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_norinori_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = borders.base_shape();
 
     let mut solver = Solver::new();
@@ -29,7 +37,7 @@ pub fn solve_norinori(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = graph::InnerGridEdges<Vec<Vec<bool>>>;
@@ -53,6 +61,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["norinori"], url)
 }
 
+pub struct Norinori;
+
+impl util::Puzzle for Norinori {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_norinori_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::Solver;
 
 pub fn solve_nothree(clues: &[Vec<bool>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_nothree_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_nothree_with_query(
+    clues: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
     let h = (h + 1) / 2;
     let w = (w + 1) / 2;
@@ -65,7 +72,7 @@ pub fn solve_nothree(clues: &[Vec<bool>]) -> Option<Vec<Vec<Option<bool>>>> {
             }
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 type Problem = Vec<Vec<bool>>;
@@ -158,6 +165,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["nothree"], url)
 }
 
+pub struct Nothree;
+
+impl util::Puzzle for Nothree {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nothree_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,40 +3,66 @@ use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
 };
-use cspuz_rs::solver::{BoolVarArray2D, Solver};
+use cspuz_rs::solver::{any, BoolVarArray2D, Solver};
 
 pub fn solve_nurikabe(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_nurikabe_with_query(clues, None, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+/// Solves Nurikabe with an optional clue on the total number of islands.
+/// When `num_islands` is given, islands without a number are also allowed.
+pub fn solve_nurikabe_with_num_islands(
+    clues: &[Vec<Option<i32>>],
+    num_islands: Option<i32>,
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_nurikabe_with_query(clues, num_islands, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_nurikabe_with_query(
+    clues: &[Vec<Option<i32>>],
+    num_islands: Option<i32>,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
+    if let Some(n) = num_islands {
+        let num_clues = clues.iter().flatten().filter(|c| c.is_some()).count();
+        if n < num_clues as i32 || n as usize > h * w {
+            return vec![];
+        }
+    }
+
     let mut solver = Solver::new();
     let is_black = &solver.bool_var_2d((h, w));
     solver.add_answer_key_bool(is_black);
 
-    add_constraints(clues, &mut solver, is_black);
+    add_constraints(clues, num_islands, &mut solver, is_black);
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 pub fn enumerate_answers_nurikabe(
     clues: &[Vec<Option<i32>>],
     num_max_answers: usize,
 ) -> Vec<Vec<Vec<bool>>> {
-    let (h, w) = util::infer_shape(clues);
+    use util::Puzzle;
 
-    let mut solver = Solver::new();
-    let is_black = &solver.bool_var_2d((h, w));
-    solver.add_answer_key_bool(is_black);
-
-    add_constraints(clues, &mut solver, is_black);
-
-    solver
-        .answer_iter()
-        .take(num_max_answers)
-        .map(|f| f.get_unwrap(is_black))
+    Nurikabe::enumerate_answers(&clues.to_vec(), num_max_answers)
+        .into_iter()
+        .map(|ans| {
+            ans.into_iter()
+                .map(|row| row.into_iter().map(Option::unwrap).collect())
+                .collect()
+        })
         .collect()
 }
 
-fn add_constraints(clues: &[Vec<Option<i32>>], solver: &mut Solver, is_black: &BoolVarArray2D) {
+fn add_constraints(
+    clues: &[Vec<Option<i32>>],
+    num_islands: Option<i32>,
+    solver: &mut Solver,
+    is_black: &BoolVarArray2D,
+) {
     let (h, w) = util::infer_shape(clues);
 
     let mut clue_pos = vec![];
@@ -48,13 +74,18 @@ fn add_constraints(clues: &[Vec<Option<i32>>], solver: &mut Solver, is_black: &B
         }
     }
 
-    let group_id = solver.int_var_2d((h, w), 0, clue_pos.len() as i32);
+    // Islands without a number get ids after those of the numbered ones
+    let num_groups = num_islands.map_or(clue_pos.len(), |n| n as usize);
+    let group_id = solver.int_var_2d((h, w), 0, num_groups as i32);
     solver.add_expr(is_black.iff(group_id.eq(0)));
 
     graph::active_vertices_connected_2d(solver, is_black);
-    for i in 1..=clue_pos.len() {
+    for i in 1..=num_groups {
         graph::active_vertices_connected_2d(solver, group_id.eq(i as i32));
     }
+    for i in (clue_pos.len() + 1)..=num_groups {
+        solver.add_expr(any(group_id.eq(i as i32)));
+    }
 
     solver.add_expr(
         (!is_black.conv2d_or((2, 1))).imp(
@@ -98,6 +129,33 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["nurikabe"], url)
 }
 
+/// Nurikabe problem with an optional total number of islands.
+///
+/// puzz.link has no encoding of the total, so variant problems are not converted from or to URLs.
+pub type VariantProblem = (Option<i32>, Problem);
+
+pub struct Nurikabe;
+
+impl util::Puzzle for Nurikabe {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nurikabe_with_query(problem, None, query)
+    }
+}
+
+pub struct NurikabeVariant;
+
+impl util::Puzzle for NurikabeVariant {
+    type Problem = VariantProblem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &VariantProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nurikabe_with_query(&problem.1, problem.0, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,4 +214,52 @@ mod tests {
         });
         assert_eq!(ans, expected);
     }
+
+    fn variant_problem_for_tests() -> VariantProblem {
+        let mut clues = vec![vec![None; 5]; 5];
+        clues[0][1] = Some(5);
+        clues[1][2] = Some(1);
+        clues[4][4] = Some(2);
+        (Some(6), clues)
+    }
+
+    #[test]
+    fn test_nurikabe_num_islands_problem() {
+        let (num_islands, clues) = variant_problem_for_tests();
+        let ans = solve_nurikabe_with_num_islands(&clues, num_islands);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 1, 1, 0],
+            [0, 1, 0, 1, 1],
+            [0, 1, 1, 1, 0],
+            [0, 1, 0, 1, 1],
+            [1, 1, 1, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+
+        assert_eq!(solve_nurikabe_with_num_islands(&clues, Some(26)), None);
+    }
+
+    #[test]
+    fn test_nurikabe_num_islands_enumerate() {
+        use util::Puzzle;
+
+        let problem = variant_problem_for_tests();
+        let answers = NurikabeVariant::enumerate_answers(&problem, 10);
+        assert_eq!(answers, vec![NurikabeVariant::solve(&problem).unwrap()]);
+
+        // Without the total, the number of islands is that of the clues
+        let answers = NurikabeVariant::enumerate_answers(&(None, problem.1), 10);
+        assert!(answers.is_empty());
+    }
+
+    #[test]
+    fn test_nurikabe_unknown_clue_serializer() {
+        let url = "https://puzz.link/p?nurikabe/6/6/m8n8i.u";
+        let problem = deserialize_problem(url);
+        assert_eq!(problem.as_ref().map(|p| p[3][2]), Some(Some(-1)));
+        assert_eq!(serialize_problem(&problem.unwrap()), Some(url.to_string()));
+    }
 }
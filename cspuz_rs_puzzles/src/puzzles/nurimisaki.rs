@@ -6,6 +6,13 @@ use cspuz_rs::serializer::{
 use cspuz_rs::solver::{any, Solver};
 
 pub fn solve_nurimisaki(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_nurimisaki_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_nurimisaki_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -79,7 +86,7 @@ pub fn solve_nurimisaki(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<boo
             }
         }
     }
-    solver.irrefutable_facts().map(|f| f.get(is_white))
+    util::query_answers(solver, query, |f| f.get(is_white))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -100,6 +107,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["nurimisaki"], url)
 }
 
+pub struct Nurimisaki;
+
+impl util::Puzzle for Nurimisaki {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_nurimisaki_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,258 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::{any, count_true, BoolExpr, Solver};
+
+pub fn solve_onsen(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_onsen_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_onsen_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(clues);
+
+    let rooms = graph::borders_to_rooms(borders);
+    let mut room_id = vec![vec![0; w]; h];
+    for (i, room) in rooms.iter().enumerate() {
+        for &(y, x) in room {
+            room_id[y][x] = i;
+        }
+    }
+    let max_size = rooms.iter().map(|room| room.len()).max().unwrap_or(0) as i32;
+
+    let mut circles = vec![];
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                circles.push(((y, x), n));
+            }
+        }
+    }
+    let n_loops = circles.len() as i32;
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    // 0 means that the cell is not passed by any loop; otherwise the cell belongs to the loop
+    // passing through the `(id - 1)`-th circle
+    let loop_id = &solver.int_var_2d((h, w), 0, n_loops);
+    let is_passed = &loop_id.ne(0);
+
+    // The number of cells the loop passes in each room, and the position of each cell in the
+    // segment of the loop within a room
+    let size = &solver.int_var_1d(circles.len(), 1, max_size);
+    let cell_size = &solver.int_var_2d((h, w), 1, max_size);
+    let pos = &solver.int_var_2d((h, w), 1, max_size);
+
+    // Whether the loop lies entirely within the room of its circle
+    let is_closed = &solver.bool_var_1d(circles.len());
+
+    for (i, &((y, x), n)) in circles.iter().enumerate() {
+        let id = i as i32 + 1;
+        solver.add_expr(loop_id.at((y, x)).eq(id));
+        if n > 0 {
+            solver.add_expr(size.at(i).eq(n));
+        }
+        solver.add_expr(loop_id.eq(id).imp(cell_size.eq(size.at(i))));
+
+        for y2 in 0..h {
+            for x2 in 0..w {
+                if room_id[y2][x2] != room_id[y][x] {
+                    solver.add_expr(is_closed.at(i).imp(loop_id.at((y2, x2)).ne(id)));
+                }
+            }
+        }
+        solver.add_expr(
+            is_closed
+                .at(i)
+                .imp(loop_id.eq(id).count_true().eq(size.at(i))),
+        );
+
+        let (edges, g) = is_line.representation();
+        graph::active_vertices_connected_via_active_edges(
+            &mut solver,
+            &loop_id.eq(id).flatten(),
+            &edges,
+            &g,
+        );
+    }
+
+    for room in &rooms {
+        solver.add_expr(is_passed.select(room).any());
+    }
+
+    let cell_closed = |p: (usize, usize)| -> BoolExpr {
+        any(circles
+            .iter()
+            .enumerate()
+            .map(|(i, _)| loop_id.at(p).eq(i as i32 + 1) & is_closed.at(i)))
+    };
+
+    for y in 0..h {
+        for x in 0..w {
+            let p = (y, x);
+            solver.add_expr(count_true(is_line.vertex_neighbors(p)).eq(is_passed.at(p).ite(2, 0)));
+            solver.add_expr(is_passed.at(p).imp(pos.at(p).le(cell_size.at(p))));
+
+            let mut neighbors = vec![];
+            if y > 0 {
+                neighbors.push(((y - 1, x), is_line.vertical.at((y - 1, x))));
+            }
+            if y < h - 1 {
+                neighbors.push(((y + 1, x), is_line.vertical.at((y, x))));
+            }
+            if x > 0 {
+                neighbors.push(((y, x - 1), is_line.horizontal.at((y, x - 1))));
+            }
+            if x < w - 1 {
+                neighbors.push(((y, x + 1), is_line.horizontal.at((y, x))));
+            }
+
+            let is_end = pos.at(p).eq(1) | pos.at(p).eq(cell_size.at(p));
+            let mut inner = vec![];
+            for (q, e) in &neighbors {
+                solver.add_expr(e.imp(loop_id.at(p).eq(loop_id.at(*q))));
+                if room_id[q.0][q.1] != room_id[y][x] {
+                    // The loop leaves the room here
+                    solver.add_expr(e.imp(&is_end));
+                } else {
+                    let wrap = (pos.at(p).eq(1) & pos.at(*q).eq(cell_size.at(p)))
+                        | (pos.at(p).eq(cell_size.at(p)) & pos.at(*q).eq(1));
+                    solver.add_expr(e.imp(
+                        pos.at(*q).eq(pos.at(p) + 1)
+                            | pos.at(*q).eq(pos.at(p) - 1)
+                            | (cell_closed(p) & wrap),
+                    ));
+                    inner.push((*q, e.clone()));
+                }
+            }
+
+            // A segment consisting of a single cell
+            solver.add_expr(
+                (is_passed.at(p) & !any(inner.iter().map(|(_, e)| e.clone())))
+                    .imp(cell_size.at(p).eq(1)),
+            );
+
+            // Positions increase monotonically along a segment
+            for i in 0..inner.len() {
+                for j in (i + 1)..inner.len() {
+                    solver.add_expr(
+                        (&inner[i].1 & &inner[j].1).imp(pos.at(inner[i].0).ne(pos.at(inner[j].0))),
+                    );
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Optionalize::new(HexInt)),
+            Box::new(Spaces::new(None, 'g')),
+            Box::new(Dict::new(Some(-1), ".")),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.0.vertical.len();
+    let width = problem.0.vertical[0].len() + 1;
+    problem_to_url_with_context(
+        combinator(),
+        "onsen",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["onsen"], url)
+}
+
+pub struct Onsen;
+
+impl util::Puzzle for Onsen {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_onsen_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            vec![
+                vec![None, Some(4), None, None],
+                vec![None, None, None, None],
+                vec![Some(3), None, None, None],
+                vec![None, None, None, None],
+            ]
+        )
+    }
+
+    #[test]
+    fn test_onsen_problem() {
+        let (borders, clues) = problem_for_tests();
+        let ans = solve_onsen(&borders, &clues);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::GridEdges {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [1, 1, 1],
+                [1, 1, 1],
+                [1, 1, 0],
+                [1, 1, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 0, 0, 1],
+                [0, 0, 0, 0],
+                [1, 0, 1, 0],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_onsen_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?onsen/4/4/9ag1p8g4l3m";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -9,6 +9,13 @@ use cspuz_rs::solver::{any, Solver};
 pub fn solve_parrot_loop(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_parrot_loop_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_parrot_loop_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -66,7 +73,7 @@ pub fn solve_parrot_loop(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -92,6 +99,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct ParrotLoop;
+
+impl util::Puzzle for ParrotLoop {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_parrot_loop_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,6 +38,17 @@ pub fn solve_pencils(
     Vec<Vec<Option<PencilsAnswer>>>,
     graph::BoolGridEdgesIrrefutableFacts,
     graph::BoolInnerGridEdgesIrrefutableFacts,
+)> {
+    solve_pencils_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_pencils_with_query(
+    clues: &[Vec<PencilsClue>],
+    query: util::AnswerQuery,
+) -> Vec<(
+    Vec<Vec<Option<PencilsAnswer>>>,
+    graph::BoolGridEdgesIrrefutableFacts,
+    graph::BoolInnerGridEdgesIrrefutableFacts,
 )> {
     let (h, w) = util::infer_shape(clues);
 
@@ -290,7 +301,7 @@ pub fn solve_pencils(
         }
     }
 
-    solver.irrefutable_facts().map(|f| {
+    util::query_answers(solver, query, |f| {
         (
             f.get(cell_answer)
                 .into_iter()
@@ -334,6 +345,21 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["pencils"], url)
 }
 
+pub struct Pencils;
+
+impl util::Puzzle for Pencils {
+    type Problem = Problem;
+    type Answer = (
+        Vec<Vec<Option<PencilsAnswer>>>,
+        graph::BoolGridEdgesIrrefutableFacts,
+        graph::BoolInnerGridEdgesIrrefutableFacts,
+    );
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_pencils_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
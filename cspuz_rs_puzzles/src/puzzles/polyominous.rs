@@ -78,6 +78,14 @@ pub fn solve_pentominous(
     clues: &[Vec<Option<i32>>],
     default_borders: &Option<graph::InnerGridEdges<Vec<Vec<bool>>>>,
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_pentominous_with_query(clues, default_borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_pentominous_with_query(
+    clues: &[Vec<Option<i32>>],
+    default_borders: &Option<graph::InnerGridEdges<Vec<Vec<bool>>>>,
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -191,7 +199,7 @@ pub fn solve_pentominous(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(&is_border))
+    util::query_answers(solver, query, |f| f.get(&is_border))
 }
 
 type Problem = (
@@ -227,6 +235,17 @@ pub fn deserialize_pentominous_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["pentominous"], url)
 }
 
+pub struct Polyominous;
+
+impl util::Puzzle for Polyominous {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_pentominous_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +42,13 @@ impl ReflectLinkClue {
 pub fn solve_reflect_link(
     clues: &[Vec<ReflectLinkClue>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_reflect_link_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_reflect_link_with_query(
+    clues: &[Vec<ReflectLinkClue>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -98,7 +105,7 @@ pub fn solve_reflect_link(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<ReflectLinkClue>>;
@@ -123,6 +130,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["reflect"], url)
 }
 
+pub struct Reflect;
+
+impl util::Puzzle for Reflect {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_reflect_link_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
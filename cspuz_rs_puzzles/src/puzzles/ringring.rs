@@ -4,6 +4,13 @@ use cspuz_rs::serializer::strip_prefix;
 use cspuz_rs::solver::{Solver, FALSE};
 
 pub fn solve_ringring(is_black: &[Vec<bool>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_ringring_with_query(is_black, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_ringring_with_query(
+    is_black: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(is_black);
 
     let mut solver = Solver::new();
@@ -79,7 +86,7 @@ pub fn solve_ringring(is_black: &[Vec<bool>]) -> Option<graph::BoolGridEdgesIrre
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<bool>>;
@@ -123,6 +130,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(ret)
 }
 
+pub struct Ringring;
+
+impl util::Puzzle for Ringring {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_ringring_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
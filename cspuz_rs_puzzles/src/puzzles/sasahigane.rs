@@ -18,10 +18,17 @@ pub enum SashiganeClue {
 pub fn solve_sashigane(
     clues: &[Vec<Option<SashiganeClue>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_sashigane_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_sashigane_with_query(
+    clues: &[Vec<Option<SashiganeClue>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     if h <= 1 || w <= 1 {
-        return None;
+        return vec![];
     }
 
     let mut solver = Solver::new();
@@ -143,7 +150,7 @@ pub fn solve_sashigane(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(edges))
+    util::query_answers(solver, query, |f| f.get(edges))
 }
 
 type Problem = Vec<Vec<Option<SashiganeClue>>>;
@@ -177,6 +184,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["sashigane"], url)
 }
 
+pub struct Sasahigane;
+
+impl util::Puzzle for Sasahigane {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_sashigane_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
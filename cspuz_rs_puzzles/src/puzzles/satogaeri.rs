@@ -0,0 +1,242 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::{any, count_true, BoolExpr, Solver};
+
+/// Solves a Satogaeri problem.
+///
+/// `clues[y][x]` is `Some(n)` if there is a circle numbered `n` at `(y, x)`, and `Some(-1)` if the circle
+/// has no number. Returns the paths of circles and whether each cell holds a circle after the moves.
+pub fn solve_satogaeri(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_satogaeri_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_satogaeri_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+    let is_goal = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_goal);
+
+    // Candidate moves of circles: each of them covers some cells and ends at a cell
+    let mut covering: Vec<Vec<Vec<BoolExpr>>> = vec![vec![vec![]; w]; h];
+    let mut edge_horizontal: Vec<Vec<Vec<BoolExpr>>> = vec![vec![vec![]; w - 1]; h];
+    let mut edge_vertical: Vec<Vec<Vec<BoolExpr>>> = vec![vec![vec![]; w]; h - 1];
+    let mut goal: Vec<Vec<Vec<BoolExpr>>> = vec![vec![vec![]; w]; h];
+
+    for y in 0..h {
+        for x in 0..w {
+            let n = match clues[y][x] {
+                Some(n) => n,
+                None => continue,
+            };
+
+            let mut moves = vec![];
+
+            if n <= 0 {
+                let stay = solver.bool_var();
+                covering[y][x].push(stay.expr());
+                goal[y][x].push(stay.expr());
+                moves.push(stay.expr());
+            }
+            if n == 0 {
+                solver.add_expr(any(moves));
+                continue;
+            }
+
+            for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let mut cells = vec![(y, x)];
+                loop {
+                    let (py, px) = cells[cells.len() - 1];
+                    let ny = py as i32 + dy;
+                    let nx = px as i32 + dx;
+                    if !(0 <= ny && ny < h as i32 && 0 <= nx && nx < w as i32) {
+                        break;
+                    }
+                    let (ny, nx) = (ny as usize, nx as usize);
+                    // Circles cannot pass through (or stop at) other circles
+                    if clues[ny][nx].is_some() {
+                        break;
+                    }
+                    cells.push((ny, nx));
+                    let dist = cells.len() as i32 - 1;
+                    if n > 0 && dist > n {
+                        break;
+                    }
+                    if n > 0 && dist < n {
+                        continue;
+                    }
+
+                    let mv = solver.bool_var();
+                    for i in 0..cells.len() {
+                        let (cy, cx) = cells[i];
+                        covering[cy][cx].push(mv.expr());
+                        if i > 0 {
+                            let (qy, qx) = cells[i - 1];
+                            if cy == qy {
+                                edge_horizontal[cy][cx.min(qx)].push(mv.expr());
+                            } else {
+                                edge_vertical[cy.min(qy)][cx].push(mv.expr());
+                            }
+                        }
+                    }
+                    goal[ny][nx].push(mv.expr());
+                    moves.push(mv.expr());
+                }
+            }
+
+            solver.add_expr(count_true(moves).eq(1));
+        }
+    }
+
+    for y in 0..h {
+        for x in 0..w {
+            solver.add_expr(count_true(&covering[y][x]).le(1));
+            solver.add_expr(is_goal.at((y, x)).iff(any(&goal[y][x])));
+            if x < w - 1 {
+                solver.add_expr(
+                    is_line
+                        .horizontal
+                        .at((y, x))
+                        .iff(any(&edge_horizontal[y][x])),
+                );
+            }
+            if y < h - 1 {
+                solver.add_expr(is_line.vertical.at((y, x)).iff(any(&edge_vertical[y][x])));
+            }
+        }
+    }
+
+    let rooms = graph::borders_to_rooms(borders);
+    for room in &rooms {
+        solver.add_expr(is_goal.select(room).count_true().eq(1));
+    }
+
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(is_goal)))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Optionalize::new(HexInt)),
+            Box::new(Spaces::new(None, 'g')),
+            Box::new(Dict::new(Some(-1), ".")),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator(),
+        "satogaeri",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["satogaeri", "sato"], url)
+}
+
+pub struct Satogaeri;
+
+impl util::Puzzle for Satogaeri {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_satogaeri_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 1, 1],
+                    [1, 1, 0, 0, 0],
+                    [0, 1, 1, 1, 0],
+                    [0, 0, 0, 1, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0, 0],
+                    [0, 1, 1, 0],
+                    [0, 1, 1, 0],
+                    [1, 0, 0, 1],
+                    [1, 0, 1, 0],
+                ]),
+            },
+            vec![
+                vec![Some(0), None, None, None, None],
+                vec![None, None, Some(0), Some(-1), Some(3)],
+                vec![None, Some(1), None, Some(1), None],
+                vec![None, None, None, None, None],
+                vec![None, None, None, None, None],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_satogaeri_problem() {
+        let (borders, clues) = problem_for_tests();
+        let ans = solve_satogaeri(&borders, &clues);
+        assert!(ans.is_some());
+        let (is_line, is_goal) = ans.unwrap();
+
+        let expected_line = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [0, 0, 0, 0],
+                [0, 0, 0, 0],
+                [1, 0, 0, 0],
+                [0, 0, 0, 0],
+                [0, 0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 1],
+                [0, 0, 0, 1, 1],
+                [0, 0, 0, 0, 1],
+            ]),
+        };
+        assert_eq!(is_line, expected_line);
+
+        let expected_goal = crate::util::tests::to_option_bool_2d([
+            [1, 0, 0, 0, 0],
+            [0, 0, 1, 1, 0],
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 1],
+        ]);
+        assert_eq!(is_goal, expected_goal);
+    }
+
+    #[test]
+    fn test_satogaeri_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?satogaeri/5/5/8pkq3oe30l0.3g1g1q";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
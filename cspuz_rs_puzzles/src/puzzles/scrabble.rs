@@ -8,6 +8,23 @@ pub fn solve_scrabble(
     num_chars: i32,
     all_shown: bool,
 ) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_scrabble_with_query(
+        board,
+        words,
+        num_chars,
+        all_shown,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_scrabble_with_query(
+    board: &[Vec<Option<i32>>],
+    words: &[Vec<i32>],
+    num_chars: i32,
+    all_shown: bool,
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(board);
 
     let mut solver = Solver::new();
@@ -16,7 +33,7 @@ pub fn solve_scrabble(
 
     add_constraints(&mut solver, answer, board, words, num_chars, all_shown);
 
-    solver.irrefutable_facts().map(|f| f.get(answer))
+    util::query_answers(solver, query, |f| f.get(answer))
 }
 
 pub fn enumerate_answers_scrabble(
@@ -153,6 +170,17 @@ fn add_constraints(
     }
 }
 
+pub struct Scrabble;
+
+impl util::Puzzle for Scrabble {
+    type Problem = (Vec<Vec<Option<i32>>>, Vec<Vec<i32>>, i32, bool);
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Self::Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_scrabble_with_query(&problem.0, &problem.1, problem.2, problem.3, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
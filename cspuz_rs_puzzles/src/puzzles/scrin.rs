@@ -0,0 +1,209 @@
+use crate::puzzles::mochikoro::combinator;
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url, url_to_problem};
+use cspuz_rs::solver::{any, count_true, Solver};
+
+pub fn solve_scrin(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_scrin_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_scrin_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    // Each black region is a rectangle
+    for y in 0..(h - 1) {
+        for x in 0..(w - 1) {
+            solver.add_expr(is_black.slice((y..(y + 2), x..(x + 2))).count_true().ne(3));
+        }
+    }
+
+    // Rectangles are connected diagonally
+    let mut g = graph::Graph::new(h * w);
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 {
+                g.add_edge(y * w + x, (y + 1) * w + x);
+            }
+            if x < w - 1 {
+                g.add_edge(y * w + x, y * w + x + 1);
+            }
+            if y < h - 1 && x < w - 1 {
+                g.add_edge(y * w + x, (y + 1) * w + x + 1);
+            }
+            if y < h - 1 && x > 0 {
+                g.add_edge(y * w + x, (y + 1) * w + x - 1);
+            }
+        }
+    }
+    graph::active_vertices_connected(&mut solver, is_black.flatten(), &g);
+
+    // Each rectangle touches exactly two other rectangles at its corners, so that the
+    // rectangles form a single loop. `touch[i]` is true if the i-th corner (top-left,
+    // top-right, bottom-left and bottom-right) of the rectangle touches another rectangle.
+    // The value is shared among all cells of the rectangle.
+    let touch = [
+        &solver.bool_var_2d((h, w)),
+        &solver.bool_var_2d((h, w)),
+        &solver.bool_var_2d((h, w)),
+        &solver.bool_var_2d((h, w)),
+    ];
+    let corners = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+    let mut n_corners = vec![];
+    for y in 0..h {
+        for x in 0..w {
+            let p = (y, x);
+            for i in 0..4 {
+                let (dy, dx) = corners[i];
+                if y < h - 1 {
+                    solver.add_expr(
+                        (is_black.at(p) & is_black.at((y + 1, x)))
+                            .imp(touch[i].at(p).iff(touch[i].at((y + 1, x)))),
+                    );
+                }
+                if x < w - 1 {
+                    solver.add_expr(
+                        (is_black.at(p) & is_black.at((y, x + 1)))
+                            .imp(touch[i].at(p).iff(touch[i].at((y, x + 1)))),
+                    );
+                }
+
+                let is_corner = is_black.at(p)
+                    & !is_black.at_offset(p, (dy, 0), false)
+                    & !is_black.at_offset(p, (0, dx), false);
+                solver.add_expr(is_corner.clone().imp(touch[i].at(p).iff(is_black.at_offset(
+                    p,
+                    (dy, dx),
+                    false,
+                ))));
+                if i == 0 {
+                    n_corners.push(is_corner);
+                }
+            }
+            solver.add_expr(
+                is_black
+                    .at(p)
+                    .imp(count_true(touch.iter().map(|t| t.at(p))).eq(2)),
+            );
+        }
+    }
+
+    let mut clue_pos = vec![];
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                clue_pos.push((y, x, n));
+            }
+        }
+    }
+
+    // Each rectangle contains exactly one circle
+    solver.add_expr(count_true(n_corners).eq(clue_pos.len() as i32));
+    for i in 0..clue_pos.len() {
+        let (y, x, n) = clue_pos[i];
+        solver.add_expr(is_black.at((y, x)));
+
+        if n > 0 {
+            let rect_up = is_black
+                .slice_fixed_x((..y, x))
+                .reverse()
+                .consecutive_prefix_true();
+            let rect_down = is_black
+                .slice_fixed_x(((y + 1).., x))
+                .consecutive_prefix_true();
+            let rect_height = rect_up + rect_down + 1;
+
+            let rect_left = is_black
+                .slice_fixed_y((y, ..x))
+                .reverse()
+                .consecutive_prefix_true();
+            let rect_right = is_black
+                .slice_fixed_y((y, (x + 1)..))
+                .consecutive_prefix_true();
+            let rect_width = rect_left + rect_right + 1;
+
+            let mut cand = vec![];
+            for a in 1..=n {
+                if n % a == 0 {
+                    let b = n / a;
+                    cand.push(rect_height.eq(a) & rect_width.eq(b));
+                }
+            }
+            solver.add_expr(any(cand));
+        }
+
+        for &(y2, x2, _) in &clue_pos[..i] {
+            let (ya, yb) = (y.min(y2), y.max(y2));
+            let (xa, xb) = (x.min(x2), x.max(x2));
+            solver.add_expr(!is_black.slice((ya..=yb, xa..=xb)).all());
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "scrin", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["scrin"], url)
+}
+
+pub struct Scrin;
+
+impl util::Puzzle for Scrin {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_scrin_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[0][2] = Some(3);
+        ret[2][0] = Some(-1);
+        ret[2][4] = Some(-1);
+        ret[4][2] = Some(3);
+        ret
+    }
+
+    #[test]
+    fn test_scrin_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_scrin(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 1, 1, 1, 0],
+            [1, 0, 0, 0, 1],
+            [1, 0, 0, 0, 1],
+            [1, 0, 0, 0, 1],
+            [0, 1, 1, 1, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_scrin_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?scrin/5/5/h3m.i.m3h";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -11,6 +11,21 @@ pub fn solve_seiza(
     num: &[Vec<Option<i32>>],
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
 ) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_seiza_with_query(
+        absent_cell,
+        num,
+        borders,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_seiza_with_query(
+    absent_cell: &[Vec<bool>],
+    num: &[Vec<Option<i32>>],
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
     let (h, w) = util::infer_shape(absent_cell);
 
     let mut solver = Solver::new();
@@ -115,7 +130,7 @@ pub fn solve_seiza(
             if let Some(c) = num[y][x] {
                 if let Some(cc) = n {
                     if cc != c {
-                        return None;
+                        return vec![];
                     }
                 } else {
                     n = Some(c);
@@ -137,9 +152,7 @@ pub fn solve_seiza(
     let (is_line_flat, conn) = is_line.representation();
     graph::active_vertices_connected(&mut solver, is_line_flat, &conn.line_graph());
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(is_star)))
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(is_star)))
 }
 
 pub type Problem = (
@@ -189,6 +202,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some((absent_cell, num, border))
 }
 
+pub struct Seiza;
+
+impl util::Puzzle for Seiza {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_seiza_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
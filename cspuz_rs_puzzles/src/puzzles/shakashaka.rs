@@ -14,6 +14,13 @@ pub enum ShakashakaCell {
 }
 
 pub fn solve_shakashaka(problem: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<ShakashakaCell>>>> {
+    solve_shakashaka_with_query(problem, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_shakashaka_with_query(
+    problem: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<ShakashakaCell>>>> {
     let (h, w) = util::infer_shape(problem);
 
     // 1   2   3   4
@@ -118,7 +125,7 @@ pub fn solve_shakashaka(problem: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<S
         }
     }
 
-    solver.irrefutable_facts().map(|f| {
+    util::query_answers(solver, query, |f| {
         let model = f.get(ans);
         model
             .into_iter()
@@ -158,6 +165,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["shakashaka"], url)
 }
 
+pub struct Shakashaka;
+
+impl util::Puzzle for Shakashaka {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<ShakashakaCell>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_shakashaka_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,13 @@ use cspuz_rs::solver::{any, Solver};
 pub fn solve_shikaku(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_shikaku_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_shikaku_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -34,7 +41,7 @@ pub fn solve_shikaku(
     }
 
     if clue_pos.len() == 0 {
-        return None;
+        return vec![];
     }
 
     let ids = solver.int_var_2d((h, w), 0, clue_pos.len() as i32 - 1);
@@ -77,7 +84,7 @@ pub fn solve_shikaku(
             .iff(ids.slice((.., ..(w - 1))).ne(ids.slice((.., 1..)))),
     );
 
-    solver.irrefutable_facts().map(|f| f.get(edges))
+    util::query_answers(solver, query, |f| f.get(edges))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -98,6 +105,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["shikaku"], url)
 }
 
+pub struct Shikaku;
+
+impl util::Puzzle for Shikaku {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_shikaku_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,7 +3,16 @@ use cspuz_rs::graph;
 use cspuz_rs::serializer::{problem_to_url, url_to_problem, Combinator, Grid, Map, MultiDigit};
 use cspuz_rs::solver::Solver;
 
+/// Solves Simple Loop, also known as Pure Loop. puzz.link publishes both under the `simpleloop`
+/// URL kind.
 pub fn solve_simpleloop(is_black: &[Vec<bool>]) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_simpleloop_with_query(is_black, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_simpleloop_with_query(
+    is_black: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(is_black);
 
     let mut parity_diff = 0;
@@ -19,7 +28,7 @@ pub fn solve_simpleloop(is_black: &[Vec<bool>]) -> Option<graph::BoolGridEdgesIr
         }
     }
     if parity_diff != 0 {
-        return None;
+        return vec![];
     }
     let mut solver = Solver::new();
     let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
@@ -34,7 +43,7 @@ pub fn solve_simpleloop(is_black: &[Vec<bool>]) -> Option<graph::BoolGridEdgesIr
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 type Problem = Vec<Vec<bool>>;
@@ -55,6 +64,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["simpleloop"], url)
 }
 
+pub struct Simpleloop;
+
+impl util::Puzzle for Simpleloop {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_simpleloop_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +118,31 @@ mod tests {
         let url = "https://puzz.link/p?simpleloop/8/7/200200a42000";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    #[test]
+    fn test_simpleloop_visits_all_unshaded_cells() {
+        let url = "https://puzz.link/p?simpleloop/8/7/200200a42000";
+        let problem = deserialize_problem(url).unwrap();
+        let ans = solve_simpleloop(&problem).unwrap();
+
+        let (h, w) = util::infer_shape(&problem);
+        for (y, row) in problem.iter().enumerate() {
+            for (x, &is_black) in row.iter().enumerate() {
+                let mut degree = 0;
+                if y > 0 && ans.vertical[y - 1][x] == Some(true) {
+                    degree += 1;
+                }
+                if y + 1 < h && ans.vertical[y][x] == Some(true) {
+                    degree += 1;
+                }
+                if x > 0 && ans.horizontal[y][x - 1] == Some(true) {
+                    degree += 1;
+                }
+                if x + 1 < w && ans.horizontal[y][x] == Some(true) {
+                    degree += 1;
+                }
+                assert_eq!(degree, if is_black { 0 } else { 2 });
+            }
+        }
+    }
 }
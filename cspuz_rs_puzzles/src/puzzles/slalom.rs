@@ -22,6 +22,15 @@ pub fn solve_slalom(
     is_black: &[Vec<bool>],
     gates: &[Gate],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_slalom_with_query(origin, is_black, gates, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_slalom_with_query(
+    origin: (usize, usize),
+    is_black: &[Vec<bool>],
+    gates: &[Gate],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(is_black);
 
     let mut solver = Solver::new();
@@ -118,7 +127,7 @@ pub fn solve_slalom(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(line))
+    util::query_answers(solver, query, |f| f.get(line))
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -321,6 +330,17 @@ pub fn parse_primitive_problem(problem: &PrimitiveProblem) -> Problem {
     (is_black, gates, *origin)
 }
 
+pub struct Slalom;
+
+impl util::Puzzle for Slalom {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slalom_with_query(problem.2, &problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,180 @@
+use crate::puzzles::slitherlink::SlitherlinkClueCombinator;
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, Size, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver, TRUE};
+
+/// Solves a Slant (Gokigen Naname) puzzle.
+/// In the returned grid, `true` represents a backslash (`\`) and `false` represents a slash (`/`).
+pub fn solve_slant(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_slant_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_slant_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h1, w1) = util::infer_shape(clues);
+    let h = h1 - 1;
+    let w = w1 - 1;
+
+    let mut solver = Solver::new();
+    let is_backslash = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_backslash);
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                if n < 0 {
+                    continue;
+                }
+                let mut touching = vec![];
+                if y > 0 && x > 0 {
+                    touching.push(is_backslash.at((y - 1, x - 1)).expr());
+                }
+                if y > 0 && x < w {
+                    touching.push(!is_backslash.at((y - 1, x)));
+                }
+                if y < h && x > 0 {
+                    touching.push(!is_backslash.at((y, x - 1)));
+                }
+                if y < h && x < w {
+                    touching.push(is_backslash.at((y, x)).expr());
+                }
+                solver.add_expr(count_true(touching).eq(n));
+            }
+        }
+    }
+
+    // Diagonals do not form any cycle. This is equivalent to the condition that every part of
+    // the board separated by the diagonals is connected to the outside of the board.
+    // Each cell is divided into 4 triangles (0: top, 1: right, 2: bottom, 3: left).
+    let triangle = |y: usize, x: usize, d: usize| (y * w + x) * 4 + d;
+    let outside = h * w * 4;
+    let mut g = graph::Graph::new(h * w * 4 + 1);
+    let mut is_active_edge = vec![];
+    for y in 0..h {
+        for x in 0..w {
+            let b = &is_backslash.at((y, x));
+
+            g.add_edge(triangle(y, x, 0), triangle(y, x, 1));
+            is_active_edge.push(b.expr());
+            g.add_edge(triangle(y, x, 2), triangle(y, x, 3));
+            is_active_edge.push(b.expr());
+            g.add_edge(triangle(y, x, 0), triangle(y, x, 3));
+            is_active_edge.push(!b);
+            g.add_edge(triangle(y, x, 1), triangle(y, x, 2));
+            is_active_edge.push(!b);
+
+            if y == 0 {
+                g.add_edge(triangle(y, x, 0), outside);
+            } else {
+                g.add_edge(triangle(y, x, 0), triangle(y - 1, x, 2));
+            }
+            is_active_edge.push(TRUE);
+            if x == 0 {
+                g.add_edge(triangle(y, x, 3), outside);
+            } else {
+                g.add_edge(triangle(y, x, 3), triangle(y, x - 1, 1));
+            }
+            is_active_edge.push(TRUE);
+            if y == h - 1 {
+                g.add_edge(triangle(y, x, 2), outside);
+                is_active_edge.push(TRUE);
+            }
+            if x == w - 1 {
+                g.add_edge(triangle(y, x, 1), outside);
+                is_active_edge.push(TRUE);
+            }
+        }
+    }
+    graph::active_vertices_connected_via_active_edges(
+        &mut solver,
+        vec![TRUE; h * w * 4 + 1],
+        is_active_edge,
+        &g,
+    );
+
+    util::query_answers(solver, query, |f| f.get(is_backslash))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::with_offset(
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(SlitherlinkClueCombinator),
+            Box::new(Spaces::new(None, 'g')),
+            Box::new(Dict::new(Some(-1), ".")),
+        ])),
+        1,
+    )
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = util::infer_shape(problem);
+    problem_to_url_with_context(
+        combinator(),
+        "gokigen",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["gokigen"], url)
+}
+
+pub struct Slant;
+
+impl util::Puzzle for Slant {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slant_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        vec![
+            vec![Some(1), None, Some(1), Some(1), None],
+            vec![None, None, None, None, Some(0)],
+            vec![Some(0), Some(3), None, Some(1), Some(1)],
+            vec![None, None, None, None, None],
+            vec![Some(1), None, Some(2), None, Some(0)],
+        ]
+    }
+
+    #[test]
+    fn test_slant_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_slant(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 0, 0, 0],
+            [1, 1, 0, 1],
+            [0, 1, 1, 1],
+            [0, 1, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_slant_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?gokigen/4/4/61bi0081bi670";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -10,6 +10,13 @@ pub const SLASHPACK_SLASH: i32 = 1;
 pub const SLASHPACK_BACKSLASH: i32 = 2;
 
 pub fn solve_slashpack(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_slashpack_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_slashpack_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -29,7 +36,7 @@ pub fn solve_slashpack(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>
 
     let count_num = clue_pos.len() as i32;
     if max_num == 0 || count_num % max_num != 0 {
-        return None;
+        return vec![];
     }
 
     let n_rooms = count_num / max_num;
@@ -135,7 +142,7 @@ pub fn solve_slashpack(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(ans))
+    util::query_answers(solver, query, |f| f.get(ans))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -156,6 +163,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["slashpack"], url)
 }
 
+pub struct Slashpack;
+
+impl util::Puzzle for Slashpack {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slashpack_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
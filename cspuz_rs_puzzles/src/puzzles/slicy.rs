@@ -1,9 +1,17 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::hex::{borders_to_rooms, BoolHexGridIrrefutableFacts, HexGrid, HexInnerGridEdges};
 use cspuz_rs::serializer::get_kudamono_url_info_detailed;
 use cspuz_rs::solver::{all, any, Solver};
 
 pub fn solve_slicy(borders: &HexInnerGridEdges<bool>) -> Option<BoolHexGridIrrefutableFacts> {
+    solve_slicy_with_query(borders, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_slicy_with_query(
+    borders: &HexInnerGridEdges<bool>,
+    query: util::AnswerQuery,
+) -> Vec<BoolHexGridIrrefutableFacts> {
     let mut solver = Solver::new();
     let is_black = &HexGrid::new(&mut solver, borders.dims);
     solver.add_answer_key_bool(is_black.flatten());
@@ -118,7 +126,7 @@ pub fn solve_slicy(borders: &HexInnerGridEdges<bool>) -> Option<BoolHexGridIrref
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 fn get_piece_variants() -> Vec<Vec<Vec<(i32, i32)>>> {
@@ -305,6 +313,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     Some(ret)
 }
 
+pub struct Slicy;
+
+impl util::Puzzle for Slicy {
+    type Problem = Problem;
+    type Answer = BoolHexGridIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slicy_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,53 @@
+use crate::puzzles::castle_wall::Side;
 use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     from_base16, problem_to_url, to_base16, url_to_problem, Choice, Combinator, Context, Grid,
     Spaces,
 };
-use cspuz_rs::solver::Solver;
+use cspuz_rs::solver::{Solver, FALSE};
 
 pub fn solve_slitherlink(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_slitherlink_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_slitherlink_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h, w));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    add_constraints(&mut solver, is_line, clues);
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+/// Solves Slitherlink with sheep (`Side::Inside`) and wolves (`Side::Outside`) in addition to
+/// the usual number clues.
+pub fn solve_slitherlink_sheep_wolves(
+    clues: &[Vec<Option<i32>>],
+    sides: &[Vec<Side>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_slitherlink_sheep_wolves_with_query(clues, sides, util::AnswerQuery::IrrefutableFacts)
+        .pop()
+}
+
+fn solve_slitherlink_sheep_wolves_with_query(
+    clues: &[Vec<Option<i32>>],
+    sides: &[Vec<Side>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
+    if util::infer_shape(sides) != (h, w) {
+        return vec![];
+    }
 
     let mut solver = Solver::new();
     let is_line = &graph::BoolGridEdges::new(&mut solver, (h, w));
@@ -18,7 +56,49 @@ pub fn solve_slitherlink(
 
     add_constraints(&mut solver, is_line, clues);
 
-    solver.irrefutable_facts().map(|f| f.get(is_line))
+    let is_inside = &solver.bool_var_2d((h, w));
+    for y in 0..=h {
+        for x in 0..w {
+            let a = if y == 0 {
+                FALSE
+            } else {
+                is_inside.at((y - 1, x)).expr()
+            };
+            let b = if y == h {
+                FALSE
+            } else {
+                is_inside.at((y, x)).expr()
+            };
+            solver.add_expr(is_line.horizontal.at((y, x)) ^ a.iff(b));
+        }
+    }
+    for y in 0..h {
+        for x in 0..=w {
+            let a = if x == 0 {
+                FALSE
+            } else {
+                is_inside.at((y, x - 1)).expr()
+            };
+            let b = if x == w {
+                FALSE
+            } else {
+                is_inside.at((y, x)).expr()
+            };
+            solver.add_expr(is_line.vertical.at((y, x)) ^ a.iff(b));
+        }
+    }
+
+    for y in 0..h {
+        for x in 0..w {
+            match sides[y][x] {
+                Side::Unspecified => (),
+                Side::Inside => solver.add_expr(is_inside.at((y, x))),
+                Side::Outside => solver.add_expr(!is_inside.at((y, x))),
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
 }
 
 pub fn enumerate_answers_slitherlink(
@@ -109,6 +189,33 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["slither", "slitherlink"], url)
 }
 
+/// Sheep and wolves Slitherlink problem: the number clues and the sheep (`Side::Inside`) and
+/// wolves (`Side::Outside`). puzz.link has no encoding of this variant, so it is not converted
+/// from or to URLs.
+pub type SheepWolvesProblem = (Vec<Vec<Option<i32>>>, Vec<Vec<Side>>);
+
+pub struct Slitherlink;
+
+impl util::Puzzle for Slitherlink {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slitherlink_with_query(problem, query)
+    }
+}
+
+pub struct SlitherlinkSheepWolves;
+
+impl util::Puzzle for SlitherlinkSheepWolves {
+    type Problem = SheepWolvesProblem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &SheepWolvesProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_slitherlink_sheep_wolves_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +252,43 @@ mod tests {
         };
         assert_eq!(ans, expected);
     }
+
+    fn sheep_wolves_problem_for_tests() -> SheepWolvesProblem {
+        let mut clues = vec![vec![None; 4]; 4];
+        clues[0][0] = Some(3);
+        clues[1][0] = Some(3);
+        clues[2][1] = Some(2);
+        clues[3][3] = Some(1);
+        let mut sides = vec![vec![Side::Unspecified; 4]; 4];
+        sides[0][1] = Side::Inside;
+        sides[1][2] = Side::Outside;
+        sides[3][0] = Side::Inside;
+        (clues, sides)
+    }
+
+    #[test]
+    fn test_slitherlink_sheep_wolves_problem() {
+        let (clues, sides) = sheep_wolves_problem_for_tests();
+        assert_eq!(enumerate_answers_slitherlink(&clues, 2).len(), 2);
+
+        let ans = solve_slitherlink_sheep_wolves(&clues, &sides);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        let expected = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [1, 1, 1, 1],
+                [1, 0, 1, 0],
+                [1, 0, 0, 0],
+                [0, 1, 0, 1],
+                [1, 0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 0, 0, 0, 1],
+                [0, 1, 1, 1, 1],
+                [1, 0, 1, 1, 1],
+                [1, 1, 0, 0, 0],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
 }
@@ -0,0 +1,305 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{BoolVarArray2D, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnakeClue {
+    None,
+    Endpoint,
+    Body,
+}
+
+pub fn solve_snake(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<SnakeClue>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_snake_with_length(None, clues_up, clues_left, cells)
+}
+
+/// Solves a Snake problem. `length`, if given, is the number of cells of the snake.
+pub fn solve_snake_with_length(
+    length: Option<i32>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<SnakeClue>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_snake_with_query(
+        length,
+        clues_up,
+        clues_left,
+        cells,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_snake_with_query(
+    length: Option<i32>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<SnakeClue>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let mut solver = Solver::new();
+    let is_snake = match add_constraints(&mut solver, length, clues_up, clues_left, cells) {
+        Some(is_snake) => is_snake,
+        None => return vec![],
+    };
+    solver.add_answer_key_bool(&is_snake);
+
+    util::query_answers(solver, query, |f| f.get(&is_snake))
+}
+
+fn add_constraints(
+    solver: &mut Solver,
+    length: Option<i32>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<SnakeClue>],
+) -> Option<BoolVarArray2D> {
+    if cells.is_empty() || cells[0].is_empty() {
+        return None;
+    }
+    let (h, w) = util::infer_shape(cells);
+    if clues_up.len() != w || clues_left.len() != h {
+        return None;
+    }
+
+    let is_snake = solver.bool_var_2d((h, w));
+    let is_end = &solver.bool_var_2d((h, w));
+
+    graph::active_vertices_connected_2d(solver, &is_snake);
+    solver.add_expr(is_end.imp(&is_snake));
+    solver.add_expr(is_end.count_true().eq(2));
+    if let Some(length) = length {
+        solver.add_expr(is_snake.count_true().eq(length));
+    }
+
+    for (y, row) in cells.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            match clue {
+                SnakeClue::None => (),
+                SnakeClue::Endpoint => solver.add_expr(is_end.at((y, x))),
+                SnakeClue::Body => solver.add_expr(is_snake.at((y, x)) & !is_end.at((y, x))),
+            }
+
+            let n_neighbors = is_snake.four_neighbors((y, x)).count_true();
+            solver.add_expr((is_snake.at((y, x)) & is_end.at((y, x))).imp(n_neighbors.eq(1)));
+            solver.add_expr((is_snake.at((y, x)) & !is_end.at((y, x))).imp(n_neighbors.eq(2)));
+        }
+    }
+
+    // The snake does not touch itself diagonally
+    for y in 0..(h - 1) {
+        for x in 0..(w - 1) {
+            solver.add_expr(
+                !(is_snake.at((y, x))
+                    & is_snake.at((y + 1, x + 1))
+                    & !is_snake.at((y, x + 1))
+                    & !is_snake.at((y + 1, x))),
+            );
+            solver.add_expr(
+                !(is_snake.at((y, x + 1))
+                    & is_snake.at((y + 1, x))
+                    & !is_snake.at((y, x))
+                    & !is_snake.at((y + 1, x + 1))),
+            );
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_snake.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_snake.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    Some(is_snake)
+}
+
+/// Snake problem: the clues above and left of the grid, and the clues in the cells.
+pub type Problem = (Vec<Option<i32>>, Vec<Option<i32>>, Vec<Vec<SnakeClue>>);
+
+/// A Snake problem with the length of the snake, if given. puzz.link has no encoding of the
+/// length, so variant problems are not converted from or to URLs.
+pub type VariantProblem = (Option<i32>, Problem);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+fn cell_combinator() -> impl Combinator<SnakeClue> {
+    Choice::new(vec![
+        Box::new(Dict::new(SnakeClue::Endpoint, "1")),
+        Box::new(Dict::new(SnakeClue::Body, "2")),
+        Box::new(Spaces::new(SnakeClue::None, 'a')),
+    ])
+}
+
+pub struct SnakeCombinator;
+
+impl Combinator<Problem> for SnakeCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let mut ret = vec![];
+        let surrounding = [&problem.0[..], &problem.1[..]].concat();
+        ret.extend(
+            Seq::new(clue_combinator(), width + height)
+                .serialize(ctx, &[surrounding])?
+                .1,
+        );
+        ret.extend(
+            ContextBasedGrid::new(cell_combinator())
+                .serialize(ctx, std::slice::from_ref(&problem.2))?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        let cells =
+            sequencer.deserialize_one_elem(ctx, ContextBasedGrid::new(cell_combinator()))?;
+
+        Some((sequencer.n_read(), vec![(clues_up, clues_left, cells)]))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(SnakeCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.1.len();
+    let width = problem.0.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "snake",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["snake"], url)
+}
+
+pub struct Snake;
+
+impl util::Puzzle for Snake {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_snake_with_query(None, &problem.0, &problem.1, &problem.2, query)
+    }
+}
+
+pub struct SnakeVariant;
+
+impl util::Puzzle for SnakeVariant {
+    type Problem = VariantProblem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &VariantProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        let (length, (clues_up, clues_left, cells)) = problem;
+        solve_snake_with_query(*length, clues_up, clues_left, cells, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{self, Puzzle};
+
+    fn problem_for_tests() -> Problem {
+        let mut cells = vec![vec![SnakeClue::None; 5]; 5];
+        cells[0][0] = SnakeClue::Endpoint;
+        cells[4][4] = SnakeClue::Endpoint;
+        (
+            vec![None, Some(3), None, Some(1), None],
+            vec![Some(3), None, Some(3), None, None],
+            cells,
+        )
+    }
+
+    #[test]
+    fn test_snake_problem() {
+        let (clues_up, clues_left, cells) = problem_for_tests();
+        let ans = solve_snake(&clues_up, &clues_left, &cells);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 1, 1, 0, 0],
+            [0, 0, 1, 0, 0],
+            [1, 1, 1, 0, 0],
+            [1, 0, 0, 0, 0],
+            [1, 1, 1, 1, 1],
+        ]);
+        assert_eq!(ans, expected);
+
+        let answers = Snake::enumerate_answers(&problem_for_tests(), 10);
+        assert_eq!(answers, vec![expected]);
+    }
+
+    #[test]
+    fn test_snake_length() {
+        let (clues_up, clues_left, cells) = problem_for_tests();
+        assert!(solve_snake_with_length(Some(13), &clues_up, &clues_left, &cells).is_some());
+        assert!(solve_snake_with_length(Some(11), &clues_up, &clues_left, &cells).is_none());
+
+        let mut cells = vec![vec![SnakeClue::None; 4]; 4];
+        cells[0][0] = SnakeClue::Endpoint;
+        cells[3][3] = SnakeClue::Endpoint;
+        let problem = (vec![None; 4], vec![None; 4], cells);
+        assert_eq!(
+            SnakeVariant::enumerate_answers(&(Some(7), problem.clone()), 100).len(),
+            20
+        );
+        for ans in SnakeVariant::enumerate_answers(&(Some(9), problem), 100) {
+            let num_cells = ans.iter().flatten().filter(|&&b| b == Some(true)).count();
+            assert_eq!(num_cells, 9);
+        }
+
+        assert!(solve_snake(&[], &[], &[]).is_none());
+    }
+
+    #[test]
+    fn test_snake_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?snake/5/5/g3g1g3g3h1w1";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -12,6 +12,16 @@ pub fn solve_sniping_arrow(
 ) -> Option<(
     graph::BoolGridEdgesIrrefutableFacts,
     Vec<Vec<Option<Arrow>>>,
+)> {
+    solve_sniping_arrow_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_sniping_arrow_with_query(
+    clues: &[Vec<Option<(Option<i32>, Option<Arrow>)>>],
+    query: util::AnswerQuery,
+) -> Vec<(
+    graph::BoolGridEdgesIrrefutableFacts,
+    Vec<Vec<Option<Arrow>>>,
 )> {
     let (h, w) = util::infer_shape(clues);
 
@@ -228,7 +238,7 @@ pub fn solve_sniping_arrow(
         }
     }
 
-    solver.irrefutable_facts().map(|f| {
+    util::query_answers(solver, query, |f| {
         let arrow = f
             .get(arrow_head)
             .into_iter()
@@ -334,6 +344,20 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct SnipingArrow;
+
+impl util::Puzzle for SnipingArrow {
+    type Problem = Problem;
+    type Answer = (
+        graph::BoolGridEdgesIrrefutableFacts,
+        Vec<Vec<Option<Arrow>>>,
+    );
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_sniping_arrow_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,13 @@ use cspuz_rs::solver::Solver;
 use cspuz_rs::graph;
 
 pub fn solve_soulmates(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_soulmates_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_soulmates_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let max_num = (h * w - 1) as i32;
@@ -103,7 +110,7 @@ pub fn solve_soulmates(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(num))
+    util::query_answers(solver, query, |f| f.get(num))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -127,6 +134,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct Soulmates;
+
+impl util::Puzzle for Soulmates {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_soulmates_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
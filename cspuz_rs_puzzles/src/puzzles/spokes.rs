@@ -12,6 +12,17 @@ pub fn solve_spokes(
     graph::BoolGridEdgesIrrefutableFacts,
     Vec<Vec<Option<bool>>>,
     Vec<Vec<Option<bool>>>,
+)> {
+    solve_spokes_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_spokes_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(
+    graph::BoolGridEdgesIrrefutableFacts,
+    Vec<Vec<Option<bool>>>,
+    Vec<Vec<Option<bool>>>,
 )> {
     let (h, w) = util::infer_shape(clues);
 
@@ -117,9 +128,9 @@ pub fn solve_spokes(
 
     graph::active_vertices_connected(&mut solver, &aux_edges, &aux_graph.line_graph());
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(diagonal_dr), f.get(diagonal_dl)))
+    util::query_answers(solver, query, |f| {
+        (f.get(is_line), f.get(diagonal_dr), f.get(diagonal_dl))
+    })
 }
 
 pub type Problem = Vec<Vec<Option<i32>>>;
@@ -143,6 +154,21 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct Spokes;
+
+impl util::Puzzle for Spokes {
+    type Problem = Problem;
+    type Answer = (
+        graph::BoolGridEdgesIrrefutableFacts,
+        Vec<Vec<Option<bool>>>,
+        Vec<Vec<Option<bool>>>,
+    );
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_spokes_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,13 @@ use cspuz_rs::solver::Solver;
 pub fn solve_square_jam(
     clues: &[Vec<Option<i32>>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_square_jam_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_square_jam_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -79,7 +86,7 @@ pub fn solve_square_jam(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(&is_border))
+    util::query_answers(solver, query, |f| f.get(&is_border))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -99,6 +106,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["squarejam"], url)
 }
 
+pub struct SquareJam;
+
+impl util::Puzzle for SquareJam {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_square_jam_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::solver::Solver;
 
 pub fn solve_star_battle(
@@ -5,6 +6,15 @@ pub fn solve_star_battle(
     k: i32,
     rooms: &[Vec<(usize, usize)>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_star_battle_with_query(n, k, rooms, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_star_battle_with_query(
+    n: usize,
+    k: i32,
+    rooms: &[Vec<(usize, usize)>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let mut solver = Solver::new();
     let has_star = solver.bool_var_2d((n, n));
     solver.add_answer_key_bool(&has_star);
@@ -22,7 +32,18 @@ pub fn solve_star_battle(
         solver.add_expr(has_star.select(room).count_true().eq(k));
     }
 
-    solver.irrefutable_facts().map(|f| f.get(&has_star))
+    util::query_answers(solver, query, |f| f.get(&has_star))
+}
+
+pub struct StarBattle;
+
+impl util::Puzzle for StarBattle {
+    type Problem = (usize, i32, Vec<Vec<(usize, usize)>>);
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Self::Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_star_battle_with_query(problem.0, problem.1, &problem.2, query)
+    }
 }
 
 #[cfg(test)]
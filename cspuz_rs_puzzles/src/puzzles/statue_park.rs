@@ -17,6 +17,14 @@ pub fn solve_statue_park(
     board: &[Vec<StatueParkClue>],
     pieces: &[Vec<Vec<bool>>],
 ) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_statue_park_with_query(board, pieces, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_statue_park_with_query(
+    board: &[Vec<StatueParkClue>],
+    pieces: &[Vec<Vec<bool>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(board);
     let (pieces_merged, cnts) = normalize_and_merge_pieces(pieces);
 
@@ -144,7 +152,7 @@ pub fn solve_statue_park(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_block))
+    util::query_answers(solver, query, |f| f.get(is_block))
 }
 
 fn rotate_piece_90(piece: &[Vec<bool>]) -> Vec<Vec<bool>> {
@@ -468,6 +476,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["statuepark"], url)
 }
 
+pub struct StatuePark;
+
+impl util::Puzzle for StatuePark {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_statue_park_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,68 +1,62 @@
 use crate::util;
 use cspuz_rs::serializer::{
-    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+    problem_to_url, url_to_problem, Choice, Combinator, DecInt, Dict, Grid, HexInt, Map,
+    Optionalize, PrefixAndSuffix, Spaces, Tuple2,
 };
-use cspuz_rs::solver::Solver;
+use cspuz_rs::solver::{IntVarArray2D, Solver};
+
+/// Returns the default block size `(block_height, block_width)` of an `n`x`n` sudoku.
+///
+/// Blocks are chosen as close to square as possible, with the height not exceeding the width
+/// (e.g. 2x3 blocks for 6x6, 3x4 blocks for 12x12). Returns `None` if no such block exists.
+pub fn default_block_size(n: usize) -> Option<(usize, usize)> {
+    let bh = (2..=n)
+        .take_while(|&d| d * d <= n)
+        .filter(|&d| n.is_multiple_of(d))
+        .last()?;
+    Some((bh, n / bh))
+}
 
 pub fn solve_sudoku(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>> {
-    let (h, w) = util::infer_shape(clues);
-    if h != w {
-        return None;
-    }
-    let n = h;
-    let (bh, bw) = match n {
-        4 => (2, 2),
-        6 => (2, 3),
-        9 => (3, 3),
-        16 => (4, 4),
-        25 => (5, 5),
-        _ => return None,
-    };
+    let block_size = default_block_size(clues.len())?;
+    solve_sudoku_with_block_size(clues, block_size)
+}
+
+pub fn solve_sudoku_with_block_size(
+    clues: &[Vec<Option<i32>>],
+    block_size: (usize, usize),
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_sudoku_with_query(clues, block_size, util::AnswerQuery::IrrefutableFacts).pop()
+}
 
+fn solve_sudoku_with_query(
+    clues: &[Vec<Option<i32>>],
+    block_size: (usize, usize),
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let mut solver = Solver::new();
-    let num = &solver.int_var_2d((n, n), 1, n as i32);
+    let num = &match add_constraints(&mut solver, clues, block_size) {
+        Some(num) => num,
+        None => return vec![],
+    };
     solver.add_answer_key_int(num);
 
-    for i in 0..n {
-        solver.all_different(num.slice_fixed_y((i, ..)));
-        solver.all_different(num.slice_fixed_x((.., i)));
-    }
-    for i in 0..bw {
-        for j in 0..bh {
-            solver
-                .all_different(num.slice((((i * bh)..((i + 1) * bh)), ((j * bw)..((j + 1) * bw)))));
-        }
-    }
-    for y in 0..n {
-        for x in 0..n {
-            if let Some(val) = clues[y][x] {
-                if val > 0 {
-                    solver.add_expr(num.at((y, x)).eq(val));
-                }
-            }
-        }
-    }
-
-    solver.irrefutable_facts().map(|f| f.get(num))
+    util::query_answers(solver, query, |f| f.get(num))
 }
 
 pub fn solve_sudoku_as_cands(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Vec<bool>>>> {
-    let (h, w) = util::infer_shape(clues);
-    if h != w {
-        return None;
-    }
-    let n = h;
-    let (bh, bw) = match n {
-        4 => (2, 2),
-        6 => (2, 3),
-        9 => (3, 3),
-        16 => (4, 4),
-        25 => (5, 5),
-        _ => return None,
-    };
+    let block_size = default_block_size(clues.len())?;
+    solve_sudoku_as_cands_with_block_size(clues, block_size)
+}
+
+pub fn solve_sudoku_as_cands_with_block_size(
+    clues: &[Vec<Option<i32>>],
+    block_size: (usize, usize),
+) -> Option<Vec<Vec<Vec<bool>>>> {
+    let n = clues.len();
 
     let mut solver = Solver::new();
-    let num = &solver.int_var_2d((n, n), 1, n as i32);
+    let num = &add_constraints(&mut solver, clues, block_size)?;
     let mut cands = vec![];
     for _ in 0..n {
         let mut row = vec![];
@@ -82,6 +76,38 @@ pub fn solve_sudoku_as_cands(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Vec<b
         }
     }
 
+    solver.irrefutable_facts().map(|f| {
+        let mut ret = vec![];
+        for y in 0..n {
+            let mut row = vec![];
+            for x in 0..n {
+                row.push(
+                    f.get(&cands[y][x])
+                        .into_iter()
+                        .map(|x| x.unwrap_or(true))
+                        .collect(),
+                );
+            }
+            ret.push(row);
+        }
+        ret
+    })
+}
+
+fn add_constraints(
+    solver: &mut Solver,
+    clues: &[Vec<Option<i32>>],
+    block_size: (usize, usize),
+) -> Option<IntVarArray2D> {
+    let (h, w) = util::infer_shape(clues);
+    let (bh, bw) = block_size;
+    if h != w || bh * bw != h {
+        return None;
+    }
+    let n = h;
+
+    let num = solver.int_var_2d((n, n), 1, n as i32);
+
     for i in 0..n {
         solver.all_different(num.slice_fixed_y((i, ..)));
         solver.all_different(num.slice_fixed_x((.., i)));
@@ -102,22 +128,7 @@ pub fn solve_sudoku_as_cands(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Vec<b
         }
     }
 
-    solver.irrefutable_facts().map(|f| {
-        let mut ret = vec![];
-        for y in 0..n {
-            let mut row = vec![];
-            for x in 0..n {
-                row.push(
-                    f.get(&cands[y][x])
-                        .into_iter()
-                        .map(|x| x.unwrap_or(true))
-                        .collect(),
-                );
-            }
-            ret.push(row);
-        }
-        ret
-    })
+    Some(num)
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -138,6 +149,80 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["sudoku"], url)
 }
 
+/// A Sudoku problem with its block size `(block_height, block_width)`.
+pub type ExtendedProblem = (Problem, (usize, usize));
+
+fn block_size_combinator() -> impl Combinator<(usize, usize)> {
+    Map::new(
+        Tuple2::new(
+            PrefixAndSuffix::new("/", DecInt, ""),
+            PrefixAndSuffix::new("/", DecInt, ""),
+        ),
+        |(bh, bw): (usize, usize)| Some((bh as i32, bw as i32)),
+        |(bh, bw): (i32, i32)| Some((bh as usize, bw as usize)),
+    )
+}
+
+/// The standard format followed by `/<block_height>/<block_width>`, which is omitted for the
+/// default block size.
+fn extended_combinator() -> impl Combinator<(Problem, Option<(usize, usize)>)> {
+    Tuple2::new(
+        combinator(),
+        Choice::new(vec![
+            Box::new(Optionalize::new(block_size_combinator())),
+            Box::new(Dict::new(None, "")),
+        ]),
+    )
+}
+
+pub fn serialize_extended_problem(problem: &ExtendedProblem) -> Option<String> {
+    let (clues, block_size) = problem;
+    let (bh, bw) = *block_size;
+    if bh * bw != clues.len() {
+        return None;
+    }
+    let block_size = if default_block_size(clues.len()) == Some(*block_size) {
+        None
+    } else {
+        Some(*block_size)
+    };
+    problem_to_url(extended_combinator(), "sudoku", (clues.clone(), block_size))
+}
+
+pub fn deserialize_extended_problem(url: &str) -> Option<ExtendedProblem> {
+    let (clues, block_size) = url_to_problem(extended_combinator(), &["sudoku"], url)?;
+    let block_size = match block_size {
+        Some(block_size) => block_size,
+        None => default_block_size(clues.len())?,
+    };
+    Some((clues, block_size))
+}
+
+pub struct Sudoku;
+
+impl util::Puzzle for Sudoku {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        match default_block_size(problem.len()) {
+            Some(block_size) => solve_sudoku_with_query(problem, block_size, query),
+            None => vec![],
+        }
+    }
+}
+
+pub struct SudokuWithBlockSize;
+
+impl util::Puzzle for SudokuWithBlockSize {
+    type Problem = ExtendedProblem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &ExtendedProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_sudoku_with_query(&problem.0, problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,10 +263,111 @@ mod tests {
         assert_eq!(ans, expected);
     }
 
+    #[test]
+    fn test_sudoku_enumerate_answers() {
+        use util::Puzzle;
+
+        let problem = problem_for_tests();
+        let answers = Sudoku::enumerate_answers(&problem, 10);
+        assert_eq!(answers, vec![Sudoku::solve(&problem).unwrap()]);
+
+        let problem = vec![vec![None; 4]; 4];
+        let answers = Sudoku::enumerate_answers(&problem, 10);
+        assert_eq!(answers.len(), 10);
+        for i in 0..answers.len() {
+            assert!(answers[i].iter().flatten().all(|n| n.is_some()));
+            for j in 0..i {
+                assert_ne!(answers[i], answers[j]);
+            }
+        }
+    }
+
     #[test]
     fn test_sudoku_serializer() {
         let problem = problem_for_tests();
         let url = "https://puzz.link/p?sudoku/9/9/k8g1g7i2i99o2g3h75q19h5g4o83i4i6g4g5k";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    fn to_clues<const N: usize>(clues: [[i32; N]; N]) -> Problem {
+        clues
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&n| if n > 0 { Some(n) } else { None })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sudoku_default_block_size() {
+        assert_eq!(default_block_size(4), Some((2, 2)));
+        assert_eq!(default_block_size(6), Some((2, 3)));
+        assert_eq!(default_block_size(12), Some((3, 4)));
+        assert_eq!(default_block_size(16), Some((4, 4)));
+        assert_eq!(default_block_size(7), None);
+    }
+
+    #[test]
+    fn test_sudoku_6x6() {
+        let problem = to_clues([
+            [0, 2, 0, 4, 5, 0],
+            [0, 0, 6, 0, 2, 0],
+            [0, 0, 0, 0, 6, 0],
+            [3, 6, 0, 0, 0, 5],
+            [0, 3, 0, 0, 0, 0],
+            [0, 4, 0, 5, 3, 1],
+        ]);
+        let ans = solve_sudoku(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        assert!(ans.iter().flatten().all(|n| n.is_some()));
+
+        let url = "https://puzz.link/p?sudoku/6/6/g2g45i6g2k6g36i5g3k4g531";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+
+    #[test]
+    fn test_sudoku_custom_block_size() {
+        let problem = to_clues([
+            [1, 0, 0, 0, 0, 6],
+            [3, 0, 0, 6, 4, 0],
+            [4, 0, 0, 0, 1, 0],
+            [0, 1, 0, 3, 0, 5],
+            [0, 0, 6, 1, 0, 0],
+            [0, 0, 0, 5, 3, 0],
+        ]);
+        let ans = solve_sudoku_with_block_size(&problem, (3, 2));
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+        assert!(ans.iter().flatten().all(|n| n.is_some()));
+
+        assert_eq!(solve_sudoku_with_block_size(&problem, (4, 2)), None);
+
+        let url = "https://puzz.link/p?sudoku/6/6/1j63h64g4i1h1g3g5h61k53g/3/2";
+        util::tests::serializer_test(
+            (problem.clone(), (3, 2)),
+            url,
+            serialize_extended_problem,
+            deserialize_extended_problem,
+        );
+        assert_eq!(serialize_extended_problem(&(problem.clone(), (4, 2))), None);
+    }
+
+    #[test]
+    fn test_sudoku_extended_serializer_default_block_size() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?sudoku/9/9/k8g1g7i2i99o2g3h75q19h5g4o83i4i6g4g5k";
+        util::tests::serializer_test(
+            (problem, (3, 3)),
+            url,
+            serialize_extended_problem,
+            deserialize_extended_problem,
+        );
+        assert_eq!(
+            deserialize_extended_problem(&format!("{}/", url)).map(|p| p.1),
+            Some((3, 3))
+        );
+    }
 }
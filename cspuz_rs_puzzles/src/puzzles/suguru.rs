@@ -0,0 +1,143 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    HexInt, Optionalize, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_suguru(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_suguru_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_suguru_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let rooms = graph::borders_to_rooms(borders);
+    let max_size = rooms.iter().map(|room| room.len()).max().unwrap_or(0) as i32;
+
+    let mut solver = Solver::new();
+    let num = &solver.int_var_2d((h, w), 1, max_size);
+    solver.add_answer_key_int(num);
+
+    for room in &rooms {
+        solver.add_expr(num.select(room).le(room.len() as i32));
+        solver.all_different(num.select(room));
+    }
+
+    // Equal numbers do not touch each other, even diagonally
+    solver.add_expr(num.slice((..(h - 1), ..)).ne(num.slice((1.., ..))));
+    solver.add_expr(num.slice((.., ..(w - 1))).ne(num.slice((.., 1..))));
+    solver.add_expr(num.slice((..(h - 1), ..(w - 1))).ne(num.slice((1.., 1..))));
+    solver.add_expr(num.slice((..(h - 1), 1..)).ne(num.slice((1.., ..(w - 1)))));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                if n > 0 {
+                    solver.add_expr(num.at((y, x)).eq(n));
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Optionalize::new(HexInt)),
+            Box::new(Spaces::new(None, 'g')),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.0.vertical.len();
+    let width = problem.0.vertical[0].len() + 1;
+    problem_to_url_with_context(
+        combinator(),
+        "tectonic",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["tectonic", "suguru"], url)
+}
+
+pub struct Suguru;
+
+impl util::Puzzle for Suguru {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_suguru_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            vec![
+                vec![Some(1), None, Some(1), Some(2)],
+                vec![Some(2), None, None, None],
+                vec![None, None, Some(1), None],
+                vec![Some(2), None, None, None],
+            ]
+        )
+    }
+
+    #[test]
+    fn test_suguru_problem() {
+        let (borders, clues) = problem_for_tests();
+        let ans = solve_suguru(&borders, &clues);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_2d([
+            [1, 4, 1, 2],
+            [2, 3, 5, 3],
+            [1, 4, 1, 4],
+            [2, 3, 2, 3],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_suguru_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tectonic/4/4/9ag1p81g122k1g2i";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
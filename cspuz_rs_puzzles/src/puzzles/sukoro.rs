@@ -0,0 +1,123 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_sukoro(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_sukoro_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_sukoro_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    // 0 means that the cell is empty
+    let num = &solver.int_var_2d((h, w), 0, 4);
+    solver.add_answer_key_int(num);
+
+    let has_number = &num.ne(0);
+    graph::active_vertices_connected_2d(&mut solver, has_number);
+
+    solver.add_expr(
+        (has_number.slice((..(h - 1), ..)) & has_number.slice((1.., ..)))
+            .imp(num.slice((..(h - 1), ..)).ne(num.slice((1.., ..)))),
+    );
+    solver.add_expr(
+        (has_number.slice((.., ..(w - 1))) & has_number.slice((.., 1..)))
+            .imp(num.slice((.., ..(w - 1))).ne(num.slice((.., 1..)))),
+    );
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            solver.add_expr(
+                has_number.at((y, x)).imp(
+                    num.at((y, x))
+                        .eq(has_number.four_neighbors((y, x)).count_true()),
+                ),
+            );
+            if let Some(n) = clue {
+                if n > 0 {
+                    solver.add_expr(num.at((y, x)).eq(n));
+                } else {
+                    solver.add_expr(has_number.at((y, x)));
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(num))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "sukoro", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["sukoro"], url)
+}
+
+pub struct Sukoro;
+
+impl util::Puzzle for Sukoro {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_sukoro_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        vec![
+            vec![Some(1), None, None, Some(3), None],
+            vec![Some(3), None, None, None, None],
+            vec![None, Some(4), None, None, Some(2)],
+            vec![None, None, None, None, Some(1)],
+            vec![None, Some(1), None, None, None],
+        ]
+    }
+
+    #[test]
+    fn test_sukoro_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_sukoro(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_2d([
+            [1, 0, 1, 3, 1],
+            [3, 2, 0, 2, 0],
+            [2, 4, 2, 3, 2],
+            [0, 2, 0, 0, 1],
+            [0, 1, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_sukoro_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?sukoro/5/5/1h3g3k4h2j1g1i";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
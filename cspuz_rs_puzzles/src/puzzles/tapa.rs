@@ -4,9 +4,9 @@ use cspuz_rs::serializer::{
     from_base36, problem_to_url, to_base36, url_to_problem, Choice, Combinator, Context, Grid,
     Optionalize, Spaces,
 };
-use cspuz_rs::solver::{all, any, count_true, Solver, FALSE};
+use cspuz_rs::solver::{all, any, count_true, BoolExpr, Solver, FALSE};
 
-const EIGHT_NEIGHBORS: [(i32, i32); 8] = [
+pub(crate) const EIGHT_NEIGHBORS: [(i32, i32); 8] = [
     (-1, -1),
     (-1, 0),
     (-1, 1),
@@ -17,7 +17,56 @@ const EIGHT_NEIGHBORS: [(i32, i32); 8] = [
     (0, -1),
 ];
 
+/// Adds the constraints of a Tapa clue `clue` on the runs (blocks of consecutive cells) around a cell.
+/// `run(s, l)` should be true iff there is a run of length `l` starting at (or right after) the `s`-th
+/// of the 8 surrounding cells, and `run_ends` should contain one expression for each `s` being true
+/// iff a run ends at the `s`-th cell. Special clues such as `0` and `8` must be handled by the caller.
+pub(crate) fn add_clue_constraints<F>(
+    solver: &mut Solver,
+    clue: &[i32; 4],
+    run: F,
+    run_ends: Vec<BoolExpr>,
+) where
+    F: Fn(usize, usize) -> BoolExpr,
+{
+    let mut clue_counts = [0; 9];
+    let mut total_clue_counts = 0;
+    let mut has_any = false;
+    for i in 0..4 {
+        if clue[i] != -1 {
+            assert!(clue[i] == -2 || 0 <= clue[i] && clue[i] <= 7);
+            if clue[i] == -2 {
+                has_any = true;
+            } else {
+                clue_counts[clue[i] as usize] += 1;
+            }
+            total_clue_counts += 1;
+        }
+    }
+
+    for l in 1..=8 {
+        if clue_counts[l] == 0 {
+            continue;
+        }
+        let conds = (0..8).map(|s| run(s, l)).collect::<Vec<_>>();
+        if has_any {
+            solver.add_expr(count_true(conds).ge(clue_counts[l]));
+        } else {
+            solver.add_expr(count_true(conds).eq(clue_counts[l]));
+        }
+    }
+
+    solver.add_expr(count_true(run_ends).eq(total_clue_counts));
+}
+
 pub fn solve_tapa(clues: &[Vec<Option<[i32; 4]>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_tapa_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tapa_with_query(
+    clues: &[Vec<Option<[i32; 4]>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -53,27 +102,10 @@ pub fn solve_tapa(clues: &[Vec<Option<[i32; 4]>>]) -> Option<Vec<Vec<Option<bool
                     continue;
                 }
 
-                let mut clue_counts = [0; 9];
-                let mut total_clue_counts = 0;
-                let mut has_any = false;
-                for i in 0..4 {
-                    if clue[i] != -1 {
-                        assert!(clue[i] == -2 || 0 <= clue[i] && clue[i] <= 7);
-                        if clue[i] == -2 {
-                            has_any = true;
-                        } else {
-                            clue_counts[clue[i] as usize] += 1;
-                        }
-                        total_clue_counts += 1;
-                    }
-                }
-
-                for l in 1..=8 {
-                    if clue_counts[l] == 0 {
-                        continue;
-                    }
-                    let mut conds = vec![];
-                    for s in 0..8 {
+                add_clue_constraints(
+                    &mut solver,
+                    &clue,
+                    |s, l| {
                         let mut cond = vec![
                             !(neighbors[s].clone()),
                             !(neighbors[(s + l + 1) % 8].clone()),
@@ -81,25 +113,17 @@ pub fn solve_tapa(clues: &[Vec<Option<[i32; 4]>>]) -> Option<Vec<Vec<Option<bool
                         for i in 0..l {
                             cond.push(neighbors[(s + i + 1) % 8].clone());
                         }
-                        conds.push(all(cond));
-                    }
-                    if has_any {
-                        solver.add_expr(count_true(conds).ge(clue_counts[l]));
-                    } else {
-                        solver.add_expr(count_true(conds).eq(clue_counts[l]));
-                    }
-                }
-
-                let mut unit_count = vec![];
-                for s in 0..8 {
-                    unit_count.push(&neighbors[s] & !&neighbors[(s + 1) % 8]);
-                }
-                solver.add_expr(count_true(unit_count).eq(total_clue_counts));
+                        all(cond)
+                    },
+                    (0..8)
+                        .map(|s| &neighbors[s] & !&neighbors[(s + 1) % 8])
+                        .collect(),
+                );
             }
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(is_black))
+    util::query_answers(solver, query, |f| f.get(is_black))
 }
 
 struct TapaClueCombinator;
@@ -189,7 +213,7 @@ impl Combinator<[i32; 4]> for TapaClueCombinator {
 
 pub type Problem = Vec<Vec<Option<[i32; 4]>>>;
 
-fn combinator() -> impl Combinator<Problem> {
+pub(crate) fn combinator() -> impl Combinator<Problem> {
     Grid::new(Choice::new(vec![
         Box::new(Optionalize::new(TapaClueCombinator)),
         Box::new(Spaces::new(None, 'g')),
@@ -204,6 +228,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["tapa"], url)
 }
 
+pub struct Tapa;
+
+impl util::Puzzle for Tapa {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tapa_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     pub use super::*;
@@ -0,0 +1,166 @@
+use super::tapa;
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url, url_to_problem};
+use cspuz_rs::solver::{all, any, count_true, Solver, FALSE};
+
+pub fn solve_tapa_loop(
+    clues: &[Vec<Option<[i32; 4]>>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_tapa_loop_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tapa_loop_with_query(
+    clues: &[Vec<Option<[i32; 4]>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    let is_passed = &graph::single_cycle_grid_edges(&mut solver, is_line);
+
+    let in_board = |y: i32, x: i32| 0 <= y && y < h as i32 && 0 <= x && x < w as i32;
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(clue) = cell {
+                solver.add_expr(!is_passed.at((y, x)));
+
+                // `passed[s]`: whether the loop passes the `s`-th surrounding cell
+                // `link[s]`: whether the loop directly connects the `s`-th and `(s + 1)`-th surrounding cells
+                let mut passed = vec![];
+                let mut link = vec![];
+                for s in 0..8 {
+                    let (dy1, dx1) = tapa::EIGHT_NEIGHBORS[s];
+                    let (dy2, dx2) = tapa::EIGHT_NEIGHBORS[(s + 1) % 8];
+                    let (y1, x1) = (y as i32 + dy1, x as i32 + dx1);
+                    let (y2, x2) = (y as i32 + dy2, x as i32 + dx2);
+
+                    if in_board(y1, x1) {
+                        passed.push(is_passed.at((y1 as usize, x1 as usize)).expr());
+                    } else {
+                        passed.push(FALSE);
+                    }
+
+                    if in_board(y1, x1) && in_board(y2, x2) {
+                        if y1 == y2 {
+                            let x = x1.min(x2) as usize;
+                            link.push(is_line.horizontal.at((y1 as usize, x)).expr());
+                        } else {
+                            let y = y1.min(y2) as usize;
+                            link.push(is_line.vertical.at((y, x1 as usize)).expr());
+                        }
+                    } else {
+                        link.push(FALSE);
+                    }
+                }
+
+                if clue[0] == -1 || clue[0] == 0 {
+                    solver.add_expr(!any(&passed));
+                    continue;
+                }
+                if clue[0] == 8 {
+                    // The segment may be either a path of 8 cells or a cycle around the clue
+                    solver.add_expr(all(&passed));
+                    solver.add_expr(count_true(link.iter().map(|e| !e)).le(1));
+                    continue;
+                }
+
+                tapa::add_clue_constraints(
+                    &mut solver,
+                    &clue,
+                    |s, l| {
+                        let mut cond = vec![
+                            passed[s].clone(),
+                            !(link[(s + 7) % 8].clone()),
+                            !(link[(s + l - 1) % 8].clone()),
+                        ];
+                        for i in 0..(l - 1) {
+                            cond.push(link[(s + i) % 8].clone());
+                        }
+                        all(cond)
+                    },
+                    (0..8).map(|s| &passed[s] & !&link[s]).collect(),
+                );
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+pub type Problem = tapa::Problem;
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(tapa::combinator(), "tapaloop", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(tapa::combinator(), &["tapaloop"], url)
+}
+
+pub struct TapaLoop;
+
+impl util::Puzzle for TapaLoop {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tapa_loop_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret: Problem = vec![vec![None; 6]; 6];
+
+        ret[1][1] = Some([7, -1, -1, -1]);
+        ret[1][3] = Some([3, -1, -1, -1]);
+        ret[3][3] = Some([2, -1, -1, -1]);
+        ret[4][3] = Some([1, -1, -1, -1]);
+        ret[5][1] = Some([2, -1, -1, -1]);
+
+        ret
+    }
+
+    #[test]
+    fn test_tapa_loop_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_tapa_loop(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::BoolGridEdgesIrrefutableFacts {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [1, 1, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+                [0, 1, 0, 0, 0],
+                [1, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 0, 1, 0, 0, 0],
+                [1, 0, 1, 0, 0, 0],
+                [1, 0, 1, 0, 0, 0],
+                [1, 1, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_tapa_loop_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tapaloop/6/6/m7g3q2k1i2j";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url, url_to_problem, Choice, Combinator, Dict, Grid, HexInt, Optionalize, Spaces,
+};
+use cspuz_rs::solver::{any, sum, Solver};
+
+pub fn solve_tasquare(clues: &[Vec<Option<i32>>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_tasquare_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tasquare_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    graph::active_vertices_connected_2d(&mut solver, !is_black);
+
+    // Each black region is a rectangle
+    for y in 0..(h - 1) {
+        for x in 0..(w - 1) {
+            solver.add_expr(is_black.slice((y..(y + 2), x..(x + 2))).count_true().ne(3));
+        }
+    }
+
+    // `size` is the side length of the square containing the cell (0 for white cells)
+    let max_size = h.min(w) as i32;
+    let size = &solver.int_var_2d((h, w), 0, max_size);
+    let area = &solver.int_var_2d((h, w), 0, max_size * max_size);
+    solver.add_expr(is_black.iff(size.ne(0)));
+    for y in 0..h {
+        for x in 0..w {
+            let width = is_black
+                .slice_fixed_y((y, ..x))
+                .reverse()
+                .consecutive_prefix_true()
+                + is_black
+                    .slice_fixed_y((y, (x + 1)..))
+                    .consecutive_prefix_true()
+                + 1;
+            let height = is_black
+                .slice_fixed_x((..y, x))
+                .reverse()
+                .consecutive_prefix_true()
+                + is_black
+                    .slice_fixed_x(((y + 1).., x))
+                    .consecutive_prefix_true()
+                + 1;
+            solver.add_expr(is_black.at((y, x)).imp(size.at((y, x)).eq(width)));
+            solver.add_expr(is_black.at((y, x)).imp(size.at((y, x)).eq(height)));
+            for s in 0..=max_size {
+                solver.add_expr(size.at((y, x)).eq(s).imp(area.at((y, x)).eq(s * s)));
+            }
+        }
+    }
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_black.at((y, x)));
+
+                let neighbors = is_black.four_neighbor_indices((y, x));
+                if n >= 0 {
+                    solver.add_expr(sum(neighbors.iter().map(|&p| area.at(p))).eq(n));
+                } else {
+                    solver.add_expr(any(neighbors.iter().map(|&p| is_black.at(p))));
+                }
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = Vec<Vec<Option<i32>>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
+    ]))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "tasquare", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["tasquare"], url)
+}
+
+pub struct Tasquare;
+
+impl util::Puzzle for Tasquare {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tasquare_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![None; 5]; 5];
+        ret[0][2] = Some(1);
+        ret[0][3] = Some(4);
+        ret[1][0] = Some(4);
+        ret[1][2] = Some(4);
+        ret[2][2] = Some(8);
+        ret[4][1] = Some(4);
+        ret[4][3] = Some(0);
+        ret
+    }
+
+    #[test]
+    fn test_tasquare_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_tasquare(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [0, 1, 0, 0, 0],
+            [0, 0, 0, 1, 1],
+            [1, 1, 0, 1, 1],
+            [1, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_tasquare_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tasquare/5/5/h14g4g4j8n4g0g";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
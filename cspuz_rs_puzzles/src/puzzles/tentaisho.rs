@@ -0,0 +1,260 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    from_base16, problem_to_url_with_context, to_base16, url_to_problem, Choice, Combinator,
+    Context, ContextBasedGrid, Size, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TentaishoDot {
+    None,
+    White,
+    Black,
+}
+
+/// `dots` has the shape (2H-1) x (2W-1) for a grid of H x W cells: `dots[2y][2x]` is the center of
+/// the cell (y, x), and odd indices correspond to edges or vertices between cells.
+///
+/// Returns the borders between regions, and whether each cell belongs to a region with a black dot.
+pub fn solve_tentaisho(
+    dots: &[Vec<TentaishoDot>],
+) -> Option<(
+    graph::BoolInnerGridEdgesIrrefutableFacts,
+    Vec<Vec<Option<bool>>>,
+)> {
+    solve_tentaisho_with_query(dots, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tentaisho_with_query(
+    dots: &[Vec<TentaishoDot>],
+    query: util::AnswerQuery,
+) -> Vec<(
+    graph::BoolInnerGridEdgesIrrefutableFacts,
+    Vec<Vec<Option<bool>>>,
+)> {
+    let (dh, dw) = util::infer_shape(dots);
+    let h = dh.div_ceil(2);
+    let w = dw.div_ceil(2);
+
+    let mut dot_pos = vec![];
+    for (y, row) in dots.iter().enumerate() {
+        for (x, &dot) in row.iter().enumerate() {
+            if dot != TentaishoDot::None {
+                dot_pos.push((y, x));
+            }
+        }
+    }
+    if dot_pos.is_empty() {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let region = &solver.int_var_2d((h, w), 0, dot_pos.len() as i32 - 1);
+
+    let is_border = graph::BoolInnerGridEdges::new(&mut solver, (h, w));
+    solver.add_answer_key_bool(&is_border.horizontal);
+    solver.add_answer_key_bool(&is_border.vertical);
+    solver.add_expr(
+        region
+            .slice((.., ..(w - 1)))
+            .ne(region.slice((.., 1..)))
+            .iff(&is_border.vertical),
+    );
+    solver.add_expr(
+        region
+            .slice((..(h - 1), ..))
+            .ne(region.slice((1.., ..)))
+            .iff(&is_border.horizontal),
+    );
+
+    for (i, &(dy, dx)) in dot_pos.iter().enumerate() {
+        let id = i as i32;
+        graph::active_vertices_connected_2d(&mut solver, region.eq(id));
+
+        for y in 0..h {
+            for x in 0..w {
+                // Cells touching the dot belong to its region
+                if (y * 2).abs_diff(dy) <= 1 && (x * 2).abs_diff(dx) <= 1 {
+                    solver.add_expr(region.at((y, x)).eq(id));
+                }
+
+                // Each region is symmetric about its dot
+                if dy < y || dx < x || dy - y >= h || dx - x >= w {
+                    solver.add_expr(region.at((y, x)).ne(id));
+                } else {
+                    let (y2, x2) = (dy - y, dx - x);
+                    solver.add_expr(region.at((y, x)).eq(id).imp(region.at((y2, x2)).eq(id)));
+                }
+            }
+        }
+    }
+
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+    for y in 0..h {
+        for x in 0..w {
+            for (i, &(dy, dx)) in dot_pos.iter().enumerate() {
+                solver.add_expr(
+                    region
+                        .at((y, x))
+                        .eq(i as i32)
+                        .imp(is_black.at((y, x)).iff(dots[dy][dx] == TentaishoDot::Black)),
+                );
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| (f.get(&is_border), f.get(is_black)))
+}
+
+pub type Problem = Vec<Vec<TentaishoDot>>;
+
+/// A dot followed by up to 7 empty positions
+struct DotCombinator;
+
+impl Combinator<TentaishoDot> for DotCombinator {
+    fn serialize(&self, _: &Context, input: &[TentaishoDot]) -> Option<(usize, Vec<u8>)> {
+        let color = match input.first()? {
+            TentaishoDot::None => return None,
+            TentaishoDot::White => 0,
+            TentaishoDot::Black => 1,
+        };
+        let mut n_spaces = 0;
+        while n_spaces < 7
+            && 1 + n_spaces < input.len()
+            && input[1 + n_spaces] == TentaishoDot::None
+        {
+            n_spaces += 1;
+        }
+        if 1 + n_spaces == input.len() {
+            // The rest of the grid is empty
+            n_spaces = 7;
+        }
+        Some((
+            (1 + n_spaces).min(input.len()),
+            vec![to_base16(n_spaces as i32 * 2 + color)],
+        ))
+    }
+
+    fn deserialize(&self, _: &Context, input: &[u8]) -> Option<(usize, Vec<TentaishoDot>)> {
+        let v = from_base16(*input.first()?)?;
+        let mut ret = vec![if v % 2 == 0 {
+            TentaishoDot::White
+        } else {
+            TentaishoDot::Black
+        }];
+        for _ in 0..(v / 2) {
+            ret.push(TentaishoDot::None);
+        }
+        Some((1, ret))
+    }
+}
+
+struct TentaishoCombinator;
+
+impl TentaishoCombinator {
+    fn grid() -> impl Combinator<Problem> {
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(DotCombinator),
+            Box::new(Spaces::new(TentaishoDot::None, 'g')),
+        ]))
+    }
+}
+
+impl Combinator<Problem> for TentaishoCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        let ctx = Context::sized(ctx.height? * 2 - 1, ctx.width? * 2 - 1);
+        Self::grid().serialize(&ctx, input)
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let ctx = Context::sized(ctx.height? * 2 - 1, ctx.width? * 2 - 1);
+        Self::grid().deserialize(&ctx, input)
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(TentaishoCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (dh, dw) = util::infer_shape(problem);
+    problem_to_url_with_context(
+        combinator(),
+        "tentaisho",
+        problem.clone(),
+        &Context::sized(dh.div_ceil(2), dw.div_ceil(2)),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["tentaisho"], url)
+}
+
+pub struct Tentaisho;
+
+impl util::Puzzle for Tentaisho {
+    type Problem = Problem;
+    type Answer = (
+        graph::BoolInnerGridEdgesIrrefutableFacts,
+        Vec<Vec<Option<bool>>>,
+    );
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tentaisho_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![TentaishoDot::None; 7]; 7];
+        ret[1][1] = TentaishoDot::Black;
+        ret[0][5] = TentaishoDot::White;
+        ret[4][4] = TentaishoDot::White;
+        ret[4][6] = TentaishoDot::Black;
+        ret[5][1] = TentaishoDot::White;
+        ret
+    }
+
+    #[test]
+    fn test_tentaisho_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_tentaisho(&problem);
+        assert!(ans.is_some());
+        let (is_border, is_black) = ans.unwrap();
+
+        let expected_border = graph::BoolInnerGridEdgesIrrefutableFacts {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [0, 0, 1, 1],
+                [1, 1, 0, 0],
+                [0, 0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [0, 1, 0],
+                [0, 1, 1],
+                [0, 1, 1],
+                [0, 1, 1],
+            ]),
+        };
+        assert_eq!(is_border, expected_border);
+
+        let expected_black = crate::util::tests::to_option_bool_2d([
+            [1, 1, 0, 0],
+            [1, 1, 0, 1],
+            [0, 0, 0, 1],
+            [0, 0, 0, 1],
+        ]);
+        assert_eq!(is_black, expected_black);
+    }
+
+    #[test]
+    fn test_tentaisho_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tentaisho/4/4/k4fv23ek";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+pub fn solve_tents(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    trees: &[Vec<bool>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_tents_with_query(
+        clues_up,
+        clues_left,
+        trees,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_tents_with_query(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    trees: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(trees);
+    if clues_up.len() != w || clues_left.len() != h {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let is_tent = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_tent);
+
+    // Tents do not touch each other, even diagonally
+    for y in 0..(h - 1) {
+        for x in 0..(w - 1) {
+            solver.add_expr(is_tent.slice((y..(y + 2), x..(x + 2))).count_true().le(1));
+        }
+    }
+
+    // Each tree is matched with exactly one of its adjacent tents, and each tent is matched with
+    // exactly one tree.
+    let mut matched = vec![vec![vec![]; w]; h];
+    for y in 0..h {
+        for x in 0..w {
+            if !trees[y][x] {
+                continue;
+            }
+            solver.add_expr(!is_tent.at((y, x)));
+
+            let neighbors = is_tent
+                .four_neighbor_indices((y, x))
+                .into_iter()
+                .filter(|&(y2, x2)| !trees[y2][x2])
+                .collect::<Vec<_>>();
+            let assign = solver.bool_var_1d(neighbors.len());
+            solver.add_expr(assign.count_true().eq(1));
+            for (i, &p) in neighbors.iter().enumerate() {
+                matched[p.0][p.1].push(assign.at(i));
+            }
+        }
+    }
+    for (y, row) in matched.iter().enumerate() {
+        for (x, cands) in row.iter().enumerate() {
+            solver.add_expr(count_true(cands).eq(is_tent.at((y, x)).ite(1, 0)));
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_tent.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_tent.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_tent))
+}
+
+pub type Problem = (Vec<Option<i32>>, Vec<Option<i32>>, Vec<Vec<bool>>);
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+fn cell_combinator() -> impl Combinator<bool> {
+    Choice::new(vec![
+        Box::new(Dict::new(true, "1")),
+        Box::new(Spaces::new(false, 'a')),
+    ])
+}
+
+pub struct TentsCombinator;
+
+impl Combinator<Problem> for TentsCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let surrounding = [&problem.0[..], &problem.1[..]].concat();
+        let mut ret = Seq::new(clue_combinator(), width + height)
+            .serialize(ctx, &[surrounding])?
+            .1;
+        ret.extend(
+            ContextBasedGrid::new(cell_combinator())
+                .serialize(ctx, std::slice::from_ref(&problem.2))?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        let trees =
+            sequencer.deserialize_one_elem(ctx, ContextBasedGrid::new(cell_combinator()))?;
+
+        Some((sequencer.n_read(), vec![(clues_up, clues_left, trees)]))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(TentsCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.1.len();
+    let width = problem.0.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "tents",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["tents"], url)
+}
+
+pub struct Tents;
+
+impl util::Puzzle for Tents {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tents_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        let mut trees = vec![vec![false; 5]; 5];
+        trees[0][1] = true;
+        trees[1][4] = true;
+        trees[2][1] = true;
+        trees[3][3] = true;
+        trees[4][0] = true;
+        (
+            vec![Some(0), Some(1), None, Some(1), None],
+            vec![Some(1), None, Some(2), None, None],
+            trees,
+        )
+    }
+
+    #[test]
+    fn test_tents_problem() {
+        let (clues_up, clues_left, trees) = problem_for_tests();
+        let ans = solve_tents(&clues_up, &clues_left, &trees);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 1, 0, 1],
+            [0, 0, 0, 0, 0],
+            [0, 1, 0, 1, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_tents_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tents/5/5/01g1g1g2ha1g1a1f1a1d";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
     get_kudamono_url_info_detailed, parse_kudamono_dimension, Combinator, Context,
@@ -7,6 +8,13 @@ use cspuz_rs::solver::{int_constant, BoolExpr, BoolVarArray1D, IntExpr, Solver,
 pub fn solve_the_longest(
     clues: &graph::GridEdges<Vec<Vec<bool>>>,
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_the_longest_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_the_longest_with_query(
+    clues: &graph::GridEdges<Vec<Vec<bool>>>,
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let h = clues.vertical.len();
     let w = clues.vertical[0].len() - 1;
 
@@ -218,7 +226,7 @@ pub fn solve_the_longest(
     let g2 = (0..g.n_edges()).map(|i| g[i]).collect::<Vec<_>>();
     solver.add_graph_division(&vec![None; h * w], &g2, &edges);
 
-    solver.irrefutable_facts().map(|f| f.get(ans))
+    util::query_answers(solver, query, |f| f.get(ans))
 }
 
 type Problem = graph::GridEdges<Vec<Vec<bool>>>;
@@ -326,6 +334,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
         .pop()
 }
 
+pub struct TheLongest;
+
+impl util::Puzzle for TheLongest {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_the_longest_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
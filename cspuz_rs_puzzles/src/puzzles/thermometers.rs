@@ -0,0 +1,290 @@
+use crate::util;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    HexInt, MultiDigit, Optionalize, Seq, Sequencer, Size, Spaces,
+};
+use cspuz_rs::solver::Solver;
+
+/// Solves a Thermometers puzzle.
+/// Each thermometer is given as the list of its cells from the bulb to the tip.
+pub fn solve_thermometers(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    thermometers: &[Vec<(usize, usize)>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_thermometers_with_query(
+        clues_up,
+        clues_left,
+        thermometers,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_thermometers_with_query(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    thermometers: &[Vec<(usize, usize)>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let h = clues_left.len();
+    let w = clues_up.len();
+
+    let mut solver = Solver::new();
+    let is_filled = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_filled);
+
+    let mut in_thermometer = vec![vec![false; w]; h];
+    for thermometer in thermometers {
+        for i in 0..thermometer.len() {
+            let (y, x) = thermometer[i];
+            if y >= h || x >= w || in_thermometer[y][x] {
+                return vec![];
+            }
+            in_thermometer[y][x] = true;
+            if i > 0 {
+                solver.add_expr(is_filled.at((y, x)).imp(is_filled.at(thermometer[i - 1])));
+            }
+        }
+    }
+    for (y, row) in in_thermometer.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if !cell {
+                solver.add_expr(!is_filled.at((y, x)));
+            }
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_filled.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_filled.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_filled))
+}
+
+pub type Problem = (Vec<Option<i32>>, Vec<Option<i32>>, Vec<Vec<(usize, usize)>>);
+
+const CELL_EMPTY: i32 = 0;
+const CELL_BULB: i32 = 1;
+// The previous cell (closer to the bulb) is in the direction of `DIRS[code - CELL_PREV_BASE]`
+const CELL_PREV_BASE: i32 = 2;
+const DIRS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+fn thermometers_to_cells(
+    height: usize,
+    width: usize,
+    thermometers: &[Vec<(usize, usize)>],
+) -> Option<Vec<Vec<i32>>> {
+    let mut ret = vec![vec![CELL_EMPTY; width]; height];
+    for thermometer in thermometers {
+        for i in 0..thermometer.len() {
+            let (y, x) = thermometer[i];
+            if y >= height || x >= width || ret[y][x] != CELL_EMPTY {
+                return None;
+            }
+            if i == 0 {
+                ret[y][x] = CELL_BULB;
+            } else {
+                let (py, px) = thermometer[i - 1];
+                let dir = (py as i32 - y as i32, px as i32 - x as i32);
+                let d = DIRS.iter().position(|&d| d == dir)?;
+                ret[y][x] = CELL_PREV_BASE + d as i32;
+            }
+        }
+    }
+    Some(ret)
+}
+
+fn cells_to_thermometers(cells: &[Vec<i32>]) -> Option<Vec<Vec<(usize, usize)>>> {
+    let height = cells.len();
+    let width = cells[0].len();
+
+    let mut visited = vec![vec![false; width]; height];
+    let mut ret = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            if cells[y][x] != CELL_BULB {
+                continue;
+            }
+            let mut thermometer = vec![(y, x)];
+            visited[y][x] = true;
+            loop {
+                let (cy, cx) = *thermometer.last().unwrap();
+                let mut next = None;
+                for (d, &(dy, dx)) in DIRS.iter().enumerate() {
+                    // the next cell is at (cy - dy, cx - dx) and its previous cell is (cy, cx)
+                    let ny = cy as i32 - dy;
+                    let nx = cx as i32 - dx;
+                    if !(0 <= ny && ny < height as i32 && 0 <= nx && nx < width as i32) {
+                        continue;
+                    }
+                    let (ny, nx) = (ny as usize, nx as usize);
+                    if cells[ny][nx] == CELL_PREV_BASE + d as i32 {
+                        if next.is_some() {
+                            return None;
+                        }
+                        next = Some((ny, nx));
+                    }
+                }
+                match next {
+                    Some((ny, nx)) => {
+                        if visited[ny][nx] {
+                            return None;
+                        }
+                        visited[ny][nx] = true;
+                        thermometer.push((ny, nx));
+                    }
+                    None => break,
+                }
+            }
+            ret.push(thermometer);
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            if cells[y][x] != CELL_EMPTY && !visited[y][x] {
+                return None;
+            }
+        }
+    }
+
+    Some(ret)
+}
+
+fn clue_combinator() -> impl Combinator<Option<i32>> {
+    Choice::new(vec![
+        Box::new(Optionalize::new(HexInt)),
+        Box::new(Spaces::new(None, 'g')),
+    ])
+}
+
+pub struct ThermometersCombinator;
+
+impl Combinator<Problem> for ThermometersCombinator {
+    fn serialize(&self, ctx: &Context, input: &[Problem]) -> Option<(usize, Vec<u8>)> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let problem = &input[0];
+
+        let surrounding = [&problem.0[..], &problem.1[..]].concat();
+        let mut ret = Seq::new(clue_combinator(), width + height)
+            .serialize(ctx, &[surrounding])?
+            .1;
+        let cells = thermometers_to_cells(height, width, &problem.2)?;
+        ret.extend(
+            ContextBasedGrid::new(MultiDigit::new(6, 2))
+                .serialize(ctx, &[cells])?
+                .1,
+        );
+
+        Some((1, ret))
+    }
+
+    fn deserialize(&self, ctx: &Context, input: &[u8]) -> Option<(usize, Vec<Problem>)> {
+        let mut sequencer = Sequencer::new(input);
+
+        let height = ctx.height?;
+        let width = ctx.width?;
+
+        let surrounding =
+            sequencer.deserialize_one_elem(ctx, Seq::new(clue_combinator(), width + height))?;
+        let clues_up = surrounding[..width].to_vec();
+        let clues_left = surrounding[width..].to_vec();
+
+        let cells =
+            sequencer.deserialize_one_elem(ctx, ContextBasedGrid::new(MultiDigit::new(6, 2)))?;
+        let thermometers = cells_to_thermometers(&cells)?;
+
+        Some((
+            sequencer.n_read(),
+            vec![(clues_up, clues_left, thermometers)],
+        ))
+    }
+}
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(ThermometersCombinator)
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.1.len();
+    let width = problem.0.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "thermometers",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["thermometers"], url)
+}
+
+pub struct Thermometers;
+
+impl util::Puzzle for Thermometers {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_thermometers_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    fn problem_for_tests() -> Problem {
+        (
+            vec![Some(2), Some(1), Some(3), Some(2)],
+            vec![Some(3), Some(2), Some(1), Some(2)],
+            vec![
+                vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+                vec![(0, 2), (0, 3), (1, 3)],
+                vec![(1, 2), (2, 2), (3, 2)],
+                vec![(3, 1), (2, 1), (1, 1), (0, 1)],
+                vec![(3, 3), (2, 3)],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_thermometers_problem() {
+        let (clues_up, clues_left, thermometers) = problem_for_tests();
+        let ans = solve_thermometers(&clues_up, &clues_left, &thermometers);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 0, 1, 1],
+            [1, 0, 1, 0],
+            [0, 0, 1, 0],
+            [0, 1, 0, 1],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_thermometers_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?thermometers/4/4/213232129af8ffdd";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::puzzles::aquarium::combinator;
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url_with_context, url_to_problem, Context};
+use cspuz_rs::solver::Solver;
+
+pub fn solve_tilepaint(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_tilepaint_with_query(
+        borders,
+        clues_up,
+        clues_left,
+        util::AnswerQuery::IrrefutableFacts,
+    )
+    .pop()
+}
+
+fn solve_tilepaint_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let h = borders.vertical.len();
+    assert!(h > 0);
+    let w = borders.vertical[0].len() + 1;
+    if clues_up.len() != w || clues_left.len() != h {
+        return vec![];
+    }
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 && !borders.horizontal[y][x] {
+                solver.add_expr(is_black.at((y, x)).iff(is_black.at((y + 1, x))));
+            }
+            if x < w - 1 && !borders.vertical[y][x] {
+                solver.add_expr(is_black.at((y, x)).iff(is_black.at((y, x + 1))));
+            }
+        }
+    }
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_black.slice_fixed_y((y, ..)).count_true().eq(n));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            solver.add_expr(is_black.slice_fixed_x((.., x)).count_true().eq(n));
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+type Problem = (
+    graph::InnerGridEdges<Vec<Vec<bool>>>,
+    Vec<Option<i32>>,
+    Vec<Option<i32>>,
+);
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.2.len();
+    let width = problem.1.len();
+
+    problem_to_url_with_context(
+        combinator(),
+        "tilepaint",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["tilepaint"], url)
+}
+
+pub struct Tilepaint;
+
+impl util::Puzzle for Tilepaint {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tilepaint_with_query(&problem.0, &problem.1, &problem.2, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            vec![Some(2), Some(1), None, None],
+            vec![None, None, Some(2), None],
+        )
+    }
+
+    #[test]
+    fn test_tilepaint_problem() {
+        let (borders, clues_up, clues_left) = problem_for_tests();
+        let ans = solve_tilepaint(&borders, &clues_up, &clues_left);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 1, 1],
+            [0, 0, 1, 1],
+            [1, 0, 0, 1],
+            [1, 1, 0, 0],
+        ]);
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_tilepaint_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?tilepaint/4/4/9ag1p821j2g";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
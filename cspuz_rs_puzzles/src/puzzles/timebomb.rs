@@ -8,6 +8,13 @@ use cspuz_rs::solver::Solver;
 pub fn solve_timebomb(
     clues: &[Vec<Option<i32>>],
 ) -> Option<(Vec<Vec<Option<bool>>>, Vec<Vec<Option<i32>>>)> {
+    solve_timebomb_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_timebomb_with_query(
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<(Vec<Vec<Option<bool>>>, Vec<Vec<Option<i32>>>)> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -96,9 +103,7 @@ pub fn solve_timebomb(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(has_number), f.get(num)))
+    util::query_answers(solver, query, |f| (f.get(has_number), f.get(num)))
 }
 
 type Problem = Vec<Vec<Option<i32>>>;
@@ -128,6 +133,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["timebomb"], url)
 }
 
+pub struct Timebomb;
+
+impl util::Puzzle for Timebomb {
+    type Problem = Problem;
+    type Answer = (Vec<Vec<Option<bool>>>, Vec<Vec<Option<i32>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_timebomb_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
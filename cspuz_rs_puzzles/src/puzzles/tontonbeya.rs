@@ -10,6 +10,14 @@ pub fn solve_tontonbeya(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Vec<Option<i32>>],
 ) -> Option<Vec<Vec<Option<i32>>>> {
+    solve_tontonbeya_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tontonbeya_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<i32>>>> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -86,7 +94,7 @@ pub fn solve_tontonbeya(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(ans))
+    util::query_answers(solver, query, |f| f.get(ans))
 }
 
 type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
@@ -116,6 +124,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["tontonbeya"], url)
 }
 
+pub struct Tontonbeya;
+
+impl util::Puzzle for Tontonbeya {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<i32>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tontonbeya_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,6 +9,13 @@ use cspuz_rs::solver::{all, count_true, Solver};
 pub fn solve_tricklayer(
     is_block: &[Vec<bool>],
 ) -> Option<graph::BoolInnerGridEdgesIrrefutableFacts> {
+    solve_tricklayer_with_query(is_block, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_tricklayer_with_query(
+    is_block: &[Vec<bool>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolInnerGridEdgesIrrefutableFacts> {
     let (h, w) = util::infer_shape(is_block);
 
     let mut solver = Solver::new();
@@ -91,7 +98,7 @@ pub fn solve_tricklayer(
         }
     }
 
-    solver.irrefutable_facts().map(|f| f.get(edges))
+    util::query_answers(solver, query, |f| f.get(edges))
 }
 
 type Problem = Vec<Vec<bool>>;
@@ -109,6 +116,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     kudamono_url_info_to_problem(combinator(), info)
 }
 
+pub struct Tricklayer;
+
+impl util::Puzzle for Tricklayer {
+    type Problem = Problem;
+    type Answer = graph::BoolInnerGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_tricklayer_with_query(problem, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,197 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::{count_true, Solver};
+
+// Shaded cells, and whether each clue cell is the liar of its room
+type Answer = (Vec<Vec<Option<bool>>>, Vec<Vec<Option<bool>>>);
+
+pub fn solve_usoone(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+) -> Option<Answer> {
+    solve_usoone_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_usoone_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<Answer> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    // `is_lie` is meaningful only for clue cells. Whether a "?" clue is the lie cannot be told,
+    // so such cells are left out of the answer.
+    let is_lie = &solver.bool_var_2d((h, w));
+
+    graph::active_vertices_connected_2d(&mut solver, !is_black);
+    solver.add_expr(!(is_black.slice((..(h - 1), ..)) & is_black.slice((1.., ..))));
+    solver.add_expr(!(is_black.slice((.., ..(w - 1))) & is_black.slice((.., 1..))));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                solver.add_expr(!is_black.at((y, x)));
+                if n >= 0 {
+                    solver.add_answer_key_bool([is_lie.at((y, x))]);
+                    solver.add_expr(
+                        is_black
+                            .four_neighbors((y, x))
+                            .count_true()
+                            .eq(n)
+                            .iff(!is_lie.at((y, x))),
+                    );
+                }
+            } else {
+                solver.add_answer_key_bool([is_lie.at((y, x))]);
+                solver.add_expr(!is_lie.at((y, x)));
+            }
+        }
+    }
+
+    let rooms = graph::borders_to_rooms(borders);
+    for room in &rooms {
+        let clue_cells = room
+            .iter()
+            .filter(|&&(y, x)| clues[y][x].is_some())
+            .map(|&p| is_lie.at(p))
+            .collect::<Vec<_>>();
+        if !clue_cells.is_empty() {
+            solver.add_expr(count_true(clue_cells).eq(1));
+        }
+    }
+
+    util::query_answers(solver, query, |f| (f.get(is_black), f.get(is_lie)))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Optionalize::new(HexInt)),
+            Box::new(Spaces::new(None, 'g')),
+            Box::new(Dict::new(Some(-1), ".")),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let (h, w) = problem.0.base_shape();
+    problem_to_url_with_context(
+        combinator(),
+        "usoone",
+        problem.clone(),
+        &Context::sized(h, w),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["usoone"], url)
+}
+
+pub struct Usoone;
+
+impl util::Puzzle for Usoone {
+    type Problem = Problem;
+    type Answer = Answer;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_usoone_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 1, 1],
+                    [1, 1, 0, 0, 0],
+                    [0, 1, 1, 1, 0],
+                    [0, 0, 0, 1, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0, 0],
+                    [0, 1, 1, 0],
+                    [0, 1, 1, 0],
+                    [1, 0, 0, 1],
+                    [1, 0, 1, 0],
+                ]),
+            },
+            vec![
+                vec![Some(1), Some(3), None, None, Some(1)],
+                vec![None, Some(1), None, None, None],
+                vec![Some(2), None, Some(0), None, Some(0)],
+                vec![None, None, None, Some(1), None],
+                vec![Some(1), None, None, None, Some(1)],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_usoone_problem() {
+        let (borders, clues) = problem_for_tests();
+        let ans = solve_usoone(&borders, &clues);
+        assert!(ans.is_some());
+        let (is_black, is_lie) = ans.unwrap();
+
+        let expected_black = crate::util::tests::to_option_bool_2d([
+            [0, 0, 0, 1, 0],
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 1, 0, 0],
+            [0, 1, 0, 0, 0],
+        ]);
+        assert_eq!(is_black, expected_black);
+
+        let expected_lie = crate::util::tests::to_option_bool_2d([
+            [0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [1, 0, 1, 0, 1],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 1],
+        ]);
+        assert_eq!(is_lie, expected_lie);
+    }
+
+    #[test]
+    fn test_usoone_unknown_clue() {
+        use util::Puzzle;
+
+        // Either "?" may be the lie, which must not make the answer ambiguous
+        let borders = graph::InnerGridEdges {
+            horizontal: vec![],
+            vertical: vec![vec![false, false]],
+        };
+        let clues = vec![vec![Some(-1), None, Some(-1)]];
+        let ans = solve_usoone(&borders, &clues);
+        assert!(ans.is_some());
+        let (is_black, is_lie) = ans.unwrap();
+        assert_eq!(is_black, vec![vec![Some(false), Some(false), Some(false)]]);
+        assert_eq!(is_lie, vec![vec![None, Some(false), None]]);
+
+        let answers = Usoone::enumerate_answers(&(borders, clues), 10);
+        assert_eq!(answers.len(), 1);
+    }
+
+    #[test]
+    fn test_usoone_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?usoone/5/5/8pkq3oe313h1g1i2g0g0i1g1i1";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
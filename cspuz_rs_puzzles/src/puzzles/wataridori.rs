@@ -0,0 +1,245 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, ContextBasedGrid,
+    Dict, HexInt, Optionalize, Rooms, Size, Spaces, Tuple2,
+};
+use cspuz_rs::solver::{any, count_true, Solver};
+
+/// Circles are connected in pairs by lines which do not branch, cross or overlap.
+/// A line must not enter the same room twice, and the number in a circle represents the number
+/// of rooms the line from the circle passes through.
+pub fn solve_wataridori(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+) -> Option<graph::BoolGridEdgesIrrefutableFacts> {
+    solve_wataridori_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_wataridori_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Vec<Option<i32>>],
+    query: util::AnswerQuery,
+) -> Vec<graph::BoolGridEdgesIrrefutableFacts> {
+    let (h, w) = util::infer_shape(clues);
+
+    let rooms = graph::borders_to_rooms(borders);
+    let mut room_id = vec![vec![0; w]; h];
+    for (i, room) in rooms.iter().enumerate() {
+        for &(y, x) in room {
+            room_id[y][x] = i;
+        }
+    }
+
+    let mut circles = vec![];
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                circles.push(((y, x), n));
+            }
+        }
+    }
+    let n_circles = circles.len();
+
+    let mut solver = Solver::new();
+    let is_line = &graph::BoolGridEdges::new(&mut solver, (h - 1, w - 1));
+    solver.add_answer_key_bool(&is_line.horizontal);
+    solver.add_answer_key_bool(&is_line.vertical);
+
+    // 0 means that the cell is not passed by any line; otherwise the cell belongs to the line
+    // whose endpoints include the `(id - 1)`-th circle
+    let line_id = &solver.int_var_2d((h, w), 0, n_circles as i32);
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            let p = (y, x);
+            let degree = count_true(is_line.vertex_neighbors(p));
+            if clue.is_some() {
+                solver.add_expr(degree.eq(1));
+            } else {
+                solver.add_expr(degree.eq(line_id.at(p).ne(0).ite(2, 0)));
+            }
+            if y < h - 1 {
+                solver.add_expr(
+                    is_line
+                        .vertical
+                        .at(p)
+                        .imp(line_id.at(p).eq(line_id.at((y + 1, x)))),
+                );
+            }
+            if x < w - 1 {
+                solver.add_expr(
+                    is_line
+                        .horizontal
+                        .at(p)
+                        .imp(line_id.at(p).eq(line_id.at((y, x + 1)))),
+                );
+            }
+        }
+    }
+
+    // Each line connects exactly two circles
+    for i in 0..n_circles {
+        let id = i as i32 + 1;
+        let (p, _) = circles[i];
+        solver.add_expr(line_id.at(p).ne(0));
+
+        let n_ends = count_true(circles.iter().map(|&(q, _)| line_id.at(q).eq(id)));
+        solver.add_expr(line_id.at(p).eq(id).imp(n_ends.eq(2)));
+        solver.add_expr(line_id.at(p).ne(id).imp(n_ends.eq(0)));
+
+        let (edges, g) = is_line.representation();
+        graph::active_vertices_connected_via_active_edges(
+            &mut solver,
+            &line_id.eq(id).flatten(),
+            &edges,
+            &g,
+        );
+    }
+
+    // Edges crossing the border of each room, as pairs of the inner cell and the edge
+    let mut room_exits = vec![vec![]; rooms.len()];
+    for y in 0..h {
+        for x in 0..w {
+            if y < h - 1 && room_id[y][x] != room_id[y + 1][x] {
+                let e = is_line.vertical.at((y, x));
+                room_exits[room_id[y][x]].push(((y, x), e.clone()));
+                room_exits[room_id[y + 1][x]].push(((y + 1, x), e));
+            }
+            if x < w - 1 && room_id[y][x] != room_id[y][x + 1] {
+                let e = is_line.horizontal.at((y, x));
+                room_exits[room_id[y][x]].push(((y, x), e.clone()));
+                room_exits[room_id[y][x + 1]].push(((y, x + 1), e));
+            }
+        }
+    }
+
+    for i in 0..n_circles {
+        let id = i as i32 + 1;
+
+        let mut n_rooms = vec![];
+        for (r, room) in rooms.iter().enumerate() {
+            let is_visited = any(room.iter().map(|&p| line_id.at(p).eq(id)));
+
+            // The line enters the room at most once
+            let n_exits = count_true(room_exits[r].iter().map(|(p, e)| e & line_id.at(*p).eq(id)));
+            let n_ends = count_true(
+                circles
+                    .iter()
+                    .filter(|&&((y, x), _)| room_id[y][x] == r)
+                    .map(|&(q, _)| line_id.at(q).eq(id)),
+            );
+            solver.add_expr((n_exits + n_ends).eq(is_visited.ite(2, 0)));
+
+            n_rooms.push(is_visited);
+        }
+
+        let n_rooms = count_true(n_rooms);
+        for &(p, n) in &circles {
+            if n > 0 {
+                solver.add_expr(line_id.at(p).eq(id).imp(n_rooms.eq(n)));
+            }
+        }
+    }
+
+    util::query_answers(solver, query, |f| f.get(is_line))
+}
+
+type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Vec<Option<i32>>>);
+
+fn combinator() -> impl Combinator<Problem> {
+    Size::new(Tuple2::new(
+        Rooms,
+        ContextBasedGrid::new(Choice::new(vec![
+            Box::new(Optionalize::new(HexInt)),
+            Box::new(Spaces::new(None, 'g')),
+            Box::new(Dict::new(Some(-1), ".")),
+        ])),
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    let height = problem.0.vertical.len();
+    let width = problem.0.vertical[0].len() + 1;
+    problem_to_url_with_context(
+        combinator(),
+        "wataridori",
+        problem.clone(),
+        &Context::sized(height, width),
+    )
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["wataridori"], url)
+}
+
+pub struct Wataridori;
+
+impl util::Puzzle for Wataridori {
+    type Problem = Problem;
+    type Answer = graph::BoolGridEdgesIrrefutableFacts;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_wataridori_with_query(&problem.0, &problem.1, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rustfmt::skip]
+    fn problem_for_tests() -> Problem {
+        (
+            graph::InnerGridEdges {
+                horizontal: crate::util::tests::to_bool_2d([
+                    [0, 0, 0, 0],
+                    [1, 1, 1, 0],
+                    [0, 1, 0, 1],
+                ]),
+                vertical: crate::util::tests::to_bool_2d([
+                    [0, 1, 0],
+                    [0, 1, 0],
+                    [1, 0, 1],
+                    [0, 1, 0],
+                ]),
+            },
+            vec![
+                vec![Some(2), Some(-1), Some(-1), None],
+                vec![None, None, None, None],
+                vec![None, None, Some(-1), None],
+                vec![None, None, None, None],
+            ]
+        )
+    }
+
+    #[test]
+    fn test_wataridori_problem() {
+        let (borders, clues) = problem_for_tests();
+        let ans = solve_wataridori(&borders, &clues);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = graph::GridEdges {
+            horizontal: crate::util::tests::to_option_bool_2d([
+                [0, 1, 0],
+                [1, 0, 0],
+                [0, 1, 0],
+                [0, 0, 0],
+            ]),
+            vertical: crate::util::tests::to_option_bool_2d([
+                [1, 0, 0, 0],
+                [0, 1, 0, 0],
+                [0, 0, 0, 0],
+            ]),
+        };
+        assert_eq!(ans, expected);
+    }
+
+    #[test]
+    fn test_wataridori_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?wataridori/4/4/9ag1p82..m.k";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -10,6 +10,25 @@ use cspuz_rs::solver::Solver;
 pub fn solve_yajilin(
     clues: &[Vec<Option<NumberedArrow>>],
 ) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_yajilin_with_variant(clues, false)
+}
+
+/// Solves Yajilin, allowing clue cells to be shaded if `gray_clues` is `true`.
+///
+/// A shaded ("gray") clue cell is treated as a shaded cell and its number is void.
+/// Whether each clue cell is shaded is reported in the second element of the answer.
+pub fn solve_yajilin_with_variant(
+    clues: &[Vec<Option<NumberedArrow>>],
+    gray_clues: bool,
+) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_yajilin_with_query(clues, gray_clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_yajilin_with_query(
+    clues: &[Vec<Option<NumberedArrow>>],
+    gray_clues: bool,
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
     let (h, w) = util::infer_shape(clues);
 
     let mut solver = Solver::new();
@@ -27,10 +46,12 @@ pub fn solve_yajilin(
         for x in 0..w {
             if let Some((dir, n)) = clues[y][x] {
                 solver.add_expr(!is_passed.at((y, x)));
-                solver.add_expr(!is_black.at((y, x)));
+                if !gray_clues {
+                    solver.add_expr(!is_black.at((y, x)));
+                }
 
                 if let Some(cells) = is_black.pointing_cells((y, x), dir) {
-                    solver.add_expr(cells.count_true().eq(n));
+                    solver.add_expr((!is_black.at((y, x))).imp(cells.count_true().eq(n)));
                 }
             } else {
                 solver.add_expr(is_passed.at((y, x)) ^ is_black.at((y, x)));
@@ -38,9 +59,7 @@ pub fn solve_yajilin(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(is_black)))
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(is_black)))
 }
 
 type Problem = Vec<Vec<Option<NumberedArrow>>>;
@@ -63,9 +82,36 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["yajilin", "yajirin"], url)
 }
 
+/// A Yajilin problem with the flag which enables gray clues. puzz.link has no encoding of the
+/// flag, so variant problems are not converted from or to URLs.
+pub type VariantProblem = (bool, Problem);
+
+pub struct Yajilin;
+
+impl util::Puzzle for Yajilin {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_yajilin_with_query(problem, false, query)
+    }
+}
+
+pub struct YajilinVariant;
+
+impl util::Puzzle for YajilinVariant {
+    type Problem = VariantProblem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &VariantProblem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_yajilin_with_query(&problem.1, problem.0, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util;
     use cspuz_rs::items::Arrow;
 
     #[test]
@@ -115,4 +161,38 @@ mod tests {
         ]);
         assert_eq!(is_black, expected);
     }
+
+    #[test]
+    fn test_yajilin_gray_clues() {
+        use util::Puzzle;
+
+        let mut problem = vec![vec![None; 5]; 5];
+        problem[1][3] = Some((Arrow::Up, 0));
+        problem[2][0] = Some((Arrow::Right, 2));
+        problem[4][3] = Some((Arrow::Up, 0));
+
+        assert!(solve_yajilin(&problem).is_none());
+
+        let ans = solve_yajilin_with_variant(&problem, true);
+        assert!(ans.is_some());
+        let (_, is_black) = ans.unwrap();
+
+        let expected = crate::util::tests::to_option_bool_2d([
+            [0, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0],
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1],
+        ]);
+        assert_eq!(is_black, expected);
+
+        assert_eq!(
+            YajilinVariant::solve(&(true, problem.clone())),
+            solve_yajilin_with_variant(&problem, true)
+        );
+        assert_eq!(
+            deserialize_problem("https://puzz.link/p?yajilin/5/5/h10a42l10a"),
+            Some(problem)
+        );
+    }
 }
@@ -1,7 +1,8 @@
+use crate::util;
 use cspuz_rs::graph;
 use cspuz_rs::serializer::{
-    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, HexInt, Optionalize,
-    RoomsWithValues, Size, Spaces,
+    problem_to_url_with_context, url_to_problem, Choice, Combinator, Context, Dict, HexInt,
+    Optionalize, RoomsWithValues, Size, Spaces,
 };
 use cspuz_rs::solver::{count_true, Solver};
 
@@ -9,6 +10,14 @@ pub fn solve_yajilin_regions(
     borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
     clues: &[Option<i32>],
 ) -> Option<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
+    solve_yajilin_regions_with_query(borders, clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_yajilin_regions_with_query(
+    borders: &graph::InnerGridEdges<Vec<Vec<bool>>>,
+    clues: &[Option<i32>],
+    query: util::AnswerQuery,
+) -> Vec<(graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>)> {
     let (h, w) = borders.base_shape();
 
     let mut solver = Solver::new();
@@ -27,7 +36,12 @@ pub fn solve_yajilin_regions(
     assert_eq!(rooms.len(), clues.len());
 
     for i in 0..rooms.len() {
+        // Rooms without a clue, as well as "?" clues (represented by -1), may contain any
+        // number of black cells
         if let Some(n) = clues[i] {
+            if n < 0 {
+                continue;
+            }
             let mut cells = vec![];
             for &pt in &rooms[i] {
                 cells.push(is_black.at(pt));
@@ -36,9 +50,7 @@ pub fn solve_yajilin_regions(
         }
     }
 
-    solver
-        .irrefutable_facts()
-        .map(|f| (f.get(is_line), f.get(is_black)))
+    util::query_answers(solver, query, |f| (f.get(is_line), f.get(is_black)))
 }
 
 type Problem = (graph::InnerGridEdges<Vec<Vec<bool>>>, Vec<Option<i32>>);
@@ -47,6 +59,7 @@ fn combinator() -> impl Combinator<Problem> {
     Size::new(RoomsWithValues::new(Choice::new(vec![
         Box::new(Optionalize::new(HexInt)),
         Box::new(Spaces::new(None, 'g')),
+        Box::new(Dict::new(Some(-1), ".")),
     ])))
 }
 
@@ -65,6 +78,17 @@ pub fn deserialize_problem(url: &str) -> Option<Problem> {
     url_to_problem(combinator(), &["yajilin-regions"], url)
 }
 
+pub struct YajilinRegions;
+
+impl util::Puzzle for YajilinRegions {
+    type Problem = Problem;
+    type Answer = (graph::BoolGridEdgesIrrefutableFacts, Vec<Vec<Option<bool>>>);
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_yajilin_regions_with_query(&problem.0, &problem.1, query)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +140,18 @@ mod tests {
         let url = "https://puzz.link/p?yajilin-regions/6/6/ii02q2070d0gg221";
         util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
     }
+
+    #[test]
+    fn test_yajilin_regions_unknown_clue() {
+        let url = "https://puzz.link/p?yajilin-regions/6/6/ii02q2070d0gg.21";
+        let problem = deserialize_problem(url);
+        assert!(problem.is_some());
+        let problem = problem.unwrap();
+        assert_eq!(problem.1, vec![None, Some(-1), Some(2), Some(1)]);
+        assert_eq!(serialize_problem(&problem), Some(String::from(url)));
+        let (borders, clues) = problem;
+
+        let ans = solve_yajilin_regions(&borders, &clues);
+        assert!(ans.is_some());
+    }
 }
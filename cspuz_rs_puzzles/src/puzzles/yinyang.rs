@@ -0,0 +1,128 @@
+use crate::util;
+use cspuz_rs::graph;
+use cspuz_rs::serializer::{problem_to_url, url_to_problem, Combinator, Grid, Map, MultiDigit};
+use cspuz_rs::solver::{BoolVarArray2D, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YinyangClue {
+    None,
+    White,
+    Black,
+}
+
+pub fn solve_yinyang(clues: &[Vec<YinyangClue>]) -> Option<Vec<Vec<Option<bool>>>> {
+    solve_yinyang_with_query(clues, util::AnswerQuery::IrrefutableFacts).pop()
+}
+
+fn solve_yinyang_with_query(
+    clues: &[Vec<YinyangClue>],
+    query: util::AnswerQuery,
+) -> Vec<Vec<Vec<Option<bool>>>> {
+    let (h, w) = util::infer_shape(clues);
+
+    let mut solver = Solver::new();
+    let is_black = &solver.bool_var_2d((h, w));
+    solver.add_answer_key_bool(is_black);
+
+    add_constraints(clues, &mut solver, is_black);
+
+    util::query_answers(solver, query, |f| f.get(is_black))
+}
+
+fn add_constraints(clues: &[Vec<YinyangClue>], solver: &mut Solver, is_black: &BoolVarArray2D) {
+    graph::active_vertices_connected_2d(solver, is_black);
+    graph::active_vertices_connected_2d(solver, !is_black);
+    solver.add_expr(!is_black.conv2d_and((2, 2)));
+    solver.add_expr(is_black.conv2d_or((2, 2)));
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            match clue {
+                YinyangClue::None => (),
+                YinyangClue::White => solver.add_expr(!is_black.at((y, x))),
+                YinyangClue::Black => solver.add_expr(is_black.at((y, x))),
+            }
+        }
+    }
+}
+
+type Problem = Vec<Vec<YinyangClue>>;
+
+fn combinator() -> impl Combinator<Problem> {
+    Grid::new(Map::new(
+        MultiDigit::new(3, 3),
+        |x: YinyangClue| {
+            Some(match x {
+                YinyangClue::None => 0,
+                YinyangClue::White => 1,
+                YinyangClue::Black => 2,
+            })
+        },
+        |n: i32| match n {
+            0 => Some(YinyangClue::None),
+            1 => Some(YinyangClue::White),
+            2 => Some(YinyangClue::Black),
+            _ => None,
+        },
+    ))
+}
+
+pub fn serialize_problem(problem: &Problem) -> Option<String> {
+    problem_to_url(combinator(), "yinyang", problem.clone())
+}
+
+pub fn deserialize_problem(url: &str) -> Option<Problem> {
+    url_to_problem(combinator(), &["yinyang"], url)
+}
+
+pub struct Yinyang;
+
+impl util::Puzzle for Yinyang {
+    type Problem = Problem;
+    type Answer = Vec<Vec<Option<bool>>>;
+
+    fn query_answers(problem: &Problem, query: util::AnswerQuery) -> Vec<Self::Answer> {
+        solve_yinyang_with_query(problem, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{self, Puzzle};
+
+    fn problem_for_tests() -> Problem {
+        let mut ret = vec![vec![YinyangClue::None; 4]; 4];
+        ret[1][0] = YinyangClue::Black;
+        ret[2][1] = YinyangClue::Black;
+        ret[3][0] = YinyangClue::White;
+        ret[3][3] = YinyangClue::Black;
+        ret
+    }
+
+    #[test]
+    fn test_yinyang_problem() {
+        let problem = problem_for_tests();
+        let ans = solve_yinyang(&problem);
+        assert!(ans.is_some());
+        let ans = ans.unwrap();
+
+        let expected = util::tests::to_option_bool_2d([
+            [1, 1, 1, 1],
+            [1, 0, 0, 1],
+            [1, 1, 0, 1],
+            [0, 0, 0, 1],
+        ]);
+        assert_eq!(ans, expected);
+
+        let answers = Yinyang::enumerate_answers(&problem, 10);
+        assert_eq!(answers, vec![expected]);
+    }
+
+    #[test]
+    fn test_yinyang_serializer() {
+        let problem = problem_for_tests();
+        let url = "https://puzz.link/p?yinyang/4/4/060i9i";
+        util::tests::serializer_test(problem, url, serialize_problem, deserialize_problem);
+    }
+}
@@ -0,0 +1,177 @@
+//! Random clue boards of selected puzzles, for fuzzing and benchmarking the puzzle solvers.
+//!
+//! Random boards mostly have no answer or many answers, which exercises the solvers on
+//! instances unlike the well-formed problems in the puzzle tests. `check_consistency` is a
+//! reference check for such boards: the irrefutable facts returned by `Puzzle::solve` must be
+//! exactly the facts shared by all the answers found by `Puzzle::enumerate_answers`.
+
+use cspuz_core::random_instance::Rng;
+use cspuz_rs::graph::GridEdges;
+
+use crate::util::Puzzle;
+
+/// Returns an `height * width` grid in which each cell has a clue chosen from `clues` with
+/// probability `density`.
+pub fn random_clue_grid(
+    height: usize,
+    width: usize,
+    clues: &[i32],
+    density: f64,
+    seed: u64,
+) -> Vec<Vec<Option<i32>>> {
+    let mut rng = Rng::new(seed);
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    if rng.chance(density) {
+                        Some(*rng.choose(clues))
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Random Slitherlink problem.
+pub fn random_slitherlink(
+    height: usize,
+    width: usize,
+    density: f64,
+    seed: u64,
+) -> Vec<Vec<Option<i32>>> {
+    random_clue_grid(height, width, &[0, 1, 2, 3], density, seed)
+}
+
+/// Random Nurikabe problem, with clues up to the number of cells.
+pub fn random_nurikabe(
+    height: usize,
+    width: usize,
+    density: f64,
+    seed: u64,
+) -> Vec<Vec<Option<i32>>> {
+    let clues = (1..=(height * width) as i32).collect::<Vec<_>>();
+    random_clue_grid(height, width, &clues, density, seed)
+}
+
+/// Random Akari problem, in which `-1` stands for a black cell without a number.
+pub fn random_akari(height: usize, width: usize, density: f64, seed: u64) -> Vec<Vec<Option<i32>>> {
+    random_clue_grid(height, width, &[-1, 0, 1, 2, 3, 4], density, seed)
+}
+
+/// Answers consisting of cells which may be undecided (`None`).
+pub trait PartialAnswer {
+    /// Returns the cells flattened in a fixed order, so that the cells of answers of the same
+    /// problem can be compared one by one.
+    fn cells(&self) -> Vec<Option<i64>>;
+}
+
+impl<T: Copy + Into<i64>> PartialAnswer for Vec<Vec<Option<T>>> {
+    fn cells(&self) -> Vec<Option<i64>> {
+        self.iter()
+            .flat_map(|row| row.iter().map(|c| c.map(Into::into)))
+            .collect()
+    }
+}
+
+impl<T: PartialAnswer> PartialAnswer for GridEdges<T> {
+    fn cells(&self) -> Vec<Option<i64>> {
+        let mut ret = self.horizontal.cells();
+        ret.extend(self.vertical.cells());
+        ret
+    }
+}
+
+/// Checks that `P::solve` and `P::enumerate_answers` agree on `problem`, enumerating at most
+/// `num_max_answers` answers. Returns a description of the disagreement if they do not.
+///
+/// If all the answers are enumerated, the facts must be decided exactly in the cells in which
+/// all the answers agree; otherwise, they must only be consistent with the enumerated answers.
+pub fn check_consistency<P>(problem: &P::Problem, num_max_answers: usize) -> Result<(), String>
+where
+    P: Puzzle,
+    P::Answer: PartialAnswer,
+{
+    let facts = P::solve(problem);
+    let answers = P::enumerate_answers(problem, num_max_answers);
+
+    let Some(facts) = facts else {
+        return if answers.is_empty() {
+            Ok(())
+        } else {
+            Err("solve found no answer, but enumerate_answers did".to_string())
+        };
+    };
+    if answers.is_empty() {
+        return Err("enumerate_answers found no answer, but solve did".to_string());
+    }
+
+    let facts = facts.cells();
+    let answers = answers.iter().map(|a| a.cells()).collect::<Vec<_>>();
+    let is_complete = answers.len() < num_max_answers;
+    for (i, &fact) in facts.iter().enumerate() {
+        if answers.iter().any(|a| a[i].is_none()) {
+            return Err(format!("cell {} of an enumerated answer is undecided", i));
+        }
+        let shared = answers.iter().all(|a| a[i] == answers[0][i]);
+        match fact {
+            Some(_) if answers.iter().any(|a| a[i] != fact) => {
+                return Err(format!("fact at cell {} contradicts an answer", i));
+            }
+            None if is_complete && shared => {
+                return Err(format!(
+                    "cell {} is shared by all answers, but undecided",
+                    i
+                ));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzles::{akari, nurikabe, slitherlink};
+
+    #[test]
+    fn test_random_clue_grid_reproducible() {
+        let a = random_clue_grid(4, 5, &[1, 2], 0.5, 7);
+        assert_eq!(a, random_clue_grid(4, 5, &[1, 2], 0.5, 7));
+        assert_eq!((a.len(), a[0].len()), (4, 5));
+        assert!(a
+            .iter()
+            .flatten()
+            .all(|c| matches!(c, None | Some(1) | Some(2))));
+    }
+
+    #[test]
+    fn test_random_slitherlink_consistency() {
+        for seed in 0..10 {
+            let problem = random_slitherlink(3, 3, 0.4, seed);
+            check_consistency::<slitherlink::Slitherlink>(&problem, 20)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+
+    #[test]
+    fn test_random_nurikabe_consistency() {
+        for seed in 0..10 {
+            let problem = random_nurikabe(3, 3, 0.2, seed);
+            check_consistency::<nurikabe::Nurikabe>(&problem, 20)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+
+    #[test]
+    fn test_random_akari_consistency() {
+        for seed in 0..10 {
+            let problem = random_akari(4, 4, 0.3, seed);
+            check_consistency::<akari::Akari>(&problem, 20)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+}
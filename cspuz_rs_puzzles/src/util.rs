@@ -1,3 +1,4 @@
+use cspuz_rs::solver::{OwnedPartialModel, Solver};
 use std::ops::{Index, IndexMut};
 
 pub fn infer_shape<T>(array: &[Vec<T>]) -> (usize, usize) {
@@ -67,6 +68,49 @@ impl<T: Clone> IndexMut<(usize, usize)> for Grid<T> {
     }
 }
 
+/// Which answers of a puzzle instance are requested from the solver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnswerQuery {
+    /// A single partial answer consisting of the facts shared by all answers.
+    IrrefutableFacts,
+    /// At most the given number of distinct answers.
+    Enumerate(usize),
+}
+
+/// Runs `solver` according to `query` and converts each resulting model by `f`.
+///
+/// For `AnswerQuery::IrrefutableFacts`, the returned vector has at most one element.
+pub fn query_answers<T, F>(solver: Solver, query: AnswerQuery, f: F) -> Vec<T>
+where
+    F: FnMut(OwnedPartialModel) -> T,
+{
+    match query {
+        AnswerQuery::IrrefutableFacts => solver.irrefutable_facts().map(f).into_iter().collect(),
+        AnswerQuery::Enumerate(num_max_answers) => {
+            solver.answer_iter().take(num_max_answers).map(f).collect()
+        }
+    }
+}
+
+/// Uniform entry point for puzzle solvers.
+///
+/// Enumerated answers have the same type as the irrefutable facts returned by `solve`,
+/// with every cell being decided.
+pub trait Puzzle {
+    type Problem;
+    type Answer;
+
+    fn query_answers(problem: &Self::Problem, query: AnswerQuery) -> Vec<Self::Answer>;
+
+    fn solve(problem: &Self::Problem) -> Option<Self::Answer> {
+        Self::query_answers(problem, AnswerQuery::IrrefutableFacts).pop()
+    }
+
+    fn enumerate_answers(problem: &Self::Problem, num_max_answers: usize) -> Vec<Self::Answer> {
+        Self::query_answers(problem, AnswerQuery::Enumerate(num_max_answers))
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use cspuz_core::custom_constraints::SimpleCustomConstraint;
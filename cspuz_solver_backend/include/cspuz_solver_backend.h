@@ -0,0 +1,64 @@
+#ifndef CSPUZ_SOLVER_BACKEND_H
+#define CSPUZ_SOLVER_BACKEND_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Solves the puzzle given by `url` (a puzz.link or Kudamono URL), reusing the cached result if
+ * `cspuz_configure_solution_cache` enabled the cache.
+ *
+ * Returns the result as a JSON string `{"status": ..., "description": ..., "stats": ...}`,
+ * which must be released by `cspuz_free_string`.
+ *
+ * # Safety
+ *
+ * `url` must be null or point to a NUL-terminated string.
+ */
+char *cspuz_solve_url(const char *url);
+
+/**
+ * Returns the list of supported puzzle kinds as a JSON string `{"status": "ok", "description": [...]}`,
+ * which must be released by `cspuz_free_string`.
+ */
+char *cspuz_list_puzzle_kinds(void);
+
+/**
+ * Enables caching the results of `cspuz_solve_url` on the current thread, keeping at most
+ * `capacity` boards for at most `ttl_ms` milliseconds (no limit if `ttl_ms` is not positive).
+ * `capacity == 0` disables the cache. Any previously cached results are discarded.
+ */
+void cspuz_configure_solution_cache(uintptr_t capacity, double ttl_ms);
+
+/**
+ * Sets the limits on the size of problems solved on the current thread (see the `limits`
+ * module). A value of 0 means no limit, except for `max_url_length`, for which 0 restores the
+ * default.
+ */
+void cspuz_configure_resource_limits(uintptr_t max_url_length,
+                                     uintptr_t max_dimension,
+                                     uintptr_t max_cells,
+                                     uintptr_t max_variables,
+                                     uintptr_t max_clauses);
+
+/**
+ * Sets the schema version of the boards returned on the current thread (see the `board`
+ * module), for applications which only understand an older version. Returns `false` if
+ * `version` is not supported.
+ */
+bool cspuz_set_board_schema_version(uint32_t version);
+
+/**
+ * Releases a string returned by the functions of this library. Does nothing if `s` is null.
+ *
+ * # Safety
+ *
+ * `s` must be null or a string returned by this library which has not been released yet.
+ */
+void cspuz_free_string(char *s);
+
+#endif  /* CSPUZ_SOLVER_BACKEND_H */
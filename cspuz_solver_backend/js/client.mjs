@@ -0,0 +1,100 @@
+// Non-blocking interface to cspuz_solver_backend for web frontends.
+//
+// Solving runs in a Web Worker (`worker.mjs`, which must be served next to
+// `cspuz_solver_backend.mjs`), so the page stays responsive on hard instances:
+//
+//   const solver = new SolverWorker(new URL("./worker.mjs", import.meta.url));
+//   const { promise, abort } = solver.solve(url, (progress) => console.log(progress.elapsedMs));
+//   const response = await promise; // `{"status": ..., "description": ...}` as in `solve_problem`
+//
+// `abort()` cancels solving at the next yield point of the solver if the page is cross-origin
+// isolated (so that SharedArrayBuffer is available). Otherwise the worker is terminated, and the
+// other pending requests are sent again to a new worker, which solves them from the beginning.
+// Either way, `promise` then resolves to an error response with `error.kind === "cancelled"`,
+// and the other requests are not cancelled.
+
+const CANCELLED = {
+  status: "error",
+  description: "cancelled",
+  error: { kind: "cancelled", message: "cancelled", userError: false },
+};
+
+export class SolverWorker {
+  constructor(workerUrl) {
+    this.workerUrl = workerUrl;
+    this.worker = null;
+    this.nextId = 0;
+    this.pending = new Map();
+  }
+
+  ensureWorker() {
+    if (this.worker === null) {
+      this.worker = new Worker(this.workerUrl, { type: "module" });
+      this.worker.onmessage = (event) => this.onMessage(event.data);
+    }
+    return this.worker;
+  }
+
+  onMessage(message) {
+    const request = this.pending.get(message.id);
+    if (request === undefined) {
+      return;
+    }
+    if (message.type === "progress") {
+      if (request.onProgress) {
+        request.onProgress({ elapsedMs: message.elapsedMs, conflicts: message.conflicts });
+      }
+    } else if (message.type === "result") {
+      this.pending.delete(message.id);
+      request.resolve(message.response);
+    }
+  }
+
+  solve(url, onProgress) {
+    const id = this.nextId++;
+    const abortBuffer = typeof SharedArrayBuffer !== "undefined" && globalThis.crossOriginIsolated ? new SharedArrayBuffer(4) : null;
+
+    const promise = new Promise((resolve) => {
+      this.pending.set(id, { url, abortBuffer, resolve, onProgress });
+    });
+    this.ensureWorker().postMessage({ id, url, abortBuffer });
+
+    const abort = () => {
+      if (!this.pending.has(id)) {
+        return;
+      }
+      if (abortBuffer !== null) {
+        Atomics.store(new Int32Array(abortBuffer), 0, 1);
+      } else {
+        this.restartWithout(id);
+      }
+    };
+    return { promise, abort };
+  }
+
+  // Cancels request `id` by restarting the worker, as it cannot be interrupted otherwise without
+  // SharedArrayBuffer, and sends the other pending requests to the new worker.
+  restartWithout(id) {
+    const request = this.pending.get(id);
+    this.pending.delete(id);
+    this.worker.terminate();
+    this.worker = null;
+    request.resolve(CANCELLED);
+
+    for (const [otherId, other] of this.pending) {
+      this.ensureWorker().postMessage({ id: otherId, url: other.url, abortBuffer: other.abortBuffer });
+    }
+  }
+
+  // Stops the worker. All requests in progress resolve to the "cancelled" error response.
+  terminate() {
+    if (this.worker !== null) {
+      this.worker.terminate();
+      this.worker = null;
+    }
+    for (const request of this.pending.values()) {
+      request.resolve(CANCELLED);
+    }
+    this.pending.clear();
+  }
+}
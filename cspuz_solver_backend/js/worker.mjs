@@ -0,0 +1,61 @@
+// Web Worker running cspuz_solver_backend (see `client.mjs`).
+//
+// Receives `{ id, url, abortBuffer }`, where `abortBuffer` is a SharedArrayBuffer of 4 bytes
+// (or null), and posts `{ id, type: "progress", elapsedMs, conflicts }` while solving and
+// `{ id, type: "result", response }` at the end. `response` is the parsed JSON returned by the
+// backend. Solving is cancelled as soon as the main thread stores a nonzero value in `abortBuffer`.
+// If loading or running the backend throws (e.g. on a panic in the backend), `response` is an error
+// response with `error.kind === "other"`.
+
+import createModule from "./cspuz_solver_backend.mjs";
+
+const PROGRESS_INTERVAL_MS = 100;
+
+const modulePromise = createModule();
+
+function readResponse(module, ptr) {
+  const length = module.HEAPU8[ptr] | (module.HEAPU8[ptr + 1] << 8) | (module.HEAPU8[ptr + 2] << 16) | (module.HEAPU8[ptr + 3] << 24);
+  return JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(ptr + 4, ptr + 4 + length)));
+}
+
+function internalError(e) {
+  return {
+    status: "error",
+    description: "internal error",
+    error: { kind: "other", message: `internal error: ${e}`, userError: false },
+  };
+}
+
+self.onmessage = async (event) => {
+  const { id, url, abortBuffer } = event.data;
+  try {
+    await solve(id, url, abortBuffer);
+  } catch (e) {
+    self.postMessage({ id, type: "result", response: internalError(e) });
+  }
+};
+
+async function solve(id, url, abortBuffer) {
+  const module = await modulePromise;
+  const abortFlag = abortBuffer ? new Int32Array(abortBuffer) : null;
+
+  let lastProgress = -Infinity;
+  const callback = module.addFunction((elapsedMs, conflicts) => {
+    if (elapsedMs - lastProgress >= PROGRESS_INTERVAL_MS) {
+      lastProgress = elapsedMs;
+      self.postMessage({ id, type: "progress", elapsedMs, conflicts });
+    }
+    return abortFlag && Atomics.load(abortFlag, 0) !== 0 ? 1 : 0;
+  }, "idd");
+
+  const urlEncoded = new TextEncoder().encode(url);
+  const buf = module._malloc(urlEncoded.length);
+  module.HEAPU8.set(urlEncoded, buf);
+  try {
+    const ptr = module._solve_problem_with_progress(buf, urlEncoded.length, callback);
+    self.postMessage({ id, type: "result", response: readResponse(module, ptr) });
+  } finally {
+    module._free(buf);
+    module.removeFunction(callback);
+  }
+}
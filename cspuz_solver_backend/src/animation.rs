@@ -0,0 +1,76 @@
+//! Compact representation of the deductions of the solver over time, for animations.
+//!
+//! The boards returned by `decode_and_explain` mostly repeat each other, since each step only
+//! adds a few facts to the previous one. An `Animation` stores the first frame (the problem
+//! without any answer) as a full board, and each following frame as the items added to and
+//! removed from the previous one (e.g. an `"undetermined"` mark is removed when the cell is
+//! decided).
+
+use crate::board::{Board, Item};
+use crate::overlay::Layer;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct FrameDelta {
+    pub added: Vec<Item>,
+    pub removed: Vec<Item>,
+}
+
+impl FrameDelta {
+    fn between(prev: &Board, next: &Board) -> FrameDelta {
+        FrameDelta {
+            added: items_not_in(&next.data, &prev.data),
+            removed: items_not_in(&prev.data, &next.data),
+        }
+    }
+}
+
+/// Returns the items of `items` which are not in `others`, counting duplicates.
+fn items_not_in(items: &[Item], others: &[Item]) -> Vec<Item> {
+    let mut used = vec![false; others.len()];
+    let mut ret = vec![];
+    for item in items {
+        let pos = others
+            .iter()
+            .enumerate()
+            .position(|(i, other)| !used[i] && other == item);
+        match pos {
+            Some(i) => used[i] = true,
+            None => ret.push(item.clone()),
+        }
+    }
+    ret
+}
+
+#[derive(Serialize)]
+pub struct Animation {
+    pub initial: Board,
+    pub frames: Vec<FrameDelta>,
+}
+
+impl Animation {
+    /// Builds an animation showing `steps` one by one, starting from the problem of the first
+    /// step. `steps` must not be empty.
+    pub fn from_steps(steps: &[Board]) -> Animation {
+        let initial = steps[0].layer(Layer::Problem);
+        let mut frames = vec![];
+        let mut prev = &initial;
+        for step in steps {
+            frames.push(FrameDelta::between(prev, step));
+            prev = step;
+        }
+        Animation { initial, frames }
+    }
+
+    /// Reconstructs the items of each frame, excluding the initial one.
+    pub fn frame_items(&self) -> Vec<Vec<Item>> {
+        let mut ret = vec![];
+        let mut items = self.initial.data.clone();
+        for frame in &self.frames {
+            items = items_not_in(&items, &frame.removed);
+            items.extend(frame.added.iter().cloned());
+            ret.push(items.clone());
+        }
+        ret
+    }
+}
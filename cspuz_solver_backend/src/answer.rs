@@ -0,0 +1,73 @@
+//! Raw answer data attached to boards.
+//!
+//! Board items describe how to draw the answer, which is inconvenient for programs consuming
+//! it: they would have to map e.g. `"block"` / `"dot"` items back to cells. Solvers therefore
+//! may attach the deduced facts themselves as named `AnswerGrid`s (see `Board::add_answer`).
+//! Each value is `null` if it could not be deduced.
+//!
+//! An answer grid is serialized as one of:
+//!
+//! - `{"kind": "cells", "data": [[bool | null]]}`: a boolean per cell (e.g. whether it is shaded).
+//! - `{"kind": "numbers", "data": [[int | null]]}`: a number per cell.
+//! - `{"kind": "borders", "horizontal": [[bool | null]], "vertical": [[bool | null]]}`: a boolean
+//!   per cell border, including the outer ones. `horizontal[y][x]` is the upper border of cell
+//!   `(y, x)` (shape `(H + 1, W)`), and `vertical[y][x]` is its left border (shape `(H, W + 1)`).
+//! - `{"kind": "lines", "horizontal": [[bool | null]], "vertical": [[bool | null]]}`: a boolean
+//!   per pair of adjacent cells, e.g. whether a loop passes between them. `horizontal[y][x]`
+//!   connects `(y, x)` and `(y, x + 1)` (shape `(H, W - 1)`), and `vertical[y][x]` connects
+//!   `(y, x)` and `(y + 1, x)` (shape `(H - 1, W)`).
+
+use cspuz_rs::graph;
+use serde::Serialize;
+
+type Grid<T> = Vec<Vec<Option<T>>>;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AnswerGrid {
+    Cells {
+        data: Grid<bool>,
+    },
+    Numbers {
+        data: Grid<i32>,
+    },
+    Borders {
+        horizontal: Grid<bool>,
+        vertical: Grid<bool>,
+    },
+    Lines {
+        horizontal: Grid<bool>,
+        vertical: Grid<bool>,
+    },
+}
+
+impl AnswerGrid {
+    pub fn cells(data: &[Vec<Option<bool>>]) -> AnswerGrid {
+        AnswerGrid::Cells {
+            data: data.to_vec(),
+        }
+    }
+
+    pub fn numbers(data: &[Vec<Option<i32>>]) -> AnswerGrid {
+        AnswerGrid::Numbers {
+            data: data.to_vec(),
+        }
+    }
+
+    /// Answer grid of the cell borders `edges`, as used by e.g. Slitherlink.
+    pub fn borders(edges: &graph::BoolGridEdgesIrrefutableFacts) -> AnswerGrid {
+        AnswerGrid::Borders {
+            horizontal: edges.horizontal.clone(),
+            vertical: edges.vertical.clone(),
+        }
+    }
+
+    /// Answer grid of the lines `edges` between cell centers, in the representation used by
+    /// `Board::add_lines_irrefutable_facts`.
+    pub fn lines(edges: &graph::BoolGridEdgesIrrefutableFacts) -> AnswerGrid {
+        AnswerGrid::Lines {
+            horizontal: edges.horizontal.clone(),
+            vertical: edges.vertical.clone(),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Verification of many problems at once, e.g. for re-checking an archive of puzzles.
+//!
+//! `verify_urls` solves the problems on a pool of threads and classifies each of them by
+//! `VerificationStatus`. Since solver state such as the default config and resource limits is
+//! thread-local, each worker thread solves its problems independently.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::SolveError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BatchOptions {
+    /// Number of worker threads. 0 means the available parallelism of the machine.
+    pub num_threads: usize,
+    /// Problems taking at least this long are reported as slow.
+    pub slow_threshold: Duration,
+    /// If set, solving each problem is stopped after this duration.
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            num_threads: 0,
+            slow_threshold: Duration::from_secs(1),
+            time_limit: None,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum VerificationStatus {
+    /// The problem has a unique answer.
+    Unique,
+    /// The problem has more than one answer.
+    NonUnique,
+    /// The problem was solved, but the puzzle kind does not tell whether the answer is unique.
+    Solved,
+    /// The problem has no answer.
+    NoAnswer,
+    /// Solving did not finish within `BatchOptions::time_limit`.
+    Timeout,
+    /// The problem could not be solved for another reason, e.g. an invalid URL.
+    Error(SolveError),
+}
+
+impl VerificationStatus {
+    /// Whether the problem needs the attention of its maintainer.
+    pub fn is_problematic(&self) -> bool {
+        !matches!(
+            self,
+            VerificationStatus::Unique | VerificationStatus::Solved
+        )
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VerificationResult {
+    pub url: String,
+    pub status: VerificationStatus,
+    pub time: Duration,
+    /// Whether `time` is at least `BatchOptions::slow_threshold`.
+    pub is_slow: bool,
+}
+
+fn verify_url(url: &str, options: &BatchOptions) -> VerificationResult {
+    let start = Instant::now();
+    let result = match options.time_limit {
+        Some(time_limit) => crate::decode_and_solve_with_time_limit(url.as_bytes(), time_limit),
+        None => crate::decode_and_solve(url.as_bytes()),
+    };
+    let time = start.elapsed();
+
+    let status = match result {
+        Ok(board) if board.is_partial() => VerificationStatus::Timeout,
+        Ok(board) => match board.is_unique() {
+            Some(true) => VerificationStatus::Unique,
+            Some(false) => VerificationStatus::NonUnique,
+            None => VerificationStatus::Solved,
+        },
+        Err(SolveError::NoSolution) => VerificationStatus::NoAnswer,
+        Err(SolveError::Timeout) => VerificationStatus::Timeout,
+        Err(e) => VerificationStatus::Error(e),
+    };
+    VerificationResult {
+        url: url.to_string(),
+        status,
+        time,
+        is_slow: time >= options.slow_threshold,
+    }
+}
+
+/// Solves the problems given by `urls` in parallel, and returns the results in the same order as
+/// `urls`.
+pub fn verify_urls<S: AsRef<str> + Sync>(
+    urls: &[S],
+    options: &BatchOptions,
+) -> Vec<VerificationResult> {
+    let num_threads = if options.num_threads == 0 {
+        std::thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        options.num_threads
+    };
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; urls.len()]);
+    std::thread::scope(|scope| {
+        for _ in 0..num_threads.min(urls.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= urls.len() {
+                    break;
+                }
+                let result = verify_url(urls[i].as_ref(), options);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.unwrap())
+        .collect()
+}
+
+/// Returns the URLs listed in `text`, one per line. Blank lines and lines starting with `#` are
+/// ignored.
+pub fn parse_url_list(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+}
@@ -0,0 +1,352 @@
+//! Benchmark harness running a fixed suite of instances, so that the performance of the encoder
+//! and the SAT layer can be compared between revisions.
+//!
+//! ```text
+//! bench [--format csv|json] [--runs N] [--filter SUBSTRING]
+//! ```
+//!
+//! The suite consists of puzzle URLs, solved through `decode_and_solve` as the frontend does, and
+//! CSP instances built directly on `cspuz_core`. Each instance is solved `--runs` times (3 by
+//! default) and its minimum, mean and maximum wall-clock times are reported, together with the
+//! size of the largest SAT instance and the number of conflicts. Per-instance results are
+//! written to stdout; in CSV format, the aggregate statistics are written to stderr.
+
+use std::time::Instant;
+
+use cspuz_core::csp::{BoolExpr, IntExpr, Stmt};
+use cspuz_core::domain::Domain;
+use cspuz_core::integration::IntegratedSolver;
+use cspuz_core::sat;
+use serde::Serialize;
+
+const USAGE: &str = "usage: bench [--format csv|json] [--runs N] [--filter SUBSTRING]";
+
+const PUZZLE_INSTANCES: &[(&str, &str)] = &[
+    (
+        "akari_10x10",
+        "https://puzz.link/p?akari/10/10/hcscl.h.idn.i.cgcndg.h.ncs.h",
+    ),
+    (
+        "creek_6x7",
+        "https://puzz.link/p?creek/6/7/q2cgcj18cdm3c88cl",
+    ),
+    (
+        "heyawake_6x6",
+        "https://puzz.link/p?heyawake/6/6/aa66aapv0fu0g2i3k",
+    ),
+    (
+        "lits_10x10",
+        "https://puzz.link/p?lits/10/10/08p0i3jbhmjg5j5ik048rgtr8q1e5gkf9hnu",
+    ),
+    (
+        "masyu_10x10",
+        "https://puzz.link/p?masyu/10/10/0600003i06b1300600000a30600i090330",
+    ),
+    ("nurikabe_6x6", "https://puzz.link/p?nurikabe/6/6/m8n8i9u"),
+    (
+        "nurimisaki_10x10",
+        "https://puzz.link/p?nurimisaki/10/10/j3l3v2t2p.g2j2w2k2n3k",
+    ),
+    (
+        "slitherlink_4x4",
+        "https://puzz.link/p?slither/4/4/dgdh2c71",
+    ),
+    (
+        "sudoku_9x9",
+        "https://puzz.link/p?sudoku/9/9/k8g1g7i2i99o2g3h75q19h5g4o83i4i6g4g5k",
+    ),
+    ("tapa_6x8", "https://puzz.link/p?tapa/6/8/q2g9g.qb0pa0ccn"),
+    (
+        "yajilin_10x10",
+        "https://puzz.link/p?yajilin/10/10/w32a41b21a21l22e30m21a12b11r20d30g",
+    ),
+];
+
+/// Builds and solves a CSP instance, returning whether it is satisfiable.
+type CspInstance = fn() -> bool;
+
+const CSP_INSTANCES: &[(&str, CspInstance)] = &[
+    ("queens_12", || queens(12)),
+    ("latin_square_9", || latin_square(9)),
+    ("magic_square_4", magic_square_4),
+    ("pigeonhole_8_7", || pigeonhole(8, 7)),
+];
+
+fn sum(terms: impl IntoIterator<Item = IntExpr>) -> IntExpr {
+    IntExpr::Linear(terms.into_iter().map(|e| (Box::new(e), 1)).collect())
+}
+
+/// Places `n` queens on an `n * n` board without two attacking each other.
+fn queens(n: i32) -> bool {
+    let mut solver = IntegratedSolver::new();
+    let rows = (0..n)
+        .map(|_| solver.new_int_var(Domain::range(0, n - 1)).expr())
+        .collect::<Vec<_>>();
+    let diagonals = |sign: i32| {
+        rows.iter()
+            .enumerate()
+            .map(|(i, r)| r.clone() + IntExpr::Const(sign * i as i32))
+            .collect::<Vec<_>>()
+    };
+    let (diagonal1, diagonal2) = (diagonals(1), diagonals(-1));
+    solver.add_constraint(Stmt::AllDifferent(rows));
+    solver.add_constraint(Stmt::AllDifferent(diagonal1));
+    solver.add_constraint(Stmt::AllDifferent(diagonal2));
+    solver.solve().is_some()
+}
+
+/// Fills an `n * n` grid with `1..=n` so that no number appears twice in a row or a column.
+fn latin_square(n: usize) -> bool {
+    let mut solver = IntegratedSolver::new();
+    let cells = (0..n)
+        .map(|_| {
+            (0..n)
+                .map(|_| solver.new_int_var(Domain::range(1, n as i32)).expr())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for i in 0..n {
+        solver.add_constraint(Stmt::AllDifferent(cells[i].clone()));
+        solver.add_constraint(Stmt::AllDifferent(
+            cells.iter().map(|row| row[i].clone()).collect(),
+        ));
+    }
+    solver.solve().is_some()
+}
+
+/// Magic square of order 4, which stresses the encoding of linear constraints.
+fn magic_square_4() -> bool {
+    const N: usize = 4;
+    const MAGIC_SUM: i32 = 34;
+
+    let mut solver = IntegratedSolver::new();
+    let cells = (0..N)
+        .map(|_| {
+            (0..N)
+                .map(|_| solver.new_int_var(Domain::range(1, 16)).expr())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    solver.add_constraint(Stmt::AllDifferent(cells.concat()));
+
+    let mut lines = vec![];
+    for i in 0..N {
+        lines.push(cells[i].clone());
+        lines.push(cells.iter().map(|row| row[i].clone()).collect());
+    }
+    lines.push((0..N).map(|i| cells[i][i].clone()).collect());
+    lines.push((0..N).map(|i| cells[i][N - 1 - i].clone()).collect());
+    for line in lines {
+        solver.add_expr(sum(line).eq(IntExpr::Const(MAGIC_SUM)));
+    }
+    solver.solve().is_some()
+}
+
+/// Puts `pigeons` pigeons into `holes` holes, at most one per hole. Unsatisfiable (and hard for
+/// SAT solvers) if `pigeons > holes`.
+fn pigeonhole(pigeons: usize, holes: usize) -> bool {
+    let mut solver = IntegratedSolver::new();
+    let placed = (0..pigeons)
+        .map(|_| {
+            (0..holes)
+                .map(|_| solver.new_bool_var().expr())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for row in &placed {
+        solver.add_expr(BoolExpr::Or(row.iter().cloned().map(Box::new).collect()));
+    }
+    for j in 0..holes {
+        let count = sum(placed
+            .iter()
+            .map(|row| row[j].clone().ite(IntExpr::Const(1), IntExpr::Const(0))));
+        solver.add_expr(count.le(IntExpr::Const(1)));
+    }
+    solver.solve().is_some()
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InstanceResult {
+    name: &'static str,
+    category: &'static str,
+    status: String,
+    runs: usize,
+    min_ms: f64,
+    mean_ms: f64,
+    max_ms: f64,
+    variables: usize,
+    clauses: usize,
+    conflicts: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+    instances: usize,
+    failures: usize,
+    /// Sum of the mean times of all instances.
+    total_ms: f64,
+    /// Geometric mean of the mean times of all instances, which is less dominated by the
+    /// slowest instances than `total_ms`.
+    geometric_mean_ms: f64,
+}
+
+#[derive(Serialize)]
+struct Report {
+    instances: Vec<InstanceResult>,
+    summary: Summary,
+}
+
+/// Runs `solve` `runs` times, where `solve` returns the status of the run (the same in all runs).
+fn measure<F>(name: &'static str, category: &'static str, runs: usize, solve: F) -> InstanceResult
+where
+    F: Fn() -> String,
+{
+    let mut times = vec![];
+    let mut status = String::new();
+    let mut stats = sat::SolveStats::default();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let (run_status, run_stats) = sat::collect_stats(&solve);
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+        status = run_status;
+        stats = run_stats;
+    }
+    InstanceResult {
+        name,
+        category,
+        status,
+        runs,
+        min_ms: times.iter().copied().fold(f64::INFINITY, f64::min),
+        mean_ms: times.iter().sum::<f64>() / runs as f64,
+        max_ms: times.iter().copied().fold(0.0, f64::max),
+        variables: stats.variables,
+        clauses: stats.clauses,
+        conflicts: stats.conflicts,
+    }
+}
+
+fn summarize(results: &[InstanceResult]) -> Summary {
+    let log_sum = results
+        .iter()
+        .map(|r| r.mean_ms.max(1e-3).ln())
+        .sum::<f64>();
+    Summary {
+        instances: results.len(),
+        failures: results
+            .iter()
+            .filter(|r| r.status.starts_with("error"))
+            .count(),
+        total_ms: results.iter().map(|r| r.mean_ms).sum(),
+        geometric_mean_ms: if results.is_empty() {
+            0.0
+        } else {
+            (log_sum / results.len() as f64).exp()
+        },
+    }
+}
+
+fn print_csv(results: &[InstanceResult], summary: &Summary) {
+    println!("name,category,status,runs,min_ms,mean_ms,max_ms,variables,clauses,conflicts");
+    for r in results {
+        println!(
+            "{},{},\"{}\",{},{:.3},{:.3},{:.3},{},{},{}",
+            r.name,
+            r.category,
+            r.status.replace('"', "\"\""),
+            r.runs,
+            r.min_ms,
+            r.mean_ms,
+            r.max_ms,
+            r.variables,
+            r.clauses,
+            r.conflicts
+        );
+    }
+    eprintln!(
+        "instances: {}, failures: {}, total: {:.3} ms, geometric mean: {:.3} ms",
+        summary.instances, summary.failures, summary.total_ms, summary.geometric_mean_ms
+    );
+}
+
+struct Args {
+    json: bool,
+    runs: usize,
+    filter: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut ret = Args {
+        json: false,
+        runs: 3,
+        filter: None,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--format" => {
+                ret.json = match value()?.as_str() {
+                    "csv" => false,
+                    "json" => true,
+                    format => return Err(format!("unknown format: {}", format)),
+                }
+            }
+            "--runs" => {
+                ret.runs = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--runs must be a positive integer")?;
+            }
+            "--filter" => ret.filter = Some(value()?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(ret)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let selected = |name: &str| {
+        args.filter
+            .as_ref()
+            .is_none_or(|f| name.contains(f.as_str()))
+    };
+
+    let mut results = vec![];
+    for &(name, url) in PUZZLE_INSTANCES.iter().filter(|(name, _)| selected(name)) {
+        results.push(measure(name, "puzzle", args.runs, || {
+            match cspuz_solver_backend::decode_and_solve(url.as_bytes()) {
+                Ok(_) => "solved".to_string(),
+                Err(e) => format!("error: {}", e),
+            }
+        }));
+    }
+    for &(name, solve) in CSP_INSTANCES.iter().filter(|(name, _)| selected(name)) {
+        results.push(measure(name, "csp", args.runs, || {
+            if solve() { "sat" } else { "unsat" }.to_string()
+        }));
+    }
+
+    let summary = summarize(&results);
+    if args.json {
+        let report = Report {
+            instances: results,
+            summary,
+        };
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        print_csv(&results, &summary);
+    }
+}
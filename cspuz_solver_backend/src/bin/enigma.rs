@@ -0,0 +1,191 @@
+//! Command line interface of the solver backend.
+//!
+//! ```text
+//! enigma generate <kind> <H>x<W> [--difficulty easy|normal|hard] [--seed N]
+//! ```
+//!
+//! prints the puzz.link URL of a generated problem of the puzz.link puzzle kind `<kind>` (e.g.
+//! `slither`). The same seed always yields the same problem; if `--seed` is omitted, a seed is
+//! chosen from the current time and reported on stderr so that the problem can be reproduced.
+//! This requires the `generator` feature.
+//!
+//! ```text
+//! enigma verify <file> [--threads N] [--slow-ms N] [--time-limit-ms N]
+//! ```
+//!
+//! solves the problems whose URLs are listed in `<file>` (one per line, `-` for stdin) in
+//! parallel, and prints those which are non-unique, unsolvable, erroneous or slow, as
+//! tab-separated `status`, `time_ms` and `url`. The exit status is 1 if any problem other than
+//! a slow one is found.
+
+use std::time::Duration;
+
+use cspuz_solver_backend::batch::{self, BatchOptions, VerificationStatus};
+#[cfg(feature = "generator")]
+use cspuz_solver_backend::generate::{Difficulty, GenerateOptions};
+
+const USAGE: &str = "usage:
+  enigma generate <kind> <H>x<W> [--difficulty easy|normal|hard] [--seed N]
+  enigma verify <file> [--threads N] [--slow-ms N] [--time-limit-ms N]";
+
+#[cfg(feature = "generator")]
+struct GenerateArgs {
+    kind: String,
+    height: usize,
+    width: usize,
+    difficulty: Difficulty,
+    seed: Option<u64>,
+}
+
+#[cfg(feature = "generator")]
+fn parse_size(size: &str) -> Option<(usize, usize)> {
+    let (height, width) = size.split_once('x')?;
+    Some((height.parse().ok()?, width.parse().ok()?))
+}
+
+#[cfg(feature = "generator")]
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut positional = vec![];
+    let mut difficulty = Difficulty::Normal;
+    let mut seed = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--difficulty" => {
+                let value = args.next().ok_or("--difficulty requires a value")?;
+                difficulty = value
+                    .parse()
+                    .map_err(|_| format!("unknown difficulty: {}", value))?;
+            }
+            "--seed" => {
+                let value = args.next().ok_or("--seed requires a value")?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {}", value))?,
+                );
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let [kind, size] = positional[..] else {
+        return Err(USAGE.to_string());
+    };
+    let (height, width) = parse_size(size).ok_or_else(|| format!("invalid size: {}", size))?;
+    Ok(GenerateArgs {
+        kind: kind.clone(),
+        height,
+        width,
+        difficulty,
+        seed,
+    })
+}
+
+#[cfg(feature = "generator")]
+fn generate(args: &[String]) -> Result<(), String> {
+    let args = parse_generate_args(args)?;
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        eprintln!("seed: {}", seed);
+        seed
+    });
+    let options = GenerateOptions::with_difficulty(seed, args.difficulty);
+
+    let problem =
+        cspuz_solver_backend::generate_problem(&args.kind, args.height, args.width, &options)
+            .map_err(|e| e.to_string())?;
+    eprintln!("steps: {}", problem.difficulty);
+    println!("{}", problem.url);
+    Ok(())
+}
+
+#[cfg(not(feature = "generator"))]
+fn generate(_args: &[String]) -> Result<(), String> {
+    Err("this build does not support problem generation (enable the `generator` feature)".into())
+}
+
+fn parse_verify_args(args: &[String]) -> Result<(String, BatchOptions), String> {
+    let mut file = None;
+    let mut options = BatchOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut number = || -> Result<u64, String> {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} requires a value", arg))?;
+            value
+                .parse()
+                .map_err(|_| format!("invalid value of {}: {}", arg, value))
+        };
+        match arg.as_str() {
+            "--threads" => options.num_threads = number()? as usize,
+            "--slow-ms" => options.slow_threshold = Duration::from_millis(number()?),
+            "--time-limit-ms" => options.time_limit = Some(Duration::from_millis(number()?)),
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok((file.ok_or(USAGE)?, options))
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let (file, options) = parse_verify_args(args)?;
+    let text = if file == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(&file)
+    }
+    .map_err(|e| format!("failed to read {}: {}", file, e))?;
+    let urls = batch::parse_url_list(&text);
+
+    let results = batch::verify_urls(&urls, &options);
+    let mut num_problematic = 0;
+    for result in &results {
+        let status = match &result.status {
+            VerificationStatus::Unique | VerificationStatus::Solved if result.is_slow => {
+                "slow".to_string()
+            }
+            VerificationStatus::Unique | VerificationStatus::Solved => continue,
+            VerificationStatus::NonUnique => "non-unique".to_string(),
+            VerificationStatus::NoAnswer => "no-answer".to_string(),
+            VerificationStatus::Timeout => "timeout".to_string(),
+            VerificationStatus::Error(e) => format!("error: {}", e),
+        };
+        if result.status.is_problematic() {
+            num_problematic += 1;
+        }
+        println!("{}\t{}\t{}", status, result.time.as_millis(), result.url);
+    }
+
+    let num_slow = results.iter().filter(|r| r.is_slow).count();
+    eprintln!(
+        "verified {} problems: {} problematic, {} slow",
+        results.len(),
+        num_problematic,
+        num_slow
+    );
+    if num_problematic > 0 {
+        return Err(format!("{} problems need attention", num_problematic));
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("generate") => generate(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
@@ -1,7 +1,95 @@
+//! Board description passed to the frontends.
+//!
+//! A `Board` is serialized to JSON as follows (version 2 of the schema):
+//!
+//! - `version`: the schema version (`BOARD_SCHEMA_VERSION`).
+//! - `kind`: always `"grid"`.
+//! - `height`, `width`: the number of rows and columns of cells.
+//! - `defaultStyle`: one of `"empty"`, `"grid"`, `"outer_grid"` and `"dots"`.
+//! - `data`: a list of items `{"y": .., "x": .., "color": .., "item": ..}`.
+//!   Coordinates are in half-cell units: cell `(r, c)` is at `(2r + 1, 2c + 1)`, and even
+//!   values refer to cell borders or vertices. `color` is any CSS color; the solvers use the
+//!   colors of `ColorTheme::DEFAULT` (see the `theme` module) unless another theme is applied.
+//!   Items which appear only in the displayed solution, rather than in all solutions, have
+//!   `"forced": false` (omitted otherwise).
+//!   Boards built by `Board::overlay` and `Board::diff_answers` also have `"layer"`
+//!   (`"problem"` or `"answer"`) and, for the latter, `"diff"` (`"common"`, `"missing"` or
+//!   `"extra"`) on each item.
+//! - `answer`: the deduced facts as named grids rather than as items, for programmatic consumers
+//!   (omitted if the solver provides none). See the `answer` module for the format.
+//! - `isUnique`: whether the displayed answer is unique (omitted when not applicable).
+//! - `isPartial`: `true` if solving was stopped early, so that the board shows only the facts
+//!   proven by then (omitted otherwise).
+//!
+//! `item` is either a string naming a shape (e.g. `"dot"`, `"block"`, `"line"`, `"boldWall"`,
+//! or `"undetermined"` for cells whose answer could not be deduced) or an object with a `kind`
+//! field.
+//!
+//! Some shapes are specific to a few genres:
+//!
+//! - `"inequality{Up,Down,Left,Right}"` on cell borders, pointing to the smaller cell
+//! - `"thermoBulb"` in cells and `"thermoPath"` on the borders between cells of a thermometer
+//! - `"shipSingle"`, `"shipMiddle"` and `"shipEnd{Up,Down,Left,Right}"` in cells
+//! - `"boldSlash"` and `"boldBackslash"` in cells
+//!
+//! The objects are:
+//!
+//! - `{"kind": "text", "data": string, "pos"?: string}`, where `pos` is one of `"upperLeft"`,
+//!   `"upperRight"`, `"lowerLeft"` and `"lowerRight"` (omitted for texts centered in the cell).
+//!   Small numbers in cell corners are texts with `pos`.
+//! - `{"kind": "compass", "up": int, "down": int, "left": int, "right": int}` (-1 if absent)
+//! - `{"kind": "tapaClue", "value": [int; 4]}`
+//! - `{"kind": "sudokuCandidateSet", "size": int, "values": [int]}`
+//! - `{"kind": "firefly", "dot": "up" | "down" | "left" | "right", "value": int}`
+//! - `{"kind": "lineTo", "destY": int, "destX": int}`
+//! - `{"kind": "wall", "width": "thin" | "medium" | "bold", "dash": "solid" | "dashed" | "dotted"}`
+//! - `{"kind": "line", "layer": int, "width": .., "dash": ..}` (lines of higher layers are drawn
+//!   beside those of lower layers on the same edge)
+//!
+//! Fields are only ever added within a version; any incompatible change bumps the version.
+//!
+//! Frontends which only understand an older version can request it with
+//! `set_board_schema_version`; boards are then downgraded when serialized. The versions are:
+//!
+//! - 1: the initial schema.
+//! - 2: adds the genre-specific shapes, the `wall` and `line` objects and `answer`. In version 1,
+//!   these shapes are replaced by the closest shapes of version 1 (e.g. `"thermoBulb"` by
+//!   `"circle"`) and `answer` is omitted.
+
+use crate::answer::AnswerGrid;
+use crate::overlay::{DiffStatus, Layer};
+use crate::theme::{ColorRole, ItemColor};
 use crate::uniqueness::Uniqueness;
 use cspuz_rs::graph;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+pub const BOARD_SCHEMA_VERSION: u32 = 2;
+/// The oldest schema version which boards can still be serialized in.
+pub const MIN_BOARD_SCHEMA_VERSION: u32 = 1;
+
+thread_local! {
+    static SCHEMA_VERSION: Cell<u32> = const { Cell::new(BOARD_SCHEMA_VERSION) };
+}
+
+/// Sets the schema version of boards serialized on the current thread. Returns `false` (and
+/// keeps the current version) if `version` is not supported.
+pub fn set_board_schema_version(version: u32) -> bool {
+    if !(MIN_BOARD_SCHEMA_VERSION..=BOARD_SCHEMA_VERSION).contains(&version) {
+        return false;
+    }
+    SCHEMA_VERSION.with(|v| v.set(version));
+    true
+}
+
+/// Returns the schema version of boards serialized on the current thread.
+pub fn board_schema_version() -> u32 {
+    SCHEMA_VERSION.with(|v| v.get())
+}
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Compass {
     pub up: Option<i32>,
     pub down: Option<i32>,
@@ -9,7 +97,7 @@ pub struct Compass {
     pub right: Option<i32>,
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum FireflyDir {
     Up,
     Down,
@@ -17,8 +105,35 @@ pub enum FireflyDir {
     Right,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrokeWidth {
+    Thin,
+    Medium,
+    Bold,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrokeDash {
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+/// Style of walls and lines drawn by `ItemKind::StyledWall` and `ItemKind::LayeredLine`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StrokeStyle {
+    pub width: StrokeWidth,
+    pub dash: StrokeDash,
+}
+
+impl StrokeStyle {
+    pub const fn new(width: StrokeWidth, dash: StrokeDash) -> StrokeStyle {
+        StrokeStyle { width, dash }
+    }
+}
+
 #[allow(unused)]
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum ItemKind {
     Dot,
     Block,
@@ -66,6 +181,33 @@ pub enum ItemKind {
     DottedSlash,
     DottedBackslash,
     Plus,
+    Undetermined,
+    /// Inequality sign on a cell border, pointing to the smaller cell (`InequalityLeft` is `<`).
+    InequalityUp,
+    InequalityDown,
+    InequalityLeft,
+    InequalityRight,
+    /// Bulb of a thermometer.
+    ThermoBulb,
+    /// Tube of a thermometer through a cell border, connecting the centers of the two cells.
+    ThermoPath,
+    /// Ship of length 1 (Battleship).
+    ShipSingle,
+    /// Ship segment at the top end of a vertical ship.
+    ShipEndUp,
+    ShipEndDown,
+    ShipEndLeft,
+    ShipEndRight,
+    /// Ship segment which is neither end of its ship.
+    ShipMiddle,
+    BoldSlash,
+    BoldBackslash,
+    /// Wall along the edge at `(y, x)`, like `Wall` but in the given style.
+    StyledWall(StrokeStyle),
+    /// Line through the edge at `(y, x)` in the given layer and style. Layer 0 is drawn like
+    /// `Line`, and higher layers are drawn beside it, so that several lines on one edge (e.g. a
+    /// loop along a region border, or two independent loops) remain distinguishable.
+    LayeredLine(u8, StrokeStyle),
     Text(&'static str),
     Num(i32),
     NumUpperLeft(i32),
@@ -80,139 +222,249 @@ pub enum ItemKind {
 }
 
 impl ItemKind {
+    /// Returns the name of this item if it is serialized as a plain string.
+    fn unit_name(&self) -> Option<&'static str> {
+        let name = match self {
+            ItemKind::Dot => "dot",
+            ItemKind::Block => "block",
+            ItemKind::Square => "square",
+            ItemKind::Triangle => "triangle",
+            ItemKind::Fill => "fill",
+            ItemKind::Circle => "circle",
+            ItemKind::FilledCircle => "filledCircle",
+            ItemKind::SmallCircle => "smallCircle",
+            ItemKind::SmallFilledCircle => "smallFilledCircle",
+            ItemKind::SideArrowUp => "sideArrowUp",
+            ItemKind::SideArrowDown => "sideArrowDown",
+            ItemKind::SideArrowLeft => "sideArrowLeft",
+            ItemKind::SideArrowRight => "sideArrowRight",
+            ItemKind::ArrowUp => "arrowUp",
+            ItemKind::ArrowDown => "arrowDown",
+            ItemKind::ArrowLeft => "arrowLeft",
+            ItemKind::ArrowRight => "arrowRight",
+            ItemKind::AboloUpperLeft => "aboloUpperLeft",
+            ItemKind::AboloUpperRight => "aboloUpperRight",
+            ItemKind::AboloLowerLeft => "aboloLowerLeft",
+            ItemKind::AboloLowerRight => "aboloLowerRight",
+            ItemKind::PencilUp => "pencilUp",
+            ItemKind::PencilDown => "pencilDown",
+            ItemKind::PencilLeft => "pencilLeft",
+            ItemKind::PencilRight => "pencilRight",
+            ItemKind::Cross => "cross",
+            ItemKind::Line => "line",
+            ItemKind::DottedLine => "dottedLine",
+            ItemKind::DoubleLine => "doubleLine",
+            ItemKind::Wall => "wall",
+            ItemKind::BoldWall => "boldWall",
+            ItemKind::DottedWall => "dottedWall",
+            ItemKind::Slash => "slash",
+            ItemKind::Backslash => "backslash",
+            ItemKind::DottedSlash => "dottedSlash",
+            ItemKind::DottedBackslash => "dottedBackslash",
+            ItemKind::Plus => "plus",
+            ItemKind::Undetermined => "undetermined",
+            ItemKind::InequalityUp => "inequalityUp",
+            ItemKind::InequalityDown => "inequalityDown",
+            ItemKind::InequalityLeft => "inequalityLeft",
+            ItemKind::InequalityRight => "inequalityRight",
+            ItemKind::ThermoBulb => "thermoBulb",
+            ItemKind::ThermoPath => "thermoPath",
+            ItemKind::ShipSingle => "shipSingle",
+            ItemKind::ShipEndUp => "shipEndUp",
+            ItemKind::ShipEndDown => "shipEndDown",
+            ItemKind::ShipEndLeft => "shipEndLeft",
+            ItemKind::ShipEndRight => "shipEndRight",
+            ItemKind::ShipMiddle => "shipMiddle",
+            ItemKind::BoldSlash => "boldSlash",
+            ItemKind::BoldBackslash => "boldBackslash",
+            ItemKind::DottedHorizontalWall => "dottedHorizontalWall",
+            ItemKind::DottedVerticalWall => "dottedVerticalWall",
+            ItemKind::FirewalkCellUnknown => "firewalkCellUnknown",
+            ItemKind::FirewalkCellUl => "firewalkCellUl",
+            ItemKind::FirewalkCellUr => "firewalkCellUr",
+            ItemKind::FirewalkCellDl => "firewalkCellDl",
+            ItemKind::FirewalkCellDr => "firewalkCellDr",
+            ItemKind::FirewalkCellUlDr => "firewalkCellUlDr",
+            ItemKind::FirewalkCellUrDl => "firewalkCellUrDl",
+            _ => return None,
+        };
+        Some(name)
+    }
+
     pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Returns the shape of schema version 1 standing in for this item, if this item was added
+    /// in a later version.
+    fn v1_fallback(&self) -> Option<ItemKind> {
+        let kind = match self {
+            ItemKind::InequalityUp | ItemKind::ShipEndUp => ItemKind::Text("^"),
+            ItemKind::InequalityDown | ItemKind::ShipEndDown => ItemKind::Text("v"),
+            ItemKind::InequalityLeft | ItemKind::ShipEndLeft => ItemKind::Text("<"),
+            ItemKind::InequalityRight | ItemKind::ShipEndRight => ItemKind::Text(">"),
+            ItemKind::ThermoBulb => ItemKind::Circle,
+            ItemKind::ThermoPath => ItemKind::Line,
+            ItemKind::ShipSingle => ItemKind::FilledCircle,
+            ItemKind::ShipMiddle => ItemKind::Square,
+            ItemKind::BoldSlash => ItemKind::Slash,
+            ItemKind::BoldBackslash => ItemKind::Backslash,
+            ItemKind::StyledWall(style) => match style.dash {
+                StrokeDash::Solid => ItemKind::BoldWall,
+                _ => ItemKind::DottedWall,
+            },
+            ItemKind::LayeredLine(_, style) => match style.dash {
+                StrokeDash::Solid => ItemKind::Line,
+                _ => ItemKind::DottedLine,
+            },
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+impl Serialize for ItemKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if board_schema_version() < 2 {
+            if let Some(kind) = self.v1_fallback() {
+                return kind.serialize(serializer);
+            }
+        }
+        if let Some(name) = self.unit_name() {
+            return serializer.serialize_str(name);
+        }
+
+        let mut map = serializer.serialize_map(None)?;
         match self {
-            &ItemKind::Dot => String::from("\"dot\""),
-            &ItemKind::Block => String::from("\"block\""),
-            &ItemKind::Square => String::from("\"square\""),
-            &ItemKind::Triangle => String::from("\"triangle\""),
-            &ItemKind::Fill => String::from("\"fill\""),
-            &ItemKind::Circle => String::from("\"circle\""),
-            &ItemKind::FilledCircle => String::from("\"filledCircle\""),
-            &ItemKind::SmallCircle => String::from("\"smallCircle\""),
-            &ItemKind::SmallFilledCircle => String::from("\"smallFilledCircle\""),
-            &ItemKind::SideArrowUp => String::from("\"sideArrowUp\""),
-            &ItemKind::SideArrowDown => String::from("\"sideArrowDown\""),
-            &ItemKind::SideArrowLeft => String::from("\"sideArrowLeft\""),
-            &ItemKind::SideArrowRight => String::from("\"sideArrowRight\""),
-            &ItemKind::ArrowUp => String::from("\"arrowUp\""),
-            &ItemKind::ArrowDown => String::from("\"arrowDown\""),
-            &ItemKind::ArrowLeft => String::from("\"arrowLeft\""),
-            &ItemKind::ArrowRight => String::from("\"arrowRight\""),
-            &ItemKind::AboloUpperLeft => String::from("\"aboloUpperLeft\""),
-            &ItemKind::AboloUpperRight => String::from("\"aboloUpperRight\""),
-            &ItemKind::AboloLowerLeft => String::from("\"aboloLowerLeft\""),
-            &ItemKind::AboloLowerRight => String::from("\"aboloLowerRight\""),
-            &ItemKind::PencilUp => String::from("\"pencilUp\""),
-            &ItemKind::PencilDown => String::from("\"pencilDown\""),
-            &ItemKind::PencilLeft => String::from("\"pencilLeft\""),
-            &ItemKind::PencilRight => String::from("\"pencilRight\""),
-            &ItemKind::Cross => String::from("\"cross\""),
-            &ItemKind::Line => String::from("\"line\""),
-            &ItemKind::DottedLine => String::from("\"dottedLine\""),
-            &ItemKind::DoubleLine => String::from("\"doubleLine\""),
-            &ItemKind::Wall => String::from("\"wall\""),
-            &ItemKind::BoldWall => String::from("\"boldWall\""),
-            &ItemKind::DottedWall => String::from("\"dottedWall\""),
-            &ItemKind::Slash => String::from("\"slash\""),
-            &ItemKind::Backslash => String::from("\"backslash\""),
-            &ItemKind::DottedSlash => String::from("\"dottedSlash\""),
-            &ItemKind::DottedBackslash => String::from("\"dottedBackslash\""),
-            &ItemKind::Plus => String::from("\"plus\""),
-            &ItemKind::DottedHorizontalWall => String::from("\"dottedHorizontalWall\""),
-            &ItemKind::DottedVerticalWall => String::from("\"dottedVerticalWall\""),
-            &ItemKind::FirewalkCellUnknown => String::from("\"firewalkCellUnknown\""),
-            &ItemKind::FirewalkCellUl => String::from("\"firewalkCellUl\""),
-            &ItemKind::FirewalkCellUr => String::from("\"firewalkCellUr\""),
-            &ItemKind::FirewalkCellDl => String::from("\"firewalkCellDl\""),
-            &ItemKind::FirewalkCellDr => String::from("\"firewalkCellDr\""),
-            &ItemKind::FirewalkCellUlDr => String::from("\"firewalkCellUlDr\""),
-            &ItemKind::FirewalkCellUrDl => String::from("\"firewalkCellUrDl\""),
-            &ItemKind::Text(text) => format!("{{\"kind\":\"text\",\"data\":\"{}\"}}", text),
-            &ItemKind::Num(num) => format!("{{\"kind\":\"text\",\"data\":\"{}\"}}", num),
-            &ItemKind::NumUpperLeft(num) => format!(
-                "{{\"kind\":\"text\",\"data\":\"{}\",\"pos\":\"upperLeft\"}}",
-                num
-            ),
-            &ItemKind::NumUpperRight(num) => format!(
-                "{{\"kind\":\"text\",\"data\":\"{}\",\"pos\":\"upperRight\"}}",
-                num
-            ),
-            &ItemKind::NumLowerLeft(num) => format!(
-                "{{\"kind\":\"text\",\"data\":\"{}\",\"pos\":\"lowerLeft\"}}",
-                num
-            ),
-            &ItemKind::NumLowerRight(num) => format!(
-                "{{\"kind\":\"text\",\"data\":\"{}\",\"pos\":\"lowerRight\"}}",
-                num
-            ),
-            ItemKind::Compass(compass) => format!(
-                "{{\"kind\":\"compass\",\"up\":{},\"down\":{},\"left\":{},\"right\":{}}}",
-                compass.up.unwrap_or(-1),
-                compass.down.unwrap_or(-1),
-                compass.left.unwrap_or(-1),
-                compass.right.unwrap_or(-1)
-            ),
-            ItemKind::TapaClue(clues) => format!(
-                "{{\"kind\":\"tapaClue\",\"value\":[{},{},{},{}]}}",
-                clues[0], clues[1], clues[2], clues[3]
-            ),
-            ItemKind::SudokuCandidateSet(size, cands) => format!(
-                "{{\"kind\":\"sudokuCandidateSet\",\"size\":{},\"values\":[{}]}}",
-                *size,
-                cands
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(",")
-            ),
-            ItemKind::Firefly(dir, n) => format!(
-                "{{\"kind\":\"firefly\",\"dot\":\"{}\",\"value\":{}}}",
-                match *dir {
+            ItemKind::Text(text) => {
+                map.serialize_entry("kind", "text")?;
+                map.serialize_entry("data", text)?;
+            }
+            ItemKind::Num(num) => {
+                map.serialize_entry("kind", "text")?;
+                map.serialize_entry("data", &num.to_string())?;
+            }
+            ItemKind::NumUpperLeft(num)
+            | ItemKind::NumUpperRight(num)
+            | ItemKind::NumLowerLeft(num)
+            | ItemKind::NumLowerRight(num) => {
+                let pos = match self {
+                    ItemKind::NumUpperLeft(_) => "upperLeft",
+                    ItemKind::NumUpperRight(_) => "upperRight",
+                    ItemKind::NumLowerLeft(_) => "lowerLeft",
+                    _ => "lowerRight",
+                };
+                map.serialize_entry("kind", "text")?;
+                map.serialize_entry("data", &num.to_string())?;
+                map.serialize_entry("pos", pos)?;
+            }
+            ItemKind::Compass(compass) => {
+                map.serialize_entry("kind", "compass")?;
+                map.serialize_entry("up", &compass.up.unwrap_or(-1))?;
+                map.serialize_entry("down", &compass.down.unwrap_or(-1))?;
+                map.serialize_entry("left", &compass.left.unwrap_or(-1))?;
+                map.serialize_entry("right", &compass.right.unwrap_or(-1))?;
+            }
+            ItemKind::TapaClue(clues) => {
+                map.serialize_entry("kind", "tapaClue")?;
+                map.serialize_entry("value", clues)?;
+            }
+            ItemKind::SudokuCandidateSet(size, cands) => {
+                map.serialize_entry("kind", "sudokuCandidateSet")?;
+                map.serialize_entry("size", size)?;
+                map.serialize_entry("values", cands)?;
+            }
+            ItemKind::Firefly(dir, n) => {
+                let dot = match *dir {
                     FireflyDir::Up => "up",
                     FireflyDir::Down => "down",
                     FireflyDir::Left => "left",
                     FireflyDir::Right => "right",
-                },
-                n
-            ),
-            ItemKind::LineTo(dy, dx) => format!(
-                "{{\"kind\":\"lineTo\",\"destY\":{},\"destX\":{}}}",
-                *dy, *dx
-            ),
+                };
+                map.serialize_entry("kind", "firefly")?;
+                map.serialize_entry("dot", dot)?;
+                map.serialize_entry("value", n)?;
+            }
+            ItemKind::LineTo(dy, dx) => {
+                map.serialize_entry("kind", "lineTo")?;
+                map.serialize_entry("destY", dy)?;
+                map.serialize_entry("destX", dx)?;
+            }
+            ItemKind::StyledWall(style) => {
+                map.serialize_entry("kind", "wall")?;
+                serialize_stroke_style(&mut map, style)?;
+            }
+            ItemKind::LayeredLine(layer, style) => {
+                map.serialize_entry("kind", "line")?;
+                map.serialize_entry("layer", layer)?;
+                serialize_stroke_style(&mut map, style)?;
+            }
+            _ => unreachable!(),
         }
+        map.end()
     }
 }
 
+fn serialize_stroke_style<M: SerializeMap>(
+    map: &mut M,
+    style: &StrokeStyle,
+) -> Result<(), M::Error> {
+    let width = match style.width {
+        StrokeWidth::Thin => "thin",
+        StrokeWidth::Medium => "medium",
+        StrokeWidth::Bold => "bold",
+    };
+    let dash = match style.dash {
+        StrokeDash::Solid => "solid",
+        StrokeDash::Dashed => "dashed",
+        StrokeDash::Dotted => "dotted",
+    };
+    map.serialize_entry("width", width)?;
+    map.serialize_entry("dash", dash)
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct Item {
     pub y: usize,
     pub x: usize,
-    pub color: &'static str,
+    pub color: ItemColor,
+    #[serde(rename = "item")]
     pub kind: ItemKind,
 }
 
 impl Item {
-    pub fn cell(cell_y: usize, cell_x: usize, color: &'static str, kind: ItemKind) -> Item {
+    pub fn cell(cell_y: usize, cell_x: usize, color: impl Into<ItemColor>, kind: ItemKind) -> Item {
         Item {
             y: cell_y * 2 + 1,
             x: cell_x * 2 + 1,
-            color,
+            color: color.into(),
             kind,
         }
     }
 
     pub fn to_json(&self) -> String {
-        format!(
-            "{{\"y\":{},\"x\":{},\"color\":\"{}\",\"item\":{}}}",
-            self.y,
-            self.x,
-            self.color,
-            self.kind.to_json()
-        )
+        serde_json::to_string(self).unwrap()
     }
 }
 
+#[derive(Serialize)]
+struct AnnotatedItem<'a> {
+    #[serde(flatten)]
+    item: &'a Item,
+    #[serde(skip_serializing_if = "is_forced")]
+    forced: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layer: Option<Layer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<DiffStatus>,
+}
+
+fn is_forced(forced: &bool) -> bool {
+    *forced
+}
+
 #[allow(unused)]
+#[derive(Clone, Copy)]
 pub enum BoardKind {
     Empty,
     Grid,
@@ -220,12 +472,25 @@ pub enum BoardKind {
     DotGrid,
 }
 
+/// Annotations of a board item, serialized as extra fields of the item.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ItemMeta {
+    /// Whether the item appears only in the displayed solution.
+    pub(crate) optional: bool,
+    pub(crate) layer: Option<Layer>,
+    pub(crate) diff: Option<DiffStatus>,
+}
+
 pub struct Board {
-    kind: BoardKind,
-    height: usize,
-    width: usize,
-    data: Vec<Item>,
-    uniqueness: Uniqueness,
+    pub(crate) kind: BoardKind,
+    pub(crate) height: usize,
+    pub(crate) width: usize,
+    pub(crate) data: Vec<Item>,
+    /// Annotations of each item of `data`.
+    meta: Vec<ItemMeta>,
+    answer: Vec<(&'static str, AnswerGrid)>,
+    pub(crate) uniqueness: Uniqueness,
+    partial: bool,
 }
 
 impl Board {
@@ -235,12 +500,77 @@ impl Board {
             height,
             width,
             data: vec![],
+            meta: vec![],
+            answer: vec![],
             uniqueness,
+            partial: false,
+        }
+    }
+
+    /// Marks this board as showing only part of the facts, because solving was stopped early.
+    /// The uniqueness of the answer is unknown in this case.
+    pub fn mark_partial(&mut self) {
+        self.partial = true;
+        self.uniqueness = Uniqueness::NotApplicable;
+    }
+
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    /// Whether the answer is unique, or `None` if the puzzle kind does not tell (or the board is
+    /// partial).
+    pub fn is_unique(&self) -> Option<bool> {
+        match self.uniqueness {
+            Uniqueness::Unique => Some(true),
+            Uniqueness::NonUnique => Some(false),
+            Uniqueness::NotApplicable => None,
+        }
+    }
+
+    /// Returns an empty board with the same size, style and status as this one.
+    pub(crate) fn empty_like(&self) -> Board {
+        Board {
+            kind: self.kind,
+            height: self.height,
+            width: self.width,
+            data: vec![],
+            meta: vec![],
+            answer: vec![],
+            uniqueness: self.uniqueness,
+            partial: self.partial,
         }
     }
 
     pub fn push(&mut self, item: Item) {
+        self.push_with_meta(item, ItemMeta::default());
+    }
+
+    /// Adds an item which is part of the displayed solution but not forced in all solutions.
+    pub fn push_optional(&mut self, item: Item) {
+        self.push_with_meta(
+            item,
+            ItemMeta {
+                optional: true,
+                ..ItemMeta::default()
+            },
+        );
+    }
+
+    pub(crate) fn push_with_meta(&mut self, item: Item, meta: ItemMeta) {
         self.data.push(item);
+        self.meta.push(meta);
+    }
+
+    /// Appends all items of `other`, keeping their annotations.
+    pub(crate) fn append(&mut self, other: &Board) {
+        for (item, meta) in other.items_with_meta() {
+            self.push_with_meta(item.clone(), *meta);
+        }
+    }
+
+    pub(crate) fn items_with_meta(&self) -> impl Iterator<Item = (&Item, &ItemMeta)> {
+        self.data.iter().zip(&self.meta)
     }
 
     pub fn extend<I>(&mut self, items: I)
@@ -248,9 +578,51 @@ impl Board {
         I: IntoIterator<Item = Item>,
     {
         self.data.extend(items);
+        self.meta.resize(self.data.len(), ItemMeta::default());
     }
 
-    pub fn add_borders(&mut self, borders: &graph::BoolInnerGridEdgesModel, color: &'static str) {
+    /// Attaches the raw answer `grid` under `name` (e.g. `"shaded"` or `"line"`).
+    pub fn add_answer(&mut self, name: &'static str, grid: AnswerGrid) {
+        self.answer.push((name, grid));
+    }
+
+    pub fn answer(&self, name: &str) -> Option<&AnswerGrid> {
+        self.answer
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, grid)| grid)
+    }
+
+    /// Marks cell `(y, x)` as undetermined, i.e. none of its possible answers is forced.
+    pub fn push_undetermined_cell(&mut self, y: usize, x: usize) {
+        self.push(Item::cell(
+            y,
+            x,
+            ColorRole::Auxiliary,
+            ItemKind::Undetermined,
+        ));
+    }
+
+    /// Same as `add_borders`, but draws the borders in `style`.
+    pub fn add_borders_with_style(
+        &mut self,
+        borders: &graph::BoolInnerGridEdgesModel,
+        color: impl Into<ItemColor>,
+        style: StrokeStyle,
+    ) {
+        let start = self.data.len();
+        self.add_borders(borders, color);
+        for item in &mut self.data[start..] {
+            item.kind = ItemKind::StyledWall(style);
+        }
+    }
+
+    pub fn add_borders(
+        &mut self,
+        borders: &graph::BoolInnerGridEdgesModel,
+        color: impl Into<ItemColor>,
+    ) {
+        let color = color.into();
         let height = self.height;
         let width = self.width;
         for y in 0..height {
@@ -278,9 +650,10 @@ impl Board {
     pub fn add_lines_irrefutable_facts(
         &mut self,
         lines: &graph::BoolGridEdgesIrrefutableFacts,
-        color: &'static str,
+        color: impl Into<ItemColor>,
         skip: Option<&Vec<Vec<bool>>>,
     ) {
+        let color = color.into();
         for y in 0..(self.height - 1) {
             for x in 0..self.width {
                 if let Some(skip) = skip {
@@ -318,29 +691,54 @@ impl Board {
     }
 
     pub fn to_json(&self) -> String {
-        let kind = "grid";
-        let height = self.height;
-        let width = self.width;
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let default_style = match self.kind {
             BoardKind::Empty => "empty",
             BoardKind::Grid => "grid",
             BoardKind::OuterGrid => "outer_grid",
             BoardKind::DotGrid => "dots",
         };
+
+        let mut map = serializer.serialize_map(None)?;
+        let version = board_schema_version();
+        map.serialize_entry("version", &version)?;
+        map.serialize_entry("kind", "grid")?;
+        map.serialize_entry("height", &self.height)?;
+        map.serialize_entry("width", &self.width)?;
+        map.serialize_entry("defaultStyle", default_style)?;
         let data = self
             .data
             .iter()
-            .map(|item| item.to_json())
-            .collect::<Vec<_>>()
-            .join(",");
-        let uniqueness = match self.uniqueness {
-            Uniqueness::Unique => ",\"isUnique\":true",
-            Uniqueness::NonUnique => ",\"isUnique\":false",
-            Uniqueness::NotApplicable => "",
-        };
-        format!(
-            "{{\"kind\":\"{}\",\"height\":{},\"width\":{},\"defaultStyle\":\"{}\",\"data\":[{}]{}}}",
-            kind, height, width, default_style, data, uniqueness
-        )
+            .zip(&self.meta)
+            .map(|(item, meta)| AnnotatedItem {
+                item,
+                forced: !meta.optional,
+                layer: meta.layer,
+                diff: meta.diff,
+            })
+            .collect::<Vec<_>>();
+        map.serialize_entry("data", &data)?;
+        if !self.answer.is_empty() && version >= 2 {
+            let answer = self
+                .answer
+                .iter()
+                .map(|(name, grid)| (*name, grid))
+                .collect::<BTreeMap<_, _>>();
+            map.serialize_entry("answer", &answer)?;
+        }
+        match self.uniqueness {
+            Uniqueness::Unique => map.serialize_entry("isUnique", &true)?,
+            Uniqueness::NonUnique => map.serialize_entry("isUnique", &false)?,
+            Uniqueness::NotApplicable => (),
+        }
+        if self.partial {
+            map.serialize_entry("isPartial", &true)?;
+        }
+        map.end()
     }
 }
@@ -0,0 +1,146 @@
+//! In-memory cache of solved boards, for deployments which solve the same problems repeatedly.
+//!
+//! Boards are cached in their serialized (JSON) form, keyed by `canonicalize_url` of the problem
+//! URL, so that URLs which differ only in the host (puzz.link / pzv.jp / ...), the puzzle type
+//! alias or the order of Kudamono parameters share an entry. Boards serialized in different
+//! schema versions (see `board::set_board_schema_version`) are cached separately.
+
+use crate::board::board_schema_version;
+use crate::kinds;
+use cspuz_rs::serializer::{get_kudamono_url_info_detailed, strip_prefix};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CacheConfig {
+    /// Maximum number of cached boards. The least recently used one is evicted when exceeded.
+    pub capacity: usize,
+    /// Entries older than this are discarded. `None` means entries never expire.
+    pub ttl: Option<Duration>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            capacity: 256,
+            ttl: None,
+        }
+    }
+}
+
+struct Entry {
+    board_json: String,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+/// LRU cache mapping canonicalized problem URLs to serialized boards.
+pub struct SolutionCache {
+    config: CacheConfig,
+    entries: HashMap<String, Entry>,
+    /// Keys of `entries` indexed by `Entry::last_used`.
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+impl SolutionCache {
+    pub fn new(config: CacheConfig) -> SolutionCache {
+        SolutionCache {
+            config,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Returns the serialized board cached for `url`, if any.
+    pub fn get(&mut self, url: &str) -> Option<&str> {
+        let key = cache_key(url);
+        let entry = self.entries.get(&key)?;
+        if self
+            .config
+            .ttl
+            .is_some_and(|ttl| entry.inserted_at.elapsed() > ttl)
+        {
+            self.remove(&key);
+            return None;
+        }
+
+        self.clock += 1;
+        let entry = self.entries.get_mut(&key).unwrap();
+        self.recency.remove(&entry.last_used);
+        entry.last_used = self.clock;
+        self.recency.insert(self.clock, key);
+        Some(&entry.board_json)
+    }
+
+    /// Caches `board_json` (the serialized board) as the result for `url`.
+    pub fn insert(&mut self, url: &str, board_json: String) {
+        if self.config.capacity == 0 {
+            return;
+        }
+        let key = cache_key(url);
+        self.remove(&key);
+        while self.entries.len() >= self.config.capacity {
+            let (_, oldest) = self.recency.pop_first().unwrap();
+            self.entries.remove(&oldest);
+        }
+
+        self.clock += 1;
+        self.recency.insert(self.clock, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                board_json,
+                inserted_at: Instant::now(),
+                last_used: self.clock,
+            },
+        );
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+fn cache_key(url: &str) -> String {
+    format!("{}@{}", canonicalize_url(url), board_schema_version())
+}
+
+/// Returns the canonical form of `url`, which is the same for all URLs of the same problem.
+pub fn canonicalize_url(url: &str) -> String {
+    let url = url.trim();
+    if let Some(body) = strip_prefix(url) {
+        if let Some((kind, rest)) = body.split_once('/') {
+            let kind = kinds::find_puzz_link_kind(kind).map_or(kind, |k| k.kind);
+            return format!("puzz.link:{}/{}", kind, rest);
+        }
+    }
+    if let Some(info) = get_kudamono_url_info_detailed(url) {
+        let params = info
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>();
+        return format!("kudamono:{}", params.join("&"));
+    }
+    url.to_string()
+}
@@ -0,0 +1,149 @@
+//! C interface of the solver backend for native applications.
+//!
+//! The header `include/cspuz_solver_backend.h` is generated from this module by
+//! [cbindgen](https://github.com/mozilla/cbindgen) (run `cbindgen --config cbindgen.toml --output
+//! include/cspuz_solver_backend.h` in the `cspuz_solver_backend` directory).
+//! All strings are NUL-terminated UTF-8, and the responses are in the same JSON format as
+//! the WASM interface.
+//!
+//! Panics never unwind into the caller: the functions returning a JSON string report them as an
+//! error response with the description "internal error", and the other functions ignore them
+//! (`cspuz_set_board_schema_version` returns `false`).
+
+use crate::board::set_board_schema_version;
+use crate::cache::{CacheConfig, SolutionCache};
+use crate::limits::{set_resource_limits, ResourceLimits};
+use crate::{
+    decode_and_solve, decode_and_solve_cached, kinds, with_stats, Response, ResponseWithStats,
+    SolveError,
+};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+
+thread_local! {
+    static CACHE: RefCell<Option<SolutionCache>> = const { RefCell::new(None) };
+}
+
+const INTERNAL_ERROR: SolveError = SolveError::Other("internal error");
+
+fn into_c_string(s: String) -> *mut c_char {
+    // JSON strings never contain NUL characters
+    CString::new(s).unwrap().into_raw()
+}
+
+/// Runs `f`, which returns a response in JSON, and converts a panic in it into an error response.
+fn json_response<F: FnOnce() -> String>(f: F) -> *mut c_char {
+    let json = catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Response::<()>::Error(INTERNAL_ERROR).to_json());
+    into_c_string(json)
+}
+
+/// Solves the puzzle given by `url` (a puzz.link or Kudamono URL), reusing the cached result if
+/// `cspuz_configure_solution_cache` enabled the cache.
+///
+/// Returns the result as a JSON string `{"status": ..., "description": ..., "stats": ...}`,
+/// which must be released by `cspuz_free_string`.
+///
+/// # Safety
+///
+/// `url` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_solve_url(url: *const c_char) -> *mut c_char {
+    json_response(|| {
+        let response = if url.is_null() {
+            ResponseWithStats {
+                response: Response::Error(SolveError::InvalidUrl {
+                    reason: "URL is null",
+                }),
+                stats: Default::default(),
+            }
+        } else {
+            let url = CStr::from_ptr(url).to_bytes();
+            let (result, stats) = with_stats(|| {
+                CACHE.with(|cache| match cache.borrow_mut().as_mut() {
+                    Some(cache) => decode_and_solve_cached(url, cache).and_then(|board_json| {
+                        serde_json::from_str::<Value>(&board_json).map_err(|_| INTERNAL_ERROR)
+                    }),
+                    None => decode_and_solve(url)
+                        .and_then(|board| serde_json::to_value(board).map_err(|_| INTERNAL_ERROR)),
+                })
+            });
+            ResponseWithStats {
+                response: Response::from(result),
+                stats,
+            }
+        };
+        response.to_json()
+    })
+}
+
+/// Returns the list of supported puzzle kinds as a JSON string `{"status": "ok", "description": [...]}`,
+/// which must be released by `cspuz_free_string`.
+#[no_mangle]
+pub extern "C" fn cspuz_list_puzzle_kinds() -> *mut c_char {
+    json_response(|| Response::<_>::Ok(kinds::list_puzzle_kinds()).to_json())
+}
+
+/// Enables caching the results of `cspuz_solve_url` on the current thread, keeping at most
+/// `capacity` boards for at most `ttl_ms` milliseconds (no limit if `ttl_ms` is not positive).
+/// `capacity == 0` disables the cache. Any previously cached results are discarded.
+#[no_mangle]
+pub extern "C" fn cspuz_configure_solution_cache(capacity: usize, ttl_ms: f64) {
+    let _ = catch_unwind(|| {
+        let cache = if capacity == 0 {
+            None
+        } else {
+            Some(SolutionCache::new(CacheConfig {
+                capacity,
+                ttl: (ttl_ms > 0.0).then(|| Duration::from_secs_f64(ttl_ms / 1000.0)),
+            }))
+        };
+        CACHE.with(|c| *c.borrow_mut() = cache);
+    });
+}
+
+/// Sets the limits on the size of problems solved on the current thread (see the `limits`
+/// module). A value of 0 means no limit, except for `max_url_length`, for which 0 restores the
+/// default.
+#[no_mangle]
+pub extern "C" fn cspuz_configure_resource_limits(
+    max_url_length: usize,
+    max_dimension: usize,
+    max_cells: usize,
+    max_variables: usize,
+    max_clauses: usize,
+) {
+    let _ = catch_unwind(|| {
+        let limit = |value: usize| (value > 0).then_some(value);
+        set_resource_limits(ResourceLimits {
+            max_url_length: limit(max_url_length).unwrap_or(crate::MAX_URL_LENGTH),
+            max_dimension: limit(max_dimension),
+            max_cells: limit(max_cells),
+            max_variables: limit(max_variables),
+            max_clauses: limit(max_clauses),
+        });
+    });
+}
+
+/// Sets the schema version of the boards returned on the current thread (see the `board`
+/// module), for applications which only understand an older version. Returns `false` if
+/// `version` is not supported.
+#[no_mangle]
+pub extern "C" fn cspuz_set_board_schema_version(version: u32) -> bool {
+    catch_unwind(|| set_board_schema_version(version)).unwrap_or(false)
+}
+
+/// Releases a string returned by the functions of this library. Does nothing if `s` is null.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this library which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn cspuz_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        let _ = catch_unwind(|| drop(CString::from_raw(s)));
+    }
+}
@@ -0,0 +1,137 @@
+//! Problem generation, built on `cspuz_rs::generator`.
+//!
+//! Problems are generated by local search over clue placements, and are accepted only once the
+//! solver fully determines their answer, so that every generated problem has a unique solution.
+
+use crate::kinds::{PuzzleKind, UrlFormat};
+use cspuz_rs::generator::{default_scorer, default_uniqueness_checker, Choice, Generator};
+use cspuz_rs_puzzles::puzzles::{nurikabe, slitherlink};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GenerateOptions {
+    /// Seed of the random number generator. The same seed always yields the same problem.
+    pub seed: u64,
+    /// Penalty for each clue in the score of candidate problems. Larger values lead to problems
+    /// with fewer clues.
+    pub clue_weight: f64,
+}
+
+/// Rough difficulty of generated problems. Harder problems are generated by penalizing clues
+/// more, so that more deductions are needed to solve them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn clue_weight(self) -> f64 {
+        match self {
+            Difficulty::Easy => 2.0,
+            Difficulty::Normal => 5.0,
+            Difficulty::Hard => 10.0,
+        }
+    }
+}
+
+impl std::str::FromStr for Difficulty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Difficulty, ()> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(()),
+        }
+    }
+}
+
+impl GenerateOptions {
+    pub fn with_difficulty(seed: u64, difficulty: Difficulty) -> GenerateOptions {
+        GenerateOptions {
+            seed,
+            clue_weight: difficulty.clue_weight(),
+        }
+    }
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            seed: 0,
+            clue_weight: Difficulty::Normal.clue_weight(),
+        }
+    }
+}
+
+/// Generates a problem of the given size and returns its URL, or `None` if generation failed.
+type GenerateFn = fn(usize, usize, &GenerateOptions, &mut StdRng) -> Option<String>;
+
+fn generate_slitherlink(
+    height: usize,
+    width: usize,
+    options: &GenerateOptions,
+    rng: &mut StdRng,
+) -> Option<String> {
+    let pattern =
+        vec![
+            vec![Choice::new(vec![None, Some(0), Some(1), Some(2), Some(3)], None); width];
+            height
+        ];
+    let problem = Generator::new(
+        |problem: &Vec<Vec<Option<i32>>>| slitherlink::solve_slitherlink(problem),
+        pattern,
+        default_uniqueness_checker(),
+        default_scorer(None, options.clue_weight),
+    )
+    .generate(rng)?;
+    slitherlink::serialize_problem(&problem)
+}
+
+fn generate_nurikabe(
+    height: usize,
+    width: usize,
+    options: &GenerateOptions,
+    rng: &mut StdRng,
+) -> Option<String> {
+    let max_clue = (height * width / 4).clamp(2, 9) as i32;
+    let mut candidates = vec![None];
+    candidates.extend((1..=max_clue).map(Some));
+    let pattern = vec![vec![Choice::new(candidates, None); width]; height];
+    let problem = Generator::new(
+        |problem: &Vec<Vec<Option<i32>>>| nurikabe::solve_nurikabe(problem),
+        pattern,
+        default_uniqueness_checker(),
+        default_scorer(None, options.clue_weight),
+    )
+    .generate(rng)?;
+    nurikabe::serialize_problem(&problem)
+}
+
+pub(crate) fn find_generator(kind: &PuzzleKind) -> Option<GenerateFn> {
+    if kind.url_format != UrlFormat::PuzzLink {
+        return None;
+    }
+    match kind.kind {
+        "slither" => Some(generate_slitherlink),
+        "nurikabe" => Some(generate_nurikabe),
+        _ => None,
+    }
+}
+
+/// Generates a problem of `kind` and returns its URL.
+/// Returns `None` if `kind` is not supported or no problem was found.
+pub(crate) fn generate_url(
+    kind: &PuzzleKind,
+    height: usize,
+    width: usize,
+    options: &GenerateOptions,
+) -> Option<String> {
+    let generate = find_generator(kind)?;
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    generate(height, width, options, &mut rng)
+}
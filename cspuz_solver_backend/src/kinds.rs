@@ -0,0 +1,174 @@
+//! Registry of puzzle kinds supported by the backend.
+//!
+//! Frontends can fetch this list (`list_puzzle_kinds`) instead of hard-coding which puzzles are
+//! available. Each entry is serialized as
+//!
+//! ```json
+//! {"kind": "slither", "aliases": ["slitherlink"], "name": "Slitherlink", "urlFormat": "puzz.link",
+//!  "enumerable": true, "generatable": false, "answerExportable": false}
+//! ```
+//!
+//! `kind` is the puzzle type as it appears in URLs: the path component for puzz.link URLs, or the
+//! `G` parameter for Kudamono URLs (in which case `variant` holds the `V` parameter, if any).
+//!
+//! Kinds are not listed here; each solver module in `puzzle` declares its own.
+
+use crate::board::Board;
+use crate::puzzle;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::sync::LazyLock;
+
+type SolveFn = fn(&str) -> Result<Board, &'static str>;
+type EnumerateFn = fn(&str, usize) -> Result<(Board, Vec<Board>), &'static str>;
+type AnswerFn = fn(&str) -> Result<(Board, Option<String>), &'static str>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub enum UrlFormat {
+    #[serde(rename = "puzz.link")]
+    PuzzLink,
+    #[serde(rename = "kudamono")]
+    Kudamono,
+}
+
+#[derive(Clone, Copy)]
+pub struct PuzzleKind {
+    pub kind: &'static str,
+    pub aliases: &'static [&'static str],
+    pub name: &'static str,
+    pub url_format: UrlFormat,
+    pub variant: Option<&'static str>,
+    pub(crate) solve: SolveFn,
+    pub(crate) enumerate: Option<EnumerateFn>,
+    /// Same as `solve`, but also returns the answer in the pzprv3 format if it is fully decided.
+    pub(crate) solve_with_answer: Option<AnswerFn>,
+}
+
+impl PuzzleKind {
+    pub(crate) const fn puzz_link(
+        kind: &'static str,
+        name: &'static str,
+        solve: SolveFn,
+    ) -> PuzzleKind {
+        PuzzleKind {
+            kind,
+            aliases: &[],
+            name,
+            url_format: UrlFormat::PuzzLink,
+            variant: None,
+            solve,
+            enumerate: None,
+            solve_with_answer: None,
+        }
+    }
+
+    pub(crate) const fn kudamono(
+        kind: &'static str,
+        name: &'static str,
+        solve: SolveFn,
+    ) -> PuzzleKind {
+        PuzzleKind {
+            url_format: UrlFormat::Kudamono,
+            ..PuzzleKind::puzz_link(kind, name, solve)
+        }
+    }
+
+    pub(crate) const fn with_aliases(self, aliases: &'static [&'static str]) -> PuzzleKind {
+        PuzzleKind { aliases, ..self }
+    }
+
+    pub(crate) const fn with_variant(self, variant: &'static str) -> PuzzleKind {
+        PuzzleKind {
+            variant: Some(variant),
+            ..self
+        }
+    }
+
+    pub(crate) const fn with_enumerate(self, enumerate: EnumerateFn) -> PuzzleKind {
+        PuzzleKind {
+            enumerate: Some(enumerate),
+            ..self
+        }
+    }
+
+    pub(crate) const fn with_answer(self, solve_with_answer: AnswerFn) -> PuzzleKind {
+        PuzzleKind {
+            solve_with_answer: Some(solve_with_answer),
+            ..self
+        }
+    }
+
+    /// Whether `enumerate_answers_problem` supports this kind.
+    pub fn is_enumerable(&self) -> bool {
+        self.enumerate.is_some()
+    }
+
+    /// Whether the backend can generate problems of this kind.
+    #[cfg(feature = "generator")]
+    pub fn is_generatable(&self) -> bool {
+        crate::generate::find_generator(self).is_some()
+    }
+
+    /// Whether the backend can generate problems of this kind.
+    #[cfg(not(feature = "generator"))]
+    pub fn is_generatable(&self) -> bool {
+        false
+    }
+
+    /// Whether `solve_problem_with_answer` can export answers of this kind.
+    pub fn is_answer_exportable(&self) -> bool {
+        self.solve_with_answer.is_some()
+    }
+
+    fn matches(&self, kind: &str) -> bool {
+        self.kind == kind || self.aliases.contains(&kind)
+    }
+}
+
+impl Serialize for PuzzleKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PuzzleKind", 8)?;
+        state.serialize_field("kind", self.kind)?;
+        state.serialize_field("aliases", self.aliases)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("urlFormat", &self.url_format)?;
+        if let Some(variant) = self.variant {
+            state.serialize_field("variant", variant)?;
+        }
+        state.serialize_field("enumerable", &self.is_enumerable())?;
+        state.serialize_field("generatable", &self.is_generatable())?;
+        state.serialize_field("answerExportable", &self.is_answer_exportable())?;
+        state.end()
+    }
+}
+
+/// Kinds declared by the modules in `puzzle` (see `puzzle_modules!`).
+static PUZZLE_KINDS: LazyLock<Vec<PuzzleKind>> = LazyLock::new(puzzle::registered_kinds);
+
+pub fn list_puzzle_kinds() -> &'static [PuzzleKind] {
+    &PUZZLE_KINDS
+}
+
+/// Finds the puzz.link puzzle kind named `kind` (either its canonical name or an alias).
+pub fn find_puzz_link_kind(kind: &str) -> Option<&'static PuzzleKind> {
+    PUZZLE_KINDS
+        .iter()
+        .find(|k| k.url_format == UrlFormat::PuzzLink && k.matches(kind))
+}
+
+/// Finds the Kudamono puzzle kind given by the `G` (`kind`) and `V` (`variant`) parameters.
+/// Kinds without a registered variant accept any `V` parameter.
+pub fn find_kudamono_kind(kind: &str, variant: &str) -> Option<&'static PuzzleKind> {
+    PUZZLE_KINDS.iter().find(|k| {
+        k.url_format == UrlFormat::Kudamono
+            && k.matches(kind)
+            && k.variant.is_none_or(|v| v == variant)
+    })
+}
+
+/// Whether some variant of the Kudamono puzzle kind `kind` is supported.
+pub fn is_known_kudamono_kind(kind: &str) -> bool {
+    PUZZLE_KINDS
+        .iter()
+        .any(|k| k.url_format == UrlFormat::Kudamono && k.matches(kind))
+}
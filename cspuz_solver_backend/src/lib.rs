@@ -1,263 +1,643 @@
 #![allow(static_mut_refs)] // TODO: remove this
 
+extern crate cspuz_core;
 extern crate cspuz_rs;
 
+pub mod animation;
+pub mod answer;
+pub mod batch;
 pub mod board;
+pub mod cache;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "generator")]
+pub mod generate;
+pub mod kinds;
+pub mod limits;
+pub mod overlay;
+#[cfg(feature = "png")]
+mod png;
 mod puzzle;
+mod pzprv3;
+pub mod svg;
+mod text;
+pub mod theme;
 mod uniqueness;
 
+use animation::Animation;
 use board::Board;
+use cache::SolutionCache;
+use cspuz_core::config::Config;
+use cspuz_core::integration::{replay_deductions, trace_deductions};
+use cspuz_core::sat::{self, SolveProgress};
 use cspuz_rs::serializer::{get_kudamono_url_info_detailed, url_to_puzzle_kind};
+use overlay::OverlayStyle;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use theme::ColorTheme;
+use uniqueness::Uniqueness;
 
 static mut SHARED_ARRAY: Vec<u8> = vec![];
 
-fn solve_puzz_link(puzzle_kind: String, url: &str) -> Result<Board, &'static str> {
-    if puzzle_kind == "nurikabe" {
-        puzzle::nurikabe::solve_nurikabe(url)
-    } else if puzzle_kind == "yajilin" || puzzle_kind == "yajirin" {
-        puzzle::yajilin::solve_yajilin(url)
-    } else if puzzle_kind == "heyawake" {
-        puzzle::heyawake::solve_heyawake(url, false)
-    } else if puzzle_kind == "ayeheya" {
-        puzzle::heyawake::solve_heyawake(url, true)
-    } else if puzzle_kind == "slither" || puzzle_kind == "slitherlink" {
-        puzzle::slitherlink::solve_slitherlink(url)
-    } else if puzzle_kind == "slalom" {
-        puzzle::slalom::solve_slalom(url)
-    } else if puzzle_kind == "nurimisaki" {
-        puzzle::nurimisaki::solve_nurimisaki(url)
-    } else if puzzle_kind == "compass" {
-        puzzle::compass::solve_compass(url)
-    } else if puzzle_kind == "akari" {
-        puzzle::akari::solve_akari(url)
-    } else if puzzle_kind == "lits" {
-        puzzle::lits::solve_lits(url)
-    } else if puzzle_kind == "masyu" || puzzle_kind == "mashu" {
-        puzzle::masyu::solve_masyu(url)
-    } else if puzzle_kind == "shakashaka" {
-        puzzle::shakashaka::solve_shakashaka(url)
-    } else if puzzle_kind == "araf" {
-        puzzle::araf::solve_araf(url)
-    } else if puzzle_kind == "aqre" {
-        puzzle::aqre::solve_aqre(url)
-    } else if puzzle_kind == "tapa" {
-        puzzle::tapa::solve_tapa(url)
-    } else if puzzle_kind == "simpleloop" {
-        puzzle::simpleloop::solve_simpleloop(url)
-    } else if puzzle_kind == "yajilin-regions" {
-        puzzle::yajilin_regions::solve_yajilin_regions(url)
-    } else if puzzle_kind == "kropki" {
-        puzzle::kropki::solve_kropki(url)
-    } else if puzzle_kind == "kurotto" {
-        puzzle::kurotto::solve_kurotto(url)
-    } else if puzzle_kind == "castle" {
-        puzzle::castle_wall::solve_castle_wall(url)
-    } else if puzzle_kind == "shimaguni" {
-        puzzle::shimaguni::solve_shimaguni(url)
-    } else if puzzle_kind == "norinori" {
-        puzzle::norinori::solve_norinori(url)
-    } else if puzzle_kind == "coral" {
-        puzzle::coral::solve_coral(url)
-    } else if puzzle_kind == "cave" {
-        puzzle::cave::solve_cave(url)
-    } else if puzzle_kind == "curvedata" {
-        puzzle::curvedata::solve_curvedata(url)
-    } else if puzzle_kind == "shikaku" {
-        puzzle::shikaku::solve_shikaku(url)
-    } else if puzzle_kind == "sudoku" {
-        puzzle::sudoku::solve_sudoku(url)
-    } else if puzzle_kind == "sashigane" {
-        puzzle::sashigane::solve_sashigane(url)
-    } else if puzzle_kind == "lohkous" {
-        puzzle::lohkous::solve_lohkous(url)
-    } else if puzzle_kind == "hashi" {
-        puzzle::hashi::solve_hashi(url)
-    } else if puzzle_kind == "herugolf" {
-        puzzle::herugolf::solve_herugolf(url)
-    } else if puzzle_kind == "slashpack" {
-        puzzle::slashpack::solve_slashpack(url)
-    } else if puzzle_kind == "moonsun" {
-        puzzle::moonsun::solve_moonsun(url)
-    } else if puzzle_kind == "fillomino" {
-        puzzle::fillomino::solve_fillomino(url)
-    } else if puzzle_kind == "cbanana" {
-        puzzle::chocobanana::solve_chocobanana(url)
-    } else if puzzle_kind == "fivecells" {
-        puzzle::fivecells::solve_fivecells(url)
-    } else if puzzle_kind == "cocktail" {
-        puzzle::cocktail::solve_cocktail(url)
-    } else if puzzle_kind == "stostone" {
-        puzzle::stostone::solve_stostone(url)
-    } else if puzzle_kind == "pencils" {
-        puzzle::pencils::solve_pencils(url)
-    } else if puzzle_kind == "barns" {
-        puzzle::barns::solve_barns(url)
-    } else if puzzle_kind == "reflect" {
-        puzzle::reflect::solve_reflect_link(url)
-    } else if puzzle_kind == "ringring" {
-        puzzle::ringring::solve_ringring(url)
-    } else if puzzle_kind == "loopsp" {
-        puzzle::loop_special::solve_loop_speical(url)
-    } else if puzzle_kind == "nagenawa" {
-        puzzle::nagenawa::solve_nagenawa(url)
-    } else if puzzle_kind == "icewalk" {
-        puzzle::icewalk::solve_icewalk(url)
-    } else if puzzle_kind == "kouchoku" {
-        puzzle::kouchoku::solve_kouchoku(url)
-    } else if puzzle_kind == "creek" {
-        puzzle::creek::solve_creek(url)
-    } else if puzzle_kind == "squarejam" {
-        puzzle::square_jam::solve_square_jam(url)
-    } else if puzzle_kind == "firefly" {
-        puzzle::firefly::solve_firefly(url)
-    } else if puzzle_kind == "nothree" {
-        puzzle::nothree::solve_nothree(url)
-    } else if puzzle_kind == "invlitso" {
-        puzzle::inverse_litso::solve_inverse_litso(url)
-    } else if puzzle_kind == "tontonbeya" {
-        puzzle::tontonbeya::solve_tontonbeya(url)
-    } else if puzzle_kind == "guidearrow" {
-        puzzle::guidearrow::solve_guidearrow(url)
-    } else if puzzle_kind == "aquapelago" {
-        puzzle::aquapelago::solve_aquapelago(url)
-    } else if puzzle_kind == "pentominous" {
-        puzzle::polyominous::solve_pentominous(url)
-    } else if puzzle_kind == "akichi" {
-        puzzle::akichiwake::solve_akichiwake(url)
-    } else if puzzle_kind == "chainedb" {
-        puzzle::chainedb::solve_chainedb(url)
-    } else if puzzle_kind == "evolmino" {
-        puzzle::evolmino::solve_evolmino(url)
-    } else if puzzle_kind == "dbchoco" {
-        puzzle::dbchoco::solve_doublechoco(url)
-    } else if puzzle_kind == "statuepark" {
-        puzzle::statue_park::solve_statue_park(url)
-    } else if puzzle_kind == "kakuro" {
-        puzzle::kakuro::solve_kakuro(url)
-    } else if puzzle_kind == "doppelblock" {
-        puzzle::doppelblock::solve_doppelblock(url)
-    } else if puzzle_kind == "lither" {
-        puzzle::litherslink::solve_litherslink(url)
-    } else if puzzle_kind == "coffeemilk" {
-        puzzle::coffeemilk::solve_coffeemilk(url)
-    } else if puzzle_kind == "nurimaze" {
-        puzzle::nurimaze::solve_nurimaze(url)
-    } else if puzzle_kind == "firewalk" {
-        puzzle::firewalk::solve_firewalk(url)
-    } else if puzzle_kind == "nikoji" {
-        puzzle::nikoji::solve_nikoji(url)
-    } else if puzzle_kind == "archipelago" {
-        puzzle::archipelago::solve_archipelago(url)
-    } else if puzzle_kind == "timebomb" {
-        puzzle::timebomb::solve_timebomb(url)
+/// Result returned to the frontend: `{"status": "ok", "description": ...}` on success, and
+/// `{"status": "error", "description": <message>, "error": <SolveError>}` on failure.
+enum Response<T> {
+    Ok(T),
+    Error(SolveError),
+}
+
+impl<T: Serialize> Serialize for Response<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            Response::Ok(x) => {
+                map.serialize_entry("status", "ok")?;
+                map.serialize_entry("description", x)?;
+            }
+            Response::Error(err) => {
+                map.serialize_entry("status", "error")?;
+                map.serialize_entry("description", err.message())?;
+                map.serialize_entry("error", err)?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl<T: Serialize> Response<T> {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl<T: Serialize> ResponseWithStats<T> {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl<T> From<Result<T, SolveError>> for Response<T> {
+    fn from(result: Result<T, SolveError>) -> Self {
+        match result {
+            Ok(x) => Response::Ok(x),
+            Err(err) => Response::Error(err),
+        }
+    }
+}
+
+/// A response together with the statistics of the solver run producing it:
+/// `{"status": ..., "description": ..., "stats": {...}}`.
+#[derive(Serialize)]
+struct ResponseWithStats<T> {
+    #[serde(flatten)]
+    response: Response<T>,
+    stats: SolveStats,
+}
+
+/// Statistics of a solver run.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolveStats {
+    /// Wall-clock time in milliseconds, including URL decoding and board construction.
+    pub wall_time_ms: f64,
+    /// Number of variables of the largest SAT instance solved.
+    pub variables: usize,
+    /// Number of clauses of the largest SAT instance solved.
+    pub clauses: usize,
+    /// Total number of conflicts over all SAT solver calls.
+    pub conflicts: u64,
+}
+
+fn with_stats<T, F: FnOnce() -> T>(f: F) -> (T, SolveStats) {
+    let start = Instant::now();
+    let (ret, sat_stats) = sat::collect_stats(f);
+    let stats = SolveStats {
+        wall_time_ms: start.elapsed().as_secs_f64() * 1000.0,
+        variables: sat_stats.variables,
+        clauses: sat_stats.clauses,
+        conflicts: sat_stats.conflicts,
+    };
+    (ret, stats)
+}
+
+#[derive(Serialize)]
+struct Enumeration {
+    common: Board,
+    answers: Vec<Board>,
+}
+
+#[derive(Serialize)]
+struct Explanation {
+    steps: Vec<Board>,
+}
+
+/// Result of `generate_problem`.
+#[derive(Serialize)]
+pub struct GeneratedProblem {
+    pub url: String,
+    /// Number of deduction steps needed to solve the problem (see `decode_and_explain`), which
+    /// serves as an estimate of its difficulty.
+    pub difficulty: usize,
+}
+
+/// Result of `decode_and_check_uniqueness`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniquenessCheck {
+    pub is_unique: bool,
+    pub answer: Board,
+    /// An answer other than `answer`, if the problem has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub second_answer: Option<Board>,
+    /// `answer` compared with `second_answer` by `Board::diff_answers`: items only in `answer` are
+    /// marked as missing, and those only in `second_answer` as extra.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difference: Option<Board>,
+}
+
+/// Error returned by the solver backend.
+///
+/// It is serialized as `{"kind": ..., "message": ..., "userError": bool}`, where `kind` is the
+/// camelCase name of the variant. `InvalidUrl` additionally has `reason`, and `UnknownPuzzleKind`
+/// and `UnsupportedVariant` have `puzzleKind` and `variant` respectively.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SolveError {
+    /// The URL could not be understood.
+    InvalidUrl { reason: &'static str },
+    /// The puzzle type given in the URL is not supported (by the requested operation).
+    UnknownPuzzleKind(String),
+    /// The puzzle type is supported, but the variant given in the URL is not.
+    UnsupportedVariant(String),
+    /// The problem has no solution.
+    NoSolution,
+    /// Solving did not finish within the time limit.
+    Timeout,
+    /// Solving was cancelled by the progress callback.
+    Cancelled,
+    /// The problem exceeds the size limits of the backend (see the `limits` module, and
+    /// `MAX_GENERATION_SIZE` for generated problems).
+    TooLarge,
+    /// Any other error reported by the puzzle-specific solver.
+    Other(&'static str),
+}
+
+impl SolveError {
+    /// Message passed to the frontend in the `description` field of error responses.
+    pub fn message(&self) -> &'static str {
+        match self {
+            SolveError::InvalidUrl { reason } => reason,
+            SolveError::UnknownPuzzleKind(_) => "unknown puzzle type",
+            SolveError::UnsupportedVariant(_) => "unsupported puzzle variant",
+            SolveError::NoSolution => "no answer",
+            SolveError::Timeout => "time limit exceeded",
+            SolveError::Cancelled => "cancelled",
+            SolveError::TooLarge => "puzzle too large",
+            SolveError::Other(msg) => msg,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            SolveError::InvalidUrl { .. } => "invalidUrl",
+            SolveError::UnknownPuzzleKind(_) => "unknownPuzzleKind",
+            SolveError::UnsupportedVariant(_) => "unsupportedVariant",
+            SolveError::NoSolution => "noSolution",
+            SolveError::Timeout => "timeout",
+            SolveError::Cancelled => "cancelled",
+            SolveError::TooLarge => "tooLarge",
+            SolveError::Other(_) => "other",
+        }
+    }
+
+    /// Whether the error is caused by the input (as opposed to the solver).
+    pub fn is_user_error(&self) -> bool {
+        !matches!(
+            self,
+            SolveError::Timeout | SolveError::Cancelled | SolveError::Other(_)
+        )
+    }
+}
+
+impl From<&'static str> for SolveError {
+    fn from(msg: &'static str) -> Self {
+        match msg {
+            "invalid url" | "invalid size" => SolveError::InvalidUrl { reason: msg },
+            "no answer" => SolveError::NoSolution,
+            _ => SolveError::Other(msg),
+        }
+    }
+}
+
+impl Serialize for SolveError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("kind", self.kind())?;
+        map.serialize_entry("message", self.message())?;
+        map.serialize_entry("userError", &self.is_user_error())?;
+        match self {
+            SolveError::InvalidUrl { reason } => map.serialize_entry("reason", reason)?,
+            SolveError::UnknownPuzzleKind(kind) => map.serialize_entry("puzzleKind", kind)?,
+            SolveError::UnsupportedVariant(variant) => map.serialize_entry("variant", variant)?,
+            _ => (),
+        }
+        map.end()
+    }
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownPuzzleKind(kind) => write!(f, "unknown puzzle type: {}", kind),
+            SolveError::UnsupportedVariant(variant) => {
+                write!(f, "unsupported puzzle variant: {}", variant)
+            }
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Default maximum length of URLs accepted by the backend (`ResourceLimits::max_url_length`).
+/// Longer URLs are rejected with `SolveError::TooLarge` before decoding, as solving such large
+/// problems would exhaust memory.
+pub const MAX_URL_LENGTH: usize = 1 << 16;
+
+/// Maximum height and width of generated problems.
+pub const MAX_GENERATION_SIZE: usize = 15;
+
+/// Solves the puzzle given by `url` (a puzz.link or Kudamono URL), detecting its kind from the URL.
+pub fn solve_auto(url: &str) -> Result<Board, SolveError> {
+    let kind = detect_kind(url)?;
+
+    run_with_limits(|| (kind.solve)(url))
+}
+
+/// Runs `solve` (a solver of some puzzle kind) within the SAT instance limits of
+/// `limits::resource_limits`.
+fn run_with_limits<T, F>(solve: F) -> Result<T, SolveError>
+where
+    F: FnOnce() -> Result<T, &'static str>,
+{
+    let (result, exceeded) =
+        sat::with_instance_limits(limits::resource_limits().instance_limits(), solve);
+    if exceeded {
+        Err(SolveError::TooLarge)
+    } else {
+        result.map_err(SolveError::from)
+    }
+}
+
+fn detect_kind(url: &str) -> Result<&'static kinds::PuzzleKind, SolveError> {
+    let limits = limits::resource_limits();
+    if url.len() > limits.max_url_length {
+        return Err(SolveError::TooLarge);
+    }
+    if is_penpa_url(url) {
+        // Penpa+ links carry a compressed editor state rather than a puzzle type and problem
+        // data, and the serializer cannot convert them into problems yet.
+        return Err(SolveError::InvalidUrl {
+            reason: "Penpa+ URLs are not supported; use a puzz.link URL instead",
+        });
+    }
+    let kind = if let Some(puzzle_kind) = url_to_puzzle_kind(url) {
+        kinds::find_puzz_link_kind(&puzzle_kind)
+            .ok_or(SolveError::UnknownPuzzleKind(puzzle_kind))?
     } else {
-        Err("unknown puzzle type")
-    }
-}
-
-fn decode_and_solve(url: &[u8]) -> Result<Board, &'static str> {
-    let url = std::str::from_utf8(url).map_err(|_| "failed to decode URL as UTF-8")?;
-
-    let puzzle_kind = url_to_puzzle_kind(url).ok_or("puzzle type not detected");
-
-    match puzzle_kind {
-        Ok(puzzle_kind) => solve_puzz_link(puzzle_kind, url),
-        Err(_) => {
-            let puzzle_info = get_kudamono_url_info_detailed(url).ok_or("failed to parse URL")?;
-
-            let puzzle_kind = *puzzle_info.get("G").unwrap_or(&"");
-            let puzzle_variant = *puzzle_info.get("V").unwrap_or(&"");
-
-            if puzzle_kind == "tricklayer" {
-                puzzle::tricklayer::solve_tricklayer(url)
-            } else if puzzle_kind == "parrot-loop" {
-                puzzle::parrot_loop::solve_parrot_loop(url)
-            } else if puzzle_kind == "crosswall" {
-                puzzle::crosswall::solve_crosswall(url)
-            } else if puzzle_kind == "soulmates" {
-                puzzle::soulmates::solve_soulmates(url)
-            } else if puzzle_kind == "cross-border-parity-loop" {
-                puzzle::cross_border_parity_loop::solve_cross_border_parity_loop(url)
-            } else if puzzle_kind == "akari-regional" {
-                puzzle::akari_regions::solve_akari_regions(url)
-            } else if puzzle_kind == "akari-rgb" {
-                puzzle::akari_rgb::solve_akari_rgb(url)
-            } else if puzzle_kind == "milk-tea" {
-                puzzle::milktea::solve_milktea(url)
-            } else if puzzle_kind == "seiza" {
-                puzzle::seiza::solve_seiza(url)
-            } else if puzzle_kind == "spokes" {
-                puzzle::spokes::solve_spokes(url)
-            } else if puzzle_kind == "kropki-pairs" {
-                puzzle::kropki_pairs::solve_kropki_pairs(url)
-            } else if puzzle_kind == "letter-weights" {
-                puzzle::letter_weights::solve_letter_weights(url)
-            } else if puzzle_kind == "sniping-arrow" {
-                puzzle::sniping_arrow::solve_sniping_arrow(url)
-            } else if puzzle_kind == "multiplication-link" {
-                puzzle::multiplication_link::solve_multiplication_link(url)
-            } else if puzzle_kind == "hidoku" {
-                puzzle::hidato::solve_hidato(url)
-            } else if puzzle_kind == "the-longest" {
-                puzzle::the_longest::solve_the_longest(url)
-            } else if puzzle_kind == "slicy" {
-                puzzle::slicy::solve_slicy(url)
-            } else if puzzle_kind == "lits" && puzzle_variant == "double" {
-                puzzle::double_lits::solve_double_lits(url)
+        let puzzle_info = get_kudamono_url_info_detailed(url).ok_or(SolveError::InvalidUrl {
+            reason: "failed to parse URL",
+        })?;
+
+        let puzzle_kind = *puzzle_info.get("G").unwrap_or(&"");
+        let puzzle_variant = *puzzle_info.get("V").unwrap_or(&"");
+
+        kinds::find_kudamono_kind(puzzle_kind, puzzle_variant).ok_or_else(|| {
+            if kinds::is_known_kudamono_kind(puzzle_kind) {
+                SolveError::UnsupportedVariant(puzzle_variant.to_string())
             } else {
-                Err("unknown puzzle type")
+                SolveError::UnknownPuzzleKind(puzzle_kind.to_string())
             }
-        }
+        })?
+    };
+    if !limits.accepts_board_of(url) {
+        return Err(SolveError::TooLarge);
     }
+    Ok(kind)
+}
+
+/// Whether `url` is a Penpa+ (penpa-edit) link, e.g.
+/// `https://swaroopg92.github.io/penpa-edit/#m=solve&p=...`. These are rejected with an error
+/// rather than reported as an unknown puzzle type.
+fn is_penpa_url(url: &str) -> bool {
+    url.contains("penpa-edit") && (url.contains("#m=") || url.contains("&p="))
+}
+
+#[derive(Serialize)]
+struct SolvedWithAnswer {
+    board: Board,
+    pzprv3: Option<String>,
+}
+
+/// Same as `decode_and_solve`, but also returns the answer as a pzprv3 file, which can be loaded
+/// into pzprjs to check it. The answer is `None` if it is not fully decided.
+///
+/// Only some puzzle kinds (currently Nurikabe and Akari) support exporting answers (see
+/// `PuzzleKind::is_answer_exportable`); for the others, `SolveError::UnknownPuzzleKind` naming
+/// the kind is returned.
+pub fn decode_and_solve_with_answer(url: &[u8]) -> Result<(Board, Option<String>), SolveError> {
+    let url = decode_url(url)?;
+
+    let kind = detect_kind(url)?;
+    let solve_with_answer = kind
+        .solve_with_answer
+        .ok_or_else(|| SolveError::UnknownPuzzleKind(kind.kind.to_string()))?;
+
+    run_with_limits(|| solve_with_answer(url))
+}
+
+fn decode_url(url: &[u8]) -> Result<&str, SolveError> {
+    std::str::from_utf8(url).map_err(|_| SolveError::InvalidUrl {
+        reason: "failed to decode URL as UTF-8",
+    })
+}
+
+pub fn decode_and_solve(url: &[u8]) -> Result<Board, SolveError> {
+    solve_auto(decode_url(url)?)
+}
+
+/// Same as `decode_and_solve`, but returns the serialized board and reuses the result cached in
+/// `cache` if any. Only successful results are cached.
+pub fn decode_and_solve_cached(
+    url: &[u8],
+    cache: &mut SolutionCache,
+) -> Result<String, SolveError> {
+    let url = decode_url(url)?;
+    if let Some(board_json) = cache.get(url) {
+        return Ok(board_json.to_string());
+    }
+
+    let board_json = solve_auto(url)?.to_json();
+    cache.insert(url, board_json.clone());
+    Ok(board_json)
+}
+
+/// Same as `decode_and_solve`, but also returns the statistics of the solver run.
+pub fn decode_and_solve_with_stats(url: &[u8]) -> (Result<Board, SolveError>, SolveStats) {
+    with_stats(|| decode_and_solve(url))
 }
 
 fn decode_and_enumerate(
     url: &[u8],
     num_max_answers: usize,
-) -> Result<(Board, Vec<Board>), &'static str> {
-    let url = std::str::from_utf8(url).map_err(|_| "failed to decode URL as UTF-8")?;
-
-    let puzzle_kind = url_to_puzzle_kind(url).ok_or("puzzle type not detected")?;
-
-    if puzzle_kind == "heyawake" {
-        puzzle::heyawake::enumerate_answers_heyawake(url, num_max_answers)
-    } else if puzzle_kind == "slither" || puzzle_kind == "slitherlink" {
-        puzzle::slitherlink::enumerate_answers_slitherlink(url, num_max_answers)
-    } else if puzzle_kind == "nurikabe" {
-        puzzle::nurikabe::enumerate_answers_nurikabe(url, num_max_answers)
-    } else if puzzle_kind == "curvedata" {
-        puzzle::curvedata::enumerate_answers_curvedata(url, num_max_answers)
+) -> Result<(Board, Vec<Board>), SolveError> {
+    let url = decode_url(url)?;
+
+    let kind = detect_kind(url)?;
+    let enumerate = kind
+        .enumerate
+        .ok_or_else(|| SolveError::UnknownPuzzleKind(kind.kind.to_string()))?;
+
+    run_with_limits(|| enumerate(url, num_max_answers))
+}
+
+/// Stores `s` in the buffer shared with the JS side, prefixed by its length (4 bytes, little endian).
+fn to_shared_array(s: String) -> *const u8 {
+    let len = s.len();
+    unsafe {
+        SHARED_ARRAY.clear();
+        SHARED_ARRAY.reserve(4 + len);
+        SHARED_ARRAY.extend_from_slice(&(len as u32).to_le_bytes());
+        SHARED_ARRAY.extend_from_slice(s.as_bytes());
+        SHARED_ARRAY.as_ptr()
+    }
+}
+
+/// Keeps `callback` installed as the progress callback, and `config` as the default config, of
+/// SAT solving on the current thread while alive. Dropping it (even while unwinding from a panic)
+/// removes the callback and restores the previous default config.
+struct SolveSettingsGuard {
+    previous_config: Config,
+}
+
+impl SolveSettingsGuard {
+    fn new(config: Config, callback: sat::ProgressCallback) -> SolveSettingsGuard {
+        let previous_config = Config::default();
+        Config::set_default(config);
+        sat::set_progress_callback(Some(callback));
+        SolveSettingsGuard { previous_config }
+    }
+}
+
+impl Drop for SolveSettingsGuard {
+    fn drop(&mut self) {
+        sat::set_progress_callback(None);
+        Config::set_default(self.previous_config);
+    }
+}
+
+/// Same as `decode_and_solve`, but calls `callback` periodically during SAT solving.
+/// Returns an error if the callback cancels solving by returning `false`.
+pub fn decode_and_solve_with_progress<F>(url: &[u8], callback: F) -> Result<Board, SolveError>
+where
+    F: FnMut(&SolveProgress) -> bool + 'static,
+{
+    let guard = SolveSettingsGuard::new(Config::default(), Box::new(callback));
+    let result = decode_and_solve(url);
+    let interrupted = sat::is_interrupted();
+    drop(guard);
+
+    if interrupted {
+        Err(SolveError::Cancelled)
+    } else {
+        result
+    }
+}
+
+/// Same as `decode_and_solve`, but stops SAT solving after `time_limit`.
+/// If the time limit is exceeded, the returned board contains only the facts proven by then
+/// and is marked as partial.
+pub fn decode_and_solve_with_time_limit(
+    url: &[u8],
+    time_limit: Duration,
+) -> Result<Board, SolveError> {
+    // The puzzle solvers create their CSP solvers with the default config, so the option for
+    // anytime deductions can only be passed through it.
+    let guard = SolveSettingsGuard::new(
+        Config {
+            anytime_irrefutable_facts: true,
+            ..Config::default()
+        },
+        Box::new(move |progress| progress.elapsed < time_limit),
+    );
+    let result = decode_and_solve(url);
+    let interrupted = sat::is_interrupted();
+    drop(guard);
+
+    if interrupted {
+        let mut board = result.map_err(|_| SolveError::Timeout)?;
+        board.mark_partial();
+        Ok(board)
     } else {
-        Err("unsupported puzzle type")
+        result
     }
 }
 
+/// Same as `decode_and_solve`, but the board is recolored according to `theme`.
+pub fn decode_and_solve_with_theme(url: &[u8], theme: &ColorTheme) -> Result<Board, SolveError> {
+    let mut board = decode_and_solve(url)?;
+    board.apply_color_theme(theme);
+    Ok(board)
+}
+
+/// Solves the puzzle given by `url` step by step. The `i`-th board contains the facts deduced in the
+/// first `i + 1` steps, where each step consists of the facts which are easiest to deduce from the
+/// previous ones. All boards but the last one are marked as partial.
+pub fn decode_and_explain(url: &[u8]) -> Result<Vec<Board>, SolveError> {
+    let (result, trace) = trace_deductions(|| decode_and_solve(url));
+    let last = result?;
+
+    let num_steps = trace.num_steps();
+    let mut steps = vec![];
+    for i in 1..num_steps {
+        let mut board = replay_deductions(&trace, i, || decode_and_solve(url))?;
+        board.mark_partial();
+        steps.push(board);
+    }
+    steps.push(last);
+    Ok(steps)
+}
+
+/// Same as `decode_and_explain`, but returns the steps as an `Animation`, in which each step is
+/// stored as the difference from the previous one.
+pub fn decode_and_animate(url: &[u8]) -> Result<Animation, SolveError> {
+    let steps = decode_and_explain(url)?;
+    Ok(Animation::from_steps(&steps))
+}
+
+/// Generates a problem of the puzz.link puzzle kind `kind` with a unique answer.
+/// Generation is only available with the `generator` feature.
+#[cfg(feature = "generator")]
+pub fn generate_problem(
+    kind: &str,
+    height: usize,
+    width: usize,
+    options: &generate::GenerateOptions,
+) -> Result<GeneratedProblem, SolveError> {
+    let puzzle_kind = kinds::find_puzz_link_kind(kind)
+        .ok_or_else(|| SolveError::UnknownPuzzleKind(kind.to_string()))?;
+    if !puzzle_kind.is_generatable() {
+        return Err(SolveError::Other(
+            "generation is not supported for this puzzle type",
+        ));
+    }
+    if height == 0 || width == 0 {
+        return Err(SolveError::Other("invalid size"));
+    }
+    if height > MAX_GENERATION_SIZE || width > MAX_GENERATION_SIZE {
+        return Err(SolveError::TooLarge);
+    }
+
+    let url = generate::generate_url(puzzle_kind, height, width, options)
+        .ok_or(SolveError::Other("failed to generate a problem"))?;
+    let (result, trace) = trace_deductions(|| decode_and_solve(url.as_bytes()));
+    result?;
+    Ok(GeneratedProblem {
+        url,
+        difficulty: trace.num_steps(),
+    })
+}
+
+/// Checks whether the problem given by `url` has a unique answer. If it does not, two different
+/// answers are returned together with the cells in which they differ.
+/// Only puzzle kinds supporting enumeration of answers are supported.
+pub fn decode_and_check_uniqueness(url: &[u8]) -> Result<UniquenessCheck, SolveError> {
+    let (common, answers) = decode_and_enumerate(url, 2)?;
+    let complete_answer = |answer: &Board| {
+        let mut board = common.empty_like();
+        board.append(&common);
+        board.append(answer);
+        board
+    };
+
+    let mut answer = complete_answer(answers.first().ok_or(SolveError::NoSolution)?);
+    let Some(second) = answers.get(1) else {
+        answer.uniqueness = Uniqueness::Unique;
+        return Ok(UniquenessCheck {
+            is_unique: true,
+            answer,
+            second_answer: None,
+            difference: None,
+        });
+    };
+    answer.uniqueness = Uniqueness::NonUnique;
+    let mut second_answer = complete_answer(second);
+    second_answer.uniqueness = Uniqueness::NonUnique;
+    let difference = answer.diff_answers(&second_answer, &OverlayStyle::default());
+
+    Ok(UniquenessCheck {
+        is_unique: false,
+        answer,
+        second_answer: Some(second_answer),
+        difference,
+    })
+}
+
 #[no_mangle]
 fn solve_problem(url: *const u8, len: usize) -> *const u8 {
     let url = unsafe { std::slice::from_raw_parts(url, len) };
-    let result = decode_and_solve(url);
+    let (result, stats) = decode_and_solve_with_stats(url);
 
-    let ret_string = match result {
-        Ok(board) => {
-            format!("{{\"status\":\"ok\",\"description\":{}}}", board.to_json())
-        }
-        Err(err) => {
-            // TODO: escape `err` if necessary
-            format!("{{\"status\":\"error\",\"description\":\"{}\"}}", err)
-        }
-    };
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
+    }
+    .to_json();
 
-    let ret_len = ret_string.len();
-    unsafe {
-        SHARED_ARRAY.clear();
-        SHARED_ARRAY.reserve(4 + ret_len);
-        SHARED_ARRAY.push((ret_len & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 8) & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 16) & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 24) & 0xff) as u8);
-        SHARED_ARRAY.extend_from_slice(ret_string.as_bytes());
-        SHARED_ARRAY.as_ptr()
+    to_shared_array(ret_string)
+}
+
+/// Progress callback passed from JS (e.g. created by `addFunction(f, "idd")`).
+/// It receives the elapsed time in milliseconds and the number of conflicts in the current SAT
+/// solver call, and returns a nonzero value to cancel solving.
+type JsProgressCallback = extern "C" fn(f64, f64) -> i32;
+
+#[no_mangle]
+fn solve_problem_with_progress(
+    url: *const u8,
+    len: usize,
+    callback: JsProgressCallback,
+) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let (result, stats) = with_stats(|| {
+        decode_and_solve_with_progress(url, move |progress| {
+            callback(
+                progress.elapsed.as_secs_f64() * 1000.0,
+                progress.conflicts as f64,
+            ) == 0
+        })
+    });
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
     }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn solve_problem_with_time_limit(url: *const u8, len: usize, time_limit_ms: f64) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let time_limit = Duration::from_secs_f64(time_limit_ms.max(0.0) / 1000.0);
+    let (result, stats) = with_stats(|| decode_and_solve_with_time_limit(url, time_limit));
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
 }
 
 #[no_mangle]
@@ -265,33 +645,136 @@ fn enumerate_answers_problem(url: *const u8, len: usize, num_max_answers: usize)
     let url = unsafe { std::slice::from_raw_parts(url, len) };
     let result = decode_and_enumerate(url, num_max_answers);
 
-    let ret_string = match result {
-        Ok((common, per_answer)) => {
-            format!(
-                "{{\"status\":\"ok\",\"description\":{{\"common\":{},\"answers\":[{}]}}}}",
-                common.to_json(),
-                per_answer
-                    .iter()
-                    .map(|x| x.to_json())
-                    .collect::<Vec<_>>()
-                    .join(",")
-            )
+    let ret_string =
+        Response::from(result.map(|(common, answers)| Enumeration { common, answers })).to_json();
+
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn solve_problem_with_theme(
+    url: *const u8,
+    len: usize,
+    theme_name: *const u8,
+    theme_name_len: usize,
+) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let theme_name = unsafe { std::slice::from_raw_parts(theme_name, theme_name_len) };
+    let (result, stats) = with_stats(|| {
+        let theme = std::str::from_utf8(theme_name)
+            .ok()
+            .and_then(ColorTheme::find)
+            .ok_or(SolveError::Other("unknown color theme"))?;
+        decode_and_solve_with_theme(url, &theme)
+    });
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn solve_problem_with_answer(url: *const u8, len: usize) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let (result, stats) = with_stats(|| decode_and_solve_with_answer(url));
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result.map(|(board, pzprv3)| SolvedWithAnswer { board, pzprv3 })),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn explain_problem(url: *const u8, len: usize) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let (result, stats) = with_stats(|| decode_and_explain(url));
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result.map(|steps| Explanation { steps })),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn animate_problem(url: *const u8, len: usize) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let (result, stats) = with_stats(|| decode_and_animate(url));
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn check_uniqueness(url: *const u8, len: usize) -> *const u8 {
+    let url = unsafe { std::slice::from_raw_parts(url, len) };
+    let (result, stats) = with_stats(|| decode_and_check_uniqueness(url));
+
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
+    }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+#[no_mangle]
+fn generate_new_problem(
+    kind: *const u8,
+    kind_len: usize,
+    height: usize,
+    width: usize,
+    seed: f64,
+) -> *const u8 {
+    let kind = unsafe { std::slice::from_raw_parts(kind, kind_len) };
+    let (result, stats) = with_stats(|| {
+        let kind =
+            std::str::from_utf8(kind).map_err(|_| SolveError::Other("invalid puzzle type"))?;
+        #[cfg(feature = "generator")]
+        {
+            let options = generate::GenerateOptions {
+                seed: seed as u64,
+                ..generate::GenerateOptions::default()
+            };
+            generate_problem(kind, height, width, &options)
         }
-        Err(err) => {
-            // TODO: escape `err` if necessary
-            format!("{{\"status\":\"error\",\"description\":\"{}\"}}", err)
+        #[cfg(not(feature = "generator"))]
+        {
+            let _ = (kind, height, width, seed);
+            Err::<GeneratedProblem, _>(SolveError::Other(
+                "this build does not support problem generation",
+            ))
         }
-    };
+    });
 
-    let ret_len = ret_string.len();
-    unsafe {
-        SHARED_ARRAY.clear();
-        SHARED_ARRAY.reserve(4 + ret_len);
-        SHARED_ARRAY.push((ret_len & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 8) & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 16) & 0xff) as u8);
-        SHARED_ARRAY.push(((ret_len >> 24) & 0xff) as u8);
-        SHARED_ARRAY.extend_from_slice(ret_string.as_bytes());
-        SHARED_ARRAY.as_ptr()
+    let ret_string = ResponseWithStats {
+        response: Response::from(result),
+        stats,
     }
+    .to_json();
+    to_shared_array(ret_string)
+}
+
+/// Sets the schema version of the boards returned by the functions above (see the `board`
+/// module). Returns `false` if `version` is not supported.
+#[no_mangle]
+fn set_schema_version(version: u32) -> bool {
+    board::set_board_schema_version(version)
+}
+
+#[no_mangle]
+fn list_puzzle_kinds() -> *const u8 {
+    let ret_string = Response::<_>::Ok(kinds::list_puzzle_kinds()).to_json();
+
+    to_shared_array(ret_string)
 }
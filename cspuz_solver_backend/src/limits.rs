@@ -0,0 +1,88 @@
+//! Limits on the size of problems accepted by the backend.
+//!
+//! Problems exceeding any limit are rejected with `SolveError::TooLarge`. The URL length and the
+//! board dimensions are checked before decoding the problem, and the size of the SAT instances
+//! when they are about to be solved, which bounds the memory used by the SAT solver.
+
+use cspuz_core::sat::InstanceLimits;
+use cspuz_rs::serializer::{get_kudamono_url_info, strip_prefix};
+use std::cell::Cell;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ResourceLimits {
+    pub max_url_length: usize,
+    /// Maximum number of rows and columns of the board.
+    pub max_dimension: Option<usize>,
+    /// Maximum number of cells of the board.
+    pub max_cells: Option<usize>,
+    /// Maximum number of variables of a SAT instance.
+    pub max_variables: Option<usize>,
+    /// Maximum number of clauses of a SAT instance. The memory usage of the SAT solver mostly
+    /// depends on this.
+    pub max_clauses: Option<usize>,
+}
+
+impl ResourceLimits {
+    pub const DEFAULT: ResourceLimits = ResourceLimits {
+        max_url_length: crate::MAX_URL_LENGTH,
+        max_dimension: Some(200),
+        max_cells: None,
+        max_variables: None,
+        max_clauses: None,
+    };
+
+    /// Whether the board of the problem given by `url` is within the limits.
+    /// URLs whose board size cannot be determined are accepted.
+    pub(crate) fn accepts_board_of(&self, url: &str) -> bool {
+        let Some((height, width)) = board_size(url) else {
+            return true;
+        };
+        self.max_dimension.is_none_or(|m| height <= m && width <= m)
+            && self
+                .max_cells
+                .is_none_or(|m| height.saturating_mul(width) <= m)
+    }
+
+    pub(crate) fn instance_limits(&self) -> InstanceLimits {
+        InstanceLimits {
+            max_variables: self.max_variables,
+            max_clauses: self.max_clauses,
+        }
+    }
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        ResourceLimits::DEFAULT
+    }
+}
+
+thread_local! {
+    static LIMITS: Cell<ResourceLimits> = const { Cell::new(ResourceLimits::DEFAULT) };
+}
+
+/// Sets the limits applied to problems solved on the current thread.
+pub fn set_resource_limits(limits: ResourceLimits) {
+    LIMITS.with(|l| l.set(limits));
+}
+
+/// Returns the limits applied to problems solved on the current thread.
+pub fn resource_limits() -> ResourceLimits {
+    LIMITS.with(|l| l.get())
+}
+
+/// Returns the `(height, width)` of the board of the problem given by `url`, if it is apparent
+/// from the URL: puzz.link URLs have the form `.../p?<kind>/<width>/<height>/...` (possibly with
+/// non-numeric segments before the width), and Kudamono URLs have the `W` and `H` parameters.
+fn board_size(url: &str) -> Option<(usize, usize)> {
+    if let Some(body) = strip_prefix(url) {
+        let segments = body.split('/').skip(1).collect::<Vec<_>>();
+        return segments.windows(2).find_map(|w| {
+            let width = w[0].parse::<usize>().ok()?;
+            let height = w[1].parse::<usize>().ok()?;
+            Some((height, width))
+        });
+    }
+    let info = get_kudamono_url_info(url)?;
+    Some((info.height, info.width))
+}
@@ -0,0 +1,162 @@
+//! Separation of boards into the problem and the answer, and comparison of answers.
+//!
+//! Puzzle solvers draw the clues and the deduced answer into a single board, and the only thing
+//! that tells them apart is the color role: answers are drawn in the `ColorRole::Answer` color.
+//! This module splits boards into these two layers based on that, so that frontends can show the
+//! problem alone, draw the answer in a distinct style, or highlight the differences between two
+//! answers of the same problem.
+
+use crate::board::{Board, Item, ItemKind, ItemMeta};
+use crate::theme::ColorRole;
+use crate::uniqueness::Uniqueness;
+use serde::Serialize;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Layer {
+    /// Clues and other parts of the problem itself.
+    Problem,
+    /// Facts deduced by the solver, including cells marked as undetermined.
+    Answer,
+}
+
+/// Status of an answer item in a board built by `Board::diff_answers`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffStatus {
+    /// The item is in both answers.
+    Common,
+    /// The item is only in the expected answer.
+    Missing,
+    /// The item is only in the compared answer.
+    Extra,
+}
+
+/// Colors of answer items in overlaid boards.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OverlayStyle {
+    pub answer: &'static str,
+    pub missing: &'static str,
+    pub extra: &'static str,
+}
+
+impl Default for OverlayStyle {
+    fn default() -> Self {
+        OverlayStyle {
+            answer: "green",
+            missing: "#e69f00",
+            extra: "#d55e00",
+        }
+    }
+}
+
+impl Item {
+    pub fn layer(&self) -> Layer {
+        if self.color.role == Some(ColorRole::Answer) || self.kind == ItemKind::Undetermined {
+            Layer::Answer
+        } else {
+            Layer::Problem
+        }
+    }
+
+    fn is_same_mark(&self, other: &Item) -> bool {
+        self.y == other.y && self.x == other.x && self.kind == other.kind
+    }
+}
+
+impl Board {
+    /// Returns a board consisting only of the items of this board in `layer`.
+    /// In particular, `board.layer(Layer::Problem)` shows the problem without its answer.
+    pub fn layer(&self, layer: Layer) -> Board {
+        let mut ret = self.empty_like();
+        for (item, meta) in self.items_with_meta() {
+            if item.layer() == layer {
+                ret.push_with_meta(item.clone(), *meta);
+            }
+        }
+        ret
+    }
+
+    /// Returns a board showing the problem over the answer: the answer items, drawn in
+    /// `style.answer`, come first so that clues are drawn on top of them. Every item is tagged
+    /// with its layer.
+    pub fn overlay(&self, style: &OverlayStyle) -> Board {
+        let mut ret = self.empty_like();
+        for (item, meta) in self.items_with_meta() {
+            if item.layer() == Layer::Answer {
+                let mut item = item.clone();
+                if item.kind != ItemKind::Undetermined {
+                    item.color.css = style.answer;
+                }
+                let meta = ItemMeta {
+                    layer: Some(Layer::Answer),
+                    ..*meta
+                };
+                ret.push_with_meta(item, meta);
+            }
+        }
+        self.push_problem_layer(&mut ret);
+        ret
+    }
+
+    /// Compares the answer of this board with that of `other`, which should be a board of the same
+    /// problem (e.g. an answer entered by the user, or one solved with different settings).
+    ///
+    /// The returned board has the problem of this board, and the answer items of both boards
+    /// colored according to whether they are common to both, missing in `other` or extra in
+    /// `other`. Returns `None` if the sizes of the boards differ.
+    pub fn diff_answers(&self, other: &Board, style: &OverlayStyle) -> Option<Board> {
+        if (self.height, self.width) != (other.height, other.width) {
+            return None;
+        }
+        let expected = self
+            .data
+            .iter()
+            .filter(|item| item.layer() == Layer::Answer)
+            .collect::<Vec<_>>();
+        let actual = other
+            .data
+            .iter()
+            .filter(|item| item.layer() == Layer::Answer)
+            .collect::<Vec<_>>();
+
+        let mut ret = self.empty_like();
+        ret.uniqueness = Uniqueness::NotApplicable;
+        let mut push_diff = |item: &Item, status: DiffStatus, color: &'static str| {
+            let mut item = item.clone();
+            item.color.css = color;
+            let meta = ItemMeta {
+                layer: Some(Layer::Answer),
+                diff: Some(status),
+                ..ItemMeta::default()
+            };
+            ret.push_with_meta(item, meta);
+        };
+        for &item in &expected {
+            if actual.iter().any(|a| a.is_same_mark(item)) {
+                push_diff(item, DiffStatus::Common, style.answer);
+            } else {
+                push_diff(item, DiffStatus::Missing, style.missing);
+            }
+        }
+        for &item in &actual {
+            if !expected.iter().any(|e| e.is_same_mark(item)) {
+                push_diff(item, DiffStatus::Extra, style.extra);
+            }
+        }
+        self.push_problem_layer(&mut ret);
+        Some(ret)
+    }
+
+    fn push_problem_layer(&self, target: &mut Board) {
+        for (item, meta) in self.items_with_meta() {
+            if item.layer() == Layer::Problem {
+                let meta = ItemMeta {
+                    layer: Some(Layer::Problem),
+                    ..*meta
+                };
+                target.push_with_meta(item.clone(), meta);
+            }
+        }
+    }
+}
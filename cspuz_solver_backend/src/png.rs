@@ -0,0 +1,69 @@
+use crate::board::Board;
+use crate::svg::Theme;
+use resvg::{tiny_skia, usvg};
+use std::sync::{Arc, LazyLock};
+
+/// System fonts, loaded on the first rendering and shared by all renderings since loading them
+/// is slow.
+static FONTDB: LazyLock<Arc<usvg::fontdb::Database>> = LazyLock::new(|| Arc::new(load_fonts()));
+
+fn load_fonts() -> usvg::fontdb::Database {
+    let mut fontdb = usvg::fontdb::Database::new();
+    fontdb.load_system_fonts();
+    // Fall back to another font if the default sans-serif font (Arial) is missing
+    let sans_serif = usvg::fontdb::Query {
+        families: &[usvg::fontdb::Family::SansSerif],
+        ..Default::default()
+    };
+    if fontdb.query(&sans_serif).is_none() {
+        let fallback = fontdb
+            .faces()
+            .find(|face| face.families.iter().any(|(name, _)| name.contains("Sans")))
+            .or_else(|| fontdb.faces().next())
+            .and_then(|face| face.families.first().cloned());
+        if let Some((family, _)) = fallback {
+            fontdb.set_sans_serif_family(family);
+        }
+    }
+    fontdb
+}
+
+impl Board {
+    /// Renders the board as a PNG image with the default theme. `scale` is applied on top of
+    /// the theme's pixel size.
+    pub fn render_png(&self, scale: f32) -> Result<Vec<u8>, &'static str> {
+        self.render_png_with_theme(&Theme::default(), scale)
+    }
+
+    pub fn render_png_with_theme(
+        &self,
+        theme: &Theme,
+        scale: f32,
+    ) -> Result<Vec<u8>, &'static str> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err("invalid scale");
+        }
+
+        let svg = self.to_svg_with_theme(theme);
+        let options = usvg::Options {
+            fontdb: Arc::clone(&FONTDB),
+            ..Default::default()
+        };
+        let tree = usvg::Tree::from_str(&svg, &options).map_err(|_| "failed to parse SVG")?;
+
+        let size = tree
+            .size()
+            .to_int_size()
+            .scale_by(scale)
+            .ok_or("invalid image size")?;
+        let mut pixmap =
+            tiny_skia::Pixmap::new(size.width(), size.height()).ok_or("invalid image size")?;
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+
+        pixmap.encode_png().map_err(|_| "failed to encode PNG")
+    }
+}
@@ -1,8 +1,19 @@
+use crate::answer::AnswerGrid;
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::pzprv3;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::akari;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("akari", "Akari", solve_akari).with_answer(solve_akari_with_answer)];
+
 pub fn solve_akari(url: &str) -> Result<Board, &'static str> {
+    solve_akari_with_answer(url).map(|(board, _)| board)
+}
+
+pub fn solve_akari_with_answer(url: &str) -> Result<(Board, Option<String>), &'static str> {
     let problem = akari::deserialize_problem(url).ok_or("invalid url")?;
     let ans = akari::solve_akari(&problem).ok_or("no answer")?;
 
@@ -12,20 +23,32 @@ pub fn solve_akari(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 if clue >= 0 {
-                    board.push(Item::cell(y, x, "white", ItemKind::Num(clue)));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Num(clue),
+                    ));
                 }
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Circle } else { ItemKind::Dot },
                 ));
+            } else {
+                board.push_undetermined_cell(y, x);
             }
         }
     }
 
-    Ok(board)
+    board.add_answer("light", AnswerGrid::cells(&ans));
+
+    let answer = pzprv3::clue_and_shade_cells(&problem, &ans)
+        .map(|cells| pzprv3::single_grid_file("lightup", &cells));
+
+    Ok((board, answer))
 }
@@ -0,0 +1,51 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::akari_mirror::{self, AkariMirrorClue};
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "akari-mirror",
+    "Mirror Akari",
+    solve_akari_mirror,
+)];
+
+pub fn solve_akari_mirror(url: &str) -> Result<Board, &'static str> {
+    let problem = akari_mirror::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = akari_mirror::solve_akari_mirror(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            match problem[y][x] {
+                AkariMirrorClue::Block => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+                }
+                AkariMirrorClue::Num(n) => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, ItemKind::Num(n)));
+                }
+                AkariMirrorClue::Slash => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Slash));
+                }
+                AkariMirrorClue::Backslash => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Backslash));
+                }
+                AkariMirrorClue::Empty => {
+                    if let Some(a) = ans[y][x] {
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::Answer,
+                            if a { ItemKind::Circle } else { ItemKind::Dot },
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::akari_regions;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "akari-regional",
+    "Regional Akari",
+    solve_akari_regions,
+)];
+
 pub fn solve_akari_regions(url: &str) -> Result<Board, &'static str> {
     let (borders, clues, has_block) =
         akari_regions::deserialize_problem(url).ok_or("invalid url")?;
@@ -12,19 +20,19 @@ pub fn solve_akari_regions(url: &str) -> Result<Board, &'static str> {
     let width = clues[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&has_light));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
             if has_block[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 continue;
             }
             if let Some(b) = has_light[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b {
                         ItemKind::FilledCircle
                     } else {
@@ -33,7 +41,12 @@ pub fn solve_akari_regions(url: &str) -> Result<Board, &'static str> {
                 ));
             }
             if let Some(n) = clues[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::NumUpperLeft(n)));
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Given,
+                    ItemKind::NumUpperLeft(n),
+                ));
             }
         }
     }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::akari_rgb::{self, AkariRGBClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "akari-rgb",
+    "Akari RGB",
+    solve_akari_rgb,
+)];
+
 pub fn solve_akari_rgb(url: &str) -> Result<Board, &'static str> {
     let problem = akari_rgb::deserialize_problem(url).ok_or("invalid url")?;
     let ans = akari_rgb::solve_akari_rgb(&problem).ok_or("no answer")?;
@@ -13,12 +21,12 @@ pub fn solve_akari_rgb(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             match problem[y][x] {
                 AkariRGBClue::Block => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                     continue;
                 }
                 AkariRGBClue::Num(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
-                    board.push(Item::cell(y, x, "white", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, ItemKind::Num(n)));
                     continue;
                 }
                 AkariRGBClue::Empty => (),
@@ -45,19 +53,34 @@ pub fn solve_akari_rgb(url: &str) -> Result<Board, &'static str> {
             match ans[y][x] {
                 None => (),
                 Some(0) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Dot));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Dot));
                 }
                 Some(1) => {
                     board.push(Item::cell(y, x, "#ff0000", ItemKind::FilledCircle));
-                    board.push(Item::cell(y, x, "white", ItemKind::Text("R")));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Text("R"),
+                    ));
                 }
                 Some(2) => {
                     board.push(Item::cell(y, x, "#00ff00", ItemKind::FilledCircle));
-                    board.push(Item::cell(y, x, "white", ItemKind::Text("G")));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Text("G"),
+                    ));
                 }
                 Some(3) => {
                     board.push(Item::cell(y, x, "#0000ff", ItemKind::FilledCircle));
-                    board.push(Item::cell(y, x, "white", ItemKind::Text("B")));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Text("B"),
+                    ));
                 }
                 _ => unreachable!(),
             }
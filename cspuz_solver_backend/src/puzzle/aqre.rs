@@ -1,8 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::graph;
 use cspuz_rs_puzzles::puzzles::aqre;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("aqre", "Aqre", solve_aqre)];
+
 pub fn solve_aqre(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = aqre::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = aqre::solve_aqre(&borders, &clues).ok_or("no answer")?;
@@ -11,7 +15,7 @@ pub fn solve_aqre(url: &str) -> Result<Board, &'static str> {
     let width = is_black[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_black));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -19,7 +23,7 @@ pub fn solve_aqre(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -30,7 +34,7 @@ pub fn solve_aqre(url: &str) -> Result<Board, &'static str> {
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
         }
     }
 
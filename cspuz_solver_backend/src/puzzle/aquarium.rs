@@ -0,0 +1,81 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::aquarium;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "aquarium",
+    "Aquarium",
+    solve_aquarium,
+)];
+
+pub fn solve_aquarium(url: &str) -> Result<Board, &'static str> {
+    let (borders, clues_up, clues_left) =
+        aquarium::deserialize_problem(url).ok_or("invalid url")?;
+    let is_water = aquarium::solve_aquarium(&borders, &clues_up, &clues_left).ok_or("no answer")?;
+
+    let height = clues_left.len();
+    let width = clues_up.len();
+    let mut board = Board::new(
+        BoardKind::Empty,
+        height + 1,
+        width + 1,
+        is_unique(&is_water),
+    );
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(0, x + 1, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+
+    for y in 0..=height {
+        for x in 0..width {
+            board.push(Item {
+                y: y * 2 + 2,
+                x: x * 2 + 3,
+                color: ColorRole::Given.into(),
+                kind: if y == 0 || y == height || borders.horizontal[y - 1][x] {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+    for y in 0..height {
+        for x in 0..=width {
+            board.push(Item {
+                y: y * 2 + 3,
+                x: x * 2 + 2,
+                color: ColorRole::Given.into(),
+                kind: if x == 0 || x == width || borders.vertical[y][x - 1] {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+
+    for (y, row) in is_water.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(b) = cell {
+                board.push(Item::cell(
+                    y + 1,
+                    x + 1,
+                    ColorRole::Answer,
+                    if b { ItemKind::Fill } else { ItemKind::Dot },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
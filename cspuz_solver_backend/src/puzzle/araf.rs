@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::araf;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("araf", "Araf", solve_araf)];
+
 pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
     let problem = araf::deserialize_problem(url).ok_or("invalid url")?;
     let ans = araf::solve_araf(&problem).ok_or("no answer")?;
@@ -12,8 +16,8 @@ pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                board.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
             }
         }
     }
@@ -25,7 +29,7 @@ pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -40,7 +44,7 @@ pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -51,7 +55,7 @@ pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -66,7 +70,7 @@ pub fn solve_araf(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
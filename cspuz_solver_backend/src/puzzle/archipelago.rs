@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::archipelago;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "archipelago",
+    "Archipelago",
+    solve_archipelago,
+)];
+
 pub fn solve_archipelago(url: &str) -> Result<Board, &'static str> {
     let clues = archipelago::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = archipelago::solve_archipelago(&clues).ok_or("no answer")?;
@@ -13,15 +21,15 @@ pub fn solve_archipelago(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = clues[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 if n >= 0 {
-                    board.push(Item::cell(y, x, "white", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, ItemKind::Num(n)));
                 }
             } else if let Some(b) = is_black[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::barns;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("barns", "Barns", solve_barns)];
+
 pub fn solve_barns(url: &str) -> Result<Board, &'static str> {
     let (icebarn, borders) = barns::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = barns::solve_barns(&icebarn, &borders).ok_or("no answer")?;
@@ -10,7 +14,7 @@ pub fn solve_barns(url: &str) -> Result<Board, &'static str> {
     let width = icebarn[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
     for y in 0..height {
         for x in 0..width {
             if icebarn[y][x] {
@@ -26,7 +30,7 @@ pub fn solve_barns(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2 + 2,
                             x: x * 2 + 1,
-                            color: "green",
+                            color: ColorRole::Answer.into(),
                             kind: if b { ItemKind::Line } else { ItemKind::Cross },
                         });
                     }
@@ -38,7 +42,7 @@ pub fn solve_barns(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2 + 1,
                             x: x * 2 + 2,
-                            color: "green",
+                            color: ColorRole::Answer.into(),
                             kind: if b { ItemKind::Line } else { ItemKind::Cross },
                         });
                     }
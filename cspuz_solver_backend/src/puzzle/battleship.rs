@@ -0,0 +1,122 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::battleship::{self, BattleshipClue};
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "battleship",
+    "Battleships",
+    solve_battleship,
+)];
+
+/// Returns the segment kind of the ship cell at `(y, x)`, or `None` if its shape is not decided
+/// yet because some neighboring cell is undecided.
+fn ship_segment(is_ship: &[Vec<Option<bool>>], y: usize, x: usize) -> Option<ItemKind> {
+    let height = is_ship.len() as i32;
+    let width = is_ship[0].len() as i32;
+    let neighbor = |dy: i32, dx: i32| -> Option<bool> {
+        let (y2, x2) = (y as i32 + dy, x as i32 + dx);
+        if 0 <= y2 && y2 < height && 0 <= x2 && x2 < width {
+            is_ship[y2 as usize][x2 as usize]
+        } else {
+            Some(false)
+        }
+    };
+    let up = neighbor(-1, 0)?;
+    let down = neighbor(1, 0)?;
+    let left = neighbor(0, -1)?;
+    let right = neighbor(0, 1)?;
+
+    Some(match (up, down, left, right) {
+        (false, false, false, false) => ItemKind::ShipSingle,
+        (false, true, false, false) => ItemKind::ShipEndUp,
+        (true, false, false, false) => ItemKind::ShipEndDown,
+        (false, false, false, true) => ItemKind::ShipEndLeft,
+        (false, false, true, false) => ItemKind::ShipEndRight,
+        _ => ItemKind::ShipMiddle,
+    })
+}
+
+pub fn solve_battleship(url: &str) -> Result<Board, &'static str> {
+    let (clues_up, clues_left, cells, fleet) =
+        battleship::deserialize_problem(url).ok_or("invalid url")?;
+    let is_ship =
+        battleship::solve_battleship(&clues_up, &clues_left, &cells, &fleet).ok_or("no answer")?;
+
+    let height = clues_left.len();
+    let width = clues_up.len();
+    let mut board = Board::new(BoardKind::Empty, height + 1, width + 1, is_unique(&is_ship));
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(0, x + 1, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+
+    for y in 0..=height {
+        for x in 0..width {
+            board.push(Item {
+                y: y * 2 + 2,
+                x: x * 2 + 3,
+                color: ColorRole::Given.into(),
+                kind: if y == 0 || y == height {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+    for y in 0..height {
+        for x in 0..=width {
+            board.push(Item {
+                y: y * 2 + 3,
+                x: x * 2 + 2,
+                color: ColorRole::Given.into(),
+                kind: if x == 0 || x == width {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let clue = match cells[y][x] {
+                BattleshipClue::None => None,
+                BattleshipClue::Water => Some(ItemKind::Text("~")),
+                BattleshipClue::Single => Some(ItemKind::ShipSingle),
+                BattleshipClue::Top => Some(ItemKind::ShipEndUp),
+                BattleshipClue::Bottom => Some(ItemKind::ShipEndDown),
+                BattleshipClue::Left => Some(ItemKind::ShipEndLeft),
+                BattleshipClue::Right => Some(ItemKind::ShipEndRight),
+                BattleshipClue::Middle => Some(ItemKind::ShipMiddle),
+                BattleshipClue::Segment => Some(ItemKind::Block),
+            };
+            if let Some(clue) = clue {
+                board.push(Item::cell(y + 1, x + 1, ColorRole::Given, clue));
+            } else if let Some(b) = is_ship[y][x] {
+                board.push(Item::cell(
+                    y + 1,
+                    x + 1,
+                    ColorRole::Answer,
+                    if b {
+                        ship_segment(&is_ship, y, x).unwrap_or(ItemKind::Block)
+                    } else {
+                        ItemKind::Dot
+                    },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
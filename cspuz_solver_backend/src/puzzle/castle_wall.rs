@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::castle_wall;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "castle",
+    "Castle Wall",
+    solve_castle_wall,
+)];
+
 pub fn solve_castle_wall(url: &str) -> Result<Board, &'static str> {
     use castle_wall::Side;
     use cspuz_rs::items::Arrow;
@@ -24,13 +32,13 @@ pub fn solve_castle_wall(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some((side, arrow)) = problem[y][x] {
                 let arrow_color = match side {
-                    Side::Outside => "white",
-                    _ => "black",
+                    Side::Outside => ColorRole::GivenOnFill,
+                    _ => ColorRole::Given,
                 };
                 if side == Side::Outside {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 } else if side == Side::Unspecified {
-                    board.push(Item::cell(y, x, "#cccccc", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Auxiliary, ItemKind::Fill));
                 }
                 let n = arrow.1;
                 let arrow = match arrow.0 {
@@ -50,7 +58,7 @@ pub fn solve_castle_wall(url: &str) -> Result<Board, &'static str> {
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&skip_line));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
 
     Ok(board)
 }
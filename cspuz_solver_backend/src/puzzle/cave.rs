@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::cave;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("cave", "Cave", solve_cave)];
+
 pub fn solve_cave(url: &str) -> Result<Board, &'static str> {
     let problem = cave::deserialize_problem(url).ok_or("invalid url")?;
     let ans = cave::solve_cave(&problem).ok_or("no answer")?;
@@ -13,15 +17,15 @@ pub fn solve_cave(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
                 if clue > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Text("?")));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
                 }
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
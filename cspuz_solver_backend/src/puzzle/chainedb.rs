@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::chainedb;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "chainedb",
+    "Chained Block",
+    solve_chainedb,
+)];
+
 pub fn solve_chainedb(url: &str) -> Result<Board, &'static str> {
     let problem = chainedb::deserialize_problem(url).ok_or("invalid url")?;
     let ans = chainedb::solve_chainedb(&problem).ok_or("no answer")?;
@@ -12,17 +20,27 @@ pub fn solve_chainedb(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 if clue > 0 {
-                    board.push(Item::cell(y, x, "white", ItemKind::Num(clue)));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Num(clue),
+                    ));
                 } else {
-                    board.push(Item::cell(y, x, "white", ItemKind::Text("?")));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Text("?"),
+                    ));
                 }
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::chocobanana;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "cbanana",
+    "Choco Banana",
+    solve_chocobanana,
+)];
+
 pub fn solve_chocobanana(url: &str) -> Result<Board, &'static str> {
     let clues = chocobanana::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = chocobanana::solve_chocobanana(&clues).ok_or("no answer")?;
@@ -16,12 +24,12 @@ pub fn solve_chocobanana(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
             if let Some(n) = clues[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
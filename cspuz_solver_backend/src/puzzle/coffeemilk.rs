@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::coffeemilk;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "coffeemilk",
+    "Coffee Milk",
+    solve_coffeemilk,
+)];
+
 pub fn solve_coffeemilk(url: &str) -> Result<Board, &'static str> {
     let clues = coffeemilk::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = coffeemilk::solve_coffeemilk(&clues).ok_or("no answer")?;
@@ -10,18 +18,28 @@ pub fn solve_coffeemilk(url: &str) -> Result<Board, &'static str> {
     let width = clues[0].len();
     let mut board = Board::new(BoardKind::Empty, height, width, is_unique(&is_line));
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     for y in 0..height {
         for x in 0..width {
             if clues[y][x] == 1 {
-                board.push(Item::cell(y, x, "white", ItemKind::FilledCircle));
-                board.push(Item::cell(y, x, "black", ItemKind::Circle));
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Background,
+                    ItemKind::FilledCircle,
+                ));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
             } else if clues[y][x] == 2 {
-                board.push(Item::cell(y, x, "black", ItemKind::FilledCircle));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle));
             } else if clues[y][x] == 3 {
-                board.push(Item::cell(y, x, "#cccccc", ItemKind::FilledCircle));
-                board.push(Item::cell(y, x, "black", ItemKind::Circle));
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Auxiliary,
+                    ItemKind::FilledCircle,
+                ));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
             }
         }
     }
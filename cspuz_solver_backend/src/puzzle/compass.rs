@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Compass, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::compass;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("compass", "Compass", solve_compass)];
+
 pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
     let problem = compass::deserialize_problem(url).ok_or("invalid url")?;
     let ans = compass::solve_compass(&problem).ok_or("no answer")?;
@@ -15,7 +20,7 @@ pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     ItemKind::Compass(Compass {
                         up: clue.up,
                         down: clue.down,
@@ -34,7 +39,7 @@ pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -49,7 +54,7 @@ pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -60,7 +65,7 @@ pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -75,7 +80,7 @@ pub fn solve_compass(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -0,0 +1,39 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::context;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("context", "Context", solve_context)];
+
+pub fn solve_context(url: &str) -> Result<Board, &'static str> {
+    let problem = context::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = context::solve_context(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            // Clue cells may be black, so the answer is drawn beneath the clue
+            if let Some(a) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+            if let Some(clue) = problem[y][x] {
+                if clue >= 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+                } else {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
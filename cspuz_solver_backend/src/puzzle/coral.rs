@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::coral;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("coral", "Coral", solve_coral)];
+
 pub fn solve_coral(url: &str) -> Result<Board, &'static str> {
     let (vertical, horizontal) = coral::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = coral::solve_coral(&vertical, &horizontal).ok_or("no answer")?;
@@ -16,7 +20,7 @@ pub fn solve_coral(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
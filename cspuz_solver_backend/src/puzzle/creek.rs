@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::creek;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("creek", "Creek", solve_creek)];
+
 pub fn solve_creek(url: &str) -> Result<Board, &'static str> {
     let problem = creek::deserialize_problem(url).ok_or("invalid url")?;
     let ans = creek::solve_creek(&problem).ok_or("no answer")?;
@@ -15,7 +19,7 @@ pub fn solve_creek(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Fill } else { ItemKind::Dot },
                 ));
             }
@@ -27,7 +31,7 @@ pub fn solve_creek(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Wall,
                 });
             }
@@ -35,7 +39,7 @@ pub fn solve_creek(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Wall,
                 });
             }
@@ -47,19 +51,19 @@ pub fn solve_creek(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "white",
+                    color: ColorRole::Background.into(),
                     kind: ItemKind::FilledCircle,
                 });
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Circle,
                 });
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Num(n),
                 });
             }
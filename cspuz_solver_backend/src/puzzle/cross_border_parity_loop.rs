@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::cross_border_parity_loop::{self, CBPLCell};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "cross-border-parity-loop",
+    "Cross Border Parity Loop",
+    solve_cross_border_parity_loop,
+)];
+
 pub fn solve_cross_border_parity_loop(url: &str) -> Result<Board, &'static str> {
     let (cells, clues_black, clues_white, borders) =
         cross_border_parity_loop::deserialize_problem(url).ok_or("invalid url")?;
@@ -48,7 +56,7 @@ pub fn solve_cross_border_parity_loop(url: &str) -> Result<Board, &'static str>
             }
             match cells[y][x] {
                 CBPLCell::Empty => (),
-                CBPLCell::Blocked => board.push(Item::cell(y, x, "black", ItemKind::Fill)),
+                CBPLCell::Blocked => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill)),
                 CBPLCell::BlackCircle => {
                     board.push(Item::cell(y, x, "#ff0000", ItemKind::SmallFilledCircle))
                 }
@@ -66,8 +74,8 @@ pub fn solve_cross_border_parity_loop(url: &str) -> Result<Board, &'static str>
         }
     }
 
-    board.add_borders(&borders, "black");
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&is_skip));
+    board.add_borders(&borders, ColorRole::Given);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&is_skip));
 
     Ok(board)
 }
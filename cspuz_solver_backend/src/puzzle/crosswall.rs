@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::crosswall;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "crosswall",
+    "Crosswall",
+    solve_crosswall,
+)];
+
 pub fn solve_crosswall(url: &str) -> Result<Board, &'static str> {
     let problem = crosswall::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = crosswall::solve_crosswall(&problem).ok_or("no answer")?;
@@ -14,10 +22,15 @@ pub fn solve_crosswall(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some((size, level)) = problem[y][x] {
                 if size > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(size)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(size)));
                 }
                 if level >= 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::NumLowerRight(level)));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::NumLowerRight(level),
+                    ));
                 }
             }
         }
@@ -29,7 +42,7 @@ pub fn solve_crosswall(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -41,7 +54,7 @@ pub fn solve_crosswall(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::{is_unique, Uniqueness};
 use cspuz_rs_puzzles::puzzles::curvedata;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[
+        PuzzleKind::puzz_link("curvedata", "Curve Data", solve_curvedata)
+            .with_enumerate(enumerate_answers_curvedata),
+    ];
+
 pub fn solve_curvedata(url: &str) -> Result<Board, &'static str> {
     let (piece_id, borders, pieces) = curvedata::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = curvedata::solve_curvedata(&piece_id, &borders, &pieces).ok_or("no answer")?;
@@ -11,7 +19,7 @@ pub fn solve_curvedata(url: &str) -> Result<Board, &'static str> {
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
     if let Some(borders) = borders {
-        board.add_borders(&borders, "black");
+        board.add_borders(&borders, ColorRole::Given);
     }
 
     for y in 0..height {
@@ -19,16 +27,16 @@ pub fn solve_curvedata(url: &str) -> Result<Board, &'static str> {
             match piece_id[y][x] {
                 curvedata::PieceId::None => (),
                 curvedata::PieceId::Block => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 }
                 curvedata::PieceId::Piece(_) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
                 }
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     Ok(board)
 }
@@ -48,7 +56,7 @@ pub fn enumerate_answers_curvedata(
     let mut board_common = Board::new(BoardKind::Grid, height, width, Uniqueness::NotApplicable);
 
     if let Some(borders) = borders {
-        board_common.add_borders(&borders, "black");
+        board_common.add_borders(&borders, ColorRole::Given);
     }
 
     for y in 0..height {
@@ -56,15 +64,15 @@ pub fn enumerate_answers_curvedata(
             match piece_id[y][x] {
                 curvedata::PieceId::None => (),
                 curvedata::PieceId::Block => {
-                    board_common.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board_common.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 }
                 curvedata::PieceId::Piece(_) => {
-                    board_common.push(Item::cell(y, x, "black", ItemKind::Circle));
+                    board_common.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
                 }
             }
         }
     }
-    board_common.add_lines_irrefutable_facts(&is_line_common, "green", None);
+    board_common.add_lines_irrefutable_facts(&is_line_common, ColorRole::Answer, None);
 
     let mut board_answers = vec![];
     for ans in answers {
@@ -73,10 +81,10 @@ pub fn enumerate_answers_curvedata(
         for y in 0..height {
             for x in 0..width {
                 if y < height - 1 && is_line_common.vertical[y][x].is_none() {
-                    board_answer.push(Item {
+                    board_answer.push_optional(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: if ans.vertical[y][x] {
                             ItemKind::Line
                         } else {
@@ -85,10 +93,10 @@ pub fn enumerate_answers_curvedata(
                     });
                 }
                 if x < width - 1 && is_line_common.horizontal[y][x].is_none() {
-                    board_answer.push(Item {
+                    board_answer.push_optional(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: if ans.horizontal[y][x] {
                             ItemKind::Line
                         } else {
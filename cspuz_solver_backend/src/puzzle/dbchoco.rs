@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::dbchoco;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "dbchoco",
+    "Double Choco",
+    solve_doublechoco,
+)];
+
 pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
     let (color, num) = dbchoco::deserialize_problem(url).ok_or("invalid url")?;
     let border = dbchoco::solve_doublechoco(&color, &num).ok_or("no answer")?;
@@ -13,10 +21,10 @@ pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if color[y][x] == 1 {
-                board.push(Item::cell(y, x, "#cccccc", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Auxiliary, ItemKind::Fill));
             }
             if let Some(n) = num[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
@@ -29,7 +37,7 @@ pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -44,7 +52,7 @@ pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -55,7 +63,7 @@ pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -70,7 +78,7 @@ pub fn solve_doublechoco(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
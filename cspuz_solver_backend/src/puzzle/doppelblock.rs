@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::Uniqueness;
 use cspuz_rs_puzzles::puzzles::doppelblock;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "doppelblock",
+    "Doppelblock",
+    solve_doppelblock,
+)];
+
 pub fn solve_doppelblock(url: &str) -> Result<Board, &'static str> {
     let (clues_up, clues_left, cells) =
         doppelblock::deserialize_problem(url).ok_or("invalid url")?;
@@ -23,12 +31,12 @@ pub fn solve_doppelblock(url: &str) -> Result<Board, &'static str> {
 
     for y in 0..height {
         if let Some(n) = clues_left[y] {
-            board.push(Item::cell(y + 1, 0, "black", ItemKind::Num(n)));
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, ItemKind::Num(n)));
         }
     }
     for x in 0..width {
         if let Some(n) = clues_up[x] {
-            board.push(Item::cell(0, x + 1, "black", ItemKind::Num(n)));
+            board.push(Item::cell(0, x + 1, ColorRole::Given, ItemKind::Num(n)));
         }
     }
 
@@ -37,7 +45,7 @@ pub fn solve_doppelblock(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: y * 2 + 2,
                 x: x * 2 + 3,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: if y == 0 || y == height {
                     ItemKind::BoldWall
                 } else {
@@ -51,7 +59,7 @@ pub fn solve_doppelblock(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: y * 2 + 3,
                 x: x * 2 + 2,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: if x == 0 || x == width {
                     ItemKind::BoldWall
                 } else {
@@ -65,18 +73,28 @@ pub fn solve_doppelblock(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(cells) = &cells {
                 if let Some(n) = cells[y][x] {
-                    board.push(Item::cell(y + 1, x + 1, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y + 1, x + 1, ColorRole::Given, ItemKind::Num(n)));
                     continue;
                 }
             }
 
             if let Some(n) = answer[y][x] {
                 if n == 0 {
-                    board.push(Item::cell(y + 1, x + 1, "green", ItemKind::Block));
+                    board.push(Item::cell(y + 1, x + 1, ColorRole::Answer, ItemKind::Block));
                 } else if n == -1 {
-                    board.push(Item::cell(y + 1, x + 1, "green", ItemKind::Circle));
+                    board.push(Item::cell(
+                        y + 1,
+                        x + 1,
+                        ColorRole::Answer,
+                        ItemKind::Circle,
+                    ));
                 } else {
-                    board.push(Item::cell(y + 1, x + 1, "green", ItemKind::Num(n)));
+                    board.push(Item::cell(
+                        y + 1,
+                        x + 1,
+                        ColorRole::Answer,
+                        ItemKind::Num(n),
+                    ));
                 }
             }
         }
@@ -0,0 +1,42 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::dosun_fuwari;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "dosufuwa",
+    "Dosun-Fuwari",
+    solve_dosun_fuwari,
+)];
+
+pub fn solve_dosun_fuwari(url: &str) -> Result<Board, &'static str> {
+    let (borders, blocks) = dosun_fuwari::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = dosun_fuwari::solve_dosun_fuwari(&borders, &blocks).ok_or("no answer")?;
+
+    let height = blocks.len();
+    let width = blocks[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    board.add_borders(&borders, ColorRole::Given);
+
+    for y in 0..height {
+        for x in 0..width {
+            if blocks[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+            } else if let Some(n) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    match n {
+                        dosun_fuwari::BALLOON => ItemKind::Circle,
+                        dosun_fuwari::IRON_BALL => ItemKind::FilledCircle,
+                        _ => ItemKind::Dot,
+                    },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
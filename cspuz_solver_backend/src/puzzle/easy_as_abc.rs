@@ -0,0 +1,102 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::easy_as_abc;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "easyasabc",
+    "Easy as ABC",
+    solve_easy_as_abc,
+)];
+
+const LETTERS: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+fn letter(n: i32) -> ItemKind {
+    if 1 <= n && n <= LETTERS.len() as i32 {
+        ItemKind::Text(LETTERS[(n - 1) as usize])
+    } else {
+        ItemKind::Text("?")
+    }
+}
+
+pub fn solve_easy_as_abc(url: &str) -> Result<Board, &'static str> {
+    let (n_letters, clues_up, clues_down, clues_left, clues_right) =
+        easy_as_abc::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = easy_as_abc::solve_easy_as_abc(
+        n_letters,
+        &clues_up,
+        &clues_down,
+        &clues_left,
+        &clues_right,
+    )
+    .ok_or("no answer")?;
+
+    let height = clues_left.len();
+    let width = clues_up.len();
+    let mut board = Board::new(BoardKind::Empty, height + 2, width + 2, is_unique(&ans));
+
+    for y in 0..height {
+        if let Some(n) = clues_left[y] {
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, letter(n)));
+        }
+        if let Some(n) = clues_right[y] {
+            board.push(Item::cell(y + 1, width + 1, ColorRole::Given, letter(n)));
+        }
+    }
+    for x in 0..width {
+        if let Some(n) = clues_up[x] {
+            board.push(Item::cell(0, x + 1, ColorRole::Given, letter(n)));
+        }
+        if let Some(n) = clues_down[x] {
+            board.push(Item::cell(height + 1, x + 1, ColorRole::Given, letter(n)));
+        }
+    }
+
+    for y in 0..=height {
+        for x in 0..width {
+            board.push(Item {
+                y: y * 2 + 2,
+                x: x * 2 + 3,
+                color: ColorRole::Given.into(),
+                kind: if y == 0 || y == height {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+    for y in 0..height {
+        for x in 0..=width {
+            board.push(Item {
+                y: y * 2 + 3,
+                x: x * 2 + 2,
+                color: ColorRole::Given.into(),
+                kind: if x == 0 || x == width {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+
+    for (y, row) in ans.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(n) = cell {
+                board.push(Item::cell(
+                    y + 1,
+                    x + 1,
+                    ColorRole::Answer,
+                    if n == 0 { ItemKind::Dot } else { letter(n) },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
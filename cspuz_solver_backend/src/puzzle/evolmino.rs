@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::evolmino;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "evolmino",
+    "Evolmino",
+    solve_evolmino,
+)];
+
 pub fn solve_evolmino(url: &str) -> Result<Board, &'static str> {
     let problem = evolmino::deserialize_problem(url).ok_or("invalid url")?;
     let is_square = evolmino::solve_evolmino(&problem).ok_or("no answer")?;
@@ -18,7 +26,7 @@ pub fn solve_evolmino(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: y1 + y2 + 1,
                 x: x1 + x2 + 1,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: ItemKind::Line,
             });
         }
@@ -27,19 +35,19 @@ pub fn solve_evolmino(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if problem.cells[y][x] == evolmino::ProblemCell::Black {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 continue;
             }
             if problem.cells[y][x] == evolmino::ProblemCell::Square {
-                board.push(Item::cell(y, x, "black", ItemKind::Square));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Square));
                 continue;
             }
             match is_square[y][x] {
                 Some(true) => {
-                    board.push(Item::cell(y, x, "green", ItemKind::Square));
+                    board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Square));
                 }
                 Some(false) => {
-                    board.push(Item::cell(y, x, "green", ItemKind::Dot));
+                    board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Dot));
                 }
                 None => (),
             }
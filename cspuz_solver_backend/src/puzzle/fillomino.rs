@@ -1,10 +1,19 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::fillomino;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "fillomino",
+    "Fillomino",
+    solve_fillomino,
+)];
+
 pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
-    let problem = fillomino::deserialize_problem(url).ok_or("invalid url")?;
-    let (num, border) = fillomino::solve_fillomino(&problem).ok_or("no answer")?;
+    let (variant, problem) = fillomino::deserialize_variant_problem(url).ok_or("invalid url")?;
+    let (num, border) =
+        fillomino::solve_fillomino_with_variant(&problem, variant).ok_or("no answer")?;
 
     let height = num.len();
     let width = num[0].len();
@@ -18,9 +27,9 @@ pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             } else if let Some(n) = num[y][x] {
-                board.push(Item::cell(y, x, "green", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
             }
         }
     }
@@ -33,7 +42,7 @@ pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -48,7 +57,7 @@ pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -59,7 +68,7 @@ pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -74,7 +83,7 @@ pub fn solve_fillomino(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
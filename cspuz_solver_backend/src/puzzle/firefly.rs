@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, FireflyDir, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::items::Arrow;
 use cspuz_rs_puzzles::puzzles::firefly;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "firefly",
+    "Hotaru Beam",
+    solve_firefly,
+)];
+
 pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
     let problem = firefly::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = firefly::solve_firefly(&problem).ok_or("no answer")?;
@@ -18,7 +26,7 @@ pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b {
                         ItemKind::BoldWall
                     } else {
@@ -31,7 +39,7 @@ pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::DottedWall,
                 });
             }
@@ -44,7 +52,7 @@ pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b {
                         ItemKind::BoldWall
                     } else {
@@ -57,7 +65,7 @@ pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::DottedWall,
                 });
             }
@@ -77,7 +85,7 @@ pub fn solve_firefly(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Firefly(dir, n),
                 });
             }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::firewalk;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "firewalk",
+    "Fire Walk",
+    solve_firewalk,
+)];
+
 pub fn solve_firewalk(url: &str) -> Result<Board, &'static str> {
     let (fire_cell, num) = firewalk::deserialize_problem(url).ok_or("invalid url")?;
     let (is_line, fire_cell_mode) =
@@ -23,7 +31,7 @@ pub fn solve_firewalk(url: &str) -> Result<Board, &'static str> {
             }
         }
     }
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     for y in 0..height {
         for x in 0..width {
@@ -55,11 +63,11 @@ pub fn solve_firewalk(url: &str) -> Result<Board, &'static str> {
                 };
 
                 if let Some(cell_item) = cell_item {
-                    board.push(Item::cell(y, x, "green", cell_item));
+                    board.push(Item::cell(y, x, ColorRole::Answer, cell_item));
                 }
             }
             if let Some(n) = num[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::guidearrow::{self, GuidearrowClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "guidearrow",
+    "Guide Arrow",
+    solve_guidearrow,
+)];
+
 pub fn solve_guidearrow(url: &str) -> Result<Board, &'static str> {
     let (ty, tx, clues) = guidearrow::deserialize_problem(url).ok_or("invalid url")?;
     let ans = guidearrow::solve_guidearrow(ty, tx, &clues).ok_or("no answer")?;
@@ -12,7 +20,7 @@ pub fn solve_guidearrow(url: &str) -> Result<Board, &'static str> {
     board.push(Item::cell(
         ty as usize,
         tx as usize,
-        "black",
+        ColorRole::Given,
         ItemKind::Circle,
     ));
     for y in 0..height {
@@ -25,7 +33,7 @@ pub fn solve_guidearrow(url: &str) -> Result<Board, &'static str> {
                     GuidearrowClue::Right => ItemKind::ArrowRight,
                     GuidearrowClue::Unknown => ItemKind::Text("?"),
                 };
-                board.push(Item::cell(y, x, "black", kind));
+                board.push(Item::cell(y, x, ColorRole::Given, kind));
             }
         }
     }
@@ -38,7 +46,7 @@ pub fn solve_guidearrow(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Fill } else { ItemKind::Dot },
                 ));
             }
@@ -1,10 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
+use cspuz_rs::items::Arrow;
 use cspuz_rs_puzzles::puzzles::hashi;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("hashi", "Hashiwokakero", solve_hashi)];
+
 pub fn solve_hashi(url: &str) -> Result<Board, &'static str> {
-    let clues = hashi::deserialize_problem(url).ok_or("invalid url")?;
-    let num_line = hashi::solve_hashi(&clues).ok_or("no answer")?;
+    let (max_bridges, clues) = hashi::deserialize_variant_problem(url).ok_or("invalid url")?;
+    let num_line = hashi::solve_hashi_with_variant(&clues, max_bridges).ok_or("no answer")?;
 
     let height = clues.len();
     let width = clues[0].len();
@@ -17,12 +23,12 @@ pub fn solve_hashi(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: match n {
                             0 => ItemKind::Cross,
                             1 => ItemKind::Line,
                             2 => ItemKind::DoubleLine,
-                            _ => unreachable!(),
+                            n => ItemKind::Num(n),
                         },
                     });
                 }
@@ -32,12 +38,12 @@ pub fn solve_hashi(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: match n {
                             0 => ItemKind::Cross,
                             1 => ItemKind::Line,
                             2 => ItemKind::DoubleLine,
-                            _ => unreachable!(),
+                            n => ItemKind::Num(n),
                         },
                     });
                 }
@@ -46,11 +52,26 @@ pub fn solve_hashi(url: &str) -> Result<Board, &'static str> {
     }
     for y in 0..height {
         for x in 0..width {
-            if let Some(n) = clues[y][x] {
-                board.push(Item::cell(y, x, "white", ItemKind::FilledCircle));
-                board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                if n > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            if let Some((dir, n)) = clues[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Background,
+                    ItemKind::FilledCircle,
+                ));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                let arrow = match dir {
+                    Arrow::Unspecified => None,
+                    Arrow::Up => Some(ItemKind::SideArrowUp),
+                    Arrow::Down => Some(ItemKind::SideArrowDown),
+                    Arrow::Left => Some(ItemKind::SideArrowLeft),
+                    Arrow::Right => Some(ItemKind::SideArrowRight),
+                };
+                if let Some(arrow) = arrow {
+                    board.push(Item::cell(y, x, ColorRole::Given, arrow));
+                }
+                if n > 0 || (n == 0 && dir != Arrow::Unspecified) {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 }
             }
         }
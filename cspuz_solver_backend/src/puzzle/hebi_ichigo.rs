@@ -0,0 +1,55 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::hebi_ichigo;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "hebi",
+    "Hebi Ichigo",
+    solve_hebi_ichigo,
+)];
+
+pub fn solve_hebi_ichigo(url: &str) -> Result<Board, &'static str> {
+    use cspuz_rs::items::Arrow;
+
+    let problem = hebi_ichigo::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = hebi_ichigo::solve_hebi_ichigo(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            if let Some((dir, n)) = problem[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+                let arrow = match dir {
+                    Arrow::Unspecified => None,
+                    Arrow::Up => Some(ItemKind::SideArrowUp),
+                    Arrow::Down => Some(ItemKind::SideArrowDown),
+                    Arrow::Left => Some(ItemKind::SideArrowLeft),
+                    Arrow::Right => Some(ItemKind::SideArrowRight),
+                };
+                if let Some(arrow) = arrow {
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, arrow));
+                }
+                if n >= 0 {
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, ItemKind::Num(n)));
+                }
+            } else if let Some(n) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if n == 0 {
+                        ItemKind::Dot
+                    } else {
+                        ItemKind::Num(n)
+                    },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
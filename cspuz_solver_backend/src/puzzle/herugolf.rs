@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::herugolf;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "herugolf",
+    "Herugolf",
+    solve_herugolf,
+)];
+
 pub fn solve_herugolf(url: &str) -> Result<Board, &'static str> {
     let (pond, clues) = herugolf::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = herugolf::solve_herugolf(&pond, &clues).ok_or("no answer")?;
@@ -17,16 +25,16 @@ pub fn solve_herugolf(url: &str) -> Result<Board, &'static str> {
             }
             if let Some(n) = clues[y][x] {
                 if n > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Text("H")));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("H")));
                 }
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     Ok(board)
 }
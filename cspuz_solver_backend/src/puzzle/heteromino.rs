@@ -0,0 +1,90 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::heteromino;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "heteromino",
+    "Heteromino",
+    solve_heteromino,
+)];
+
+pub fn solve_heteromino(url: &str) -> Result<Board, &'static str> {
+    let is_black = heteromino::deserialize_problem(url).ok_or("invalid url")?;
+    let is_border = heteromino::solve_heteromino(&is_black).ok_or("no answer")?;
+
+    let height = is_black.len();
+    let width = is_black[0].len();
+    let mut board = Board::new(BoardKind::OuterGrid, height, width, is_unique(&is_border));
+
+    for (y, row) in is_black.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if cell {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+            }
+        }
+    }
+    for y in 0..height {
+        for x in 0..width {
+            if y < height - 1 {
+                let mut need_default_edge = true;
+                if is_black[y][x] || is_black[y + 1][x] {
+                    need_default_edge = false;
+                } else if let Some(b) = is_border.horizontal[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 2,
+                        x: x * 2 + 1,
+                        color: ColorRole::Answer.into(),
+                        kind: if b {
+                            ItemKind::BoldWall
+                        } else {
+                            ItemKind::Cross
+                        },
+                    });
+                    if b {
+                        need_default_edge = false;
+                    }
+                }
+                if need_default_edge {
+                    board.push(Item {
+                        y: y * 2 + 2,
+                        x: x * 2 + 1,
+                        color: ColorRole::Auxiliary.into(),
+                        kind: ItemKind::Wall,
+                    });
+                }
+            }
+            if x < width - 1 {
+                let mut need_default_edge = true;
+                if is_black[y][x] || is_black[y][x + 1] {
+                    need_default_edge = false;
+                } else if let Some(b) = is_border.vertical[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 1,
+                        x: x * 2 + 2,
+                        color: ColorRole::Answer.into(),
+                        kind: if b {
+                            ItemKind::BoldWall
+                        } else {
+                            ItemKind::Cross
+                        },
+                    });
+                    if b {
+                        need_default_edge = false;
+                    }
+                }
+                if need_default_edge {
+                    board.push(Item {
+                        y: y * 2 + 1,
+                        x: x * 2 + 2,
+                        color: ColorRole::Auxiliary.into(),
+                        kind: ItemKind::Wall,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
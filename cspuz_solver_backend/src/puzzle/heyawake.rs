@@ -1,8 +1,17 @@
+use crate::answer::AnswerGrid;
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::{is_unique, Uniqueness};
 use cspuz_rs::graph;
 use cspuz_rs_puzzles::puzzles::{ayeheya, heyawake};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[
+    PuzzleKind::puzz_link("heyawake", "Heyawake", |url| solve_heyawake(url, false))
+        .with_enumerate(enumerate_answers_heyawake),
+    PuzzleKind::puzz_link("ayeheya", "Ayeheya", |url| solve_heyawake(url, true)),
+];
+
 pub fn solve_heyawake(url: &str, is_ayeheya: bool) -> Result<Board, &'static str> {
     let borders;
     let clues;
@@ -27,7 +36,8 @@ pub fn solve_heyawake(url: &str, is_ayeheya: bool) -> Result<Board, &'static str
     let width = is_black[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_black));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
+    board.add_answer("shaded", AnswerGrid::cells(&is_black));
 
     for y in 0..height {
         for x in 0..width {
@@ -35,7 +45,7 @@ pub fn solve_heyawake(url: &str, is_ayeheya: bool) -> Result<Board, &'static str
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -46,7 +56,16 @@ pub fn solve_heyawake(url: &str, is_ayeheya: bool) -> Result<Board, &'static str
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board.push(Item::cell(
+                y,
+                x,
+                ColorRole::Given,
+                if n >= 0 {
+                    ItemKind::Num(n)
+                } else {
+                    ItemKind::Text("?")
+                },
+            ));
         }
     }
 
@@ -65,7 +84,7 @@ pub fn enumerate_answers_heyawake(
     let width = is_black_common[0].len();
 
     let mut board_common = Board::new(BoardKind::Grid, height, width, Uniqueness::NotApplicable);
-    board_common.add_borders(&borders, "black");
+    board_common.add_borders(&borders, ColorRole::Given);
     for y in 0..height {
         for x in 0..width {
             if let Some(b) = is_black_common[y][x] {
@@ -83,7 +102,16 @@ pub fn enumerate_answers_heyawake(
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board_common.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board_common.push(Item::cell(
+                y,
+                x,
+                ColorRole::Given,
+                if n >= 0 {
+                    ItemKind::Num(n)
+                } else {
+                    ItemKind::Text("?")
+                },
+            ));
         }
     }
 
@@ -94,10 +122,10 @@ pub fn enumerate_answers_heyawake(
         for y in 0..height {
             for x in 0..width {
                 if is_black_common[y][x].is_none() {
-                    board_answer.push(Item::cell(
+                    board_answer.push_optional(Item::cell(
                         y,
                         x,
-                        "#cccccc",
+                        ColorRole::Auxiliary,
                         if ans[y][x] {
                             ItemKind::Block
                         } else {
@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::hidato;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono("hidoku", "Hidoku", solve_hidato)];
+
 pub fn solve_hidato(url: &str) -> Result<Board, &'static str> {
     let problem = hidato::deserialize_problem(url).ok_or("invalid url")?;
     let answer = hidato::solve_hidato(&problem).ok_or("no answer")?;
@@ -14,15 +18,15 @@ pub fn solve_hidato(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
                 if n == -1 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 }
             } else if let Some(n) = answer[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if n == 0 {
                         ItemKind::Dot
                     } else {
@@ -0,0 +1,94 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs::items::Arrow;
+use cspuz_rs_puzzles::puzzles::icebarn;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("icebarn", "Icebarn", solve_icebarn)];
+
+fn arrow_kind(arrow: Arrow) -> Option<ItemKind> {
+    match arrow {
+        Arrow::Unspecified => None,
+        Arrow::Up => Some(ItemKind::ArrowUp),
+        Arrow::Down => Some(ItemKind::ArrowDown),
+        Arrow::Left => Some(ItemKind::ArrowLeft),
+        Arrow::Right => Some(ItemKind::ArrowRight),
+    }
+}
+
+fn opposite(arrow: Arrow) -> Arrow {
+    match arrow {
+        Arrow::Unspecified => Arrow::Unspecified,
+        Arrow::Up => Arrow::Down,
+        Arrow::Down => Arrow::Up,
+        Arrow::Left => Arrow::Right,
+        Arrow::Right => Arrow::Left,
+    }
+}
+
+pub fn solve_icebarn(url: &str) -> Result<Board, &'static str> {
+    let (icebarn, arrows, entrance, exit) =
+        icebarn::deserialize_problem(url).ok_or("invalid url")?;
+    let is_line = icebarn::solve_icebarn(&icebarn, &arrows, entrance, exit).ok_or("no answer")?;
+
+    let height = icebarn.len();
+    let width = icebarn[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
+
+    for (y, row) in icebarn.iter().enumerate() {
+        for (x, &ice) in row.iter().enumerate() {
+            if ice {
+                board.push(Item::cell(y, x, "#e0e0ff", ItemKind::Fill));
+            }
+        }
+    }
+    for y in 0..height {
+        for x in 0..(width - 1) {
+            if let Some(kind) = arrow_kind(arrows.horizontal[y][x]) {
+                board.push(Item {
+                    y: y * 2 + 1,
+                    x: x * 2 + 2,
+                    color: ColorRole::Given.into(),
+                    kind,
+                });
+            }
+        }
+    }
+    for y in 0..(height - 1) {
+        for x in 0..width {
+            if let Some(kind) = arrow_kind(arrows.vertical[y][x]) {
+                board.push(Item {
+                    y: y * 2 + 2,
+                    x: x * 2 + 1,
+                    color: ColorRole::Given.into(),
+                    kind,
+                });
+            }
+        }
+    }
+
+    // The path enters the grid at the entrance and leaves it at the exit
+    for (((y, x), side), dir) in [(entrance, opposite(entrance.1)), (exit, exit.1)] {
+        let (py, px) = match side {
+            Arrow::Up => (0, x * 2 + 1),
+            Arrow::Down => (height * 2, x * 2 + 1),
+            Arrow::Left => (y * 2 + 1, 0),
+            Arrow::Right => (y * 2 + 1, width * 2),
+            Arrow::Unspecified => continue,
+        };
+        if let Some(kind) = arrow_kind(dir) {
+            board.push(Item {
+                y: py,
+                x: px,
+                color: ColorRole::Given.into(),
+                kind,
+            });
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+
+    Ok(board)
+}
@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::icewalk;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("icewalk", "Ice Walk", solve_icewalk)];
+
 pub fn solve_icewalk(url: &str) -> Result<Board, &'static str> {
     let (icebarn, num) = icewalk::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = icewalk::solve_icewalk(&icebarn, &num).ok_or("no answer")?;
@@ -16,12 +21,12 @@ pub fn solve_icewalk(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(y, x, "#e0e0ff", ItemKind::Fill));
             }
             if let Some(n) = num[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     Ok(board)
 }
@@ -1,10 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::kakuro;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("kakuro", "Kakuro", solve_kakuro)];
+
 pub fn solve_kakuro(url: &str) -> Result<Board, &'static str> {
-    let problem = kakuro::deserialize_problem(url).ok_or("invalid url")?;
-    let answer: Vec<Vec<Option<i32>>> = kakuro::solve_kakuro(&problem).ok_or("no answer")?;
+    let (problem, options) = kakuro::deserialize_extended_problem(url).ok_or("invalid url")?;
+    let answer: Vec<Vec<Option<i32>>> =
+        kakuro::solve_kakuro_with_options(&problem, &options).ok_or("no answer")?;
 
     let height = answer.len();
     let width = answer[0].len();
@@ -13,22 +18,32 @@ pub fn solve_kakuro(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "#cccccc", ItemKind::Fill));
-                board.push(Item::cell(y, x, "black", ItemKind::Backslash));
+                board.push(Item::cell(y, x, ColorRole::Auxiliary, ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Backslash));
 
                 if let Some(n) = clue.down {
                     if n > 0 {
-                        board.push(Item::cell(y, x, "black", ItemKind::NumLowerLeft(n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::Given,
+                            ItemKind::NumLowerLeft(n),
+                        ));
                     }
                 }
                 if let Some(n) = clue.right {
                     if n > 0 {
-                        board.push(Item::cell(y, x, "black", ItemKind::NumUpperRight(n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::Given,
+                            ItemKind::NumUpperRight(n),
+                        ));
                     }
                 }
             } else {
                 if let Some(n) = answer[y][x] {
-                    board.push(Item::cell(y, x, "green", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
                 }
             }
         }
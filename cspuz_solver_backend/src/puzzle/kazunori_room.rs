@@ -0,0 +1,51 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::kazunori_room;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "kazunori",
+    "Kazunori Room",
+    solve_kazunori_room,
+)];
+
+pub fn solve_kazunori_room(url: &str) -> Result<Board, &'static str> {
+    let (borders, sums) = kazunori_room::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = kazunori_room::solve_kazunori_room(&borders, &sums).ok_or("no answer")?;
+
+    let height = ans.len();
+    let width = ans[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    board.add_borders(&borders, ColorRole::Given);
+
+    for (y, row) in ans.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(n) = cell {
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
+            }
+            if y < height - 1 {
+                if let Some(n) = sums.horizontal[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 2,
+                        x: x * 2 + 1,
+                        color: ColorRole::Given.into(),
+                        kind: ItemKind::Num(n),
+                    });
+                }
+            }
+            if x < width - 1 {
+                if let Some(n) = sums.vertical[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 1,
+                        x: x * 2 + 2,
+                        color: ColorRole::Given.into(),
+                        kind: ItemKind::Num(n),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
@@ -0,0 +1,58 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::koburin;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("koburin", "Koburin", solve_koburin)];
+
+pub fn solve_koburin(url: &str) -> Result<Board, &'static str> {
+    let problem = koburin::deserialize_problem(url).ok_or("invalid url")?;
+    let (is_line, is_black) = koburin::solve_koburin(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(
+        BoardKind::Grid,
+        height,
+        width,
+        is_unique(&(&is_line, &is_black)),
+    );
+
+    let mut skip_line = vec![];
+    for y in 0..height {
+        let mut row = vec![];
+        for x in 0..width {
+            row.push(problem[y][x].is_some() || is_black[y][x] == Some(true));
+        }
+        skip_line.push(row);
+    }
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(n) = problem[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Given,
+                    if n >= 0 {
+                        ItemKind::Num(n)
+                    } else {
+                        ItemKind::Text("?")
+                    },
+                ));
+            } else if let Some(b) = is_black[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if b { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
+
+    Ok(board)
+}
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::Uniqueness;
 use cspuz_rs_puzzles::puzzles::kouchoku;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "kouchoku",
+    "Kouchoku",
+    solve_kouchoku,
+)];
+
 pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
     let problem = kouchoku::deserialize_problem(url).ok_or("invalid url")?;
     let (fixed_lines, undet_lines) = kouchoku::solve_kouchoku(&problem).ok_or("no answer")?;
@@ -24,7 +32,7 @@ pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 2,
                     x: x * 2 + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::DottedLine,
                 });
             }
@@ -32,7 +40,7 @@ pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2 + 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::DottedLine,
                 });
             }
@@ -42,11 +50,16 @@ pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
                 if clue >= 0 {
-                    board.push(Item::cell(y, x, "white", ItemKind::FilledCircle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue + 1)));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Background,
+                        ItemKind::FilledCircle,
+                    ));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue + 1)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::FilledCircle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle));
                 }
             }
         }
@@ -56,7 +69,7 @@ pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: y1 * 2 + 1,
             x: x1 * 2 + 1,
-            color: "green",
+            color: ColorRole::Answer.into(),
             kind: ItemKind::LineTo(y2 as i32 * 2 + 1, x2 as i32 * 2 + 1),
         });
     }
@@ -64,7 +77,7 @@ pub fn solve_kouchoku(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: y1 * 2 + 1,
             x: x1 * 2 + 1,
-            color: "#888888",
+            color: "#888888".into(),
             kind: ItemKind::LineTo(y2 as i32 * 2 + 1, x2 as i32 * 2 + 1),
         });
     }
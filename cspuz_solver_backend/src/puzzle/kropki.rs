@@ -1,33 +1,44 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::kropki::{self, KropkiClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("kropki", "Kropki", solve_kropki)];
+
 pub fn solve_kropki(url: &str) -> Result<Board, &'static str> {
-    let problem = kropki::deserialize_problem(url).ok_or("invalid url")?;
-    let ans = kropki::solve_kropki(&problem).ok_or("no answer")?;
+    let (problem, regions) = kropki::deserialize_problem_with_regions(url).ok_or("invalid url")?;
+    let ans = match &regions {
+        Some(regions) => kropki::solve_kropki_with_regions(&problem, regions),
+        None => kropki::solve_kropki(&problem),
+    }
+    .ok_or("no answer")?;
 
     let height = ans.len();
     let width = ans[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    if let Some(regions) = &regions {
+        board.add_borders(regions, ColorRole::Given);
+    }
 
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = ans[y][x] {
-                board.push(Item::cell(y, x, "green", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
             }
             if y < height - 1 {
                 if problem.horizontal[y][x] == KropkiClue::White {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallCircle,
                     });
                 } else if problem.horizontal[y][x] == KropkiClue::Black {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallFilledCircle,
                     });
                 }
@@ -37,14 +48,14 @@ pub fn solve_kropki(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallCircle,
                     });
                 } else if problem.vertical[y][x] == KropkiClue::Black {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallFilledCircle,
                     });
                 }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::kropki_pairs::{self, KropkiClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "kropki-pairs",
+    "Kropki Pairs",
+    solve_kropki_pairs,
+)];
+
 pub fn solve_kropki_pairs(url: &str) -> Result<Board, &'static str> {
     let (walls, cells) = kropki_pairs::deserialize_problem(url).ok_or("invalid url")?;
     let ans = kropki_pairs::solve_kropki_pairs(&walls, &cells).ok_or("no answer")?;
@@ -14,26 +22,26 @@ pub fn solve_kropki_pairs(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(n) = cells[y][x] {
                 if n > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 }
             } else if let Some(n) = ans[y][x] {
-                board.push(Item::cell(y, x, "green", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
             }
             if y < height - 1 {
                 if walls.horizontal[y][x] == KropkiClue::White {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallCircle,
                     });
                 } else if walls.horizontal[y][x] == KropkiClue::Black {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallFilledCircle,
                     });
                 }
@@ -43,14 +51,14 @@ pub fn solve_kropki_pairs(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallCircle,
                     });
                 } else if walls.vertical[y][x] == KropkiClue::Black {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::SmallFilledCircle,
                     });
                 }
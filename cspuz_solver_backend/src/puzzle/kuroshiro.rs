@@ -0,0 +1,40 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::kuroshiro;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "kuroshiro",
+    "Kuroshiro",
+    solve_kuroshiro,
+)];
+
+pub fn solve_kuroshiro(url: &str) -> Result<Board, &'static str> {
+    use kuroshiro::KuroshiroClue;
+
+    let problem = kuroshiro::deserialize_problem(url).ok_or("invalid url")?;
+    let is_line = kuroshiro::solve_kuroshiro(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
+
+    for (y, row) in problem.iter().enumerate() {
+        for (x, clue) in row.iter().enumerate() {
+            match clue {
+                KuroshiroClue::None => (),
+                KuroshiroClue::White => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle))
+                }
+                KuroshiroClue::Black => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle))
+                }
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+
+    Ok(board)
+}
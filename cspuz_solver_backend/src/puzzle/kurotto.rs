@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::kurotto;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("kurotto", "Kurotto", solve_kurotto)];
+
 pub fn solve_kurotto(url: &str) -> Result<Board, &'static str> {
     let problem = kurotto::deserialize_problem(url).ok_or("invalid url")?;
     let ans = kurotto::solve_kurotto(&problem).ok_or("no answer")?;
@@ -12,15 +17,15 @@ pub fn solve_kurotto(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Circle));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
                 if clue > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                 }
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Fill } else { ItemKind::Dot },
                 ));
             }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::letter_weights;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "letter-weights",
+    "Letter Weights",
+    solve_letter_weights,
+)];
+
 const ALPHA: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 pub fn solve_letter_weights(url: &str) -> Result<Board, &'static str> {
@@ -19,11 +27,21 @@ pub fn solve_letter_weights(url: &str) -> Result<Board, &'static str> {
     for y in 0..chars.len() {
         assert!('A' <= chars[y] && chars[y] <= 'Z');
         let i = (chars[y] as u8 - 'A' as u8) as usize;
-        board.push(Item::cell(y + 1, 0, "black", ItemKind::Text(&ALPHA[i..=i])));
+        board.push(Item::cell(
+            y + 1,
+            0,
+            ColorRole::Given,
+            ItemKind::Text(&ALPHA[i..=i]),
+        ));
     }
 
     for x in 0..nums.len() {
-        board.push(Item::cell(0, x + 1, "black", ItemKind::Num(nums[x])));
+        board.push(Item::cell(
+            0,
+            x + 1,
+            ColorRole::Given,
+            ItemKind::Num(nums[x]),
+        ));
     }
 
     for y in 0..chars.len() {
@@ -32,7 +50,7 @@ pub fn solve_letter_weights(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y + 1,
                     x + 1,
-                    "green",
+                    ColorRole::Answer,
                     if clue {
                         ItemKind::Circle
                     } else {
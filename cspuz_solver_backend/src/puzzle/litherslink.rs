@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::litherslink;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "lither",
+    "Litherslink",
+    solve_litherslink,
+)];
+
 pub fn solve_litherslink(url: &str) -> Result<Board, &'static str> {
     let problem = litherslink::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = litherslink::solve_litherslink(&problem).ok_or("no answer")?;
@@ -13,7 +21,7 @@ pub fn solve_litherslink(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
@@ -23,7 +31,7 @@ pub fn solve_litherslink(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -35,7 +43,7 @@ pub fn solve_litherslink(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
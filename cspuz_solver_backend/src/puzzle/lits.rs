@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::lits;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("lits", "LITS", solve_lits)];
+
 pub fn solve_lits(url: &str) -> Result<Board, &'static str> {
     let borders = lits::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = lits::solve_lits(&borders).ok_or("no answer")?;
@@ -10,7 +14,7 @@ pub fn solve_lits(url: &str) -> Result<Board, &'static str> {
     let width = is_black[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_black));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -18,7 +22,7 @@ pub fn solve_lits(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
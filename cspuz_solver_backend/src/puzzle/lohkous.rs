@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::lohkous;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("lohkous", "Lohkous", solve_lohkous)];
+
 pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
     let problem = lohkous::deserialize_problem(url).ok_or("invalid url")?;
     let ans = lohkous::solve_lohkous(&problem).ok_or("no answer")?;
@@ -17,9 +22,9 @@ pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
                     for i in 0..clue.len() {
                         c[i] = if clue[i] == -1 { -2 } else { clue[i] };
                     }
-                    board.push(Item::cell(y, x, "black", ItemKind::TapaClue(c)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::TapaClue(c)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Text("...")));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("...")));
                 }
             }
         }
@@ -32,7 +37,7 @@ pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -47,7 +52,7 @@ pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -58,7 +63,7 @@ pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -73,7 +78,7 @@ pub fn solve_lohkous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
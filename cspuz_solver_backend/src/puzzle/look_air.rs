@@ -0,0 +1,38 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::look_air;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("lookair", "Look-Air", solve_look_air)];
+
+pub fn solve_look_air(url: &str) -> Result<Board, &'static str> {
+    let problem = look_air::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = look_air::solve_look_air(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(a) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+            if let Some(clue) = problem[y][x] {
+                if clue >= 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+                } else {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::loop_special::{self, LoopSpecialClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "loopsp",
+    "Loop Special",
+    solve_loop_speical,
+)];
+
 pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
     let problem = loop_special::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = loop_special::solve_loop_special(&problem).ok_or("no answer")?;
@@ -10,14 +18,14 @@ pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
     let width = problem[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     for y in 0..height {
         for x in 0..width {
             match problem[y][x] {
                 LoopSpecialClue::Num(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 }
                 LoopSpecialClue::Empty => (),
                 _ => {
@@ -35,7 +43,7 @@ pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2,
                             x: x * 2 + 1,
-                            color: "black",
+                            color: ColorRole::Given.into(),
                             kind: ItemKind::Line,
                         });
                     }
@@ -43,7 +51,7 @@ pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2 + 2,
                             x: x * 2 + 1,
-                            color: "black",
+                            color: ColorRole::Given.into(),
                             kind: ItemKind::Line,
                         });
                     }
@@ -51,7 +59,7 @@ pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2 + 1,
                             x: x * 2,
-                            color: "black",
+                            color: ColorRole::Given.into(),
                             kind: ItemKind::Line,
                         });
                     }
@@ -59,7 +67,7 @@ pub fn solve_loop_speical(url: &str) -> Result<Board, &'static str> {
                         board.push(Item {
                             y: y * 2 + 1,
                             x: x * 2 + 2,
-                            color: "black",
+                            color: ColorRole::Given.into(),
                             kind: ItemKind::Line,
                         });
                     }
@@ -0,0 +1,46 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs::items::Arrow;
+use cspuz_rs_puzzles::puzzles::makaro::{self, MakaroCell};
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("makaro", "Makaro", solve_makaro)];
+
+pub fn solve_makaro(url: &str) -> Result<Board, &'static str> {
+    let (borders, cells) = makaro::deserialize_problem(url).ok_or("invalid url")?;
+    let answer = makaro::solve_makaro(&borders, &cells).ok_or("no answer")?;
+
+    let height = cells.len();
+    let width = cells[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&answer));
+    board.add_borders(&borders, ColorRole::Given);
+
+    for y in 0..height {
+        for x in 0..width {
+            match cells[y][x] {
+                MakaroCell::Black => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill)),
+                MakaroCell::Arrow(dir) => {
+                    let kind = match dir {
+                        Arrow::Unspecified => continue,
+                        Arrow::Up => ItemKind::ArrowUp,
+                        Arrow::Down => ItemKind::ArrowDown,
+                        Arrow::Left => ItemKind::ArrowLeft,
+                        Arrow::Right => ItemKind::ArrowRight,
+                    };
+                    board.push(Item::cell(y, x, ColorRole::Given, kind));
+                }
+                MakaroCell::Num(n) if n > 0 => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
+                }
+                _ => {
+                    if let Some(n) = answer[y][x] {
+                        board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
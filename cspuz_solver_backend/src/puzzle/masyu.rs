@@ -1,12 +1,28 @@
+use crate::answer::AnswerGrid;
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::masyu;
 
-pub fn solve_masyu(url: &str) -> Result<Board, &'static str> {
+pub(crate) const KINDS: &[PuzzleKind] = &[
+    PuzzleKind::puzz_link("masyu", "Masyu", |url| solve_masyu(url, false)).with_aliases(&["mashu"]),
+    PuzzleKind::puzz_link("ura-mashu", "Ura-Masyu", |url| solve_masyu(url, true))
+        .with_aliases(&["ura-masyu"]),
+];
+
+pub fn solve_masyu(url: &str, is_ura: bool) -> Result<Board, &'static str> {
     use masyu::MasyuClue;
 
-    let problem = masyu::deserialize_problem(url).ok_or("invalid url")?;
-    let is_line = masyu::solve_masyu(&problem).ok_or("no answer")?;
+    let problem;
+    let is_line;
+    if is_ura {
+        problem = masyu::deserialize_ura_problem(url).ok_or("invalid url")?;
+        is_line = masyu::solve_ura_masyu(&problem).ok_or("no answer")?;
+    } else {
+        problem = masyu::deserialize_problem(url).ok_or("invalid url")?;
+        is_line = masyu::solve_masyu(&problem).ok_or("no answer")?;
+    }
 
     let height = problem.len();
     let width = problem[0].len();
@@ -16,13 +32,18 @@ pub fn solve_masyu(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             match problem[y][x] {
                 MasyuClue::None => (),
-                MasyuClue::White => board.push(Item::cell(y, x, "black", ItemKind::Circle)),
-                MasyuClue::Black => board.push(Item::cell(y, x, "black", ItemKind::FilledCircle)),
+                MasyuClue::White => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle))
+                }
+                MasyuClue::Black => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle))
+                }
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+    board.add_answer("line", AnswerGrid::lines(&is_line));
 
     Ok(board)
 }
@@ -0,0 +1,38 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::meandering_numbers;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "meander",
+    "Meandering Numbers",
+    solve_meandering_numbers,
+)];
+
+pub fn solve_meandering_numbers(url: &str) -> Result<Board, &'static str> {
+    let (borders, clues) = meandering_numbers::deserialize_problem(url).ok_or("invalid url")?;
+    let answer =
+        meandering_numbers::solve_meandering_numbers(&borders, &clues).ok_or("no answer")?;
+
+    let height = clues.len();
+    let width = clues[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&answer));
+    board.add_borders(&borders, ColorRole::Given);
+
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(n) = clues[y][x] {
+                if n > 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
+                    continue;
+                }
+            }
+            if let Some(n) = answer[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(n)));
+            }
+        }
+    }
+
+    Ok(board)
+}
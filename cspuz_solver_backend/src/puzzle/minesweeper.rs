@@ -0,0 +1,40 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::minesweeper;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[
+        PuzzleKind::puzz_link("minesweeper", "Minesweeper", solve_minesweeper)
+            .with_aliases(&["mines"]),
+    ];
+
+pub fn solve_minesweeper(url: &str) -> Result<Board, &'static str> {
+    let problem = minesweeper::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = minesweeper::solve_minesweeper(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(clue) = problem[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+            } else if let Some(a) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a {
+                        ItemKind::FilledCircle
+                    } else {
+                        ItemKind::Dot
+                    },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
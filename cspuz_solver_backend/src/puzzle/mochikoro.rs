@@ -0,0 +1,40 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::mochikoro;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "mochikoro",
+    "Mochikoro",
+    solve_mochikoro,
+)];
+
+pub fn solve_mochikoro(url: &str) -> Result<Board, &'static str> {
+    let problem = mochikoro::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = mochikoro::solve_mochikoro(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(clue) = problem[y][x] {
+                if clue > 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+                } else {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
+                }
+            } else if let Some(a) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
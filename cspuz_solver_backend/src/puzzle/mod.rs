@@ -1,86 +1,147 @@
-pub mod akari;
-pub mod akari_regions;
-pub mod akari_rgb;
-pub mod akichiwake;
-pub mod aqre;
-pub mod aquapelago;
-pub mod araf;
-pub mod archipelago;
-pub mod barns;
-pub mod castle_wall;
-pub mod cave;
-pub mod chainedb;
-pub mod chocobanana;
-pub mod cocktail;
-pub mod coffeemilk;
-pub mod compass;
-pub mod coral;
-pub mod creek;
-pub mod cross_border_parity_loop;
-pub mod crosswall;
-pub mod curvedata;
-pub mod dbchoco;
-pub mod doppelblock;
-pub mod double_lits;
-pub mod evolmino;
-pub mod fillomino;
-pub mod firefly;
-pub mod firewalk;
-pub mod fivecells;
-pub mod guidearrow;
-pub mod hashi;
-pub mod herugolf;
-pub mod heyawake;
-pub mod hidato;
-pub mod icewalk;
-pub mod inverse_litso;
-pub mod kakuro;
-pub mod kouchoku;
-pub mod kropki;
-pub mod kropki_pairs;
-pub mod kurotto;
-pub mod letter_weights;
-pub mod litherslink;
-pub mod lits;
-pub mod lohkous;
-pub mod loop_special;
-pub mod masyu;
-pub mod milktea;
-pub mod moonsun;
-pub mod multiplication_link;
-pub mod nagenawa;
-pub mod nikoji;
-pub mod norinori;
-pub mod nothree;
-pub mod nurikabe;
-pub mod nurimaze;
-pub mod nurimisaki;
-pub mod parrot_loop;
-pub mod pencils;
-pub mod polyominous;
-pub mod reflect;
-pub mod ringring;
-pub mod sashigane;
-pub mod seiza;
-pub mod shakashaka;
-pub mod shikaku;
-pub mod shimaguni;
-pub mod simpleloop;
-pub mod slalom;
-pub mod slashpack;
-pub mod slicy;
-pub mod slitherlink;
-pub mod sniping_arrow;
-pub mod soulmates;
-pub mod spokes;
-pub mod square_jam;
-pub mod statue_park;
-pub mod stostone;
-pub mod sudoku;
-pub mod tapa;
-pub mod the_longest;
-pub mod timebomb;
-pub mod tontonbeya;
-pub mod tricklayer;
-pub mod yajilin;
-pub mod yajilin_regions;
+//! Puzzle solvers of the backend.
+//!
+//! Each module declares the puzzle kinds it handles in a `KINDS` constant, next to the solver
+//! functions themselves. Modules are declared with `puzzle_modules!`, which also collects their
+//! `KINDS` into the registry of `crate::kinds`, so that a solver cannot be left out of the dispatch.
+//!
+//! rustfmt does not visit modules declared inside a macro, so they have to be formatted by passing
+//! the files to it directly (`rustfmt --edition 2021 src/puzzle/*.rs`).
+
+use crate::kinds::PuzzleKind;
+
+macro_rules! puzzle_modules {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub(crate) fn registered_kinds() -> Vec<PuzzleKind> {
+            [$($module::KINDS),*].concat()
+        }
+    };
+}
+
+puzzle_modules!(
+    akari,
+    akari_mirror,
+    akari_regions,
+    akari_rgb,
+    akichiwake,
+    aqre,
+    aquapelago,
+    aquarium,
+    araf,
+    archipelago,
+    barns,
+    battleship,
+    canal_view,
+    castle_wall,
+    cave,
+    chainedb,
+    chocobanana,
+    cocktail,
+    coffeemilk,
+    compass,
+    context,
+    coral,
+    creek,
+    cross_border_parity_loop,
+    crosswall,
+    curvedata,
+    dbchoco,
+    doppelblock,
+    dosun_fuwari,
+    double_lits,
+    easy_as_abc,
+    evolmino,
+    fillomino,
+    firefly,
+    firewalk,
+    fivecells,
+    guidearrow,
+    hashi,
+    hebi_ichigo,
+    herugolf,
+    heteromino,
+    heyawake,
+    hidato,
+    icebarn,
+    icewalk,
+    inverse_litso,
+    kakurasu,
+    kakuro,
+    kazunori_room,
+    koburin,
+    kouchoku,
+    kropki,
+    kropki_pairs,
+    kurochute,
+    kuroshiro,
+    kurotto,
+    letter_weights,
+    litherslink,
+    lits,
+    lohkous,
+    look_air,
+    loop_special,
+    makaro,
+    masyu,
+    meandering_numbers,
+    milktea,
+    minesweeper,
+    mochikoro,
+    mochinyoro,
+    moonsun,
+    multiplication_link,
+    nagareru_loop,
+    nagenawa,
+    nikoji,
+    norinori,
+    nothree,
+    nurikabe,
+    nurimaze,
+    nurimisaki,
+    onsen,
+    parrot_loop,
+    pencils,
+    polyominous,
+    reflect,
+    ringring,
+    sashigane,
+    satogaeri,
+    scrin,
+    seiza,
+    shakashaka,
+    shikaku,
+    shimaguni,
+    simpleloop,
+    slalom,
+    slant,
+    slashpack,
+    slicy,
+    slitherlink,
+    snake,
+    sniping_arrow,
+    soulmates,
+    spokes,
+    square_jam,
+    statue_park,
+    stostone,
+    sudoku,
+    suguru,
+    sukoro,
+    tapa,
+    tapa_loop,
+    tasquare,
+    tentaisho,
+    tents,
+    the_longest,
+    thermometers,
+    tilepaint,
+    timebomb,
+    tontonbeya,
+    tricklayer,
+    usoone,
+    wataridori,
+    yajilin,
+    yajilin_regions,
+    yinyang,
+);
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::moonsun;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "moonsun",
+    "Moon or Sun",
+    solve_moonsun,
+)];
+
 pub fn solve_moonsun(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = moonsun::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = moonsun::solve_moonsun(&borders, &clues).ok_or("no answer")?;
@@ -9,7 +17,7 @@ pub fn solve_moonsun(url: &str) -> Result<Board, &'static str> {
     let height = clues.len();
     let width = clues[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -17,7 +25,7 @@ pub fn solve_moonsun(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     if clues[y][x] == 1 {
                         ItemKind::Circle
                     } else {
@@ -28,7 +36,7 @@ pub fn solve_moonsun(url: &str) -> Result<Board, &'static str> {
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     Ok(board)
 }
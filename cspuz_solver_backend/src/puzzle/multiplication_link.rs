@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::multiplication_link;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "multiplication-link",
+    "Multiplication Link",
+    solve_multiplication_link,
+)];
+
 pub fn solve_multiplication_link(url: &str) -> Result<Board, &'static str> {
     let problem = multiplication_link::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = multiplication_link::solve_multiplication_link(&problem).ok_or("no answer")?;
@@ -24,7 +32,7 @@ pub fn solve_multiplication_link(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     if n == -2 {
                         ItemKind::Fill
                     } else if n == -1 {
@@ -37,7 +45,7 @@ pub fn solve_multiplication_link(url: &str) -> Result<Board, &'static str> {
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&skip_line));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
 
     Ok(board)
 }
@@ -0,0 +1,62 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs::items::Arrow;
+use cspuz_rs_puzzles::puzzles::nagareru_loop::{self, NagareruCell};
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "nagare",
+    "Nagareru-Loop",
+    solve_nagareru_loop,
+)];
+
+fn arrow_kind(dir: Arrow) -> Option<ItemKind> {
+    match dir {
+        Arrow::Unspecified => None,
+        Arrow::Up => Some(ItemKind::ArrowUp),
+        Arrow::Down => Some(ItemKind::ArrowDown),
+        Arrow::Left => Some(ItemKind::ArrowLeft),
+        Arrow::Right => Some(ItemKind::ArrowRight),
+    }
+}
+
+pub fn solve_nagareru_loop(url: &str) -> Result<Board, &'static str> {
+    let problem = nagareru_loop::deserialize_problem(url).ok_or("invalid url")?;
+    let is_line = nagareru_loop::solve_nagareru_loop(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
+
+    let skip_line = problem
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| matches!(cell, NagareruCell::Black(_)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            match cell {
+                NagareruCell::Empty => (),
+                NagareruCell::White(dir) => {
+                    if let Some(kind) = arrow_kind(dir) {
+                        board.push(Item::cell(y, x, ColorRole::Given, kind));
+                    }
+                }
+                NagareruCell::Black(dir) => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
+                    if let Some(kind) = arrow_kind(dir) {
+                        board.push(Item::cell(y, x, ColorRole::GivenOnFill, kind));
+                    }
+                }
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
+
+    Ok(board)
+}
@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::graph;
 use cspuz_rs_puzzles::puzzles::nagenawa;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "nagenawa",
+    "Nagenawa",
+    solve_nagenawa,
+)];
+
 pub fn solve_nagenawa(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = nagenawa::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = nagenawa::solve_nagenawa(&borders, &clues).ok_or("no answer")?;
@@ -11,15 +19,15 @@ pub fn solve_nagenawa(url: &str) -> Result<Board, &'static str> {
     let width = is_line.horizontal[0].len() + 1;
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
-    board.add_borders(&borders, "black");
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_borders(&borders, ColorRole::Given);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     let rooms = graph::borders_to_rooms(&borders);
     assert_eq!(rooms.len(), clues.len());
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
         }
     }
 
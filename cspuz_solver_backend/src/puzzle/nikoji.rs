@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::nikoji;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("nikoji", "NIKOJI", solve_nikoji)];
+
 const ALPHA: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
@@ -16,9 +20,14 @@ pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
             if let Some(clue) = problem[y][x] {
                 if 1 <= clue && clue <= 26 {
                     let p = (clue - 1) as usize;
-                    board.push(Item::cell(y, x, "black", ItemKind::Text(&ALPHA[p..=p])));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::Text(&ALPHA[p..=p]),
+                    ));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue - 26)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue - 26)));
                 }
             }
         }
@@ -32,7 +41,7 @@ pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -47,7 +56,7 @@ pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -58,7 +67,7 @@ pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -73,7 +82,7 @@ pub fn solve_nikoji(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::norinori;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "norinori",
+    "Norinori",
+    solve_norinori,
+)];
+
 pub fn solve_norinori(url: &str) -> Result<Board, &'static str> {
     let borders = norinori::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = norinori::solve_norinori(&borders).ok_or("no answer")?;
@@ -10,7 +18,7 @@ pub fn solve_norinori(url: &str) -> Result<Board, &'static str> {
     let width = is_black[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_black));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -18,7 +26,7 @@ pub fn solve_norinori(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::nothree;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("nothree", "No Three", solve_nothree)];
+
 pub fn solve_nothree(url: &str) -> Result<Board, &'static str> {
     let problem = nothree::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = nothree::solve_nothree(&problem).ok_or("no answer")?;
@@ -15,7 +20,7 @@ pub fn solve_nothree(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: y * 2 + 1,
                 x: x * 2 + 2,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: ItemKind::Wall,
             });
         }
@@ -25,7 +30,7 @@ pub fn solve_nothree(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: y * 2 + 2,
                 x: x * 2 + 1,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: ItemKind::Wall,
             });
         }
@@ -37,7 +42,7 @@ pub fn solve_nothree(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -50,13 +55,13 @@ pub fn solve_nothree(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y + 1,
                     x: x + 1,
-                    color: "white",
+                    color: ColorRole::Background.into(),
                     kind: ItemKind::SmallFilledCircle,
                 });
                 board.push(Item {
                     y: y + 1,
                     x: x + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::SmallCircle,
                 });
             }
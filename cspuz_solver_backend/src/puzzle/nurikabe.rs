@@ -1,8 +1,23 @@
+use crate::answer::AnswerGrid;
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::pzprv3;
+use crate::theme::ColorRole;
 use crate::uniqueness::{is_unique, Uniqueness};
 use cspuz_rs_puzzles::puzzles::nurikabe;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[
+        PuzzleKind::puzz_link("nurikabe", "Nurikabe", solve_nurikabe)
+            .with_enumerate(enumerate_answers_nurikabe)
+            .with_answer(solve_nurikabe_with_answer),
+    ];
+
 pub fn solve_nurikabe(url: &str) -> Result<Board, &'static str> {
+    solve_nurikabe_with_answer(url).map(|(board, _)| board)
+}
+
+pub fn solve_nurikabe_with_answer(url: &str) -> Result<(Board, Option<String>), &'static str> {
     let problem = nurikabe::deserialize_problem(url).ok_or("invalid url")?;
     let ans = nurikabe::solve_nurikabe(&problem).ok_or("no answer")?;
 
@@ -13,22 +28,29 @@ pub fn solve_nurikabe(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
                 if clue > 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                 } else {
-                    board.push(Item::cell(y, x, "black", ItemKind::Text("?")));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
                 }
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Block } else { ItemKind::Dot },
                 ));
+            } else {
+                board.push_undetermined_cell(y, x);
             }
         }
     }
 
-    Ok(board)
+    board.add_answer("shaded", AnswerGrid::cells(&ans));
+
+    let answer = pzprv3::clue_and_shade_cells(&problem, &ans)
+        .map(|cells| pzprv3::single_grid_file("nurikabe", &cells));
+
+    Ok((board, answer))
 }
 
 pub fn enumerate_answers_nurikabe(
@@ -47,15 +69,15 @@ pub fn enumerate_answers_nurikabe(
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
                 if clue > 0 {
-                    board_common.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                    board_common.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                 } else {
-                    board_common.push(Item::cell(y, x, "black", ItemKind::Text("?")));
+                    board_common.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
                 }
             } else if let Some(a) = ans_common[y][x] {
                 board_common.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -73,16 +95,16 @@ pub fn enumerate_answers_nurikabe(
                 }
                 if let Some(clue) = problem[y][x] {
                     if clue > 0 {
-                        board_answer.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                        board_answer.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                     } else {
-                        board_answer.push(Item::cell(y, x, "black", ItemKind::Text("?")));
+                        board_answer.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
                     }
                 } else {
                     let a = ans[y][x];
-                    board_answer.push(Item::cell(
+                    board_answer.push_optional(Item::cell(
                         y,
                         x,
-                        "green",
+                        ColorRole::Answer,
                         if a { ItemKind::Block } else { ItemKind::Dot },
                     ));
                 }
@@ -93,3 +115,20 @@ pub fn enumerate_answers_nurikabe(
 
     Ok((board_common, boards))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enumerate_answers_nurikabe() {
+        let url = "https://puzz.link/p?nurikabe/6/6/m8n8i9u";
+        let (_, answers) = enumerate_answers_nurikabe(url, 10).unwrap();
+        assert_eq!(answers.len(), 3);
+
+        // The size of the island with a "?" clue is not fixed
+        let url = "https://puzz.link/p?nurikabe/3/3/.n";
+        let (_, answers) = enumerate_answers_nurikabe(url, 10).unwrap();
+        assert!(answers.len() > 1);
+    }
+}
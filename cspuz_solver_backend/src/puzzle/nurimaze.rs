@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::nurimaze;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "nurimaze",
+    "Nurimaze",
+    solve_nurimaze,
+)];
+
 pub fn solve_nurimaze(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = nurimaze::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = nurimaze::solve_nurimaze(&borders, &clues).ok_or("no answer")?;
@@ -13,10 +21,10 @@ pub fn solve_nurimaze(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             match clues[y][x] {
-                1 => board.push(Item::cell(y, x, "black", ItemKind::Text("S"))),
-                2 => board.push(Item::cell(y, x, "black", ItemKind::Text("G"))),
-                3 => board.push(Item::cell(y, x, "black", ItemKind::Circle)),
-                4 => board.push(Item::cell(y, x, "black", ItemKind::Triangle)),
+                1 => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("S"))),
+                2 => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("G"))),
+                3 => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle)),
+                4 => board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Triangle)),
                 _ => (),
             }
 
@@ -24,14 +32,14 @@ pub fn solve_nurimaze(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Fill } else { ItemKind::Dot },
                 ));
             }
         }
     }
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     Ok(board)
 }
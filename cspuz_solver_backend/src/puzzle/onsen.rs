@@ -0,0 +1,33 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::onsen;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("onsen", "Onsen-meguri", solve_onsen)];
+
+pub fn solve_onsen(url: &str) -> Result<Board, &'static str> {
+    let (borders, clues) = onsen::deserialize_problem(url).ok_or("invalid url")?;
+    let is_line = onsen::solve_onsen(&borders, &clues).ok_or("no answer")?;
+
+    let height = clues.len();
+    let width = clues[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
+    board.add_borders(&borders, ColorRole::Given);
+
+    for (y, row) in clues.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                if n > 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
+                }
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+
+    Ok(board)
+}
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::parrot_loop;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "parrot-loop",
+    "Parrot Loop",
+    solve_parrot_loop,
+)];
+
 pub fn solve_parrot_loop(url: &str) -> Result<Board, &'static str> {
     let problem = parrot_loop::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = parrot_loop::solve_parrot_loop(&problem).ok_or("no answer")?;
@@ -24,7 +32,7 @@ pub fn solve_parrot_loop(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     if n < 0 {
                         ItemKind::Fill
                     } else {
@@ -35,7 +43,7 @@ pub fn solve_parrot_loop(url: &str) -> Result<Board, &'static str> {
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&skip_line));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
 
     Ok(board)
 }
@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::pencils::{self, PencilsAnswer, PencilsClue};
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("pencils", "Pencils", solve_pencils)];
+
 pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
     let problem = pencils::deserialize_problem(url).ok_or("invalid url")?;
     let (cell, line, border) = pencils::solve_pencils(&problem).ok_or("no answer")?;
@@ -18,26 +23,45 @@ pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             match &problem[y][x] {
-                &PencilsClue::Num(n) => board.push(Item::cell(y, x, "black", ItemKind::Num(n))),
-                &PencilsClue::Left => board.push(Item::cell(y, x, "black", ItemKind::PencilLeft)),
-                &PencilsClue::Right => board.push(Item::cell(y, x, "black", ItemKind::PencilRight)),
-                &PencilsClue::Up => board.push(Item::cell(y, x, "black", ItemKind::PencilUp)),
-                &PencilsClue::Down => board.push(Item::cell(y, x, "black", ItemKind::PencilDown)),
+                &PencilsClue::Num(n) => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)))
+                }
+                &PencilsClue::Left => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::PencilLeft))
+                }
+                &PencilsClue::Right => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::PencilRight))
+                }
+                &PencilsClue::Up => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::PencilUp))
+                }
+                &PencilsClue::Down => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::PencilDown))
+                }
                 _ => {
                     if let Some(c) = cell[y][x] {
                         match c {
-                            PencilsAnswer::Left => {
-                                board.push(Item::cell(y, x, "green", ItemKind::PencilLeft))
-                            }
-                            PencilsAnswer::Right => {
-                                board.push(Item::cell(y, x, "green", ItemKind::PencilRight))
-                            }
+                            PencilsAnswer::Left => board.push(Item::cell(
+                                y,
+                                x,
+                                ColorRole::Answer,
+                                ItemKind::PencilLeft,
+                            )),
+                            PencilsAnswer::Right => board.push(Item::cell(
+                                y,
+                                x,
+                                ColorRole::Answer,
+                                ItemKind::PencilRight,
+                            )),
                             PencilsAnswer::Up => {
-                                board.push(Item::cell(y, x, "green", ItemKind::PencilUp))
-                            }
-                            PencilsAnswer::Down => {
-                                board.push(Item::cell(y, x, "green", ItemKind::PencilDown))
+                                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::PencilUp))
                             }
+                            PencilsAnswer::Down => board.push(Item::cell(
+                                y,
+                                x,
+                                ColorRole::Answer,
+                                ItemKind::PencilDown,
+                            )),
                             _ => (),
                         }
                     }
@@ -59,7 +83,7 @@ pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -67,7 +91,7 @@ pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind,
                     })
                 }
@@ -83,7 +107,7 @@ pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -91,7 +115,7 @@ pub fn solve_pencils(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind,
                     })
                 }
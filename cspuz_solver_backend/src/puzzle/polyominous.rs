@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::graph::InnerGridEdges;
 use cspuz_rs_puzzles::puzzles::polyominous;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "pentominous",
+    "Pentominous",
+    solve_pentominous,
+)];
+
 const PENTOMINO_NAMES: [&'static str; 12] =
     ["F", "I", "L", "N", "P", "T", "U", "V", "W", "X", "Y", "Z"];
 
@@ -21,7 +29,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     if n >= 0 {
                         ItemKind::Text(PENTOMINO_NAMES[n as usize])
                     } else {
@@ -44,7 +52,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::BoldWall,
                     });
                     need_default_edge = false;
@@ -52,7 +60,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -67,7 +75,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -78,7 +86,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::BoldWall,
                     });
                     need_default_edge = false;
@@ -86,7 +94,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -101,7 +109,7 @@ pub fn solve_pentominous(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
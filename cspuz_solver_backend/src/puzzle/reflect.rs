@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::reflect::{self, ReflectLinkClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "reflect",
+    "Reflect Link",
+    solve_reflect_link,
+)];
+
 pub fn solve_reflect_link(url: &str) -> Result<Board, &'static str> {
     let problem = reflect::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = reflect::solve_reflect_link(&problem).ok_or("no answer")?;
@@ -10,37 +18,67 @@ pub fn solve_reflect_link(url: &str) -> Result<Board, &'static str> {
     let width = problem[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
     for y in 0..height {
         for x in 0..width {
             match &problem[y][x] {
                 ReflectLinkClue::None => (),
                 ReflectLinkClue::UpperLeft(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::AboloUpperLeft));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::AboloUpperLeft));
                     if *n > 0 {
-                        board.push(Item::cell(y, x, "white", ItemKind::NumUpperLeft(*n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::GivenOnFill,
+                            ItemKind::NumUpperLeft(*n),
+                        ));
                     }
                 }
                 ReflectLinkClue::UpperRight(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::AboloUpperRight));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::AboloUpperRight,
+                    ));
                     if *n > 0 {
-                        board.push(Item::cell(y, x, "white", ItemKind::NumUpperRight(*n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::GivenOnFill,
+                            ItemKind::NumUpperRight(*n),
+                        ));
                     }
                 }
                 ReflectLinkClue::LowerLeft(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::AboloLowerLeft));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::AboloLowerLeft));
                     if *n > 0 {
-                        board.push(Item::cell(y, x, "white", ItemKind::NumLowerLeft(*n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::GivenOnFill,
+                            ItemKind::NumLowerLeft(*n),
+                        ));
                     }
                 }
                 ReflectLinkClue::LowerRight(n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::AboloLowerRight));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::AboloLowerRight,
+                    ));
                     if *n > 0 {
-                        board.push(Item::cell(y, x, "white", ItemKind::NumLowerRight(*n)));
+                        board.push(Item::cell(
+                            y,
+                            x,
+                            ColorRole::GivenOnFill,
+                            ItemKind::NumLowerRight(*n),
+                        ));
                     }
                 }
                 ReflectLinkClue::Cross => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Plus));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Plus));
                 }
             }
         }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::ringring;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "ringring",
+    "Ring-ring",
+    solve_ringring,
+)];
+
 pub fn solve_ringring(url: &str) -> Result<Board, &'static str> {
     let problem = ringring::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = ringring::solve_ringring(&problem).ok_or("no answer")?;
@@ -13,12 +21,12 @@ pub fn solve_ringring(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&problem));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&problem));
 
     Ok(board)
 }
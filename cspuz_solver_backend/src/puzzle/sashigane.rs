@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::sasahigane::{self, SashiganeClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "sashigane",
+    "Sashigane",
+    solve_sashigane,
+)];
+
 pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
     let problem = sasahigane::deserialize_problem(url).ok_or("invalid url")?;
     let ans = sasahigane::solve_sashigane(&problem).ok_or("no answer")?;
@@ -19,10 +27,10 @@ pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
                     SashiganeClue::Right => ItemKind::ArrowRight,
                     SashiganeClue::Corner(_) => ItemKind::Circle,
                 };
-                board.push(Item::cell(y, x, "black", kind));
+                board.push(Item::cell(y, x, ColorRole::Given, kind));
                 if let SashiganeClue::Corner(n) = clue {
                     if n > 0 {
-                        board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                        board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                     }
                 }
             }
@@ -36,7 +44,7 @@ pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -51,7 +59,7 @@ pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -62,7 +70,7 @@ pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -77,7 +85,7 @@ pub fn solve_sashigane(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
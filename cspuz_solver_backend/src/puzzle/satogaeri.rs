@@ -0,0 +1,40 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::satogaeri;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("satogaeri", "Satogaeri", solve_satogaeri).with_aliases(&["sato"])];
+
+pub fn solve_satogaeri(url: &str) -> Result<Board, &'static str> {
+    let (borders, clues) = satogaeri::deserialize_problem(url).ok_or("invalid url")?;
+    let (is_line, is_goal) = satogaeri::solve_satogaeri(&borders, &clues).ok_or("no answer")?;
+
+    let height = clues.len();
+    let width = clues[0].len();
+    let mut board = Board::new(
+        BoardKind::Grid,
+        height,
+        width,
+        is_unique(&(&is_line, &is_goal)),
+    );
+    board.add_borders(&borders, ColorRole::Given);
+
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(n) = clues[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                if n >= 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
+                }
+            } else if is_goal[y][x] == Some(true) {
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Circle));
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+
+    Ok(board)
+}
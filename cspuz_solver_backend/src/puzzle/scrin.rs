@@ -0,0 +1,36 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::scrin;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("scrin", "Scrin", solve_scrin)];
+
+pub fn solve_scrin(url: &str) -> Result<Board, &'static str> {
+    let problem = scrin::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = scrin::solve_scrin(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(a) = ans[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+            if let Some(clue) = problem[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                if clue > 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
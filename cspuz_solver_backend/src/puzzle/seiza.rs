@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::seiza;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono("seiza", "Seiza", solve_seiza)];
+
 pub fn solve_seiza(url: &str) -> Result<Board, &'static str> {
     let (absent_cell, num, borders) = seiza::deserialize_problem(url).ok_or("invalid url")?;
     let (is_line, is_star) = seiza::solve_seiza(&absent_cell, &num, &borders).ok_or("no answer")?;
@@ -15,19 +19,19 @@ pub fn solve_seiza(url: &str) -> Result<Board, &'static str> {
         is_unique(&(&is_line, &is_star)),
     );
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
             if absent_cell[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 continue;
             }
             if let Some(b) = is_star[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b {
                         ItemKind::FilledCircle
                     } else {
@@ -36,12 +40,17 @@ pub fn solve_seiza(url: &str) -> Result<Board, &'static str> {
                 ));
             }
             if let Some(n) = num[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::NumUpperLeft(n)));
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Given,
+                    ItemKind::NumUpperLeft(n),
+                ));
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&absent_cell));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&absent_cell));
 
     Ok(board)
 }
@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::shikaku;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("shikaku", "Shikaku", solve_shikaku)];
+
 pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
     let problem = shikaku::deserialize_problem(url).ok_or("invalid url")?;
     let ans = shikaku::solve_shikaku(&problem).ok_or("no answer")?;
@@ -12,9 +17,14 @@ pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::FilledCircle));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle));
                 if clue > 0 {
-                    board.push(Item::cell(y, x, "white", ItemKind::Num(clue)));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::GivenOnFill,
+                        ItemKind::Num(clue),
+                    ));
                 }
             }
         }
@@ -27,7 +37,7 @@ pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -42,7 +52,7 @@ pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -53,7 +63,7 @@ pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -68,7 +78,7 @@ pub fn solve_shikaku(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
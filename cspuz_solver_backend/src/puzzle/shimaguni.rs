@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::graph;
 use cspuz_rs_puzzles::puzzles::shimaguni;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "shimaguni",
+    "Shimaguni",
+    solve_shimaguni,
+)];
+
 pub fn solve_shimaguni(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = shimaguni::deserialize_problem(url).ok_or("invalid url")?;
     let is_black = shimaguni::solve_shimaguni(&borders, &clues).ok_or("no answer")?;
@@ -11,7 +19,7 @@ pub fn solve_shimaguni(url: &str) -> Result<Board, &'static str> {
     let width = is_black[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_black));
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -19,7 +27,7 @@ pub fn solve_shimaguni(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -30,7 +38,7 @@ pub fn solve_shimaguni(url: &str) -> Result<Board, &'static str> {
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
         }
     }
 
@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::slalom;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("slalom", "Slalom", solve_slalom)];
+
 pub fn solve_slalom(url: &str) -> Result<Board, &'static str> {
     use slalom::{SlalomBlackCellDir, SlalomCell};
 
@@ -14,11 +18,16 @@ pub fn solve_slalom(url: &str) -> Result<Board, &'static str> {
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
 
     let (origin_y, origin_x) = origin;
-    board.push(Item::cell(origin_y, origin_x, "black", ItemKind::Circle));
     board.push(Item::cell(
         origin_y,
         origin_x,
-        "black",
+        ColorRole::Given,
+        ItemKind::Circle,
+    ));
+    board.push(Item::cell(
+        origin_y,
+        origin_x,
+        ColorRole::Given,
         ItemKind::Num(gates.len() as i32),
     ));
 
@@ -26,9 +35,9 @@ pub fn solve_slalom(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             match problem.0[y][x] {
                 SlalomCell::Black(d, n) => {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                     if n >= 0 {
-                        board.push(Item::cell(y, x, "white", ItemKind::Num(n)));
+                        board.push(Item::cell(y, x, ColorRole::GivenOnFill, ItemKind::Num(n)));
                     }
                     let arrow = match d {
                         SlalomBlackCellDir::Up => ItemKind::SideArrowUp,
@@ -37,20 +46,30 @@ pub fn solve_slalom(url: &str) -> Result<Board, &'static str> {
                         SlalomBlackCellDir::Right => ItemKind::SideArrowRight,
                         _ => continue,
                     };
-                    board.push(Item::cell(y, x, "white", arrow));
+                    board.push(Item::cell(y, x, ColorRole::GivenOnFill, arrow));
                 }
                 SlalomCell::Horizontal => {
-                    board.push(Item::cell(y, x, "black", ItemKind::DottedHorizontalWall));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::DottedHorizontalWall,
+                    ));
                 }
                 SlalomCell::Vertical => {
-                    board.push(Item::cell(y, x, "black", ItemKind::DottedVerticalWall));
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Given,
+                        ItemKind::DottedVerticalWall,
+                    ));
                 }
                 SlalomCell::White => (),
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&is_black));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&is_black));
 
     Ok(board)
 }
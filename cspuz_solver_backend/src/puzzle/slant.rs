@@ -0,0 +1,60 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::slant;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("gokigen", "Gokigen", solve_slant)];
+
+pub fn solve_slant(url: &str) -> Result<Board, &'static str> {
+    let problem = slant::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = slant::solve_slant(&problem).ok_or("no answer")?;
+
+    let height = ans.len();
+    let width = ans[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&ans));
+    for (y, row) in ans.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(a) = cell {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if a {
+                        ItemKind::Backslash
+                    } else {
+                        ItemKind::Slash
+                    },
+                ));
+            }
+        }
+    }
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(n) = clue {
+                board.push(Item {
+                    y: y * 2,
+                    x: x * 2,
+                    color: ColorRole::Background.into(),
+                    kind: ItemKind::FilledCircle,
+                });
+                board.push(Item {
+                    y: y * 2,
+                    x: x * 2,
+                    color: ColorRole::Given.into(),
+                    kind: ItemKind::Circle,
+                });
+                if n >= 0 {
+                    board.push(Item {
+                        y: y * 2,
+                        x: x * 2,
+                        color: ColorRole::Given.into(),
+                        kind: ItemKind::Num(n),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(board)
+}
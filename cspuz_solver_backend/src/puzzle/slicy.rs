@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::slicy;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono("slicy", "Slicy", solve_slicy)];
+
 pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
     let borders = slicy::deserialize_problem(url).ok_or("invalid url")?;
     let ans = slicy::solve_slicy(&borders).ok_or("no answer")?;
@@ -25,10 +29,15 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
         for dy in 0..2 {
             for dx in 0..2 {
                 if let Some(b) = ans[(y, x)] {
+                    let role = if b {
+                        ColorRole::Answer
+                    } else {
+                        ColorRole::Auxiliary
+                    };
                     board.push(Item {
                         y: ty * 2 + 1 + dy * 2,
                         x: tx * 2 + 1 + dx * 2,
-                        color: if b { "green" } else { "#cccccc" },
+                        color: role.into(),
                         kind: ItemKind::Fill,
                     });
                 }
@@ -47,7 +56,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: ty * 2,
             x: tx * 2 + 1,
-            color: "black",
+            color: ColorRole::Given.into(),
             kind: if !ans.is_valid_coord_offset((y, x), (-1, -1))
                 || borders.to_bottom_right[(y - 1, x - 1)]
             {
@@ -59,7 +68,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: ty * 2 + 4,
             x: tx * 2 + 3,
-            color: "black",
+            color: ColorRole::Given.into(),
             kind: if !ans.is_valid_coord_offset((y, x), (1, 1)) || borders.to_bottom_right[(y, x)] {
                 ItemKind::BoldWall
             } else {
@@ -69,7 +78,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: ty * 2,
             x: tx * 2 + 3,
-            color: "black",
+            color: ColorRole::Given.into(),
             kind: if !ans.is_valid_coord_offset((y, x), (-1, 0))
                 || borders.to_bottom_left[(y - 1, x)]
             {
@@ -81,7 +90,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
         board.push(Item {
             y: ty * 2 + 4,
             x: tx * 2 + 1,
-            color: "black",
+            color: ColorRole::Given.into(),
             kind: if !ans.is_valid_coord_offset((y, x), (1, 0)) || borders.to_bottom_left[(y, x)] {
                 ItemKind::BoldWall
             } else {
@@ -92,7 +101,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: ty * 2 + t,
                 x: tx * 2,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: if !ans.is_valid_coord_offset((y, x), (0, -1)) || borders.to_right[(y, x - 1)]
                 {
                     ItemKind::BoldWall
@@ -103,7 +112,7 @@ pub fn solve_slicy(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: ty * 2 + t,
                 x: tx * 2 + 4,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: if !ans.is_valid_coord_offset((y, x), (0, 1)) || borders.to_right[(y, x)] {
                     ItemKind::BoldWall
                 } else {
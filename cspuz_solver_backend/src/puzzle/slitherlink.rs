@@ -1,7 +1,16 @@
+use crate::answer::AnswerGrid;
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::{is_unique, Uniqueness};
 use cspuz_rs_puzzles::puzzles::slitherlink;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[
+    PuzzleKind::puzz_link("slither", "Slitherlink", solve_slitherlink)
+        .with_aliases(&["slitherlink"])
+        .with_enumerate(enumerate_answers_slitherlink),
+];
+
 pub fn solve_slitherlink(url: &str) -> Result<Board, &'static str> {
     let problem = slitherlink::deserialize_problem(url).ok_or("invalid url")?;
     let is_line = slitherlink::solve_slitherlink(&problem).ok_or("no answer")?;
@@ -13,7 +22,7 @@ pub fn solve_slitherlink(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
@@ -23,7 +32,7 @@ pub fn solve_slitherlink(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -35,12 +44,13 @@ pub fn solve_slitherlink(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
         }
     }
+    board.add_answer("line", AnswerGrid::borders(&is_line));
 
     Ok(board)
 }
@@ -60,7 +70,7 @@ pub fn enumerate_answers_slitherlink(
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board_common.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board_common.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             }
         }
     }
@@ -70,7 +80,7 @@ pub fn enumerate_answers_slitherlink(
                 board_common.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -82,7 +92,7 @@ pub fn enumerate_answers_slitherlink(
                 board_common.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 })
             }
@@ -100,10 +110,10 @@ pub fn enumerate_answers_slitherlink(
                     continue;
                 }
                 let b = ans.vertical[y][x];
-                board_answer.push(Item {
+                board_answer.push_optional(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 });
             }
@@ -114,10 +124,10 @@ pub fn enumerate_answers_slitherlink(
                     continue;
                 }
                 let b = ans.horizontal[y][x];
-                board_answer.push(Item {
+                board_answer.push_optional(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 });
             }
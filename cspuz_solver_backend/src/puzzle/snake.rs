@@ -0,0 +1,156 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::{is_unique, Uniqueness};
+use cspuz_rs_puzzles::puzzles::snake::{self, Snake, SnakeClue};
+use cspuz_rs_puzzles::util::Puzzle;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[
+    PuzzleKind::puzz_link("snake", "Snake", solve_snake).with_enumerate(enumerate_answers_snake)
+];
+
+fn base_board(
+    clues_up: &[Option<i32>],
+    clues_left: &[Option<i32>],
+    cells: &[Vec<SnakeClue>],
+    uniqueness: Uniqueness,
+) -> Board {
+    let height = clues_left.len();
+    let width = clues_up.len();
+    let mut board = Board::new(BoardKind::Empty, height + 1, width + 1, uniqueness);
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(0, x + 1, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+
+    for y in 0..=height {
+        for x in 0..width {
+            board.push(Item {
+                y: y * 2 + 2,
+                x: x * 2 + 3,
+                color: ColorRole::Given.into(),
+                kind: if y == 0 || y == height {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+    for y in 0..height {
+        for x in 0..=width {
+            board.push(Item {
+                y: y * 2 + 3,
+                x: x * 2 + 2,
+                color: ColorRole::Given.into(),
+                kind: if x == 0 || x == width {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+
+    for (y, row) in cells.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            match clue {
+                SnakeClue::None => (),
+                SnakeClue::Endpoint => {
+                    board.push(Item::cell(y + 1, x + 1, ColorRole::Given, ItemKind::Circle));
+                }
+                SnakeClue::Body => {
+                    board.push(Item::cell(y + 1, x + 1, ColorRole::Given, ItemKind::Block));
+                }
+            }
+        }
+    }
+
+    board
+}
+
+fn answer_item(y: usize, x: usize, is_snake: bool) -> Item {
+    Item::cell(
+        y + 1,
+        x + 1,
+        ColorRole::Answer,
+        if is_snake {
+            ItemKind::Block
+        } else {
+            ItemKind::Dot
+        },
+    )
+}
+
+fn solved_board(
+    problem: &snake::Problem,
+    is_snake: &[Vec<Option<bool>>],
+    uniqueness: Uniqueness,
+) -> Board {
+    let (clues_up, clues_left, cells) = problem;
+    let mut board = base_board(clues_up, clues_left, cells, uniqueness);
+
+    for (y, row) in is_snake.iter().enumerate() {
+        for (x, &b) in row.iter().enumerate() {
+            if cells[y][x] != SnakeClue::None {
+                continue;
+            }
+            if let Some(b) = b {
+                board.push(answer_item(y, x, b));
+            }
+        }
+    }
+
+    board
+}
+
+pub fn solve_snake(url: &str) -> Result<Board, &'static str> {
+    let problem = snake::deserialize_problem(url).ok_or("invalid url")?;
+    let is_snake = Snake::solve(&problem).ok_or("no answer")?;
+
+    Ok(solved_board(&problem, &is_snake, is_unique(&is_snake)))
+}
+
+pub fn enumerate_answers_snake(
+    url: &str,
+    num_max_answers: usize,
+) -> Result<(Board, Vec<Board>), &'static str> {
+    let problem = snake::deserialize_problem(url).ok_or("invalid url")?;
+    let is_snake_common = Snake::solve(&problem).ok_or("no answer")?;
+    let answers = Snake::enumerate_answers(&problem, num_max_answers);
+
+    let board_common = solved_board(&problem, &is_snake_common, Uniqueness::NotApplicable);
+
+    let cells = &problem.2;
+    let height = cells.len();
+    let width = cells[0].len();
+    let mut board_answers = vec![];
+    for ans in answers {
+        let mut board_answer = Board::new(
+            BoardKind::Empty,
+            height + 1,
+            width + 1,
+            Uniqueness::NotApplicable,
+        );
+        for y in 0..height {
+            for x in 0..width {
+                if cells[y][x] != SnakeClue::None || is_snake_common[y][x].is_some() {
+                    continue;
+                }
+                if let Some(b) = ans[y][x] {
+                    board_answer.push_optional(answer_item(y, x, b));
+                }
+            }
+        }
+        board_answers.push(board_answer);
+    }
+
+    Ok((board_common, board_answers))
+}
@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::items::Arrow;
 use cspuz_rs_puzzles::puzzles::sniping_arrow;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "sniping-arrow",
+    "Sniping Arrow",
+    solve_sniping_arrow,
+)];
+
 fn convert_arrow(arrow: Arrow) -> ItemKind {
     match arrow {
         Arrow::Up => ItemKind::ArrowUp,
@@ -30,23 +38,23 @@ pub fn solve_sniping_arrow(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some((n, ar)) = clues[y][x] {
                 if let Some(n) = n {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 }
                 if let Some(ar) = ar {
-                    board.push(Item::cell(y, x, "black", convert_arrow(ar)));
+                    board.push(Item::cell(y, x, ColorRole::Given, convert_arrow(ar)));
                     continue;
                 }
 
                 if let Some(ar) = arrow[y][x] {
-                    board.push(Item::cell(y, x, "green", convert_arrow(ar)));
+                    board.push(Item::cell(y, x, ColorRole::Answer, convert_arrow(ar)));
                 }
             } else {
-                board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", None);
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
 
     Ok(board)
 }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::soulmates;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "soulmates",
+    "Soulmates",
+    solve_soulmates,
+)];
+
 pub fn solve_soulmates(url: &str) -> Result<Board, &'static str> {
     let problem = soulmates::deserialize_problem(url).ok_or("invalid url")?;
     let answer = soulmates::solve_soulmates(&problem).ok_or("no answer")?;
@@ -13,12 +21,12 @@ pub fn solve_soulmates(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             } else if let Some(n) = answer[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if n == 0 {
                         ItemKind::Dot
                     } else {
@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::spokes;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono("spokes", "Spokes", solve_spokes)];
+
 pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
     let clues = spokes::deserialize_problem(url).ok_or("invalid url")?;
     let (lines, lines_dr, lines_dl) = spokes::solve_spokes(&clues).ok_or("no answer")?;
@@ -22,7 +26,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -31,7 +35,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::DottedWall,
                     });
                 }
@@ -45,7 +49,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -54,7 +58,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::DottedWall,
                     });
                 }
@@ -68,7 +72,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: ItemKind::Backslash,
                     });
                 }
@@ -77,7 +81,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::DottedBackslash,
                     });
                 }
@@ -88,7 +92,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: ItemKind::Slash,
                     });
                 }
@@ -97,7 +101,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 1,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::DottedSlash,
                     });
                 }
@@ -111,13 +115,13 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "white",
+                    color: ColorRole::Background.into(),
                     kind: ItemKind::FilledCircle,
                 });
                 board.push(Item {
                     y: y * 2,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::Circle,
                 });
 
@@ -125,7 +129,7 @@ pub fn solve_spokes(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2,
                         x: x * 2,
-                        color: "black",
+                        color: ColorRole::Given.into(),
                         kind: ItemKind::Num(n),
                     });
                 }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::square_jam;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "squarejam",
+    "Square Jam",
+    solve_square_jam,
+)];
+
 pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
     let problem = square_jam::deserialize_problem(url).ok_or("invalid url")?;
     let border = square_jam::solve_square_jam(&problem).ok_or("no answer")?;
@@ -14,9 +22,9 @@ pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
                 if n >= 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
                 } else if n == -2 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Fill));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill));
                 }
             }
         }
@@ -30,7 +38,7 @@ pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -45,7 +53,7 @@ pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -56,7 +64,7 @@ pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -71,7 +79,7 @@ pub fn solve_square_jam(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::statue_park::{self, StatueParkClue};
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "statuepark",
+    "Statue Park",
+    solve_statue_park,
+)];
+
 pub fn solve_statue_park(url: &str) -> Result<Board, &'static str> {
     let (problem, pieces) = statue_park::deserialize_problem(url).ok_or("invalid url")?;
     let ans = statue_park::solve_statue_park(&problem, &pieces).ok_or("no answer")?;
@@ -17,13 +25,17 @@ pub fn solve_statue_park(url: &str) -> Result<Board, &'static str> {
                         board.push(Item::cell(
                             y,
                             x,
-                            "green",
+                            ColorRole::Answer,
                             if a { ItemKind::Block } else { ItemKind::Dot },
                         ));
                     }
                 }
-                StatueParkClue::White => board.push(Item::cell(y, x, "black", ItemKind::Dot)),
-                StatueParkClue::Black => board.push(Item::cell(y, x, "black", ItemKind::Fill)),
+                StatueParkClue::White => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Dot))
+                }
+                StatueParkClue::Black => {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Fill))
+                }
             }
         }
     }
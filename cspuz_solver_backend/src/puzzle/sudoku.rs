@@ -1,10 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::Uniqueness;
 use cspuz_rs_puzzles::puzzles::sudoku;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("sudoku", "Sudoku", solve_sudoku)];
+
 pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
-    let problem = sudoku::deserialize_problem(url).ok_or("invalid url")?;
-    let ans = sudoku::solve_sudoku_as_cands(&problem).ok_or("no answer")?;
+    let (problem, (bh, bw)) = sudoku::deserialize_extended_problem(url).ok_or("invalid url")?;
+    let ans =
+        sudoku::solve_sudoku_as_cands_with_block_size(&problem, (bh, bw)).ok_or("no answer")?;
 
     let height = ans.len();
     let width = ans[0].len();
@@ -19,19 +24,10 @@ pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
     }
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique);
 
-    let (bh, bw) = match height {
-        4 => (2, 2),
-        6 => (2, 3),
-        9 => (3, 3),
-        16 => (4, 4),
-        25 => (5, 5),
-        _ => return Err("invalid size"),
-    };
-
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(n)));
             } else {
                 let mut cands = vec![];
                 for i in 0..height {
@@ -40,12 +36,12 @@ pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
                     }
                 }
                 if cands.len() == 1 {
-                    board.push(Item::cell(y, x, "green", ItemKind::Num(cands[0])));
+                    board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Num(cands[0])));
                 } else {
                     board.push(Item::cell(
                         y,
                         x,
-                        "green",
+                        ColorRole::Answer,
                         ItemKind::SudokuCandidateSet(bw as i32, cands),
                     ));
                 }
@@ -57,7 +53,7 @@ pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: 2 * y + 1,
                 x: 2 * x * bw,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: ItemKind::BoldWall,
             });
         }
@@ -67,7 +63,7 @@ pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
             board.push(Item {
                 y: 2 * y * bh,
                 x: 2 * x + 1,
-                color: "black",
+                color: ColorRole::Given.into(),
                 kind: ItemKind::BoldWall,
             });
         }
@@ -75,3 +71,18 @@ pub fn solve_sudoku(url: &str) -> Result<Board, &'static str> {
 
     Ok(board)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_sudoku_block_size() {
+        let url = "https://puzz.link/p?sudoku/6/6/1j63h64g4i1h1g3g5h61k53g/3/2";
+        let board = solve_sudoku(url).unwrap();
+        assert!(matches!(board.uniqueness, Uniqueness::Unique));
+
+        let url = "https://puzz.link/p?sudoku/6/6/1j63h64g4i1h1g3g5h61k53g/4/2";
+        assert!(solve_sudoku(url).is_err());
+    }
+}
@@ -1,7 +1,11 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::tapa;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("tapa", "Tapa", solve_tapa)];
+
 pub fn solve_tapa(url: &str) -> Result<Board, &'static str> {
     let problem = tapa::deserialize_problem(url).ok_or("invalid url")?;
     let ans = tapa::solve_tapa(&problem).ok_or("no answer")?;
@@ -12,12 +16,12 @@ pub fn solve_tapa(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
-                board.push(Item::cell(y, x, "black", ItemKind::TapaClue(clue)));
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::TapaClue(clue)));
             } else if let Some(a) = ans[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if a { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
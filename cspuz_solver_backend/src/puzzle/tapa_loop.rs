@@ -0,0 +1,31 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::tapa_loop;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "tapaloop",
+    "Tapa-Like Loop",
+    solve_tapa_loop,
+)];
+
+pub fn solve_tapa_loop(url: &str) -> Result<Board, &'static str> {
+    let problem = tapa_loop::deserialize_problem(url).ok_or("invalid url")?;
+    let is_line = tapa_loop::solve_tapa_loop(&problem).ok_or("no answer")?;
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&is_line));
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if let Some(clue) = cell {
+                board.push(Item::cell(y, x, ColorRole::Given, ItemKind::TapaClue(clue)));
+            }
+        }
+    }
+
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, None);
+
+    Ok(board)
+}
@@ -0,0 +1,107 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::tentaisho::{self, TentaishoDot};
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "tentaisho",
+    "Tentaisho",
+    solve_tentaisho,
+)];
+
+pub fn solve_tentaisho(url: &str) -> Result<Board, &'static str> {
+    let problem = tentaisho::deserialize_problem(url).ok_or("invalid url")?;
+    let (is_border, is_black) = tentaisho::solve_tentaisho(&problem).ok_or("no answer")?;
+
+    let height = is_black.len();
+    let width = is_black[0].len();
+    let mut board = Board::new(
+        BoardKind::OuterGrid,
+        height,
+        width,
+        is_unique(&(&is_border, &is_black)),
+    );
+
+    for (y, row) in is_black.iter().enumerate() {
+        for (x, &b) in row.iter().enumerate() {
+            if b == Some(true) {
+                board.push(Item::cell(y, x, ColorRole::Auxiliary, ItemKind::Fill));
+            }
+        }
+    }
+    for y in 0..height {
+        for x in 0..width {
+            if y < height - 1 {
+                let mut need_default_edge = true;
+                if let Some(b) = is_border.horizontal[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 2,
+                        x: x * 2 + 1,
+                        color: ColorRole::Answer.into(),
+                        kind: if b {
+                            ItemKind::BoldWall
+                        } else {
+                            ItemKind::Cross
+                        },
+                    });
+                    if b {
+                        need_default_edge = false;
+                    }
+                }
+                if need_default_edge {
+                    board.push(Item {
+                        y: y * 2 + 2,
+                        x: x * 2 + 1,
+                        color: ColorRole::Auxiliary.into(),
+                        kind: ItemKind::Wall,
+                    });
+                }
+            }
+            if x < width - 1 {
+                let mut need_default_edge = true;
+                if let Some(b) = is_border.vertical[y][x] {
+                    board.push(Item {
+                        y: y * 2 + 1,
+                        x: x * 2 + 2,
+                        color: ColorRole::Answer.into(),
+                        kind: if b {
+                            ItemKind::BoldWall
+                        } else {
+                            ItemKind::Cross
+                        },
+                    });
+                    if b {
+                        need_default_edge = false;
+                    }
+                }
+                if need_default_edge {
+                    board.push(Item {
+                        y: y * 2 + 1,
+                        x: x * 2 + 2,
+                        color: ColorRole::Auxiliary.into(),
+                        kind: ItemKind::Wall,
+                    });
+                }
+            }
+        }
+    }
+
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &dot) in row.iter().enumerate() {
+            let kind = match dot {
+                TentaishoDot::None => continue,
+                TentaishoDot::White => ItemKind::SmallCircle,
+                TentaishoDot::Black => ItemKind::SmallFilledCircle,
+            };
+            board.push(Item {
+                y: y + 1,
+                x: x + 1,
+                color: ColorRole::Given.into(),
+                kind,
+            });
+        }
+    }
+
+    Ok(board)
+}
@@ -0,0 +1,73 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::tents;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("tents", "Tents", solve_tents)];
+
+pub fn solve_tents(url: &str) -> Result<Board, &'static str> {
+    let (clues_up, clues_left, trees) = tents::deserialize_problem(url).ok_or("invalid url")?;
+    let is_tent = tents::solve_tents(&clues_up, &clues_left, &trees).ok_or("no answer")?;
+
+    let height = clues_left.len();
+    let width = clues_up.len();
+    let mut board = Board::new(BoardKind::Empty, height + 1, width + 1, is_unique(&is_tent));
+
+    for (y, &clue) in clues_left.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(y + 1, 0, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+    for (x, &clue) in clues_up.iter().enumerate() {
+        if let Some(n) = clue {
+            board.push(Item::cell(0, x + 1, ColorRole::Given, ItemKind::Num(n)));
+        }
+    }
+
+    for y in 0..=height {
+        for x in 0..width {
+            board.push(Item {
+                y: y * 2 + 2,
+                x: x * 2 + 3,
+                color: ColorRole::Given.into(),
+                kind: if y == 0 || y == height {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+    for y in 0..height {
+        for x in 0..=width {
+            board.push(Item {
+                y: y * 2 + 3,
+                x: x * 2 + 2,
+                color: ColorRole::Given.into(),
+                kind: if x == 0 || x == width {
+                    ItemKind::BoldWall
+                } else {
+                    ItemKind::Wall
+                },
+            })
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            if trees[y][x] {
+                board.push(Item::cell(y + 1, x + 1, ColorRole::Given, ItemKind::Circle));
+            } else if let Some(b) = is_tent[y][x] {
+                board.push(Item::cell(
+                    y + 1,
+                    x + 1,
+                    ColorRole::Answer,
+                    if b { ItemKind::Triangle } else { ItemKind::Dot },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::the_longest;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "the-longest",
+    "The Longest",
+    solve_the_longest,
+)];
+
 pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
     let problem = the_longest::deserialize_problem(url).ok_or("invalid url")?;
     let ans = the_longest::solve_the_longest(&problem).ok_or("no answer")?;
@@ -16,7 +24,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::BoldWall,
                 });
                 continue;
@@ -26,7 +34,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: ItemKind::Wall,
                 });
                 continue;
@@ -36,7 +44,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2,
                     x: x * 2 + 1,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 });
             }
@@ -48,7 +56,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "black",
+                    color: ColorRole::Given.into(),
                     kind: ItemKind::BoldWall,
                 });
                 continue;
@@ -58,7 +66,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: ItemKind::Wall,
                 });
                 continue;
@@ -68,7 +76,7 @@ pub fn solve_the_longest(url: &str) -> Result<Board, &'static str> {
                 board.push(Item {
                     y: y * 2 + 1,
                     x: x * 2,
-                    color: "green",
+                    color: ColorRole::Answer.into(),
                     kind: if b { ItemKind::Wall } else { ItemKind::Cross },
                 });
             }
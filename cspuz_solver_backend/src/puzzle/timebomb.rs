@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::timebomb;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "timebomb",
+    "Time Bomb",
+    solve_timebomb,
+)];
+
 pub fn solve_timebomb(url: &str) -> Result<Board, &'static str> {
     let problem = timebomb::deserialize_problem(url).ok_or("invalid url")?;
     let (has_number, num) = timebomb::solve_timebomb(&problem).ok_or("no answer")?;
@@ -18,19 +26,19 @@ pub fn solve_timebomb(url: &str) -> Result<Board, &'static str> {
         for x in 0..width {
             if let Some(clue) = problem[y][x] {
                 if clue >= 0 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Num(clue)));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
                 } else if clue == -1 {
-                    board.push(Item::cell(y, x, "black", ItemKind::Circle));
-                    board.push(Item::cell(y, x, "black", ItemKind::Text("?")));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Circle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
                 } else if clue == -2 {
-                    board.push(Item::cell(y, x, "black", ItemKind::FilledCircle));
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::FilledCircle));
                 }
             } else if let Some(n) = num[y][x] {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if n == -1 {
                         ItemKind::Dot
                     } else {
@@ -38,7 +46,7 @@ pub fn solve_timebomb(url: &str) -> Result<Board, &'static str> {
                     },
                 ));
             } else if has_number[y][x] == Some(true) {
-                board.push(Item::cell(y, x, "green", ItemKind::Text("?")));
+                board.push(Item::cell(y, x, ColorRole::Answer, ItemKind::Text("?")));
             }
         }
     }
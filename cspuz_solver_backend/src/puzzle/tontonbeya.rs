@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::tontonbeya;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "tontonbeya",
+    "Tonton Beya",
+    solve_tontonbeya,
+)];
+
 fn num_to_item(n: i32) -> ItemKind {
     match n {
         0 => ItemKind::Circle,
@@ -18,14 +26,14 @@ pub fn solve_tontonbeya(url: &str) -> Result<Board, &'static str> {
     let height = clues.len();
     let width = clues[0].len();
     let mut board = Board::new(BoardKind::Grid, height, width, is_unique(&answer));
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
             if let Some(n) = clues[y][x] {
-                board.push(Item::cell(y, x, "black", num_to_item(n)));
+                board.push(Item::cell(y, x, ColorRole::Given, num_to_item(n)));
             } else if let Some(n) = answer[y][x] {
-                board.push(Item::cell(y, x, "green", num_to_item(n)));
+                board.push(Item::cell(y, x, ColorRole::Answer, num_to_item(n)));
             }
         }
     }
@@ -1,7 +1,15 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::tricklayer;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::kudamono(
+    "tricklayer",
+    "Tricklayer",
+    solve_tricklayer,
+)];
+
 pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
     let problem = tricklayer::deserialize_problem(url).ok_or("invalid url")?;
     let ans = tricklayer::solve_tricklayer(&problem).ok_or("no answer")?;
@@ -12,7 +20,7 @@ pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
     for y in 0..height {
         for x in 0..width {
             if problem[y][x] {
-                board.push(Item::cell(y, x, "#cccccc", ItemKind::Fill));
+                board.push(Item::cell(y, x, ColorRole::Auxiliary, ItemKind::Fill));
             }
         }
     }
@@ -24,7 +32,7 @@ pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -39,7 +47,7 @@ pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 2,
                         x: x * 2 + 1,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
@@ -50,7 +58,7 @@ pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "green",
+                        color: ColorRole::Answer.into(),
                         kind: if b {
                             ItemKind::BoldWall
                         } else {
@@ -65,7 +73,7 @@ pub fn solve_tricklayer(url: &str) -> Result<Board, &'static str> {
                     board.push(Item {
                         y: y * 2 + 1,
                         x: x * 2 + 2,
-                        color: "#cccccc",
+                        color: ColorRole::Auxiliary.into(),
                         kind: ItemKind::Wall,
                     });
                 }
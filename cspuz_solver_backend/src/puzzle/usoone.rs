@@ -0,0 +1,58 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::is_unique;
+use cspuz_rs_puzzles::puzzles::usoone;
+
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link("usoone", "Uso-one", solve_usoone)];
+
+pub fn solve_usoone(url: &str) -> Result<Board, &'static str> {
+    let (borders, clues) = usoone::deserialize_problem(url).ok_or("invalid url")?;
+    let (is_black, is_lie) = usoone::solve_usoone(&borders, &clues).ok_or("no answer")?;
+
+    let height = clues.len();
+    let width = clues[0].len();
+    // Whether a "?" clue is the lie is never determined, so it does not count for uniqueness
+    let determined_lies = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (y, x)))
+        .filter(|&(y, x)| clues[y][x].is_none_or(|n| n >= 0))
+        .map(|(y, x)| is_lie[y][x])
+        .collect::<Vec<_>>();
+    let mut board = Board::new(
+        BoardKind::Grid,
+        height,
+        width,
+        is_unique(&(&is_black, &determined_lies)),
+    );
+    board.add_borders(&borders, ColorRole::Given);
+
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(clue) = clues[y][x] {
+                if clue >= 0 {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Num(clue)));
+                } else {
+                    board.push(Item::cell(y, x, ColorRole::Given, ItemKind::Text("?")));
+                }
+                // Lying clues are crossed out, and truthful ones are circled
+                if let Some(l) = is_lie[y][x] {
+                    board.push(Item::cell(
+                        y,
+                        x,
+                        ColorRole::Answer,
+                        if l { ItemKind::Cross } else { ItemKind::Circle },
+                    ));
+                }
+            } else if let Some(b) = is_black[y][x] {
+                board.push(Item::cell(
+                    y,
+                    x,
+                    ColorRole::Answer,
+                    if b { ItemKind::Block } else { ItemKind::Dot },
+                ));
+            }
+        }
+    }
+
+    Ok(board)
+}
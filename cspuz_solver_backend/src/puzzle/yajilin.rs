@@ -1,7 +1,12 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs_puzzles::puzzles::yajilin;
 
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("yajilin", "Yajilin", solve_yajilin).with_aliases(&["yajirin"])];
+
 pub fn solve_yajilin(url: &str) -> Result<Board, &'static str> {
     use cspuz_rs::items::Arrow;
 
@@ -37,12 +42,12 @@ pub fn solve_yajilin(url: &str) -> Result<Board, &'static str> {
                 };
                 let n = clue.1;
                 if let Some(arrow) = arrow {
-                    board.push(Item::cell(y, x, "black", arrow));
+                    board.push(Item::cell(y, x, ColorRole::Given, arrow));
                 }
                 board.push(Item::cell(
                     y,
                     x,
-                    "black",
+                    ColorRole::Given,
                     if n >= 0 {
                         ItemKind::Num(n)
                     } else {
@@ -53,14 +58,14 @@ pub fn solve_yajilin(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
         }
     }
 
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&skip_line));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
 
     Ok(board)
 }
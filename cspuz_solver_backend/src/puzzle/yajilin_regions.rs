@@ -1,8 +1,16 @@
 use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
 use crate::uniqueness::is_unique;
 use cspuz_rs::graph;
 use cspuz_rs_puzzles::puzzles::yajilin_regions;
 
+pub(crate) const KINDS: &[PuzzleKind] = &[PuzzleKind::puzz_link(
+    "yajilin-regions",
+    "Regional Yajilin",
+    solve_yajilin_regions,
+)];
+
 pub fn solve_yajilin_regions(url: &str) -> Result<Board, &'static str> {
     let (borders, clues) = yajilin_regions::deserialize_problem(url).ok_or("invalid url")?;
     let (is_line, is_black) =
@@ -17,7 +25,7 @@ pub fn solve_yajilin_regions(url: &str) -> Result<Board, &'static str> {
         is_unique(&(&is_line, &is_black)),
     );
 
-    board.add_borders(&borders, "black");
+    board.add_borders(&borders, ColorRole::Given);
 
     for y in 0..height {
         for x in 0..width {
@@ -25,7 +33,7 @@ pub fn solve_yajilin_regions(url: &str) -> Result<Board, &'static str> {
                 board.push(Item::cell(
                     y,
                     x,
-                    "green",
+                    ColorRole::Answer,
                     if b { ItemKind::Block } else { ItemKind::Dot },
                 ));
             }
@@ -40,14 +48,23 @@ pub fn solve_yajilin_regions(url: &str) -> Result<Board, &'static str> {
         }
         skip_line.push(row);
     }
-    board.add_lines_irrefutable_facts(&is_line, "green", Some(&skip_line));
+    board.add_lines_irrefutable_facts(&is_line, ColorRole::Answer, Some(&skip_line));
 
     let rooms = graph::borders_to_rooms(&borders);
     assert_eq!(rooms.len(), clues.len());
     for i in 0..rooms.len() {
         if let Some(n) = clues[i] {
             let (y, x) = rooms[i][0];
-            board.push(Item::cell(y, x, "black", ItemKind::Num(n)));
+            board.push(Item::cell(
+                y,
+                x,
+                ColorRole::Given,
+                if n >= 0 {
+                    ItemKind::Num(n)
+                } else {
+                    ItemKind::Text("?")
+                },
+            ));
         }
     }
 
@@ -0,0 +1,95 @@
+use crate::board::{Board, BoardKind, Item, ItemKind};
+use crate::kinds::PuzzleKind;
+use crate::theme::ColorRole;
+use crate::uniqueness::{is_unique, Uniqueness};
+use cspuz_rs_puzzles::puzzles::yinyang::{self, Yinyang, YinyangClue};
+use cspuz_rs_puzzles::util::Puzzle;
+
+pub(crate) const KINDS: &[PuzzleKind] =
+    &[PuzzleKind::puzz_link("yinyang", "Yin-Yang", solve_yinyang)
+        .with_enumerate(enumerate_answers_yinyang)];
+
+fn clue_item(clue: YinyangClue) -> Option<ItemKind> {
+    match clue {
+        YinyangClue::None => None,
+        YinyangClue::White => Some(ItemKind::Circle),
+        YinyangClue::Black => Some(ItemKind::FilledCircle),
+    }
+}
+
+fn answer_item(is_black: bool) -> ItemKind {
+    if is_black {
+        ItemKind::FilledCircle
+    } else {
+        ItemKind::Circle
+    }
+}
+
+fn solved_board(
+    problem: &[Vec<YinyangClue>],
+    ans: &[Vec<Option<bool>>],
+    uniqueness: Uniqueness,
+) -> Board {
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut board = Board::new(BoardKind::Grid, height, width, uniqueness);
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if let Some(kind) = clue_item(clue) {
+                board.push(Item::cell(y, x, ColorRole::Given, kind));
+            } else if let Some(a) = ans[y][x] {
+                board.push(Item::cell(y, x, ColorRole::Answer, answer_item(a)));
+            }
+        }
+    }
+
+    board
+}
+
+pub fn solve_yinyang(url: &str) -> Result<Board, &'static str> {
+    let problem = yinyang::deserialize_problem(url).ok_or("invalid url")?;
+    let ans = Yinyang::solve(&problem).ok_or("no answer")?;
+
+    let mut board = solved_board(&problem, &ans, is_unique(&ans));
+    for (y, row) in problem.iter().enumerate() {
+        for (x, &clue) in row.iter().enumerate() {
+            if clue == YinyangClue::None && ans[y][x].is_none() {
+                board.push_undetermined_cell(y, x);
+            }
+        }
+    }
+
+    Ok(board)
+}
+
+pub fn enumerate_answers_yinyang(
+    url: &str,
+    num_max_answers: usize,
+) -> Result<(Board, Vec<Board>), &'static str> {
+    let problem = yinyang::deserialize_problem(url).ok_or("invalid url")?;
+    let ans_common = Yinyang::solve(&problem).ok_or("no answer")?;
+    let answers = Yinyang::enumerate_answers(&problem, num_max_answers);
+
+    let board_common = solved_board(&problem, &ans_common, Uniqueness::NotApplicable);
+
+    let height = problem.len();
+    let width = problem[0].len();
+    let mut boards = vec![];
+    for ans in answers {
+        let mut board_answer =
+            Board::new(BoardKind::Empty, height, width, Uniqueness::NotApplicable);
+        for (y, row) in problem.iter().enumerate() {
+            for (x, &clue) in row.iter().enumerate() {
+                if ans_common[y][x].is_some() || clue != YinyangClue::None {
+                    continue;
+                }
+                if let Some(a) = ans[y][x] {
+                    board_answer.push_optional(Item::cell(y, x, ColorRole::Answer, answer_item(a)));
+                }
+            }
+        }
+        boards.push(board_answer);
+    }
+
+    Ok((board_common, boards))
+}
@@ -0,0 +1,52 @@
+//! Export of answers in the pzprv3 file format, which can be loaded into pzprjs (puzz.link)
+//! to check the answer there.
+//!
+//! A pzprv3 file consists of the lines `pzprv3`, the puzzle type, the number of rows and the
+//! number of columns, followed by puzzle-specific data. Grid data is written one row per line,
+//! each cell followed by a single space.
+
+/// Cell symbol of a shaded cell (or a light in Akari).
+pub(crate) const SHADED: &str = "#";
+/// Cell symbol of a cell marked as unshaded with a dot.
+pub(crate) const DOT: &str = "+";
+/// Cell symbol of a clue cell without a number.
+pub(crate) const UNKNOWN_CLUE: &str = "-";
+
+/// Builds a pzprv3 file for `puzzle_type` whose data is the single cell grid `cells`.
+pub(crate) fn single_grid_file(puzzle_type: &str, cells: &[Vec<String>]) -> String {
+    let height = cells.len();
+    let width = cells.first().map_or(0, |row| row.len());
+
+    let mut ret = format!("pzprv3\n{}\n{}\n{}\n", puzzle_type, height, width);
+    for row in cells {
+        for cell in row {
+            ret.push_str(cell);
+            ret.push(' ');
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+/// Builds the pzprv3 cell grid of a puzzle with numbered clue cells and shaded / unshaded answer
+/// cells (e.g. Nurikabe and Akari), where a negative clue stands for a clue without a number.
+/// Returns `None` if some cell in `answer` is undecided.
+pub(crate) fn clue_and_shade_cells(
+    clues: &[Vec<Option<i32>>],
+    answer: &[Vec<Option<bool>>],
+) -> Option<Vec<Vec<String>>> {
+    let mut ret = vec![];
+    for (clue_row, answer_row) in clues.iter().zip(answer) {
+        let mut row = vec![];
+        for (&clue, &a) in clue_row.iter().zip(answer_row) {
+            let cell = match clue {
+                Some(n) if n >= 0 => n.to_string(),
+                Some(_) => UNKNOWN_CLUE.to_string(),
+                None => (if a? { SHADED } else { DOT }).to_string(),
+            };
+            row.push(cell);
+        }
+        ret.push(row);
+    }
+    Some(ret)
+}
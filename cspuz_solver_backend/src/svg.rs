@@ -0,0 +1,795 @@
+use crate::board::{
+    Board, BoardKind, Compass, FireflyDir, Item, ItemKind, StrokeDash, StrokeStyle, StrokeWidth,
+};
+use std::fmt::Write;
+
+/// Visual parameters used when rendering a `Board` as SVG.
+pub struct Theme {
+    pub cell_size: f64,
+    pub margin: f64,
+    pub background: &'static str,
+    pub grid_color: &'static str,
+    pub frame_color: &'static str,
+    pub font_family: &'static str,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            cell_size: 30.0,
+            margin: 10.0,
+            background: "white",
+            grid_color: "#999999",
+            frame_color: "black",
+            font_family: "sans-serif",
+        }
+    }
+}
+
+/// Maps the half-cell coordinates used by `Item` (odd values are cell centers, even values are
+/// cell borders) to pixel coordinates.
+struct Layout<'a> {
+    theme: &'a Theme,
+}
+
+impl<'a> Layout<'a> {
+    fn unit(&self) -> f64 {
+        self.theme.cell_size
+    }
+
+    fn pos(&self, v: f64) -> f64 {
+        self.theme.margin + v * self.theme.cell_size / 2.0
+    }
+
+    fn point(&self, y: f64, x: f64) -> (f64, f64) {
+        (self.pos(x), self.pos(y))
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn line(out: &mut String, (x1, y1): (f64, f64), (x2, y2): (f64, f64), color: &str, width: f64) {
+    let _ = writeln!(
+        out,
+        r#"<line x1="{x1:.2}" y1="{y1:.2}" x2="{x2:.2}" y2="{y2:.2}" stroke="{color}" stroke-width="{width:.2}" stroke-linecap="round"/>"#
+    );
+}
+
+fn dashed_line(
+    out: &mut String,
+    (x1, y1): (f64, f64),
+    (x2, y2): (f64, f64),
+    color: &str,
+    width: f64,
+    dash: f64,
+) {
+    let _ = writeln!(
+        out,
+        r#"<line x1="{x1:.2}" y1="{y1:.2}" x2="{x2:.2}" y2="{y2:.2}" stroke="{color}" stroke-width="{width:.2}" stroke-dasharray="{dash:.2}"/>"#
+    );
+}
+
+/// Draws a line from `p` to `q` in `style`, whose widths are `widths` (thin, medium, bold).
+fn styled_line(
+    out: &mut String,
+    p: (f64, f64),
+    q: (f64, f64),
+    color: &str,
+    style: &StrokeStyle,
+    widths: (f64, f64, f64),
+) {
+    let width = match style.width {
+        StrokeWidth::Thin => widths.0,
+        StrokeWidth::Medium => widths.1,
+        StrokeWidth::Bold => widths.2,
+    };
+    match style.dash {
+        StrokeDash::Solid => line(out, p, q, color, width),
+        StrokeDash::Dashed => dashed_line(out, p, q, color, width, width * 2.5),
+        StrokeDash::Dotted => dashed_line(out, p, q, color, width, width),
+    }
+}
+
+fn rect(out: &mut String, (x, y): (f64, f64), w: f64, h: f64, fill: &str) {
+    let _ = writeln!(
+        out,
+        r#"<rect x="{x:.2}" y="{y:.2}" width="{w:.2}" height="{h:.2}" fill="{fill}"/>"#
+    );
+}
+
+fn circle(out: &mut String, (cx, cy): (f64, f64), r: f64, color: &str, filled: bool, width: f64) {
+    if filled {
+        let _ = writeln!(
+            out,
+            r#"<circle cx="{cx:.2}" cy="{cy:.2}" r="{r:.2}" fill="{color}"/>"#
+        );
+    } else {
+        let _ = writeln!(
+            out,
+            r#"<circle cx="{cx:.2}" cy="{cy:.2}" r="{r:.2}" fill="none" stroke="{color}" stroke-width="{width:.2}"/>"#
+        );
+    }
+}
+
+fn polygon(out: &mut String, points: &[(f64, f64)], color: &str, filled: bool, width: f64) {
+    let points = points
+        .iter()
+        .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if filled {
+        let _ = writeln!(out, r#"<polygon points="{points}" fill="{color}"/>"#);
+    } else {
+        let _ = writeln!(
+            out,
+            r#"<polygon points="{points}" fill="none" stroke="{color}" stroke-width="{width:.2}"/>"#
+        );
+    }
+}
+
+fn text(out: &mut String, (x, y): (f64, f64), s: &str, size: f64, color: &str, theme: &Theme) {
+    let _ = writeln!(
+        out,
+        r#"<text x="{x:.2}" y="{y:.2}" font-size="{size:.2}" font-family="{}" fill="{color}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+        theme.font_family,
+        escape(s)
+    );
+}
+
+/// Returns the unit vector (dy, dx) pointing in the given direction.
+fn direction(dir: &FireflyDir) -> (f64, f64) {
+    match dir {
+        FireflyDir::Up => (-1.0, 0.0),
+        FireflyDir::Down => (1.0, 0.0),
+        FireflyDir::Left => (0.0, -1.0),
+        FireflyDir::Right => (0.0, 1.0),
+    }
+}
+
+fn arrow(out: &mut String, layout: &Layout, y: f64, x: f64, dir: &FireflyDir, color: &str) {
+    let u = layout.unit();
+    let (dy, dx) = direction(dir);
+    let (cx, cy) = layout.point(y, x);
+    let tip = (cx + dx * u * 0.35, cy + dy * u * 0.35);
+    let tail = (cx - dx * u * 0.35, cy - dy * u * 0.35);
+    line(out, tail, tip, color, u * 0.06);
+    polygon(
+        out,
+        &[
+            tip,
+            (
+                tip.0 - dx * u * 0.2 + dy * u * 0.12,
+                tip.1 - dy * u * 0.2 + dx * u * 0.12,
+            ),
+            (
+                tip.0 - dx * u * 0.2 - dy * u * 0.12,
+                tip.1 - dy * u * 0.2 - dx * u * 0.12,
+            ),
+        ],
+        color,
+        true,
+        0.0,
+    );
+}
+
+/// Draws a small arrowhead near the side of the cell in the given direction.
+fn side_arrow(out: &mut String, layout: &Layout, y: f64, x: f64, dir: &FireflyDir, color: &str) {
+    let u = layout.unit();
+    let (dy, dx) = direction(dir);
+    let (cx, cy) = layout.point(y, x);
+    let (bx, by) = (
+        cx + dx * u * 0.3 - dy * u * 0.3,
+        cy + dy * u * 0.3 - dx * u * 0.3,
+    );
+    let tip = (bx + dx * u * 0.12, by + dy * u * 0.12);
+    polygon(
+        out,
+        &[
+            tip,
+            (bx + dy * u * 0.08, by + dx * u * 0.08),
+            (bx - dy * u * 0.08, by - dx * u * 0.08),
+        ],
+        color,
+        true,
+        0.0,
+    );
+}
+
+fn pencil(out: &mut String, layout: &Layout, y: f64, x: f64, dir: &FireflyDir, color: &str) {
+    let u = layout.unit();
+    let (dy, dx) = direction(dir);
+    let (cx, cy) = layout.point(y, x);
+    let tip = (cx + dx * u * 0.45, cy + dy * u * 0.45);
+    let base = (cx + dx * u * 0.15, cy + dy * u * 0.15);
+    let back = (cx - dx * u * 0.45, cy - dy * u * 0.45);
+    let side = |p: (f64, f64), s: f64| (p.0 + dy * s, p.1 + dx * s);
+    polygon(
+        out,
+        &[
+            tip,
+            side(base, u * 0.15),
+            side(back, u * 0.15),
+            side(back, -u * 0.15),
+            side(base, -u * 0.15),
+        ],
+        color,
+        false,
+        u * 0.05,
+    );
+}
+
+/// Draws a curve in a cell connecting the midpoints of two adjacent sides, given as unit
+/// vectors from the cell center.
+fn corner_arc(
+    out: &mut String,
+    layout: &Layout,
+    y: f64,
+    x: f64,
+    a: (f64, f64),
+    b: (f64, f64),
+    color: &str,
+) {
+    let u = layout.unit();
+    let (cx, cy) = layout.point(y, x);
+    let p = (cx + a.1 * u * 0.5, cy + a.0 * u * 0.5);
+    let q = (cx + b.1 * u * 0.5, cy + b.0 * u * 0.5);
+    let _ = writeln!(
+        out,
+        r#"<path d="M {:.2} {:.2} Q {cx:.2} {cy:.2} {:.2} {:.2}" fill="none" stroke="{color}" stroke-width="{:.2}"/>"#,
+        p.0,
+        p.1,
+        q.0,
+        q.1,
+        u * 0.1
+    );
+}
+
+fn render_item(out: &mut String, layout: &Layout, item: &Item) {
+    let theme = layout.theme;
+    let u = layout.unit();
+    let color = item.color.css;
+    let (y, x) = (item.y as f64, item.x as f64);
+    let center = layout.point(y, x);
+    let is_vertical_edge = item.y % 2 == 1 && item.x % 2 != 1;
+
+    match &item.kind {
+        ItemKind::Dot => circle(out, center, u * 0.1, color, true, 0.0),
+        ItemKind::Block | ItemKind::Fill => rect(out, layout.point(y - 1.0, x - 1.0), u, u, color),
+        ItemKind::Square => polygon(
+            out,
+            &[
+                layout.point(y - 0.6, x - 0.6),
+                layout.point(y - 0.6, x + 0.6),
+                layout.point(y + 0.6, x + 0.6),
+                layout.point(y + 0.6, x - 0.6),
+            ],
+            color,
+            false,
+            u * 0.05,
+        ),
+        ItemKind::Triangle => polygon(
+            out,
+            &[
+                layout.point(y - 0.6, x),
+                layout.point(y + 0.5, x + 0.6),
+                layout.point(y + 0.5, x - 0.6),
+            ],
+            color,
+            false,
+            u * 0.05,
+        ),
+        ItemKind::Circle => circle(out, center, u * 0.4, color, false, u * 0.05),
+        ItemKind::FilledCircle => circle(out, center, u * 0.4, color, true, 0.0),
+        ItemKind::SmallCircle => circle(out, center, u * 0.2, color, false, u * 0.05),
+        ItemKind::SmallFilledCircle => circle(out, center, u * 0.2, color, true, 0.0),
+        ItemKind::SideArrowUp => side_arrow(out, layout, y, x, &FireflyDir::Up, color),
+        ItemKind::SideArrowDown => side_arrow(out, layout, y, x, &FireflyDir::Down, color),
+        ItemKind::SideArrowLeft => side_arrow(out, layout, y, x, &FireflyDir::Left, color),
+        ItemKind::SideArrowRight => side_arrow(out, layout, y, x, &FireflyDir::Right, color),
+        ItemKind::ArrowUp => arrow(out, layout, y, x, &FireflyDir::Up, color),
+        ItemKind::ArrowDown => arrow(out, layout, y, x, &FireflyDir::Down, color),
+        ItemKind::ArrowLeft => arrow(out, layout, y, x, &FireflyDir::Left, color),
+        ItemKind::ArrowRight => arrow(out, layout, y, x, &FireflyDir::Right, color),
+        ItemKind::AboloUpperLeft => polygon(
+            out,
+            &[
+                layout.point(y - 1.0, x - 1.0),
+                layout.point(y - 1.0, x + 1.0),
+                layout.point(y + 1.0, x - 1.0),
+            ],
+            color,
+            true,
+            0.0,
+        ),
+        ItemKind::AboloUpperRight => polygon(
+            out,
+            &[
+                layout.point(y - 1.0, x - 1.0),
+                layout.point(y - 1.0, x + 1.0),
+                layout.point(y + 1.0, x + 1.0),
+            ],
+            color,
+            true,
+            0.0,
+        ),
+        ItemKind::AboloLowerLeft => polygon(
+            out,
+            &[
+                layout.point(y - 1.0, x - 1.0),
+                layout.point(y + 1.0, x + 1.0),
+                layout.point(y + 1.0, x - 1.0),
+            ],
+            color,
+            true,
+            0.0,
+        ),
+        ItemKind::AboloLowerRight => polygon(
+            out,
+            &[
+                layout.point(y - 1.0, x + 1.0),
+                layout.point(y + 1.0, x + 1.0),
+                layout.point(y + 1.0, x - 1.0),
+            ],
+            color,
+            true,
+            0.0,
+        ),
+        ItemKind::PencilUp => pencil(out, layout, y, x, &FireflyDir::Up, color),
+        ItemKind::PencilDown => pencil(out, layout, y, x, &FireflyDir::Down, color),
+        ItemKind::PencilLeft => pencil(out, layout, y, x, &FireflyDir::Left, color),
+        ItemKind::PencilRight => pencil(out, layout, y, x, &FireflyDir::Right, color),
+        ItemKind::Cross => {
+            let d = 0.2;
+            line(
+                out,
+                layout.point(y - d, x - d),
+                layout.point(y + d, x + d),
+                color,
+                u * 0.05,
+            );
+            line(
+                out,
+                layout.point(y - d, x + d),
+                layout.point(y + d, x - d),
+                color,
+                u * 0.05,
+            );
+        }
+        ItemKind::Line | ItemKind::DottedLine | ItemKind::DoubleLine => {
+            // Lines connect the centers of the two cells sharing the edge at (y, x)
+            let (p, q, offset) = if is_vertical_edge {
+                (
+                    layout.point(y, x - 1.0),
+                    layout.point(y, x + 1.0),
+                    (0.0, u * 0.1),
+                )
+            } else {
+                (
+                    layout.point(y - 1.0, x),
+                    layout.point(y + 1.0, x),
+                    (u * 0.1, 0.0),
+                )
+            };
+            match item.kind {
+                ItemKind::Line => line(out, p, q, color, u * 0.1),
+                ItemKind::DottedLine => dashed_line(out, p, q, color, u * 0.1, u * 0.15),
+                _ => {
+                    for s in [-1.0, 1.0] {
+                        line(
+                            out,
+                            (p.0 + offset.0 * s, p.1 + offset.1 * s),
+                            (q.0 + offset.0 * s, q.1 + offset.1 * s),
+                            color,
+                            u * 0.06,
+                        );
+                    }
+                }
+            }
+        }
+        ItemKind::Wall
+        | ItemKind::BoldWall
+        | ItemKind::DottedWall
+        | ItemKind::DottedHorizontalWall
+        | ItemKind::DottedVerticalWall => {
+            // Walls lie along the edge at (y, x)
+            let (p, q) = if is_vertical_edge {
+                (layout.point(y - 1.0, x), layout.point(y + 1.0, x))
+            } else {
+                (layout.point(y, x - 1.0), layout.point(y, x + 1.0))
+            };
+            match item.kind {
+                ItemKind::Wall => line(out, p, q, color, u * 0.06),
+                ItemKind::BoldWall => line(out, p, q, color, u * 0.12),
+                _ => dashed_line(out, p, q, color, u * 0.06, u * 0.1),
+            }
+        }
+        ItemKind::StyledWall(style) => {
+            let (p, q) = if is_vertical_edge {
+                (layout.point(y - 1.0, x), layout.point(y + 1.0, x))
+            } else {
+                (layout.point(y, x - 1.0), layout.point(y, x + 1.0))
+            };
+            styled_line(out, p, q, color, style, (u * 0.06, u * 0.09, u * 0.12));
+        }
+        ItemKind::LayeredLine(layer, style) => {
+            // Layers 1, 2, 3, 4, ... are shifted to alternating sides of layer 0
+            let side = if layer % 2 == 1 { 1.0 } else { -1.0 };
+            let shift = side * (layer.div_ceil(2) as f64) * 0.3;
+            let (p, q) = if is_vertical_edge {
+                (
+                    layout.point(y + shift, x - 1.0),
+                    layout.point(y + shift, x + 1.0),
+                )
+            } else {
+                (
+                    layout.point(y - 1.0, x + shift),
+                    layout.point(y + 1.0, x + shift),
+                )
+            };
+            styled_line(out, p, q, color, style, (u * 0.06, u * 0.1, u * 0.14));
+        }
+        ItemKind::FirewalkCellUnknown => rect(out, layout.point(y - 1.0, x - 1.0), u, u, "#eeeeee"),
+        ItemKind::FirewalkCellUl => corner_arc(out, layout, y, x, (-1.0, 0.0), (0.0, -1.0), color),
+        ItemKind::FirewalkCellUr => corner_arc(out, layout, y, x, (-1.0, 0.0), (0.0, 1.0), color),
+        ItemKind::FirewalkCellDl => corner_arc(out, layout, y, x, (1.0, 0.0), (0.0, -1.0), color),
+        ItemKind::FirewalkCellDr => corner_arc(out, layout, y, x, (1.0, 0.0), (0.0, 1.0), color),
+        ItemKind::FirewalkCellUlDr => {
+            corner_arc(out, layout, y, x, (-1.0, 0.0), (0.0, -1.0), color);
+            corner_arc(out, layout, y, x, (1.0, 0.0), (0.0, 1.0), color);
+        }
+        ItemKind::FirewalkCellUrDl => {
+            corner_arc(out, layout, y, x, (-1.0, 0.0), (0.0, 1.0), color);
+            corner_arc(out, layout, y, x, (1.0, 0.0), (0.0, -1.0), color);
+        }
+        ItemKind::Slash | ItemKind::DottedSlash => {
+            let p = layout.point(y + 1.0, x - 1.0);
+            let q = layout.point(y - 1.0, x + 1.0);
+            if item.kind == ItemKind::Slash {
+                line(out, p, q, color, u * 0.08);
+            } else {
+                dashed_line(out, p, q, color, u * 0.08, u * 0.1);
+            }
+        }
+        ItemKind::Backslash | ItemKind::DottedBackslash => {
+            let p = layout.point(y - 1.0, x - 1.0);
+            let q = layout.point(y + 1.0, x + 1.0);
+            if item.kind == ItemKind::Backslash {
+                line(out, p, q, color, u * 0.08);
+            } else {
+                dashed_line(out, p, q, color, u * 0.08, u * 0.1);
+            }
+        }
+        ItemKind::Undetermined => text(out, center, "?", u * 0.5, color, theme),
+        ItemKind::InequalityUp
+        | ItemKind::InequalityDown
+        | ItemKind::InequalityLeft
+        | ItemKind::InequalityRight => {
+            // (dy, dx) is the direction of the tip of the sign
+            let (dy, dx) = match item.kind {
+                ItemKind::InequalityUp => (-1.0, 0.0),
+                ItemKind::InequalityDown => (1.0, 0.0),
+                ItemKind::InequalityLeft => (0.0, -1.0),
+                _ => (0.0, 1.0),
+            };
+            let tip = layout.point(y + dy * 0.3, x + dx * 0.3);
+            for s in [-1.0, 1.0] {
+                let end = layout.point(y - dy * 0.3 + dx * s * 0.4, x - dx * 0.3 + dy * s * 0.4);
+                line(out, tip, end, color, u * 0.06);
+            }
+        }
+        ItemKind::ThermoBulb => circle(out, center, u * 0.35, color, true, 0.0),
+        ItemKind::ThermoPath => {
+            let (p, q) = if is_vertical_edge {
+                (layout.point(y, x - 1.0), layout.point(y, x + 1.0))
+            } else {
+                (layout.point(y - 1.0, x), layout.point(y + 1.0, x))
+            };
+            line(out, p, q, color, u * 0.3);
+        }
+        ItemKind::ShipSingle => circle(out, center, u * 0.4, color, true, 0.0),
+        ItemKind::ShipMiddle => rect(out, layout.point(y - 0.8, x - 0.8), u * 0.8, u * 0.8, color),
+        ItemKind::ShipEndUp
+        | ItemKind::ShipEndDown
+        | ItemKind::ShipEndLeft
+        | ItemKind::ShipEndRight => {
+            // The end is rounded, and the other half is square to join the next segment
+            circle(out, center, u * 0.4, color, true, 0.0);
+            let (top, left, h, w) = match item.kind {
+                ItemKind::ShipEndUp => (y, x - 0.8, 0.8, 1.6),
+                ItemKind::ShipEndDown => (y - 0.8, x - 0.8, 0.8, 1.6),
+                ItemKind::ShipEndLeft => (y - 0.8, x, 1.6, 0.8),
+                _ => (y - 0.8, x - 0.8, 1.6, 0.8),
+            };
+            rect(
+                out,
+                layout.point(top, left),
+                u * w / 2.0,
+                u * h / 2.0,
+                color,
+            );
+        }
+        ItemKind::BoldSlash => line(
+            out,
+            layout.point(y + 1.0, x - 1.0),
+            layout.point(y - 1.0, x + 1.0),
+            color,
+            u * 0.16,
+        ),
+        ItemKind::BoldBackslash => line(
+            out,
+            layout.point(y - 1.0, x - 1.0),
+            layout.point(y + 1.0, x + 1.0),
+            color,
+            u * 0.16,
+        ),
+        ItemKind::Plus => {
+            line(
+                out,
+                layout.point(y - 1.0, x),
+                layout.point(y + 1.0, x),
+                color,
+                u * 0.08,
+            );
+            line(
+                out,
+                layout.point(y, x - 1.0),
+                layout.point(y, x + 1.0),
+                color,
+                u * 0.08,
+            );
+        }
+        ItemKind::Text(s) => text(out, center, s, u * 0.7, color, theme),
+        ItemKind::Num(n) => text(out, center, &n.to_string(), u * 0.7, color, theme),
+        ItemKind::NumUpperLeft(n) => text(
+            out,
+            layout.point(y - 0.55, x - 0.55),
+            &n.to_string(),
+            u * 0.35,
+            color,
+            theme,
+        ),
+        ItemKind::NumUpperRight(n) => text(
+            out,
+            layout.point(y - 0.55, x + 0.55),
+            &n.to_string(),
+            u * 0.35,
+            color,
+            theme,
+        ),
+        ItemKind::NumLowerLeft(n) => text(
+            out,
+            layout.point(y + 0.55, x - 0.55),
+            &n.to_string(),
+            u * 0.35,
+            color,
+            theme,
+        ),
+        ItemKind::NumLowerRight(n) => text(
+            out,
+            layout.point(y + 0.55, x + 0.55),
+            &n.to_string(),
+            u * 0.35,
+            color,
+            theme,
+        ),
+        ItemKind::Compass(Compass {
+            up,
+            down,
+            left,
+            right,
+        }) => {
+            line(
+                out,
+                layout.point(y - 0.7, x - 0.7),
+                layout.point(y + 0.7, x + 0.7),
+                color,
+                u * 0.03,
+            );
+            line(
+                out,
+                layout.point(y - 0.7, x + 0.7),
+                layout.point(y + 0.7, x - 0.7),
+                color,
+                u * 0.03,
+            );
+            for (n, dy, dx) in [
+                (up, -0.55, 0.0),
+                (down, 0.55, 0.0),
+                (left, 0.0, -0.55),
+                (right, 0.0, 0.55),
+            ] {
+                if let Some(n) = n {
+                    text(
+                        out,
+                        layout.point(y + dy, x + dx),
+                        &n.to_string(),
+                        u * 0.3,
+                        color,
+                        theme,
+                    );
+                }
+            }
+        }
+        ItemKind::TapaClue(clues) => {
+            let clues = clues
+                .iter()
+                .filter(|&&c| c != -1)
+                .map(|c| {
+                    if *c == -2 {
+                        "?".to_string()
+                    } else {
+                        c.to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let offsets: &[(f64, f64)] = match clues.len() {
+                1 => &[(0.0, 0.0)],
+                2 => &[(-0.35, -0.35), (0.35, 0.35)],
+                3 => &[(-0.4, -0.4), (-0.4, 0.4), (0.4, 0.0)],
+                _ => &[(-0.4, -0.4), (-0.4, 0.4), (0.4, -0.4), (0.4, 0.4)],
+            };
+            let size = if clues.len() == 1 { u * 0.7 } else { u * 0.4 };
+            for (c, &(dy, dx)) in clues.iter().zip(offsets) {
+                text(out, layout.point(y + dy, x + dx), c, size, color, theme);
+            }
+        }
+        ItemKind::SudokuCandidateSet(size, cands) => {
+            let size = *size as usize;
+            let (bh, bw) = match size {
+                4 => (2, 2),
+                6 => (2, 3),
+                9 => (3, 3),
+                _ => {
+                    let bw = (size as f64).sqrt().ceil() as usize;
+                    (size.div_ceil(bw.max(1)), bw.max(1))
+                }
+            };
+            for &c in cands {
+                if c <= 0 {
+                    continue;
+                }
+                let i = (c - 1) as usize;
+                let dy = ((i / bw) as f64 + 0.5) / bh as f64 * 2.0 - 1.0;
+                let dx = ((i % bw) as f64 + 0.5) / bw as f64 * 2.0 - 1.0;
+                text(
+                    out,
+                    layout.point(y + dy, x + dx),
+                    &c.to_string(),
+                    u * 0.8 / bh.max(bw) as f64,
+                    color,
+                    theme,
+                );
+            }
+        }
+        ItemKind::Firefly(dir, n) => {
+            circle(out, center, u * 0.35, color, false, u * 0.05);
+            let (dy, dx) = direction(dir);
+            circle(
+                out,
+                (center.0 + dx * u * 0.35, center.1 + dy * u * 0.35),
+                u * 0.08,
+                color,
+                true,
+                0.0,
+            );
+            if *n >= 0 {
+                text(out, center, &n.to_string(), u * 0.4, color, theme);
+            }
+        }
+        ItemKind::LineTo(dest_y, dest_x) => line(
+            out,
+            center,
+            layout.point(*dest_y as f64, *dest_x as f64),
+            color,
+            u * 0.1,
+        ),
+    }
+}
+
+fn render_background(out: &mut String, layout: &Layout, board: &Board) {
+    let theme = layout.theme;
+    let u = layout.unit();
+    let (height, width) = (board.height, board.width);
+    let h2 = height as f64 * 2.0;
+    let w2 = width as f64 * 2.0;
+
+    match board.kind {
+        BoardKind::Empty => (),
+        BoardKind::Grid | BoardKind::OuterGrid => {
+            if let BoardKind::Grid = board.kind {
+                for y in 1..height {
+                    let y = y as f64 * 2.0;
+                    line(
+                        out,
+                        layout.point(y, 0.0),
+                        layout.point(y, w2),
+                        theme.grid_color,
+                        u * 0.03,
+                    );
+                }
+                for x in 1..width {
+                    let x = x as f64 * 2.0;
+                    line(
+                        out,
+                        layout.point(0.0, x),
+                        layout.point(h2, x),
+                        theme.grid_color,
+                        u * 0.03,
+                    );
+                }
+            }
+            let (x0, y0) = layout.point(0.0, 0.0);
+            let _ = writeln!(
+                out,
+                r#"<rect x="{x0:.2}" y="{y0:.2}" width="{:.2}" height="{:.2}" fill="none" stroke="{}" stroke-width="{:.2}"/>"#,
+                u * width as f64,
+                u * height as f64,
+                theme.frame_color,
+                u * 0.1
+            );
+        }
+        BoardKind::DotGrid => {
+            for y in 0..=height {
+                for x in 0..=width {
+                    circle(
+                        out,
+                        layout.point(y as f64 * 2.0, x as f64 * 2.0),
+                        u * 0.05,
+                        theme.frame_color,
+                        true,
+                        0.0,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Board {
+    /// Renders the board as a standalone SVG document with the default theme.
+    pub fn to_svg(&self) -> String {
+        self.to_svg_with_theme(&Theme::default())
+    }
+
+    pub fn to_svg_with_theme(&self, theme: &Theme) -> String {
+        let layout = Layout { theme };
+        let svg_width = theme.margin * 2.0 + theme.cell_size * self.width as f64;
+        let svg_height = theme.margin * 2.0 + theme.cell_size * self.height as f64;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{svg_width:.2}" height="{svg_height:.2}" viewBox="0 0 {svg_width:.2} {svg_height:.2}">"#
+        );
+        rect(
+            &mut out,
+            (0.0, 0.0),
+            svg_width,
+            svg_height,
+            theme.background,
+        );
+
+        // Fills are drawn first so that they don't hide the grid or other items
+        let is_fill = |item: &&Item| {
+            matches!(
+                item.kind,
+                ItemKind::Block | ItemKind::Fill | ItemKind::FirewalkCellUnknown
+            )
+        };
+        for item in self.data.iter().filter(is_fill) {
+            render_item(&mut out, &layout, item);
+        }
+        render_background(&mut out, &layout, self);
+        for item in self.data.iter().filter(|item| !is_fill(item)) {
+            render_item(&mut out, &layout, item);
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
@@ -0,0 +1,260 @@
+use crate::board::{Board, BoardKind, Item, ItemKind, StrokeDash, StrokeWidth};
+
+/// Width in characters of cell columns (odd `x`) in the text rendering.
+/// Border columns (even `x`) are 1 character wide.
+const CELL_WIDTH: usize = 3;
+
+/// Character canvas addressed by the half-cell coordinates used by `Item`.
+struct Canvas {
+    rows: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn new(height: usize, width: usize) -> Canvas {
+        let row_len = (width + 1) + width * CELL_WIDTH;
+        Canvas {
+            rows: vec![vec![' '; row_len]; height * 2 + 1],
+        }
+    }
+
+    fn column(x: usize) -> usize {
+        (x / 2) * (CELL_WIDTH + 1) + if x % 2 == 1 { 1 } else { 0 }
+    }
+
+    fn width_of(x: usize) -> usize {
+        if x % 2 == 1 {
+            CELL_WIDTH
+        } else {
+            1
+        }
+    }
+
+    fn get(&self, y: usize, x: usize, offset: usize) -> char {
+        self.rows[y][Canvas::column(x) + offset]
+    }
+
+    fn set(&mut self, y: usize, x: usize, offset: usize, c: char) {
+        if y < self.rows.len() && Canvas::column(x) + offset < self.rows[y].len() {
+            self.rows[y][Canvas::column(x) + offset] = c;
+        }
+    }
+
+    /// Fills the whole slot at `(y, x)` with `c`.
+    fn fill(&mut self, y: usize, x: usize, c: char) {
+        for i in 0..Canvas::width_of(x) {
+            self.set(y, x, i, c);
+        }
+    }
+
+    /// Writes `s` centered in the slot at `(y, x)`, truncating it if it does not fit.
+    fn put(&mut self, y: usize, x: usize, s: &str) {
+        let width = Canvas::width_of(x);
+        let chars = s.chars().take(width).collect::<Vec<_>>();
+        let start = (width - chars.len()) / 2;
+        for (i, c) in chars.into_iter().enumerate() {
+            self.set(y, x, start + i, c);
+        }
+    }
+
+    fn into_string(self) -> String {
+        let mut ret = String::new();
+        for row in self.rows {
+            let line = row.into_iter().collect::<String>();
+            ret.push_str(line.trim_end());
+            ret.push('\n');
+        }
+        ret
+    }
+}
+
+fn render_background(canvas: &mut Canvas, board: &Board) {
+    let (height, width) = (board.height, board.width);
+    let is_outer = |y: usize, x: usize| y == 0 || y == height * 2 || x == 0 || x == width * 2;
+
+    for y in 0..=(height * 2) {
+        for x in 0..=(width * 2) {
+            if y % 2 == 1 && x % 2 == 1 {
+                continue;
+            }
+            let c = match board.kind {
+                BoardKind::Empty => continue,
+                BoardKind::DotGrid => {
+                    if y % 2 == 0 && x % 2 == 0 {
+                        '.'
+                    } else {
+                        continue;
+                    }
+                }
+                BoardKind::Grid | BoardKind::OuterGrid => {
+                    if y % 2 == 0 && x % 2 == 0 {
+                        if matches!(board.kind, BoardKind::Grid) || is_outer(y, x) {
+                            '+'
+                        } else {
+                            continue;
+                        }
+                    } else if !is_outer(y, x) {
+                        continue;
+                    } else if y % 2 == 0 {
+                        '-'
+                    } else {
+                        '|'
+                    }
+                }
+            };
+            canvas.fill(y, x, c);
+        }
+    }
+}
+
+/// Draws a segment of a line (e.g. a loop) passing through the border at `(y, x)` between two
+/// cells, extending it towards the centers of the cells.
+fn render_line_through_border(canvas: &mut Canvas, y: usize, x: usize, c: char) {
+    if y % 2 == 1 {
+        // connects (y, x - 1) and (y, x + 1)
+        canvas.set(y, x, 0, c);
+        if x > 0 {
+            canvas.set(y, x - 1, CELL_WIDTH - 1, c);
+            mark_line_center(canvas, y, x - 1);
+        }
+        canvas.set(y, x + 1, 0, c);
+        mark_line_center(canvas, y, x + 1);
+    } else {
+        let c = if c == '=' { '"' } else { '|' };
+        canvas.set(y, x, CELL_WIDTH / 2, c);
+        if y > 0 {
+            mark_line_center(canvas, y - 1, x);
+        }
+        mark_line_center(canvas, y + 1, x);
+    }
+}
+
+fn mark_line_center(canvas: &mut Canvas, y: usize, x: usize) {
+    if y < canvas.rows.len()
+        && Canvas::column(x) + CELL_WIDTH / 2 < canvas.rows[y].len()
+        && canvas.get(y, x, CELL_WIDTH / 2) == ' '
+    {
+        canvas.set(y, x, CELL_WIDTH / 2, '+');
+    }
+}
+
+fn render_item(canvas: &mut Canvas, item: &Item) {
+    let (y, x) = (item.y, item.x);
+    let is_cell = y % 2 == 1 && x % 2 == 1;
+    let is_vertical_border = y % 2 == 1 && x % 2 == 0;
+
+    match &item.kind {
+        ItemKind::Block | ItemKind::Fill => canvas.fill(y, x, '#'),
+        ItemKind::Dot => canvas.put(y, x, "."),
+        ItemKind::Circle | ItemKind::SmallCircle => canvas.put(y, x, "o"),
+        ItemKind::FilledCircle | ItemKind::SmallFilledCircle => canvas.put(y, x, "*"),
+        ItemKind::Square => canvas.put(y, x, "[]"),
+        ItemKind::Triangle => canvas.put(y, x, "^"),
+        ItemKind::ArrowUp | ItemKind::SideArrowUp | ItemKind::PencilUp => canvas.put(y, x, "^"),
+        ItemKind::ArrowDown | ItemKind::SideArrowDown | ItemKind::PencilDown => {
+            canvas.put(y, x, "v")
+        }
+        ItemKind::ArrowLeft | ItemKind::SideArrowLeft | ItemKind::PencilLeft => {
+            canvas.put(y, x, "<")
+        }
+        ItemKind::ArrowRight | ItemKind::SideArrowRight | ItemKind::PencilRight => {
+            canvas.put(y, x, ">")
+        }
+        ItemKind::Slash | ItemKind::DottedSlash => canvas.put(y, x, "/"),
+        ItemKind::Backslash | ItemKind::DottedBackslash => canvas.put(y, x, "\\"),
+        ItemKind::Plus => canvas.put(y, x, "+"),
+        ItemKind::Cross => canvas.put(y, x, "x"),
+        ItemKind::Undetermined => canvas.put(y, x, "?"),
+        ItemKind::InequalityUp => canvas.put(y, x, "^"),
+        ItemKind::InequalityDown => canvas.put(y, x, "v"),
+        ItemKind::InequalityLeft => canvas.put(y, x, "<"),
+        ItemKind::InequalityRight => canvas.put(y, x, ">"),
+        ItemKind::ThermoBulb => canvas.put(y, x, "O"),
+        ItemKind::ThermoPath => render_line_through_border(canvas, y, x, '='),
+        ItemKind::ShipSingle => canvas.put(y, x, "(#)"),
+        ItemKind::ShipEndUp => canvas.put(y, x, "/#\\"),
+        ItemKind::ShipEndDown => canvas.put(y, x, "\\#/"),
+        ItemKind::ShipEndLeft => canvas.put(y, x, "(##"),
+        ItemKind::ShipEndRight => canvas.put(y, x, "##)"),
+        ItemKind::ShipMiddle => canvas.fill(y, x, '#'),
+        ItemKind::BoldSlash => canvas.put(y, x, "/"),
+        ItemKind::BoldBackslash => canvas.put(y, x, "\\"),
+        ItemKind::Line | ItemKind::DottedLine | ItemKind::DoubleLine => {
+            let c = if matches!(item.kind, ItemKind::DoubleLine) {
+                '='
+            } else {
+                '-'
+            };
+            if is_cell {
+                canvas.put(y, x, "+");
+            } else {
+                render_line_through_border(canvas, y, x, c);
+            }
+        }
+        ItemKind::Wall | ItemKind::BoldWall => {
+            canvas.fill(y, x, if is_vertical_border { '|' } else { '-' })
+        }
+        ItemKind::DottedWall | ItemKind::DottedHorizontalWall | ItemKind::DottedVerticalWall => {
+            canvas.fill(y, x, if is_vertical_border { ':' } else { '.' })
+        }
+        ItemKind::StyledWall(style) => {
+            let c = match (style.dash, is_vertical_border) {
+                (StrokeDash::Solid, true) => '|',
+                (StrokeDash::Solid, false) => '-',
+                (_, true) => ':',
+                (_, false) => '.',
+            };
+            canvas.fill(y, x, c);
+        }
+        // Only the first layer is drawn, as there is no room for lines side by side
+        ItemKind::LayeredLine(0, style) => {
+            let c = if style.width == StrokeWidth::Bold {
+                '='
+            } else {
+                '-'
+            };
+            render_line_through_border(canvas, y, x, c);
+        }
+        ItemKind::Text(text) => canvas.put(y, x, text),
+        ItemKind::Num(n)
+        | ItemKind::NumUpperLeft(n)
+        | ItemKind::NumUpperRight(n)
+        | ItemKind::NumLowerLeft(n)
+        | ItemKind::NumLowerRight(n) => canvas.put(y, x, &n.to_string()),
+        ItemKind::Firefly(_, n) => {
+            if *n >= 0 {
+                canvas.put(y, x, &n.to_string())
+            } else {
+                canvas.put(y, x, "o")
+            }
+        }
+        ItemKind::SudokuCandidateSet(_, cands) => {
+            let s = cands.iter().map(|c| c.to_string()).collect::<String>();
+            canvas.put(y, x, &s);
+        }
+        // Items which have no sensible monospace representation are omitted
+        _ => (),
+    }
+}
+
+impl Board {
+    /// Renders the board as monospace ASCII text, e.g. for terminals and test outputs.
+    ///
+    /// Each cell is 3 characters wide and each border 1 character wide: numbers are written in
+    /// cells, shaded cells are drawn as `###`, and lines through cell borders as `-` and `|`.
+    /// Colors are ignored, as are items which cannot be represented by a few characters.
+    pub fn to_text(&self) -> String {
+        let mut canvas = Canvas::new(self.height, self.width);
+        render_background(&mut canvas, self);
+
+        // Fills are drawn first so that clues on them remain visible
+        let is_fill = |item: &&Item| matches!(item.kind, ItemKind::Block | ItemKind::Fill);
+        for item in self.data.iter().filter(is_fill) {
+            render_item(&mut canvas, item);
+        }
+        for item in self.data.iter().filter(|item| !is_fill(item)) {
+            render_item(&mut canvas, item);
+        }
+
+        canvas.into_string()
+    }
+}
@@ -0,0 +1,151 @@
+//! Color themes for boards.
+//!
+//! Puzzle solvers give the color of most items by its meaning (`ColorRole`): given clues, answers
+//! and so on. Each item records its role in its `ItemColor`, and is drawn in the color of
+//! `ColorTheme::DEFAULT` for the role unless another theme is applied. A `ColorTheme` assigns an
+//! actual color to each role; `Board::apply_color_theme` recolors a board accordingly. Colors
+//! without a role (e.g. the red and blue clues distinguishing two kinds of clues) are kept as they
+//! are.
+
+use crate::board::Board;
+use crate::svg;
+use serde::{Serialize, Serializer};
+
+/// Meaning of the color of a board item.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorRole {
+    /// Clues and other parts of the problem, drawn in black by default.
+    Given,
+    /// Clues drawn on top of `Given` fills (e.g. numbers on black cells), white by default.
+    GivenOnFill,
+    /// Deduced parts of the answer, green by default.
+    Answer,
+    /// Supplementary marks such as shaded regions and undetermined cells, light gray by default.
+    Auxiliary,
+    /// Fills hiding the items below them (e.g. lines under circled clues), in the background
+    /// color of the board.
+    Background,
+}
+
+/// Color of a board item: a CSS color, together with its role if it has one.
+///
+/// Colors with a role are made from the role (`ColorRole::Given.into()`), which gives them the
+/// color of `ColorTheme::DEFAULT`; other colors are made from the CSS color (`"#ff0000".into()`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ItemColor {
+    pub css: &'static str,
+    pub role: Option<ColorRole>,
+}
+
+impl From<ColorRole> for ItemColor {
+    fn from(role: ColorRole) -> ItemColor {
+        ItemColor {
+            css: ColorTheme::DEFAULT.color(role),
+            role: Some(role),
+        }
+    }
+}
+
+impl From<&'static str> for ItemColor {
+    fn from(css: &'static str) -> ItemColor {
+        ItemColor { css, role: None }
+    }
+}
+
+impl Serialize for ItemColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.css)
+    }
+}
+
+/// Colors for each `ColorRole`, together with the colors of the board itself.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ColorTheme {
+    pub name: &'static str,
+    pub given: &'static str,
+    pub given_on_fill: &'static str,
+    pub answer: &'static str,
+    pub auxiliary: &'static str,
+    pub background: &'static str,
+    pub grid: &'static str,
+}
+
+impl ColorTheme {
+    /// The colors used by the puzzle solvers.
+    pub const DEFAULT: ColorTheme = ColorTheme {
+        name: "default",
+        given: "black",
+        given_on_fill: "white",
+        answer: "green",
+        auxiliary: "#cccccc",
+        background: "white",
+        grid: "#999999",
+    };
+
+    /// Same as `DEFAULT`, but answers are drawn in blue, which is distinguishable from black and
+    /// gray for all common types of color vision deficiency.
+    pub const COLORBLIND_SAFE: ColorTheme = ColorTheme {
+        name: "colorblind",
+        answer: "#0072b2",
+        ..ColorTheme::DEFAULT
+    };
+
+    /// Light items on a dark background.
+    pub const DARK: ColorTheme = ColorTheme {
+        name: "dark",
+        given: "#e0e0e0",
+        given_on_fill: "#202020",
+        answer: "#66bb6a",
+        auxiliary: "#555555",
+        background: "#1e1e1e",
+        grid: "#666666",
+    };
+
+    pub const ALL: [ColorTheme; 3] = [
+        ColorTheme::DEFAULT,
+        ColorTheme::COLORBLIND_SAFE,
+        ColorTheme::DARK,
+    ];
+
+    /// Looks up a theme by its name.
+    pub fn find(name: &str) -> Option<ColorTheme> {
+        ColorTheme::ALL.into_iter().find(|theme| theme.name == name)
+    }
+
+    pub const fn color(&self, role: ColorRole) -> &'static str {
+        match role {
+            ColorRole::Given => self.given,
+            ColorRole::GivenOnFill => self.given_on_fill,
+            ColorRole::Answer => self.answer,
+            ColorRole::Auxiliary => self.auxiliary,
+            ColorRole::Background => self.background,
+        }
+    }
+
+    /// SVG rendering parameters matching this theme.
+    pub fn svg_theme(&self) -> svg::Theme {
+        svg::Theme {
+            background: self.background,
+            grid_color: self.grid,
+            frame_color: self.given,
+            ..svg::Theme::default()
+        }
+    }
+}
+
+impl Default for ColorTheme {
+    fn default() -> Self {
+        ColorTheme::DEFAULT
+    }
+}
+
+impl Board {
+    /// Recolors the items of this board with a color role according to `theme`.
+    pub fn apply_color_theme(&mut self, theme: &ColorTheme) {
+        for item in &mut self.data {
+            if let Some(role) = item.color.role {
+                item.color.css = theme.color(role);
+            }
+        }
+    }
+}
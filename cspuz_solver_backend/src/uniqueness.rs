@@ -1,5 +1,6 @@
 use cspuz_rs::graph::{GridEdges, InnerGridEdges};
 
+#[derive(Clone, Copy)]
 pub enum Uniqueness {
     Unique,
     NonUnique,
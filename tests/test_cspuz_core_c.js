@@ -0,0 +1,48 @@
+(async () => {
+  const assert = await import("assert");
+  const m = await import("./cspuz_core_c.mjs");
+  const module = await m.default();
+
+  // Values of `CspuzComparison`
+  const EQ = 0, NE = 1, GE = 4;
+
+  const withArray = (values, f) => {
+    const buf = module._malloc(values.length * 4);
+    module.HEAP32.set(values, buf / 4);
+    const ret = f(buf);
+    module._free(buf);
+    return ret;
+  };
+  const addLinear = (solver, coefs, vars, op, rhs) =>
+    withArray(coefs, (coefsBuf) =>
+      withArray(vars, (varsBuf) =>
+        module._cspuz_solver_add_linear(solver, coefsBuf, varsBuf, vars.length, op, rhs)));
+  const addClause = (solver, lits) =>
+    withArray(lits, (buf) => module._cspuz_solver_add_clause(solver, buf, lits.length));
+
+  const solver = module._cspuz_solver_new();
+  const x = module._cspuz_solver_add_int_var(solver, 0, 5);
+  const y = module._cspuz_solver_add_int_var(solver, 0, 5);
+  const a = module._cspuz_solver_add_bool_var(solver);
+  const b = module._cspuz_solver_add_bool_var(solver);
+  assert.deepStrictEqual([x, y, a, b], [1, 2, 1, 2]);
+  assert.strictEqual(module._cspuz_solver_add_int_var(solver, 3, 2), 0);
+
+  // x + y == 7, x - y >= 3, !a, a || b
+  assert.strictEqual(addLinear(solver, [1, 1], [x, y], EQ, 7), 1);
+  assert.strictEqual(addLinear(solver, [1, -1], [x, y], GE, 3), 1);
+  assert.strictEqual(addClause(solver, [-a]), 1);
+  assert.strictEqual(addClause(solver, [a, b]), 1);
+  assert.strictEqual(addClause(solver, [a, 3]), 0);
+
+  assert.strictEqual(module._cspuz_solver_solve(solver), 1);
+  assert.strictEqual(module._cspuz_solver_get_int(solver, x), 5);
+  assert.strictEqual(module._cspuz_solver_get_int(solver, y), 2);
+  assert.strictEqual(module._cspuz_solver_get_bool(solver, a), 0);
+  assert.strictEqual(module._cspuz_solver_get_bool(solver, b), 1);
+
+  assert.strictEqual(addLinear(solver, [1], [y], NE, 2), 1);
+  assert.strictEqual(module._cspuz_solver_solve(solver), 0);
+
+  module._cspuz_solver_free(solver);
+})();
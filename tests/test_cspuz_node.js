@@ -0,0 +1,48 @@
+const assert = require("assert");
+const cspuz = require("./cspuz_node.node");
+
+(async () => {
+  // Backend
+  const board = cspuz.solve("https://puzz.link/p?nurikabe/6/6/m8n8i9u");
+  assert.strictEqual(board.kind, "grid");
+  assert.strictEqual(board.height, 6);
+  assert.deepStrictEqual(await cspuz.solveAsync("https://puzz.link/p?nurikabe/6/6/m8n8i9u"), board);
+  assert.throws(() => cspuz.solve("https://puzz.link/p?unknown/6/6"), /unknown puzzle type/);
+  await assert.rejects(cspuz.solveAsync("foo"), Error);
+
+  assert.strictEqual(cspuz.checkUniqueness("https://puzz.link/p?nurikabe/6/6/m8n8i9u").isUnique, false);
+  assert.ok(cspuz.listPuzzleKinds().some((k) => k.kind === "slither"));
+
+  const results = await cspuz.verifyUrls(
+    ["https://puzz.link/p?slither/4/4/dgdh2c71", "https://puzz.link/p?nurikabe/6/6/m8n8i9u", "foo"],
+    { threads: 2 });
+  assert.deepStrictEqual(results.map((r) => r.status), ["unique", "nonUnique", "error"]);
+  assert.strictEqual(typeof results[2].error, "string");
+
+  // CSP solver
+  const solver = new cspuz.Solver();
+  const x = solver.addIntVar(0, 5);
+  const y = solver.addIntVar(0, 5);
+  const a = solver.addBoolVar();
+  const b = solver.addBoolVar();
+  assert.deepStrictEqual([x, y, a, b], [1, 2, 1, 2]);
+  assert.throws(() => solver.addIntVar(3, 2));
+
+  // x + y == 7, x - y >= 3, !a, a || b
+  solver.addLinear([1, 1], [x, y], "==", 7);
+  solver.addLinear([1, -1], [x, y], ">=", 3);
+  solver.addClause([-a]);
+  solver.addClause([a, b]);
+  assert.throws(() => solver.addClause([a, 3]), /unknown boolean variable/);
+  assert.throws(() => solver.addLinear([1], [x], "=", 0), /unknown operator/);
+
+  assert.strictEqual(solver.solve(), true);
+  assert.strictEqual(solver.getInt(x), 5);
+  assert.strictEqual(solver.getInt(y), 2);
+  assert.strictEqual(solver.getBool(a), false);
+  assert.strictEqual(solver.getBool(b), true);
+
+  solver.addLinear([1], [y], "!=", 2);
+  assert.strictEqual(solver.solve(), false);
+  assert.throws(() => solver.getInt(x), /no solution/);
+})();
@@ -8,6 +8,11 @@
   const buf = module._malloc(urlEncoded.length);
   module.HEAPU8.set(urlEncoded, buf);
 
+  // The expectations below are those of frontends which only understand version 1 of the board
+  // schema, so that newer backends keep working with them
+  assert.strictEqual(module._set_schema_version(99), 0);
+  assert.strictEqual(module._set_schema_version(1), 1);
+
   const ans = module._solve_problem(buf, urlEncoded.length);
   module._free(buf);
 
@@ -15,8 +20,88 @@
   const actualStr = new TextDecoder().decode(module.HEAPU8.slice(ans + 4, ans + 4 + length));
   const actual = JSON.parse(actualStr);
 
-  const expectedStr = '{"status":"ok","description":{"kind":"grid","height":6,"width":6,"defaultStyle":"grid","data":[{"y":1,"x":1,"color":"green","item":"dot"},{"y":1,"x":3,"color":"green","item":"dot"},{"y":1,"x":7,"color":"green","item":"dot"},{"y":1,"x":9,"color":"green","item":"dot"},{"y":3,"x":3,"color":"black","item":{"kind":"text","data":"8"}},{"y":5,"x":3,"color":"green","item":"block"},{"y":5,"x":5,"color":"green","item":"block"},{"y":5,"x":7,"color":"green","item":"block"},{"y":5,"x":9,"color":"black","item":{"kind":"text","data":"8"}},{"y":5,"x":11,"color":"green","item":"dot"},{"y":7,"x":1,"color":"green","item":"dot"},{"y":7,"x":5,"color":"black","item":{"kind":"text","data":"9"}},{"y":7,"x":7,"color":"green","item":"block"},{"y":9,"x":1,"color":"green","item":"dot"},{"y":9,"x":5,"color":"green","item":"dot"},{"y":9,"x":9,"color":"green","item":"dot"},{"y":9,"x":11,"color":"green","item":"dot"},{"y":11,"x":1,"color":"green","item":"dot"},{"y":11,"x":5,"color":"green","item":"dot"},{"y":11,"x":9,"color":"green","item":"dot"},{"y":11,"x":11,"color":"green","item":"dot"}],"isUnique":false}}';
+  const expectedStr = '{"status":"ok","description":{"version":1,"kind":"grid","height":6,"width":6,"defaultStyle":"grid","data":[{"y":1,"x":1,"color":"green","item":"dot"},{"y":1,"x":3,"color":"green","item":"dot"},{"y":1,"x":5,"color":"#cccccc","item":"undetermined"},{"y":1,"x":7,"color":"green","item":"dot"},{"y":1,"x":9,"color":"green","item":"dot"},{"y":1,"x":11,"color":"#cccccc","item":"undetermined"},{"y":3,"x":1,"color":"#cccccc","item":"undetermined"},{"y":3,"x":3,"color":"black","item":{"kind":"text","data":"8"}},{"y":3,"x":5,"color":"#cccccc","item":"undetermined"},{"y":3,"x":7,"color":"#cccccc","item":"undetermined"},{"y":3,"x":9,"color":"#cccccc","item":"undetermined"},{"y":3,"x":11,"color":"#cccccc","item":"undetermined"},{"y":5,"x":1,"color":"#cccccc","item":"undetermined"},{"y":5,"x":3,"color":"green","item":"block"},{"y":5,"x":5,"color":"green","item":"block"},{"y":5,"x":7,"color":"green","item":"block"},{"y":5,"x":9,"color":"black","item":{"kind":"text","data":"8"}},{"y":5,"x":11,"color":"green","item":"dot"},{"y":7,"x":1,"color":"green","item":"dot"},{"y":7,"x":3,"color":"#cccccc","item":"undetermined"},{"y":7,"x":5,"color":"black","item":{"kind":"text","data":"9"}},{"y":7,"x":7,"color":"green","item":"block"},{"y":7,"x":9,"color":"#cccccc","item":"undetermined"},{"y":7,"x":11,"color":"#cccccc","item":"undetermined"},{"y":9,"x":1,"color":"green","item":"dot"},{"y":9,"x":3,"color":"#cccccc","item":"undetermined"},{"y":9,"x":5,"color":"green","item":"dot"},{"y":9,"x":7,"color":"#cccccc","item":"undetermined"},{"y":9,"x":9,"color":"green","item":"dot"},{"y":9,"x":11,"color":"green","item":"dot"},{"y":11,"x":1,"color":"green","item":"dot"},{"y":11,"x":3,"color":"#cccccc","item":"undetermined"},{"y":11,"x":5,"color":"green","item":"dot"},{"y":11,"x":7,"color":"#cccccc","item":"undetermined"},{"y":11,"x":9,"color":"green","item":"dot"},{"y":11,"x":11,"color":"green","item":"dot"}],"isUnique":false}}';
   const expected = JSON.parse(expectedStr);
 
+  const stats = actual.stats;
+  delete actual.stats;
   assert.deepStrictEqual(actual, expected);
+  assert.deepStrictEqual(Object.keys(stats).sort(), ["clauses", "conflicts", "variables", "wallTimeMs"]);
+  assert.ok(stats.variables > 0 && stats.clauses > 0);
+
+  const explainBuf = module._malloc(urlEncoded.length);
+  module.HEAPU8.set(urlEncoded, explainBuf);
+  const explainPtr = module._explain_problem(explainBuf, urlEncoded.length);
+  module._free(explainBuf);
+  const explainLength = module.HEAPU8[explainPtr] | (module.HEAPU8[explainPtr + 1] << 8) | (module.HEAPU8[explainPtr + 2] << 16) | (module.HEAPU8[explainPtr + 3] << 24);
+  const explanation = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(explainPtr + 4, explainPtr + 4 + explainLength)));
+
+  assert.strictEqual(explanation.status, "ok");
+  const steps = explanation.description.steps;
+  assert.ok(steps.length >= 1);
+  assert.deepStrictEqual(steps[steps.length - 1], expected.description);
+  steps.slice(0, -1).forEach((step) => assert.strictEqual(step.isPartial, true));
+
+  const uniquenessBuf = module._malloc(urlEncoded.length);
+  module.HEAPU8.set(urlEncoded, uniquenessBuf);
+  const uniquenessPtr = module._check_uniqueness(uniquenessBuf, urlEncoded.length);
+  module._free(uniquenessBuf);
+  const uniquenessLength = module.HEAPU8[uniquenessPtr] | (module.HEAPU8[uniquenessPtr + 1] << 8) | (module.HEAPU8[uniquenessPtr + 2] << 16) | (module.HEAPU8[uniquenessPtr + 3] << 24);
+  const uniqueness = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(uniquenessPtr + 4, uniquenessPtr + 4 + uniquenessLength)));
+
+  assert.strictEqual(uniqueness.status, "ok");
+  assert.strictEqual(uniqueness.description.isUnique, false);
+  assert.notDeepStrictEqual(uniqueness.description.answer, uniqueness.description.secondAnswer);
+  assert.ok(uniqueness.description.difference.data.some((item) => item.diff === "missing"));
+
+  const themeName = new TextEncoder().encode("dark");
+  const themeBuf = module._malloc(urlEncoded.length + themeName.length);
+  module.HEAPU8.set(urlEncoded, themeBuf);
+  module.HEAPU8.set(themeName, themeBuf + urlEncoded.length);
+  const themedPtr = module._solve_problem_with_theme(themeBuf, urlEncoded.length, themeBuf + urlEncoded.length, themeName.length);
+  module._free(themeBuf);
+  const themedLength = module.HEAPU8[themedPtr] | (module.HEAPU8[themedPtr + 1] << 8) | (module.HEAPU8[themedPtr + 2] << 16) | (module.HEAPU8[themedPtr + 3] << 24);
+  const themed = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(themedPtr + 4, themedPtr + 4 + themedLength)));
+
+  assert.strictEqual(themed.status, "ok");
+  assert.strictEqual(themed.description.data.length, expected.description.data.length);
+  assert.ok(themed.description.data.every((item) => item.color !== "green" && item.color !== "black"));
+
+  const badUrl = new TextEncoder().encode("https://puzz.link/p?unknownpuzzle/3/3/");
+  const badBuf = module._malloc(badUrl.length);
+  module.HEAPU8.set(badUrl, badBuf);
+  const errorPtr = module._solve_problem(badBuf, badUrl.length);
+  module._free(badBuf);
+  const errorLength = module.HEAPU8[errorPtr] | (module.HEAPU8[errorPtr + 1] << 8) | (module.HEAPU8[errorPtr + 2] << 16) | (module.HEAPU8[errorPtr + 3] << 24);
+  const errorResponse = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(errorPtr + 4, errorPtr + 4 + errorLength)));
+
+  assert.strictEqual(errorResponse.status, "error");
+  assert.strictEqual(errorResponse.description, "unknown puzzle type");
+  assert.deepStrictEqual(
+    errorResponse.error,
+    {"kind":"unknownPuzzleKind","message":"unknown puzzle type","userError":true,"puzzleKind":"unknownpuzzle"},
+  );
+
+  const kindsPtr = module._list_puzzle_kinds();
+  const kindsLength = module.HEAPU8[kindsPtr] | (module.HEAPU8[kindsPtr + 1] << 8) | (module.HEAPU8[kindsPtr + 2] << 16) | (module.HEAPU8[kindsPtr + 3] << 24);
+  const kinds = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(kindsPtr + 4, kindsPtr + 4 + kindsLength)));
+
+  assert.strictEqual(kinds.status, "ok");
+  assert.deepStrictEqual(
+    kinds.description.find((k) => k.kind === "nurikabe"),
+    {"kind":"nurikabe","aliases":[],"name":"Nurikabe","urlFormat":"puzz.link","enumerable":true,"generatable":false,"answerExportable":true},
+  );
+
+  assert.strictEqual(module._set_schema_version(2), 1);
+  const latestBuf = module._malloc(urlEncoded.length);
+  module.HEAPU8.set(urlEncoded, latestBuf);
+  const latestPtr = module._solve_problem(latestBuf, urlEncoded.length);
+  module._free(latestBuf);
+  const latestLength = module.HEAPU8[latestPtr] | (module.HEAPU8[latestPtr + 1] << 8) | (module.HEAPU8[latestPtr + 2] << 16) | (module.HEAPU8[latestPtr + 3] << 24);
+  const latest = JSON.parse(new TextDecoder().decode(module.HEAPU8.slice(latestPtr + 4, latestPtr + 4 + latestLength)));
+
+  assert.strictEqual(latest.description.version, 2);
+  assert.strictEqual(latest.description.answer.shaded.kind, "cells");
+  assert.strictEqual(latest.description.answer.shaded.data[2][1], true);
+  assert.deepStrictEqual(latest.description.data, expected.description.data);
 })();